@@ -0,0 +1,360 @@
+use network::Endpoint;
+use protos::ledger::Ledger;
+use std::{
+    cmp::min,
+    collections::{BTreeMap, HashMap, VecDeque},
+    time::{Duration, Instant},
+};
+
+pub struct BlockRequest {
+    pub peer: Endpoint,
+    pub begin: u64,
+    pub end: u64,
+    sent: Instant,
+}
+
+/// Splits the heights above the last executed block into ranges requested from several
+/// peers at once, and holds the blocks arriving out of order until they are next to execute.
+pub struct BlockDownloader {
+    // height of the next block to execute
+    next_execute: u64,
+    // heights from here on were never requested
+    next_request: u64,
+    // ranges to request again, after a timeout, a short response or an invalid block
+    retry: VecDeque<(u64, u64)>,
+    inflight: HashMap<i64, BlockRequest>,
+    received: BTreeMap<u64, (Endpoint, Ledger)>,
+    next_id: i64,
+}
+
+impl Default for BlockDownloader {
+    fn default() -> Self {
+        Self {
+            next_execute: 0,
+            next_request: 0,
+            retry: VecDeque::new(),
+            inflight: HashMap::new(),
+            received: BTreeMap::new(),
+            next_id: 1,
+        }
+    }
+}
+
+impl BlockDownloader {
+    /// Follow the last executed height, which consensus moves too.
+    pub fn sync_height(&mut self, height: u64) {
+        let next = height + 1;
+        if next > self.next_execute {
+            self.next_execute = next;
+            self.received = self.received.split_off(&next);
+            self.retry = self
+                .retry
+                .iter()
+                .filter(|(_, end)| *end >= next)
+                .map(|(begin, end)| ((*begin).max(next), *end))
+                .collect();
+        }
+        if self.next_request < next {
+            self.next_request = next;
+        }
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.inflight.is_empty() && self.received.is_empty()
+    }
+
+    pub fn inflight_count(&self) -> usize {
+        self.inflight.len()
+    }
+
+    pub fn is_busy(&self, peer: &Endpoint) -> bool {
+        self.inflight.values().any(|req| req.peer == *peer)
+    }
+
+    /// The next range for a peer at `peer_height`, at most `batch` blocks and within
+    /// `window` blocks of the next one to execute. Ranges to retry go first.
+    pub fn next_range(&mut self, peer_height: u64, batch: u64, window: u64) -> Option<(u64, u64)> {
+        let batch = batch.max(1);
+        if let Some(pos) = self
+            .retry
+            .iter()
+            .position(|(begin, _)| *begin <= peer_height)
+        {
+            let (begin, end) = self.retry.remove(pos)?;
+            let last = min(min(end, peer_height), begin + batch - 1);
+            if last < end {
+                self.retry.push_front((last + 1, end));
+            }
+            return Some((begin, last));
+        }
+
+        let limit = self.next_execute + window.max(1) - 1;
+        let begin = self.next_request;
+        if begin > peer_height || begin > limit {
+            return None;
+        }
+        let end = min(min(begin + batch - 1, peer_height), limit);
+        self.next_request = end + 1;
+        Some((begin, end))
+    }
+
+    pub fn start(&mut self, peer: Endpoint, begin: u64, end: u64) -> i64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.inflight.insert(
+            id,
+            BlockRequest {
+                peer,
+                begin,
+                end,
+                sent: Instant::now(),
+            },
+        );
+        id
+    }
+
+    /// Give up a request, its range is requested again.
+    pub fn cancel(&mut self, id: i64) {
+        if let Some(req) = self.inflight.remove(&id) {
+            self.requeue(req.begin, req.end);
+        }
+    }
+
+    /// Cancel the requests older than `timeout`, returning the peers that did not answer.
+    pub fn expire(&mut self, timeout: Duration) -> Vec<Endpoint> {
+        let expired: Vec<i64> = self
+            .inflight
+            .iter()
+            .filter(|(_, req)| req.sent.elapsed() > timeout)
+            .map(|(id, _)| *id)
+            .collect();
+        expired
+            .into_iter()
+            .filter_map(|id| {
+                let peer = self.inflight.get(&id).map(|req| req.peer);
+                self.cancel(id);
+                peer
+            })
+            .collect()
+    }
+
+    /// Take the blocks answering request `id`, false when `peer` has no such request. Blocks
+    /// are kept up to the first one out of sequence, the rest of the range is requested again.
+    pub fn receive(&mut self, peer: Endpoint, id: i64, blocks: &[Ledger]) -> bool {
+        if self.inflight.get(&id).map(|req| req.peer) != Some(peer) {
+            return false;
+        }
+        let req = self.inflight.remove(&id).unwrap();
+        let mut expected = req.begin;
+        for block in blocks {
+            let height = block.get_header().get_height();
+            if height != expected || height > req.end {
+                break;
+            }
+            if height >= self.next_execute {
+                self.received.insert(height, (peer, block.clone()));
+            }
+            expected += 1;
+        }
+        if expected <= req.end {
+            self.requeue(expected, req.end);
+        }
+        true
+    }
+
+    /// The block to execute next, with the peer it came from, once it arrived.
+    pub fn pop_next(&mut self) -> Option<(Endpoint, Ledger)> {
+        self.received.remove(&self.next_execute)
+    }
+
+    /// Request a height again after its block failed to execute, along with the blocks above
+    /// it from the same peer.
+    pub fn reject(&mut self, peer: Endpoint, height: u64) {
+        let heights: Vec<u64> = self
+            .received
+            .iter()
+            .filter(|(_, (from, _))| *from == peer)
+            .map(|(height, _)| *height)
+            .collect();
+        for other in heights {
+            self.received.remove(&other);
+            self.requeue(other, other);
+        }
+        self.requeue(height, height);
+    }
+
+    fn requeue(&mut self, begin: u64, end: u64) {
+        let begin = begin.max(self.next_execute);
+        if begin <= end {
+            self.retry.push_front((begin, end));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn peer() -> Endpoint {
+        network::Node::generate_listen_endpoint("127.0.0.1:0")
+    }
+
+    fn blocks(heights: &[u64]) -> Vec<Ledger> {
+        heights
+            .iter()
+            .map(|height| {
+                let mut block = Ledger::new();
+                block.mut_header().set_height(*height);
+                block
+            })
+            .collect()
+    }
+
+    fn popped(downloader: &mut BlockDownloader) -> Vec<(Endpoint, u64)> {
+        let mut popped = Vec::new();
+        while let Some((peer, block)) = downloader.pop_next() {
+            popped.push((peer, block.get_header().get_height()));
+            downloader.sync_height(block.get_header().get_height());
+        }
+        popped
+    }
+
+    #[test]
+    fn test_next_range_window() {
+        let mut downloader = BlockDownloader::default();
+        downloader.sync_height(10);
+        assert_eq!(downloader.next_range(100, 8, 20), Some((11, 18)));
+        assert_eq!(downloader.next_range(100, 8, 20), Some((19, 26)));
+        assert_eq!(downloader.next_range(100, 8, 20), Some((27, 30)));
+        assert_eq!(downloader.next_range(100, 8, 20), None);
+
+        downloader.sync_height(14);
+        assert_eq!(downloader.next_range(32, 8, 20), Some((31, 32)));
+        assert_eq!(downloader.next_range(32, 8, 20), None);
+    }
+
+    #[test]
+    fn test_retry_first() {
+        let mut downloader = BlockDownloader::default();
+        downloader.sync_height(0);
+        let (begin, end) = downloader.next_range(100, 10, 100).unwrap();
+        downloader.requeue(begin, end);
+        assert_eq!(downloader.next_range(5, 10, 100), Some((1, 5)));
+        assert_eq!(downloader.next_range(100, 10, 100), Some((6, 10)));
+        assert_eq!(downloader.next_range(100, 10, 100), Some((11, 20)));
+    }
+
+    #[test]
+    fn test_receive_out_of_order() {
+        let (a, b) = (peer(), peer());
+        let mut downloader = BlockDownloader::default();
+        downloader.sync_height(0);
+        let first = downloader.start(a, 1, 2);
+        let second = downloader.start(b, 3, 4);
+
+        // only the peer asked may answer
+        assert!(!downloader.receive(a, second, &blocks(&[3, 4])));
+        assert!(downloader.receive(b, second, &blocks(&[3, 4])));
+        assert!(downloader.pop_next().is_none());
+        assert!(!downloader.receive(b, second, &blocks(&[3, 4])));
+
+        assert!(downloader.receive(a, first, &blocks(&[1, 2])));
+        assert_eq!(
+            popped(&mut downloader),
+            vec![(a, 1), (a, 2), (b, 3), (b, 4)]
+        );
+        assert!(downloader.is_idle());
+    }
+
+    #[test]
+    fn test_receive_requeues_the_rest() {
+        let (a, b) = (peer(), peer());
+        let mut downloader = BlockDownloader::default();
+        downloader.sync_height(0);
+        assert_eq!(downloader.next_range(100, 4, 100), Some((1, 4)));
+        assert_eq!(downloader.next_range(100, 4, 100), Some((5, 8)));
+
+        // a short response
+        let id = downloader.start(a, 1, 4);
+        assert!(downloader.receive(a, id, &blocks(&[1, 2])));
+        assert_eq!(downloader.next_range(100, 4, 100), Some((3, 4)));
+
+        // a block out of sequence, the ones after it are dropped too
+        let id = downloader.start(a, 5, 8);
+        assert!(downloader.receive(a, id, &blocks(&[5, 7, 8])));
+        assert_eq!(downloader.next_range(100, 4, 100), Some((6, 8)));
+        assert_eq!(downloader.next_range(100, 4, 100), Some((9, 12)));
+
+        // as is a block beyond the range, the one a sent stays
+        let id = downloader.start(b, 3, 4);
+        assert!(downloader.receive(b, id, &blocks(&[3, 4, 5])));
+        assert_eq!(
+            popped(&mut downloader),
+            vec![(a, 1), (a, 2), (b, 3), (b, 4), (a, 5)]
+        );
+    }
+
+    #[test]
+    fn test_reject_drops_the_peer_blocks() {
+        let (a, b) = (peer(), peer());
+        let mut downloader = BlockDownloader::default();
+        downloader.sync_height(0);
+        assert_eq!(downloader.next_range(100, 3, 100), Some((1, 3)));
+        assert_eq!(downloader.next_range(100, 3, 100), Some((4, 6)));
+        let id = downloader.start(a, 1, 3);
+        assert!(downloader.receive(a, id, &blocks(&[1, 2, 3])));
+        let id = downloader.start(b, 4, 6);
+        assert!(downloader.receive(b, id, &blocks(&[4, 5, 6])));
+
+        // block 1 fails to execute, nothing else a sent is trusted
+        let (from, block) = downloader.pop_next().unwrap();
+        assert_eq!((from, block.get_header().get_height()), (a, 1));
+        downloader.reject(a, 1);
+        assert!(downloader.pop_next().is_none());
+
+        let mut retried: Vec<_> = (0..3)
+            .filter_map(|_| downloader.next_range(100, 3, 100))
+            .collect();
+        retried.sort_unstable();
+        assert_eq!(retried, vec![(1, 1), (2, 2), (3, 3)]);
+        assert_eq!(downloader.next_range(100, 3, 100), Some((7, 9)));
+
+        let id = downloader.start(b, 1, 3);
+        assert!(downloader.receive(b, id, &blocks(&[1, 2, 3])));
+        let heights: Vec<_> = popped(&mut downloader)
+            .into_iter()
+            .map(|(from, height)| {
+                assert_eq!(from, b);
+                height
+            })
+            .collect();
+        assert_eq!(heights, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_expire_hands_the_range_over() {
+        let (a, b) = (peer(), peer());
+        let mut downloader = BlockDownloader::default();
+        downloader.sync_height(0);
+        assert_eq!(downloader.next_range(100, 4, 100), Some((1, 4)));
+        let slow = downloader.start(a, 1, 4);
+        assert!(downloader.expire(Duration::from_secs(60)).is_empty());
+
+        std::thread::sleep(Duration::from_millis(10));
+        assert_eq!(downloader.expire(Duration::from_millis(1)), vec![a]);
+        assert!(!downloader.is_busy(&a));
+        assert_eq!(downloader.inflight_count(), 0);
+
+        assert_eq!(downloader.next_range(100, 4, 100), Some((1, 4)));
+        let id = downloader.start(b, 1, 4);
+        assert!(downloader.is_busy(&b));
+
+        // the late answer of the expired request is not taken
+        assert!(!downloader.receive(a, slow, &blocks(&[1, 2, 3, 4])));
+        assert!(downloader.receive(b, id, &blocks(&[1, 2, 3, 4])));
+        assert_eq!(
+            popped(&mut downloader),
+            vec![(b, 1), (b, 2), (b, 3), (b, 4)]
+        );
+    }
+}
@@ -153,7 +153,7 @@ impl PeerInfo{
         self.score = i64::max(self.score - ERROR_RESPONSE_DELTA, IGNORE_PEER_THRESHOLD);
     }
 
-    fn ignored(&self) -> bool{
+    pub fn ignored(&self) -> bool{
         self.score.le(&IGNORE_PEER_THRESHOLD)
     }
 
@@ -1,23 +1,41 @@
+use crate::block_download::BlockDownloader;
 use crate::catchup_status::{CatchupStatus, Peers};
+use crate::light::{light_headers, light_response, LIGHT_MAX_HEADERS};
 use crate::network::CatchupNetworkInterace;
 use crate::notification::{
-    BlocksReceiver, BroadcastSender, ChainStatusReceiver, CommitBlockSender,
-    TimerNotificationReceiver,
+    BlocksReceiver, BroadcastSender, ChainStatusReceiver, CommitBlockSender, LightReceiver,
+    SnapshotReceiver, TimerNotificationReceiver,
+};
+use crate::snapshot_sync::{
+    snapshot_request, snapshot_response, verify_snapshot_proof, SnapshotSync,
+    SNAPSHOT_EXPORT_INTERVAL, SNAPSHOT_SYNC_MAX_IDLE_TICKS, SNAPSHOT_SYNC_MIN_BLOCKS,
 };
 use crate::storage_executor::StorageExecutorInterface;
 
+use configure::CONFIGURE_INSTANCE_REF;
 use ledger_store::LedgerStorage;
 use network::{Endpoint, LocalBusSubscriber, ReturnableProtocolsMessage};
 use protos::ledger::Ledger;
 use protos::{
     common::{ProtocolsActionMessageType, ProtocolsMessage, ProtocolsMessageType},
     ledger::{
-        SyncBlockRequest, SyncBlockResponse, SyncChain, SyncChainStatus, TransactionSignBrodcast,
+        LightHeaderRequest, LightHeaderResponse, SyncBlockRequest, SyncBlockResponse, SyncChain,
+        SyncChainStatus, SyncSnapshotRequest, SyncSnapshotResponse, TransactionSignBrodcast,
+    },
+};
+use state_store::snapshot::{Snapshot, SnapshotImporter};
+use std::{
+    cmp::min,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
     },
+    time::Duration,
 };
 use types::SignedTransaction;
 use utils::{
-    general::self_chain_id,
+    general::{self_chain_id, GENESIS_HEIGHT},
     parse::ProtocolParser,
     timer_manager::{TimerEventType, TimerManager, TimterEventParam},
 };
@@ -35,7 +53,14 @@ pub struct Catchuper<S, N> {
     txns_notify: BroadcastSender,
     status_notify: ChainStatusReceiver,
     blocks_notify: BlocksReceiver,
+    snapshot_notify: SnapshotReceiver,
+    light_notify: LightReceiver,
     commit_sender: CommitBlockSender,
+    snapshot_sync: Option<SnapshotSync>,
+    // set once the node is past genesis, it never syncs a snapshot after
+    snapshot_done: bool,
+    snapshot_exporting: Arc<AtomicBool>,
+    downloader: BlockDownloader,
 }
 
 impl<S, N> Catchuper<S, N>
@@ -57,6 +82,8 @@ where
         );
         let (chain_status_sender, chain_status_receiverer) = unbounded();
         let (blocks_sender, blocks_receiver) = unbounded();
+        let (snapshot_sender, snapshot_receiver) = unbounded();
+        let (light_sender, light_receiver) = unbounded();
         let (commit_sender, commit_receiver) = unbounded();
         let mut catchup = Catchuper::new(
             network,
@@ -65,6 +92,8 @@ where
             txns_notify,
             chain_status_receiverer,
             blocks_receiver,
+            snapshot_receiver,
+            light_receiver,
             commit_sender,
         );
 
@@ -80,6 +109,12 @@ where
         catchup
             .network
             .register_sync_handler(ProtocolsMessageType::SYNCBLOCK, blocks_sender);
+        catchup
+            .network
+            .register_sync_handler(ProtocolsMessageType::SYNCSNAPSHOT, snapshot_sender);
+        catchup
+            .network
+            .register_sync_handler(ProtocolsMessageType::LIGHT, light_sender);
 
         let _ = std::thread::spawn(move || loop {
             catchup.start(subscriber.clone());
@@ -94,6 +129,8 @@ where
         txns_notify: BroadcastSender,
         status_notify: ChainStatusReceiver,
         blocks_notify: BlocksReceiver,
+        snapshot_notify: SnapshotReceiver,
+        light_notify: LightReceiver,
         commit_sender: CommitBlockSender,
     ) -> Self {
         Self {
@@ -105,7 +142,13 @@ where
             txns_notify,
             status_notify,
             blocks_notify,
+            snapshot_notify,
+            light_notify,
             commit_sender,
+            snapshot_sync: None,
+            snapshot_done: false,
+            snapshot_exporting: Arc::new(AtomicBool::new(false)),
+            downloader: BlockDownloader::default(),
         }
     }
 
@@ -123,6 +166,12 @@ where
             recv(self.blocks_notify) -> msg =>{
                 self.handle_catchup_blocks(msg)
             }
+            recv(self.snapshot_notify) -> msg =>{
+                self.handle_catchup_snapshot(msg)
+            }
+            recv(self.light_notify) -> msg =>{
+                self.handle_light_headers(msg)
+            }
             recv(self.timer_notify) -> msg =>{
                 self.handle_timer(msg)
             }
@@ -195,18 +244,12 @@ where
         match msg {
             Ok(param) => match param.event_type {
                 TimerEventType::LedgerSync => {
+                    self.export_snapshot();
                     self.catchup_chain();
-                    if !self.status.is_catchuping() {
-                        self.catchup_block(None);
-                    } else {
-                        let height = match LedgerStorage::load_max_block_height() {
-                            Ok(Some(h)) => h,
-                            _ => return,
-                        };
-                        if self.status.check(height) {
-                            self.status.catchup_done()
-                        }
+                    if self.catchup_snapshot() {
+                        return;
                     }
+                    self.catchup_block();
                 }
                 _ => {}
             },
@@ -216,36 +259,77 @@ where
         }
     }
 
-    pub fn catchup_block(&mut self, peer_id: Option<Endpoint>) {
-        let active_peer = match peer_id {
-            Some(peer_id) => peer_id,
-            None => {
-                let active_peer = self.peers.select_peer();
-                match active_peer {
-                    Some((peer_point, peer)) => peer_point.clone(),
-                    None => return,
-                }
-            }
-        };
-        info!("catchup_block select peer_id:{}", active_peer.addr());
+    /// Request the missing height ranges from the peers ahead of the node, one request in
+    /// flight per peer and the best scored peers first.
+    pub fn catchup_block(&mut self) {
         let cur_height = match self.executor.get_block_height() {
             Ok(Some(h)) => h,
             Ok(None) => return,
             Err(e) => return,
         };
+        self.downloader.sync_height(cur_height);
 
-        let mut req: SyncBlockRequest = SyncBlockRequest::new();
-        req.set_chain_id(self_chain_id());
-        req.set_begin(cur_height as i64);
-        req.set_end(0);
-        req.set_requestid(0);
+        let config = &CONFIGURE_INSTANCE_REF.catchup;
+        for peer_id in self
+            .downloader
+            .expire(Duration::from_secs(config.request_timeout_secs))
+        {
+            info!("catchup_block request to {} timed out", peer_id.addr());
+            self.peers.update_score_error(peer_id);
+        }
 
-        let mut message = protos::common::ProtocolsMessage::new();
-        message.set_msg_type(protos::common::ProtocolsMessageType::SYNCBLOCK);
-        message.set_action(protos::common::ProtocolsActionMessageType::REQUEST);
-        message.set_data(req.write_to_bytes().unwrap());
+        let mut candidates: Vec<(Endpoint, u64, i64)> = self
+            .peers
+            .iter()
+            .filter(|(_, peer)| !peer.ignored() && peer.block_id() > cur_height)
+            .map(|(peer_point, peer)| (peer_point.clone(), peer.block_id(), peer.score()))
+            .collect();
+        candidates.sort_by(|x, y| y.2.cmp(&x.2));
+
+        for (peer_id, peer_height, _) in candidates {
+            if self.downloader.inflight_count() >= config.max_inflight {
+                break;
+            }
+            if self.downloader.is_busy(&peer_id) {
+                continue;
+            }
+            let (begin, end) =
+                match self
+                    .downloader
+                    .next_range(peer_height, config.batch_size, config.window)
+                {
+                    Some(range) => range,
+                    None => continue,
+                };
+            let request_id = self.downloader.start(peer_id, begin, end);
+            info!(
+                "catchup_block request {}..={} from peer_id:{}",
+                begin,
+                end,
+                peer_id.addr()
+            );
+
+            let mut req: SyncBlockRequest = SyncBlockRequest::new();
+            req.set_chain_id(self_chain_id());
+            req.set_begin(begin as i64 - 1);
+            req.set_end(end as i64);
+            req.set_requestid(request_id);
 
-        let _ = self.network.send_msg(active_peer, message);
+            let mut message = protos::common::ProtocolsMessage::new();
+            message.set_msg_type(protos::common::ProtocolsMessageType::SYNCBLOCK);
+            message.set_action(protos::common::ProtocolsActionMessageType::REQUEST);
+            message.set_data(req.write_to_bytes().unwrap());
+
+            if self.network.send_msg(peer_id, message).is_err() {
+                self.downloader.cancel(request_id);
+            }
+        }
+
+        if self.downloader.is_idle() {
+            self.status.catchup_done();
+        } else {
+            self.status.catchup_ing(cur_height);
+        }
     }
 
     fn handle_catchup_block_reqest(&self, peer_id: Endpoint, protocol_msg: &ProtocolsMessage) {
@@ -255,7 +339,7 @@ where
             Err(e) => return,
         };
 
-        if block_req.get_chain_id() != self_chain_id() {
+        if block_req.get_chain_id() != self_chain_id() || block_req.get_begin() < 0 {
             return;
         }
 
@@ -265,38 +349,45 @@ where
             Err(e) => return,
         };
 
+        // the blocks after `begin` up to `end`, 0 leaves the end to the batch size
+        let config = &CONFIGURE_INSTANCE_REF.catchup;
         let begin = block_req.get_begin() as u64;
-        //let end = block_req.get_end() as u64;
-
-        let end_rep = if last_h > begin { begin + 5 } else { begin + 1 };
+        let mut end_rep = min(begin + config.batch_size.max(1), last_h);
+        if block_req.get_end() > 0 {
+            end_rep = min(end_rep, block_req.get_end() as u64);
+        }
 
         let mut block_rep = SyncBlockResponse::new();
         let mut blocks = vec![];
+        let mut bytes = 0;
         for h in (begin + 1)..=end_rep {
             match self.executor.get_block(h) {
-                Ok(Some(v)) => blocks.push(v),
-                Ok(None) => {
-                    block_rep.set_finish(true);
-                    break;
+                Ok(Some(v)) => {
+                    bytes += v.compute_size() as u64;
+                    if !blocks.is_empty() && bytes > config.response_bytes {
+                        break;
+                    }
+                    blocks.push(v);
                 }
+                Ok(None) => break,
                 Err(e) => return,
             };
         }
         let block_len = blocks.len();
+        block_rep.set_finish(begin + block_len as u64 >= last_h);
 
-        if block_len != 0 {
-            //send Ledger
-            block_rep.set_chain_id(self_chain_id());
-            block_rep.set_number(block_len as i64);
-            block_rep.set_blocks(RepeatedField::from(blocks));
+        // an empty response lets the peer give the range to another node at once
+        block_rep.set_chain_id(self_chain_id());
+        block_rep.set_number(block_len as i64);
+        block_rep.set_requestid(block_req.get_requestid());
+        block_rep.set_blocks(RepeatedField::from(blocks));
 
-            let mut message = protos::common::ProtocolsMessage::new();
-            message.set_msg_type(protos::common::ProtocolsMessageType::SYNCBLOCK);
-            message.set_action(protos::common::ProtocolsActionMessageType::RESPONSE);
-            message.set_data(block_rep.write_to_bytes().unwrap());
+        let mut message = protos::common::ProtocolsMessage::new();
+        message.set_msg_type(protos::common::ProtocolsMessageType::SYNCBLOCK);
+        message.set_action(protos::common::ProtocolsActionMessageType::RESPONSE);
+        message.set_data(block_rep.write_to_bytes().unwrap());
 
-            let _ = self.network.send_msg(peer_id, message);
-        }
+        let _ = self.network.send_msg(peer_id, message);
     }
 
     fn handle_catchup_block_response(
@@ -308,45 +399,338 @@ where
             match ProtocolParser::deserialize(protocol_msg.get_data()) {
                 Ok(value) => value,
                 Err(e) => {
-                    self.status.catchup_done();
+                    self.peers.update_score_error(peer_id);
                     return;
                 }
             };
 
         if block_rep.get_chain_id() != self_chain_id() {
-            self.status.catchup_prepare();
             return;
         }
+        if !self
+            .downloader
+            .receive(peer_id, block_rep.get_requestid(), block_rep.get_blocks())
+        {
+            return;
+        }
+        if block_rep.get_blocks().is_empty() {
+            info!("catchup_block peer_id:{} has no blocks", peer_id.addr());
+        } else {
+            self.peers.update_score_success(peer_id);
+        }
 
-        let last_h = match self.executor.get_block_height() {
-            Ok(Some(v)) => v,
-            _ => {
-                self.status.catchup_prepare();
+        self.execute_downloaded_blocks();
+        self.catchup_block();
+    }
+
+    // execute the downloaded blocks following the last one in order
+    fn execute_downloaded_blocks(&mut self) {
+        match self.executor.get_block_height() {
+            Ok(Some(h)) => self.downloader.sync_height(h),
+            _ => return,
+        };
+        while let Some((peer_id, block)) = self.downloader.pop_next() {
+            let height = block.get_header().get_height();
+            info!("execute verify block {}", height);
+            match self.execute_verify_block(block.clone()) {
+                Ok(_) => {
+                    self.downloader.sync_height(height);
+                    let _ = self.commit_sender.send(block);
+                }
+                Err(e) => {
+                    error!(
+                        "execute verify block {} from {} error: {}",
+                        height,
+                        peer_id.addr(),
+                        e
+                    );
+                    self.peers.update_score_error(peer_id);
+                    self.downloader.reject(peer_id, height);
+                    return;
+                }
+            }
+        }
+    }
+
+    fn handle_catchup_snapshot(&mut self, msg: Result<(Endpoint, ProtocolsMessage), RecvError>) {
+        match msg {
+            Ok((peer_endpoint, proto_message)) => match proto_message.get_action() {
+                ProtocolsActionMessageType::REQUEST => {
+                    self.handle_catchup_snapshot_request(peer_endpoint, &proto_message);
+                }
+                ProtocolsActionMessageType::RESPONSE => {
+                    self.handle_catchup_snapshot_response(peer_endpoint, &proto_message);
+                }
+                _ => (),
+            },
+            Err(e) => {
+                error!("catchup snapshot receive error: {}", e);
+            }
+        }
+    }
+
+    fn handle_light_headers(&mut self, msg: Result<(Endpoint, ProtocolsMessage), RecvError>) {
+        match msg {
+            Ok((peer_endpoint, proto_message)) => match proto_message.get_action() {
+                ProtocolsActionMessageType::REQUEST => {
+                    self.handle_light_headers_request(peer_endpoint, &proto_message);
+                }
+                _ => (),
+            },
+            Err(e) => {
+                error!("catchup light receive error: {}", e);
+            }
+        }
+    }
+
+    // serve the headers and commit proofs a light node follows the chain with
+    fn handle_light_headers_request(&self, peer_id: Endpoint, protocol_msg: &ProtocolsMessage) {
+        let req: LightHeaderRequest = match ProtocolParser::deserialize(protocol_msg.get_data()) {
+            Ok(value) => value,
+            Err(e) => return,
+        };
+        if req.get_chain_id() != self_chain_id() || req.get_begin() <= GENESIS_HEIGHT {
+            return;
+        }
+
+        let end = min(
+            req.get_end(),
+            req.get_begin().saturating_add(LIGHT_MAX_HEADERS - 1),
+        );
+        let headers = match light_headers(
+            req.get_begin(),
+            end,
+            CONFIGURE_INSTANCE_REF.catchup.response_bytes,
+        ) {
+            Ok(headers) => headers,
+            Err(e) => {
+                error!("light headers from {} error: {}", req.get_begin(), e);
                 return;
             }
         };
-        self.status.catchup_ing(0);
-        let blocks = block_rep.get_blocks();
-        for (i, block) in blocks.iter().enumerate() {
-            if last_h + 1 + i as u64 == block.get_header().get_height() {
-                info!("execute verify block {}", block.get_header().get_height());
-                match self.execute_verify_block(block.to_owned()) {
-                    Ok(_) => {
-                        let _ = self.commit_sender.send(block.to_owned());
+
+        let mut resp = LightHeaderResponse::new();
+        resp.set_chain_id(self_chain_id());
+        resp.set_requestid(req.get_requestid());
+        resp.set_headers(RepeatedField::from(headers));
+        let _ = self.network.send_msg(peer_id, light_response(&resp));
+    }
+
+    // returns true while a snapshot sync holds block catch-up back
+    fn catchup_snapshot(&mut self) -> bool {
+        if !CONFIGURE_INSTANCE_REF.db.snapshot_sync || self.snapshot_done {
+            return false;
+        }
+        if let Some(sync) = self.snapshot_sync.as_mut() {
+            sync.idle_ticks += 1;
+            if sync.idle_ticks > SNAPSHOT_SYNC_MAX_IDLE_TICKS {
+                info!("snapshot sync from {} timed out", sync.peer.addr());
+                let peer = sync.peer;
+                self.peers.update_score_error(peer);
+                self.snapshot_sync = None;
+            }
+            return true;
+        }
+
+        let cur_height = match self.executor.get_block_height() {
+            Ok(Some(h)) => h,
+            _ => return false,
+        };
+        if cur_height > GENESIS_HEIGHT {
+            self.snapshot_done = true;
+            return false;
+        }
+        let peer = match self.peers.select_peer() {
+            Some((peer_point, peer))
+                if peer.block_id() >= cur_height + SNAPSHOT_SYNC_MIN_BLOCKS =>
+            {
+                peer_point.clone()
+            }
+            _ => return false,
+        };
+        info!("catchup_snapshot select peer_id:{}", peer.addr());
+        let _ = self.network.send_msg(peer, snapshot_request(0, true, 0));
+        self.snapshot_sync = Some(SnapshotSync::new(peer));
+        true
+    }
+
+    fn handle_catchup_snapshot_request(
+        &mut self,
+        peer_id: Endpoint,
+        protocol_msg: &ProtocolsMessage,
+    ) {
+        let req: SyncSnapshotRequest = match ProtocolParser::deserialize(protocol_msg.get_data()) {
+            Ok(value) => value,
+            Err(e) => return,
+        };
+        if req.get_chain_id() != self_chain_id() {
+            return;
+        }
+
+        let root = PathBuf::from(&CONFIGURE_INSTANCE_REF.db.snapshot_path);
+        let mut resp = SyncSnapshotResponse::new();
+        resp.set_chain_id(self_chain_id());
+        if req.get_manifest() {
+            // an empty response tells the peer no snapshot is ready yet
+            match Snapshot::latest(&root) {
+                Ok(Some((height, dir))) => match Snapshot::read_manifest(&dir) {
+                    Ok(manifest) => {
+                        resp.set_height(height);
+                        resp.set_manifest(manifest);
                     }
-                    Err(_) => {
-                        self.peers.update_score_error(peer_id);
-                        self.status.catchup_done();
-                        return;
+                    Err(e) => error!("read snapshot manifest {} error: {}", height, e),
+                },
+                Ok(None) => (),
+                Err(e) => error!("list snapshots error: {}", e),
+            }
+        } else {
+            let dir = root.join(req.get_height().to_string());
+            match Snapshot::read_chunk(&dir, req.get_index()) {
+                Ok(chunk) => {
+                    resp.set_height(req.get_height());
+                    resp.set_chunk(chunk);
+                }
+                Err(e) => return,
+            }
+        }
+        let _ = self.network.send_msg(peer_id, snapshot_response(&resp));
+    }
+
+    // export the state before the last block in the background when the newest snapshot is
+    // missing or too old, peers are only served snapshots exported here or by the cli
+    fn export_snapshot(&self) {
+        if !CONFIGURE_INSTANCE_REF.db.snapshot_export
+            || self.snapshot_exporting.load(Ordering::SeqCst)
+        {
+            return;
+        }
+        let last_h = match self.executor.get_block_height() {
+            Ok(Some(v)) if v > GENESIS_HEIGHT => v,
+            _ => return,
+        };
+        let root = PathBuf::from(&CONFIGURE_INSTANCE_REF.db.snapshot_path);
+        let stale = match Snapshot::latest(&root) {
+            Ok(Some((height, _))) => last_h > height + SNAPSHOT_EXPORT_INTERVAL,
+            Ok(None) => true,
+            Err(e) => {
+                error!("list snapshots error: {}", e);
+                false
+            }
+        };
+        if !stale {
+            return;
+        }
+        // the last block proves the state of the one before it
+        let mut next_block = match self.executor.get_block(last_h) {
+            Ok(Some(block)) => block,
+            _ => return,
+        };
+        next_block.clear_transaction_signs();
+        let height = last_h - 1;
+
+        self.snapshot_exporting.store(true, Ordering::SeqCst);
+        let exporting = self.snapshot_exporting.clone();
+        let _ = std::thread::spawn(move || {
+            match Snapshot::export(height, next_block, &root.join(height.to_string())) {
+                Ok(manifest) => {
+                    info!(
+                        "exported snapshot of block {} with {} chunks",
+                        height,
+                        manifest.get_chunk_hashes().len()
+                    );
+                    if let Err(e) = Snapshot::remove_older(&root, height) {
+                        error!("remove old snapshots error: {}", e);
                     }
-                };
+                }
+                Err(e) => error!("export snapshot of block {} error: {}", height, e),
+            }
+            exporting.store(false, Ordering::SeqCst);
+        });
+    }
+
+    fn handle_catchup_snapshot_response(
+        &mut self,
+        peer_id: Endpoint,
+        protocol_msg: &ProtocolsMessage,
+    ) {
+        let sync = match self.snapshot_sync.as_mut() {
+            Some(sync) if sync.peer == peer_id => sync,
+            _ => return,
+        };
+        let mut resp: SyncSnapshotResponse =
+            match ProtocolParser::deserialize(protocol_msg.get_data()) {
+                Ok(value) => value,
+                Err(e) => {
+                    self.peers.update_score_error(peer_id);
+                    self.snapshot_sync = None;
+                    return;
+                }
+            };
+        if resp.get_chain_id() != self_chain_id() {
+            self.snapshot_sync = None;
+            return;
+        }
+        sync.idle_ticks = 0;
+
+        let result = match sync.importer.as_mut() {
+            None if resp.has_manifest() => {
+                SnapshotImporter::new(resp.take_manifest(), &verify_snapshot_proof).map(
+                    |importer| {
+                        info!(
+                            "snapshot sync of block {} from {}",
+                            importer.height(),
+                            peer_id.addr()
+                        );
+                        sync.importer = Some(importer);
+                    },
+                )
             }
+            // the peer has no snapshot ready, ask again on a later tick
+            None => {
+                self.snapshot_sync = None;
+                return;
+            }
+            Some(importer) if resp.has_chunk() && resp.get_height() == importer.height() => {
+                importer.import_chunk(resp.get_chunk())
+            }
+            Some(_) => return,
+        };
+        if let Err(e) = result {
+            error!("snapshot sync from {} error: {}", peer_id.addr(), e);
+            self.peers.update_score_error(peer_id);
+            self.snapshot_sync = None;
+            return;
         }
 
-        if !block_rep.get_finish() {
-            self.catchup_block(Some(peer_id));
-        } else {
-            self.status.catchup_done();
+        let importer = match sync.importer.as_ref() {
+            Some(importer) => importer,
+            None => return,
+        };
+        if let Some(index) = importer.next_chunk() {
+            let _ = self
+                .network
+                .send_msg(peer_id, snapshot_request(importer.height(), false, index));
+            return;
+        }
+
+        let importer = match self.snapshot_sync.take().and_then(|sync| sync.importer) {
+            Some(importer) => importer,
+            None => return,
+        };
+        match importer
+            .finish()
+            .and_then(|header| self.executor.reload_last_block().map(|_| header))
+        {
+            Ok(header) => {
+                info!("snapshot sync done at block {}", header.get_height());
+                self.snapshot_done = true;
+                self.peers.update_score_success(peer_id);
+                self.catchup_block();
+            }
+            Err(e) => {
+                error!("snapshot sync from {} error: {}", peer_id.addr(), e);
+                self.peers.update_score_error(peer_id);
+            }
         }
     }
 
@@ -435,9 +819,8 @@ where
         self.peers.remmove_ignored();
     }
 
-    fn execute_verify_block(&self, block: protos::ledger::Ledger) -> Result<(), ()> {
-        let _ = self.executor.execute_verify_block(block);
-        Ok(())
+    fn execute_verify_block(&self, block: protos::ledger::Ledger) -> anyhow::Result<()> {
+        self.executor.execute_verify_block(block)
     }
 
     fn handle_txns_broadcast(&mut self, peer_id: Endpoint, protocol_msg: &ProtocolsMessage) {
@@ -3,4 +3,9 @@ mod notification;
 pub mod catchuper;
 mod catchup_status;
 pub mod storage_executor;
-pub mod network;
\ No newline at end of file
+pub mod network;
+pub mod snapshot_sync;
+mod block_download;
+pub mod light;
+#[cfg(test)]
+mod test_util;
\ No newline at end of file
@@ -0,0 +1,881 @@
+use crate::catchup_status::Peers;
+use crate::network::CatchupNetworkInterace;
+use crate::notification::{ChainStatusReceiver, LightReceiver, TimerNotificationReceiver};
+
+use anyhow::{bail, ensure, format_err};
+use configure::CONFIGURE_INSTANCE_REF;
+use consensus_pbft::bft_check_value::CheckValue;
+use consensus_store::bft_storage::BftStorage;
+use crossbeam_channel::{bounded, select, unbounded, RecvError};
+use executor::{block_verify::Verify, BlockExecutor, LAST_COMMITTED_BLOCK_INFO_REF};
+use ledger_store::LedgerStorage;
+use network::Endpoint;
+use protobuf::{Message, RepeatedField};
+use protos::{
+    common::{
+        KeyPair, ProtocolsActionMessageType, ProtocolsMessage, ProtocolsMessageType, ValidatorSet,
+    },
+    consensus::{BftProof, TxHashList},
+    ledger::{
+        Account, LedgerHeader, LightHeader, LightHeaderRequest, LightHeaderResponse, SyncChain,
+        SyncChainStatus,
+    },
+};
+use state::{account_frame::CONTRACT_META_PREFIX, AccountFrame, TrieHash, TrieReader};
+use state_store::StateStorage;
+use std::{
+    collections::HashSet,
+    time::{Duration, Instant},
+};
+use storage_db::{MemWriteBatch, WriteBatchTrait, STORAGE_INSTANCE_REF};
+use syscontract::{
+    contract_factory::VALIDATORS_ELECT_CONTRACT_INDEX, system_address::get_system_address,
+    VALIDATORS_KEY,
+};
+use tracing::{error, info, Span};
+use utils::{
+    general::{compose_metadata_key, hash_crypto_byte, self_chain_id, GENESIS_HEIGHT},
+    parse::ProtocolParser,
+    timer_manager::{TimerEventType, TimerManager, TimterEventParam},
+    verify_sign::get_sign_address,
+};
+
+// headers one light request may ask for
+pub const LIGHT_MAX_HEADERS: u64 = 1024;
+
+/// The validators set by a block, with the trie proofs tying them to its state_hash.
+struct ValidatorsProof {
+    account: Vec<u8>,
+    account_proof: Vec<Vec<u8>>,
+    entry: Vec<u8>,
+    entry_proof: Vec<Vec<u8>>,
+}
+
+fn trie_root(name: &str, hash: &[u8]) -> anyhow::Result<TrieHash> {
+    let mut root = TrieHash::default();
+    ensure!(hash.len() == root.len(), "{} is not a trie root", name);
+    root.clone_from_slice(hash);
+    Ok(root)
+}
+
+fn validators_address() -> anyhow::Result<String> {
+    get_system_address(VALIDATORS_ELECT_CONTRACT_INDEX)
+        .ok_or_else(|| format_err!("no validators contract address"))
+}
+
+// the validators contract account in the state of `header`, None once the state is pruned
+fn validators_account(header: &LedgerHeader) -> anyhow::Result<Option<Vec<u8>>> {
+    let root = trie_root("state hash", header.get_state_hash())?;
+    let reader = TrieReader::new(STORAGE_INSTANCE_REF.account_db(), Some(root));
+    match reader.get(validators_address()?.as_bytes()) {
+        Ok(account) => Ok(account),
+        Err(_) => Ok(None),
+    }
+}
+
+fn validators_proof(header: &LedgerHeader, account: Vec<u8>) -> anyhow::Result<ValidatorsProof> {
+    let address = validators_address()?;
+    let root = trie_root("state hash", header.get_state_hash())?;
+    let reader = TrieReader::new(STORAGE_INSTANCE_REF.account_db(), Some(root));
+    let account_proof = reader.get_proof(&[address.as_bytes().to_vec()])?;
+    let (entry, entry_proof) =
+        AccountFrame::deserialize(&account)?.contract_metadata_proof(VALIDATORS_KEY.as_bytes())?;
+    match entry {
+        Some(entry) => Ok(ValidatorsProof {
+            account,
+            account_proof,
+            entry,
+            entry_proof,
+        }),
+        None => bail!("block {} has no validators", header.get_height()),
+    }
+}
+
+/// Headers `begin..=end` with their commit proofs, as many as fit in `max_bytes`. Each header
+/// proves the validators it leaves, so a change cannot be left out.
+pub fn light_headers(begin: u64, end: u64, max_bytes: u64) -> anyhow::Result<Vec<LightHeader>> {
+    let mut headers = Vec::new();
+    let mut bytes = 0;
+    for height in begin..=end {
+        let header = match LedgerStorage::load_ledger_header_by_seq(height)? {
+            Some(header) => header,
+            None => break,
+        };
+        let proof = match StateStorage::load_proof(height)? {
+            Some(proof) => proof,
+            None => break,
+        };
+
+        // a pruned state cannot prove the validators any more
+        let validators = match validators_account(&header)? {
+            Some(account) => validators_proof(&header, account)?,
+            None => break,
+        };
+
+        let mut light = LightHeader::new();
+        if let Some(previous_proof) = StateStorage::load_proof(height - 1)? {
+            light.set_previous_proof(ProtocolParser::serialize::<BftProof>(&previous_proof));
+        }
+        if let Some(tx_list) = LedgerStorage::load_ledger_tx_list(height)? {
+            if !tx_list.get_entry().is_empty() {
+                let mut hash_list = TxHashList::new();
+                hash_list.set_hash_set(RepeatedField::from(tx_list.get_entry().to_vec()));
+                light.set_tx_hash_list(ProtocolParser::serialize::<TxHashList>(&hash_list));
+            }
+        }
+        light.set_validators_account(validators.account);
+        light.set_account_proof(RepeatedField::from(validators.account_proof));
+        light.set_validators_entry(validators.entry);
+        light.set_validators_proof(RepeatedField::from(validators.entry_proof));
+        light.set_header(header);
+        light.set_proof(ProtocolParser::serialize::<BftProof>(&proof));
+
+        bytes += light.compute_size() as u64;
+        if !headers.is_empty() && bytes > max_bytes {
+            break;
+        }
+        headers.push(light);
+    }
+    Ok(headers)
+}
+
+/// The validators a light header proves under its state_hash.
+pub fn verify_light_validators(light: &LightHeader) -> anyhow::Result<ValidatorSet> {
+    let header = light.get_header();
+    ensure!(
+        !light.get_validators_entry().is_empty(),
+        "header {} does not prove its validators",
+        header.get_height()
+    );
+    let address = validators_address()?;
+    TrieReader::verify_proof(
+        &trie_root("state hash", header.get_state_hash())?,
+        light.get_account_proof(),
+        &[(
+            address.as_bytes().to_vec(),
+            Some(light.get_validators_account().to_vec()),
+        )],
+    )?;
+
+    let account = ProtocolParser::deserialize::<Account>(light.get_validators_account())?;
+    let outer_key = compose_metadata_key(CONTRACT_META_PREFIX, &address, VALIDATORS_KEY.as_bytes());
+    TrieReader::verify_proof(
+        &trie_root("metadata hash", account.get_metadata_hash())?,
+        light.get_validators_proof(),
+        &[(outer_key, Some(light.get_validators_entry().to_vec()))],
+    )?;
+
+    let entry = ProtocolParser::deserialize::<KeyPair>(light.get_validators_entry())?;
+    ProtocolParser::deserialize::<ValidatorSet>(entry.get_value())
+}
+
+// the consensus value of the header's block, rebuilt the way the proposer built it
+fn light_consensus_value_hash(light: &LightHeader) -> Vec<u8> {
+    let header = light.get_header();
+    let optional = |data: &[u8]| {
+        if data.is_empty() {
+            None
+        } else {
+            Some(data.to_vec())
+        }
+    };
+    let value = BlockExecutor::initialize_new_block(
+        header.get_height(),
+        header.get_previous_hash().to_vec(),
+        header.get_timestamp(),
+        header.get_version(),
+        header.get_tx_count(),
+        header.get_total_tx_count(),
+        header.get_proposer().to_string(),
+        optional(light.get_previous_proof()),
+        optional(light.get_tx_hash_list()),
+    );
+    BlockExecutor::caculate_consensus_value_hash(&value)
+}
+
+/// Check `light` follows `last` and is committed by a quorum of `validators`, the set in
+/// effect after `last`. Returns the validators the header's state holds. The proof does not
+/// cover the state_hash, so they are only claimed until a header committed by `validators`
+/// links to this one, see `LightChain`.
+pub fn verify_light_header(
+    light: &LightHeader,
+    last: &LedgerHeader,
+    validators: &ValidatorSet,
+) -> anyhow::Result<ValidatorSet> {
+    let header = light.get_header();
+    ensure!(
+        header.get_height() == last.get_height() + 1,
+        "header {} does not follow {}",
+        header.get_height(),
+        last.get_height()
+    );
+    ensure!(
+        header.get_chain_id() == self_chain_id(),
+        "header {} is of chain {}",
+        header.get_height(),
+        header.get_chain_id()
+    );
+    ensure!(
+        header.verify_pre_hash(last.get_hash())?,
+        "header {} previous hash does not match",
+        header.get_height()
+    );
+
+    let mut unhashed = header.clone();
+    unhashed.clear_hash();
+    let hash = hash_crypto_byte(&ProtocolParser::serialize::<LedgerHeader>(&unhashed));
+    ensure!(
+        header.verify_block_hash(&hash)?,
+        "header {} hash does not match",
+        header.get_height()
+    );
+
+    let proof = ProtocolParser::deserialize::<BftProof>(light.get_proof())?;
+    let consensus_value_hash = light_consensus_value_hash(light);
+    ensure!(
+        CheckValue::check_proof(validators, &consensus_value_hash, &proof, &Span::current()),
+        "header {} consensus proof is not valid",
+        header.get_height()
+    );
+
+    verify_light_validators(light)
+}
+
+/// Check `light` is committed by a quorum of `trusted`, other signers left aside. Its value
+/// covers the previous header's hash, and so the state_hash that header's own proof does not.
+pub fn verify_trusted_link(light: &LightHeader, trusted: &ValidatorSet) -> anyhow::Result<()> {
+    let proof = ProtocolParser::deserialize::<BftProof>(light.get_proof())?;
+    let addresses: HashSet<&str> = trusted
+        .get_validators()
+        .iter()
+        .map(|validator| validator.get_address())
+        .collect();
+    let mut trusted_proof = BftProof::new();
+    trusted_proof.set_commits(
+        proof
+            .get_commits()
+            .iter()
+            .filter(|commit| match get_sign_address(commit.get_signature()) {
+                Ok(address) => addresses.contains(address.as_str()),
+                Err(_) => false,
+            })
+            .cloned()
+            .collect(),
+    );
+    ensure!(
+        CheckValue::check_proof(
+            trusted,
+            &light_consensus_value_hash(light),
+            &trusted_proof,
+            &Span::current()
+        ),
+        "header {} is not committed by the trusted validators",
+        light.get_header().get_height()
+    );
+    Ok(())
+}
+
+/// The headers a light node trusts. The last confirmed header and the validators it leaves,
+/// then the header after it, held back with the validators it claims until a header
+/// committed by the confirmed validators links to it.
+struct LightChain {
+    header: LedgerHeader,
+    validators: ValidatorSet,
+    pending: Option<(LightHeader, ValidatorSet)>,
+}
+
+impl LightChain {
+    fn new(header: LedgerHeader, validators: ValidatorSet) -> Self {
+        Self {
+            header,
+            validators,
+            pending: None,
+        }
+    }
+
+    // the height of the header the next one follows
+    fn height(&self) -> u64 {
+        match &self.pending {
+            Some((pending, _)) => pending.get_header().get_height(),
+            None => self.header.get_height(),
+        }
+    }
+
+    /// Take `light` as the next header, returns the pending header it confirms. Any error
+    /// drops the pending header, it is fetched again after the confirmed one.
+    fn push(&mut self, light: &LightHeader) -> anyhow::Result<Option<LightHeader>> {
+        let pending = self.pending.take();
+        let (last, validators) = match &pending {
+            Some((pending, validators)) => (pending.get_header(), validators),
+            None => (&self.header, &self.validators),
+        };
+        let claimed = verify_light_header(light, last, validators)?;
+
+        let confirmed = match pending {
+            Some((pending, validators)) => {
+                verify_trusted_link(light, &self.validators)?;
+                if validators != self.validators {
+                    info!(
+                        "light header {} changes the validators",
+                        pending.get_header().get_height()
+                    );
+                }
+                self.header = pending.get_header().clone();
+                self.validators = validators;
+                Some(pending)
+            }
+            None => None,
+        };
+        self.pending = Some((light.clone(), claimed));
+        Ok(confirmed)
+    }
+}
+
+fn light_message(action: ProtocolsActionMessageType, data: Vec<u8>) -> ProtocolsMessage {
+    let mut message = ProtocolsMessage::new();
+    message.set_msg_type(ProtocolsMessageType::LIGHT);
+    message.set_action(action);
+    message.set_data(data);
+    message
+}
+
+pub fn light_request(begin: u64, end: u64, request_id: i64) -> ProtocolsMessage {
+    let mut req = LightHeaderRequest::new();
+    req.set_chain_id(self_chain_id());
+    req.set_begin(begin);
+    req.set_end(end);
+    req.set_requestid(request_id);
+    light_message(
+        ProtocolsActionMessageType::REQUEST,
+        req.write_to_bytes().unwrap(),
+    )
+}
+
+pub fn light_response(resp: &LightHeaderResponse) -> ProtocolsMessage {
+    light_message(
+        ProtocolsActionMessageType::RESPONSE,
+        resp.write_to_bytes().unwrap(),
+    )
+}
+
+/// Follows the chain by headers and their commit proofs only, for a node in light mode.
+pub struct LightClient<N> {
+    network: N,
+    peers: Peers,
+    timer_notify: TimerNotificationReceiver,
+    status_notify: ChainStatusReceiver,
+    light_notify: LightReceiver,
+    chain: LightChain,
+    request: Option<(Endpoint, i64, Instant)>,
+    next_id: i64,
+}
+
+impl<N> LightClient<N>
+where
+    N: CatchupNetworkInterace + Send + 'static,
+{
+    /// Load the last synced header in place of `BlockExecutor::block_initialize`, a light
+    /// node has no state to read the validators from past genesis.
+    pub fn initialize() -> anyhow::Result<()> {
+        let height = match LedgerStorage::load_max_block_height()? {
+            Some(height) if height > GENESIS_HEIGHT => height,
+            _ => return BlockExecutor::block_initialize(),
+        };
+        let header = match LedgerStorage::load_ledger_header_by_seq(height)? {
+            Some(header) => header,
+            None => bail!("light initialize load header failed:{}", height),
+        };
+        let validators = match BftStorage::load_validators(height)? {
+            Some(validators) => validators,
+            None => bail!("light initialize load validators failed:{}", height),
+        };
+        let proof = StateStorage::load_last_proof()?;
+        LAST_COMMITTED_BLOCK_INFO_REF
+            .write()
+            .update(&header, &validators, proof);
+        Ok(())
+    }
+
+    pub fn create_and_start(network: N) {
+        let (sender, timer_notify) = bounded(1024);
+        let _id: i64 = TimerManager::instance().new_repeating_timer(
+            chrono::Duration::seconds(5),
+            sender,
+            TimerEventType::LedgerSync,
+            None,
+        );
+        let (chain_status_sender, status_notify) = unbounded();
+        let (light_sender, light_notify) = unbounded();
+        network.register_sync_handler(ProtocolsMessageType::SYNCCHAIN, chain_status_sender);
+        network.register_sync_handler(ProtocolsMessageType::LIGHT, light_sender);
+
+        let (header, validators) = {
+            let last_committed = LAST_COMMITTED_BLOCK_INFO_REF.read();
+            (
+                last_committed.get_header().clone(),
+                last_committed.get_validators().clone(),
+            )
+        };
+        let mut client = Self {
+            network,
+            peers: Peers::default(),
+            timer_notify,
+            status_notify,
+            light_notify,
+            chain: LightChain::new(header, validators),
+            request: None,
+            next_id: 1,
+        };
+        let _ = std::thread::spawn(move || loop {
+            client.start();
+        });
+    }
+
+    fn start(&mut self) {
+        select! {
+            recv(self.status_notify) -> msg =>{
+                self.handle_chain_status(msg)
+            }
+            recv(self.light_notify) -> msg =>{
+                self.handle_light(msg)
+            }
+            recv(self.timer_notify) -> msg =>{
+                self.handle_timer(msg)
+            }
+        }
+    }
+
+    fn handle_timer(&mut self, msg: Result<TimterEventParam, RecvError>) {
+        match msg {
+            Ok(param) => match param.event_type {
+                TimerEventType::LedgerSync => {
+                    self.broadcast_chain();
+                    let timeout =
+                        Duration::from_secs(CONFIGURE_INSTANCE_REF.catchup.request_timeout_secs);
+                    if let Some((peer_id, _, sent)) = self.request {
+                        if sent.elapsed() > timeout {
+                            info!("light request to {} timed out", peer_id.addr());
+                            self.peers.update_score_error(peer_id);
+                            self.request = None;
+                        }
+                    }
+                    self.request_headers();
+                }
+                _ => {}
+            },
+            Err(e) => {
+                error!("light timer receive error: {}", e);
+            }
+        }
+    }
+
+    // full nodes answer with their height
+    fn broadcast_chain(&self) {
+        let mut chain = SyncChain::new();
+        chain.set_height(self.chain.header.get_height() as i64);
+        chain.set_chain_id(self_chain_id());
+
+        let mut message = ProtocolsMessage::new();
+        message.set_msg_type(ProtocolsMessageType::SYNCCHAIN);
+        message.set_action(ProtocolsActionMessageType::BROADCAST);
+        message.set_data(chain.write_to_bytes().unwrap());
+        let _ = self.network.broadcast_msg(message);
+    }
+
+    fn handle_chain_status(&mut self, msg: Result<(Endpoint, ProtocolsMessage), RecvError>) {
+        let (peer_id, protocol_msg) = match msg {
+            Ok(value) => value,
+            Err(e) => {
+                error!("light status receive error: {}", e);
+                return;
+            }
+        };
+        if protocol_msg.get_action() != ProtocolsActionMessageType::RESPONSE {
+            return;
+        }
+        let status: SyncChainStatus = match ProtocolParser::deserialize(protocol_msg.get_data()) {
+            Ok(value) => value,
+            Err(_) => {
+                self.peers.update_score_error(peer_id);
+                return;
+            }
+        };
+        if status.get_chain_id() != self_chain_id() {
+            return;
+        }
+        let height = status.get_height() as u64;
+        self.peers.insert_peer(peer_id, height);
+        if let Some(peer) = self.peers.get_mut(&peer_id) {
+            peer.set_block(height);
+        }
+        self.peers.remmove_ignored();
+        self.request_headers();
+    }
+
+    // one request in flight, to the best scored peer ahead of the node
+    fn request_headers(&mut self) {
+        if self.request.is_some() {
+            return;
+        }
+        let next = self.chain.height() + 1;
+        let peer = self
+            .peers
+            .iter()
+            .filter(|(_, peer)| !peer.ignored() && peer.block_id() >= next)
+            .max_by(|x, y| x.1.score().cmp(&y.1.score()))
+            .map(|(peer_id, peer)| (peer_id.clone(), peer.block_id()));
+        let (peer_id, peer_height) = match peer {
+            Some(peer) => peer,
+            None => return,
+        };
+        let end = std::cmp::min(peer_height, next + LIGHT_MAX_HEADERS - 1);
+        let request_id = self.next_id;
+        self.next_id += 1;
+        if self
+            .network
+            .send_msg(peer_id, light_request(next, end, request_id))
+            .is_ok()
+        {
+            info!(
+                "light request headers {}..={} from peer_id:{}",
+                next,
+                end,
+                peer_id.addr()
+            );
+            self.request = Some((peer_id, request_id, Instant::now()));
+        }
+    }
+
+    fn handle_light(&mut self, msg: Result<(Endpoint, ProtocolsMessage), RecvError>) {
+        let (peer_id, protocol_msg) = match msg {
+            Ok(value) => value,
+            Err(e) => {
+                error!("light receive error: {}", e);
+                return;
+            }
+        };
+        // a light node serves no headers
+        if protocol_msg.get_action() != ProtocolsActionMessageType::RESPONSE {
+            return;
+        }
+        let resp: LightHeaderResponse = match ProtocolParser::deserialize(protocol_msg.get_data())
+        {
+            Ok(value) => value,
+            Err(_) => {
+                self.peers.update_score_error(peer_id);
+                return;
+            }
+        };
+        match self.request {
+            Some((request_peer, request_id, _))
+                if request_peer == peer_id && request_id == resp.get_requestid() =>
+            {
+                self.request = None
+            }
+            _ => return,
+        }
+        if resp.get_chain_id() != self_chain_id() {
+            self.peers.update_score_error(peer_id);
+            return;
+        }
+
+        for light in resp.get_headers() {
+            if let Err(e) = self.apply_header(light) {
+                error!(
+                    "light header {} from {} error: {}",
+                    light.get_header().get_height(),
+                    peer_id.addr(),
+                    e
+                );
+                self.peers.update_score_error(peer_id);
+                return;
+            }
+        }
+        if resp.get_headers().is_empty() {
+            // the peer is not as far as it said
+            self.peers.update_score_error(peer_id);
+        } else {
+            self.peers.update_score_success(peer_id);
+            self.request_headers();
+        }
+    }
+
+    // a header is stored once the next one confirms it, a restart resumes after it
+    fn apply_header(&mut self, light: &LightHeader) -> anyhow::Result<()> {
+        let confirmed = match self.chain.push(light)? {
+            Some(confirmed) => confirmed,
+            None => return Ok(()),
+        };
+        let header = confirmed.get_header();
+        let height = header.get_height();
+        let proof = ProtocolParser::deserialize::<BftProof>(confirmed.get_proof())?;
+
+        // the proof and validators go first
+        let mut state_batch = MemWriteBatch::new();
+        StateStorage::store_last_proof(&mut state_batch, &proof);
+        StateStorage::store_proof(&mut state_batch, height, &proof);
+        StateStorage::commit(state_batch)?;
+        BftStorage::store_validators(height, &self.chain.validators);
+        if height > 3 {
+            let _ = BftStorage::delete_validators(height - 3);
+        }
+
+        let mut ledger_batch = MemWriteBatch::new();
+        LedgerStorage::store_max_block_height(&mut ledger_batch, height);
+        LedgerStorage::store_ledger_header(&mut ledger_batch, header);
+        LedgerStorage::commit(ledger_batch)?;
+
+        LAST_COMMITTED_BLOCK_INFO_REF
+            .write()
+            .update(header, &self.chain.validators, Some(proof));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::TestValidators;
+    use state::{TrieJournal, TrieWriter};
+    use std::collections::HashMap;
+
+    // a state whose validators contract holds `validators`
+    fn state(validators: &ValidatorSet) -> TrieHash {
+        let address = validators_address().unwrap();
+        let mut batch = MemWriteBatch::new();
+        let mut journal = TrieJournal::default();
+        let mut account = AccountFrame::new(address.clone(), 0);
+        account.upsert_contract_metadata(
+            VALIDATORS_KEY.as_bytes(),
+            &ProtocolParser::serialize::<ValidatorSet>(validators),
+        );
+        account
+            .commit_metadata_trie(&mut batch, &mut journal)
+            .unwrap();
+
+        let mut datas = HashMap::new();
+        datas.insert(address.into_bytes(), Some(account.serialize()));
+        let account_db = STORAGE_INSTANCE_REF.account_db();
+        let root = TrieWriter::commit(account_db, None, &datas, &mut batch, &mut journal).unwrap();
+        StateStorage::commit(batch).unwrap();
+        root
+    }
+
+    fn seal(header: &mut LedgerHeader) {
+        header.clear_hash();
+        let hash = hash_crypto_byte(&ProtocolParser::serialize::<LedgerHeader>(header));
+        header.set_hash(hash);
+    }
+
+    fn genesis() -> LedgerHeader {
+        let mut header = BlockExecutor::initialize_new_header(
+            GENESIS_HEIGHT,
+            Vec::new(),
+            0,
+            1,
+            0,
+            0,
+            String::new(),
+        );
+        seal(&mut header);
+        header
+    }
+
+    fn prove_validators(light: &mut LightHeader) {
+        let header = light.get_header();
+        let account = validators_account(header).unwrap().unwrap();
+        let validators = validators_proof(header, account).unwrap();
+        light.set_validators_account(validators.account);
+        light.set_account_proof(RepeatedField::from(validators.account_proof));
+        light.set_validators_entry(validators.entry);
+        light.set_validators_proof(RepeatedField::from(validators.entry_proof));
+    }
+
+    // the header after `last` leaving `next`, committed by the first `signers` of `committee`
+    fn light(
+        last: &LedgerHeader,
+        committee: &TestValidators,
+        signers: usize,
+        next: &ValidatorSet,
+    ) -> LightHeader {
+        let height = last.get_height() + 1;
+        let mut header = BlockExecutor::initialize_new_header(
+            height,
+            last.get_hash().to_vec(),
+            height as i64,
+            1,
+            1,
+            height,
+            String::new(),
+        );
+        header.set_state_hash(state(next).to_vec());
+        seal(&mut header);
+
+        let mut hash_list = TxHashList::new();
+        hash_list.mut_hash_set().push(vec![height as u8; 32]);
+        let mut light = LightHeader::new();
+        light.set_header(header);
+        light.set_tx_hash_list(ProtocolParser::serialize::<TxHashList>(&hash_list));
+        light.set_previous_proof(ProtocolParser::serialize::<BftProof>(
+            &committee.proof(last.get_hash(), 3),
+        ));
+        let value_hash = light_consensus_value_hash(&light);
+        light.set_proof(ProtocolParser::serialize::<BftProof>(
+            &committee.proof(&value_hash, signers),
+        ));
+        prove_validators(&mut light);
+        light
+    }
+
+    #[test]
+    fn light_header_test() {
+        configure::init_test_config();
+        let committee = TestValidators::new(4);
+        let genesis = genesis();
+        let second = light(&genesis, &committee, 3, &committee.set);
+        let validators = verify_light_header(&second, &genesis, &committee.set).unwrap();
+        assert_eq!(validators, committee.set);
+
+        let third = light(second.get_header(), &committee, 4, &committee.set);
+        assert!(verify_light_header(&third, second.get_header(), &committee.set).is_ok());
+        // not the header after genesis
+        assert!(verify_light_header(&third, &genesis, &committee.set).is_err());
+
+        // below the quorum of 3 out of 4
+        let unproven = light(&genesis, &committee, 2, &committee.set);
+        assert!(verify_light_header(&unproven, &genesis, &committee.set).is_err());
+    }
+
+    #[test]
+    fn forged_light_header_test() {
+        configure::init_test_config();
+        let committee = TestValidators::new(4);
+        let genesis = genesis();
+        let second = light(&genesis, &committee, 3, &committee.set);
+
+        // signed by validators the node does not trust
+        let strangers = TestValidators::new(4);
+        assert!(verify_light_header(&second, &genesis, &strangers.set).is_err());
+
+        // a forged header under the reused proof
+        let mut forged = second.clone();
+        forged.mut_header().set_timestamp(100);
+        seal(forged.mut_header());
+        assert!(verify_light_header(&forged, &genesis, &committee.set).is_err());
+
+        // other transactions under the reused proof
+        let mut forged = second.clone();
+        forged.set_tx_hash_list(ProtocolParser::serialize::<TxHashList>(&TxHashList::new()));
+        assert!(verify_light_header(&forged, &genesis, &committee.set).is_err());
+
+        // validators the state does not hold
+        let mut forged = second.clone();
+        let mut entry = KeyPair::new();
+        entry.set_key(VALIDATORS_KEY.as_bytes().to_vec());
+        entry.set_value(ProtocolParser::serialize::<ValidatorSet>(&strangers.set));
+        forged.set_validators_entry(ProtocolParser::serialize::<KeyPair>(&entry));
+        assert!(verify_light_header(&forged, &genesis, &committee.set).is_err());
+
+        // a header leaving out its validators
+        let mut omitted = second;
+        omitted.clear_validators_entry();
+        omitted.clear_validators_proof();
+        assert!(verify_light_header(&omitted, &genesis, &committee.set).is_err());
+    }
+
+    #[test]
+    fn stale_validators_test() {
+        configure::init_test_config();
+        let old = TestValidators::new(4);
+        let new = TestValidators::new(4);
+        let genesis = genesis();
+
+        // the second block elects the new validators
+        let second = light(&genesis, &old, 3, &new.set);
+        let validators = verify_light_header(&second, &genesis, &old.set).unwrap();
+        assert_eq!(validators, new.set);
+
+        // the old validators no longer commit
+        let stale = light(second.get_header(), &old, 4, &new.set);
+        assert!(verify_light_header(&stale, second.get_header(), &validators).is_err());
+
+        let third = light(second.get_header(), &new, 3, &new.set);
+        assert!(verify_light_header(&third, second.get_header(), &validators).is_ok());
+    }
+
+    #[test]
+    fn light_chain_test() {
+        configure::init_test_config();
+        let committee = TestValidators::new(4);
+        let genesis = genesis();
+        let mut chain = LightChain::new(genesis.clone(), committee.set.clone());
+
+        // held back until the next header links to it
+        let second = light(&genesis, &committee, 3, &committee.set);
+        assert!(chain.push(&second).unwrap().is_none());
+        assert_eq!(chain.header, genesis);
+        assert_eq!(chain.height(), second.get_header().get_height());
+
+        let third = light(second.get_header(), &committee, 3, &committee.set);
+        assert_eq!(chain.push(&third).unwrap(), Some(second.clone()));
+        assert_eq!(&chain.header, second.get_header());
+        assert_eq!(chain.validators, committee.set);
+        assert_eq!(chain.height(), third.get_header().get_height());
+    }
+
+    #[test]
+    fn forged_state_test() {
+        configure::init_test_config();
+        let committee = TestValidators::new(4);
+        let strangers = TestValidators::new(4);
+        let genesis = genesis();
+        let second = light(&genesis, &committee, 3, &committee.set);
+
+        // the real proof over a state electing the strangers, the proof does not cover it
+        let mut forged = second.clone();
+        forged
+            .mut_header()
+            .set_state_hash(state(&strangers.set).to_vec());
+        seal(forged.mut_header());
+        prove_validators(&mut forged);
+        assert_eq!(
+            verify_light_header(&forged, &genesis, &committee.set).unwrap(),
+            strangers.set
+        );
+
+        // the strangers cannot confirm it, nothing is adopted
+        let mut chain = LightChain::new(genesis.clone(), committee.set.clone());
+        assert!(chain.push(&forged).unwrap().is_none());
+        let next = light(forged.get_header(), &strangers, 4, &strangers.set);
+        assert!(chain.push(&next).is_err());
+        assert_eq!(chain.header, genesis);
+        assert_eq!(chain.validators, committee.set);
+        assert!(chain.pending.is_none());
+
+        // the real chain is taken again after the confirmed header
+        assert!(chain.push(&second).unwrap().is_none());
+        let third = light(second.get_header(), &committee, 3, &committee.set);
+        assert_eq!(chain.push(&third).unwrap(), Some(second));
+    }
+
+    #[test]
+    fn changed_validators_test() {
+        configure::init_test_config();
+        let old = TestValidators::new(5);
+        let genesis = genesis();
+        let mut chain = LightChain::new(genesis.clone(), old.set.clone());
+
+        // the second block drops the last validator, the rest still hold a quorum of the old set
+        let mut new = old.set.clone();
+        new.mut_validators().truncate(4);
+        let second = light(&genesis, &old, 4, &new);
+        assert!(chain.push(&second).unwrap().is_none());
+        let third = light(second.get_header(), &old, 4, &new);
+        assert!(chain.push(&third).unwrap().is_some());
+        assert_eq!(chain.validators, new);
+
+        // the dropped validator no longer counts
+        let stale = light(third.get_header(), &old, 5, &new);
+        assert!(chain.push(&stale).is_err());
+    }
+}
@@ -34,6 +34,8 @@ pub type ChainStatusReceiver = Receiver<(Endpoint, ProtocolsMessage)>;
 pub type ChainStatusSender = Sender<(Endpoint, ProtocolsMessage)>;
 pub type BlocksReceiver = Receiver<(Endpoint, ProtocolsMessage)>;
 pub type BlocksSender = Sender<(Endpoint, ProtocolsMessage)>;
+pub type SnapshotReceiver = Receiver<(Endpoint, ProtocolsMessage)>;
+pub type LightReceiver = Receiver<(Endpoint, ProtocolsMessage)>;
 //pub type TimerNotificationReceiver = Receiver<TimerNotification>;
 pub type TimerNotificationReceiver = Receiver<TimterEventParam>;
 pub type TxpoolNotificationSender = Sender<TxpoolNotification>;
@@ -0,0 +1,192 @@
+use anyhow::{bail, ensure};
+use consensus_pbft::bft_check_value::CheckValue;
+use executor::{block_executor::BlockExecutor, LAST_COMMITTED_BLOCK_INFO_REF};
+use network::Endpoint;
+use protobuf::Message;
+use protos::{
+    common::{ProtocolsActionMessageType, ProtocolsMessage, ProtocolsMessageType, ValidatorSet},
+    consensus::BftProof,
+    ledger::{SnapshotManifest, SyncSnapshotRequest, SyncSnapshotResponse},
+};
+use state_store::snapshot::SnapshotImporter;
+use tracing::Span;
+use utils::{general::self_chain_id, parse::ProtocolParser};
+
+// a node at genesis syncs a snapshot only from peers at least this many blocks ahead
+pub const SNAPSHOT_SYNC_MIN_BLOCKS: u64 = 128;
+// sync timer ticks without a response before the peer is given up
+pub const SNAPSHOT_SYNC_MAX_IDLE_TICKS: u64 = 6;
+// blocks the exported snapshot may lag behind the last block before a newer one is exported
+pub const SNAPSHOT_EXPORT_INTERVAL: u64 = 10_000;
+
+/// A snapshot download from one peer, waiting for the manifest until `importer` is set.
+pub struct SnapshotSync {
+    pub peer: Endpoint,
+    pub importer: Option<SnapshotImporter>,
+    pub idle_ticks: u64,
+}
+
+impl SnapshotSync {
+    pub fn new(peer: Endpoint) -> Self {
+        Self {
+            peer,
+            importer: None,
+            idle_ticks: 0,
+        }
+    }
+}
+
+/// Ask for the manifest of the peer's latest snapshot, or for one chunk of the snapshot at `height`.
+pub fn snapshot_request(height: u64, manifest: bool, index: u64) -> ProtocolsMessage {
+    let mut req = SyncSnapshotRequest::new();
+    req.set_chain_id(self_chain_id());
+    req.set_height(height);
+    req.set_manifest(manifest);
+    req.set_index(index);
+
+    let mut message = ProtocolsMessage::new();
+    message.set_msg_type(ProtocolsMessageType::SYNCSNAPSHOT);
+    message.set_action(ProtocolsActionMessageType::REQUEST);
+    message.set_data(req.write_to_bytes().unwrap());
+    message
+}
+
+pub fn snapshot_response(resp: &SyncSnapshotResponse) -> ProtocolsMessage {
+    let mut message = ProtocolsMessage::new();
+    message.set_msg_type(ProtocolsMessageType::SYNCSNAPSHOT);
+    message.set_action(ProtocolsActionMessageType::RESPONSE);
+    message.set_data(resp.write_to_bytes().unwrap());
+    message
+}
+
+/// Check the next block of a snapshot manifest was committed by the validators of the node's
+/// last block, which binds the snapshot header through its previous hash. Only a node whose
+/// validators still sign at the snapshot height can import it.
+pub fn verify_snapshot_proof(manifest: &SnapshotManifest) -> anyhow::Result<()> {
+    let (last, validators) = {
+        let last_committed = LAST_COMMITTED_BLOCK_INFO_REF.read();
+        (
+            last_committed.get_header().get_height(),
+            last_committed.get_validators().clone(),
+        )
+    };
+    check_snapshot_proof(manifest, &validators)
+        .map_err(|e| e.context(format!("snapshot checked at block {}", last)))
+}
+
+fn check_snapshot_proof(
+    manifest: &SnapshotManifest,
+    validators: &ValidatorSet,
+) -> anyhow::Result<()> {
+    let next = manifest.get_next_block();
+    let height = next.get_header().get_height();
+    let proof = match BlockExecutor::extract_proof(next) {
+        Some(data) => ProtocolParser::deserialize::<BftProof>(&data)?,
+        None => bail!("block {} has no consensus proof", height),
+    };
+    // the proof stored with the snapshot block is the one its next block carries
+    ensure!(
+        BlockExecutor::extract_previous_proof(next).as_deref() == Some(manifest.get_proof()),
+        "snapshot proof is not the previous proof of block {}",
+        height
+    );
+
+    let value_hash = BlockExecutor::caculate_consensus_value_hash(next);
+    ensure!(
+        CheckValue::check_proof(validators, &value_hash, &proof, &Span::current()),
+        "block {} proof is not signed by the trusted validators",
+        height
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::TestValidators;
+    use protos::ledger::LedgerHeader;
+    use utils::general::hash_crypto_byte;
+
+    const HEIGHT: u64 = 9;
+
+    fn seal(header: &mut LedgerHeader) {
+        header.clear_hash();
+        let hash = hash_crypto_byte(&ProtocolParser::serialize::<LedgerHeader>(header));
+        header.set_hash(hash);
+    }
+
+    // a manifest whose next block carries the commits of the first `signers` of `validators`
+    fn manifest(
+        validators: &TestValidators,
+        state_hash: &[u8],
+        signers: usize,
+    ) -> SnapshotManifest {
+        let mut header =
+            BlockExecutor::initialize_new_header(HEIGHT, vec![1; 32], 1, 1, 0, 0, String::new());
+        header.set_state_hash(state_hash.to_vec());
+        seal(&mut header);
+
+        let previous_proof = ProtocolParser::serialize::<BftProof>(&validators.proof(&[2; 32], 3));
+        let mut next = BlockExecutor::initialize_new_block(
+            HEIGHT + 1,
+            header.get_hash().to_vec(),
+            2,
+            1,
+            0,
+            0,
+            String::new(),
+            Some(previous_proof.clone()),
+            None,
+        );
+        let value_hash = BlockExecutor::caculate_consensus_value_hash(&next);
+        BlockExecutor::inject_current_proof(
+            &mut next,
+            ProtocolParser::serialize::<BftProof>(&validators.proof(&value_hash, signers)),
+        );
+
+        let mut manifest = SnapshotManifest::new();
+        manifest.set_chain_id(self_chain_id());
+        manifest.set_header(header);
+        manifest.set_proof(previous_proof);
+        manifest.set_next_block(next);
+        manifest
+    }
+
+    #[test]
+    fn snapshot_proof_test() {
+        configure::init_test_config();
+        let validators = TestValidators::new(4);
+        let proven = manifest(&validators, &[3; 32], 3);
+        assert!(check_snapshot_proof(&proven, &validators.set).is_ok());
+
+        // below the quorum of 3 out of 4
+        let unproven = manifest(&validators, &[3; 32], 2);
+        assert!(check_snapshot_proof(&unproven, &validators.set).is_err());
+    }
+
+    #[test]
+    fn forged_snapshot_proof_test() {
+        configure::init_test_config();
+        let validators = TestValidators::new(4);
+        let manifest = manifest(&validators, &[3; 32], 4);
+
+        // signed by validators the node does not trust
+        let strangers = TestValidators::new(4);
+        assert!(check_snapshot_proof(&manifest, &strangers.set).is_err());
+
+        // a forged state root, with the next block relinked to the resealed header
+        let mut forged = manifest.clone();
+        forged.mut_header().set_state_hash(vec![4; 32]);
+        seal(forged.mut_header());
+        let hash = forged.get_header().get_hash().to_vec();
+        forged.mut_next_block().mut_header().set_previous_hash(hash);
+        assert!(check_snapshot_proof(&forged, &validators.set).is_err());
+
+        // a stored proof other than the one the next block commits to
+        let mut forged = manifest;
+        forged.set_proof(ProtocolParser::serialize::<BftProof>(
+            &validators.proof(&[5; 32], 3),
+        ));
+        assert!(check_snapshot_proof(&forged, &validators.set).is_err());
+    }
+}
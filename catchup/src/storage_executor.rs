@@ -1,76 +1,109 @@
 use anyhow::bail;
-use executor::block_executor::BlockExecutor;
+use consensus_hotstuff::proof::check_commit_proof;
+use consensus_pbft::bft_check_value::CheckValue;
+use consensus_store::{bft_storage::BftStorage, hotstuff_storage::HotStuffStorage};
+use executor::{block_executor::BlockExecutor, LAST_COMMITTED_BLOCK_INFO_REF};
 use ledger_store::LedgerStorage;
 use msp::bytes_to_hex_str;
 use protos::{
-    consensus::{BftProof, TxHashList},
+    common::ValidatorSet,
+    consensus::{BftProof, HotStuffProof, TxHashList},
     ledger::{Ledger, LedgerHeader},
 };
 use state_store::StateStorage;
-use utils::{general::hash_bytes_to_string, parse::ProtocolParser, TransactionSign};
+use tracing::Span;
+use utils::{
+    general::{hash_bytes_to_string, GENESIS_HEIGHT},
+    parse::ProtocolParser,
+    TransactionSign,
+};
 
 pub trait StorageExecutorInterface {
     fn execute_verify_block(&self, block: Ledger) -> anyhow::Result<()>;
 
+    /// Reload the last committed block after the storage moved under the executor.
+    fn reload_last_block(&self) -> anyhow::Result<()> {
+        BlockExecutor::block_initialize()
+    }
+
     fn get_block_height(&self) -> anyhow::Result<Option<u64>> {
         LedgerStorage::load_max_block_height()
     }
 
     fn get_block(&self, block_num: u64) -> anyhow::Result<Option<Ledger>> {
-        let header = match LedgerStorage::load_ledger_header_by_seq(block_num) {
-            Ok(Some(v)) => v,
-            Ok(None) => return Ok(None),
-            Err(e) => bail!(e),
-        };
+        load_block(block_num)
+    }
+}
 
-        let txs_list = match LedgerStorage::load_ledger_tx_list(block_num) {
-            Ok(Some(v)) => v,
-            Ok(None) => return Ok(None),
-            Err(e) => bail!(e),
-        };
+/// The committed block at `block_num` the way peers get it, with the proofs committing it.
+pub fn load_block(block_num: u64) -> anyhow::Result<Option<Ledger>> {
+    let header = match LedgerStorage::load_ledger_header_by_seq(block_num) {
+        Ok(Some(v)) => v,
+        Ok(None) => return Ok(None),
+        Err(e) => bail!(e),
+    };
 
-        let txs: Vec<TransactionSign> = txs_list
-            .get_entry()
-            .iter()
-            .map(|hash| {
-                let tx = match LedgerStorage::load_tx(&bytes_to_hex_str(hash)) {
-                    Ok(Some(v)) => v.get_transaction_sign().to_owned(),
-                    Ok(None) => TransactionSign::new(),
-                    Err(e) => TransactionSign::new(),
-                };
-                tx.to_owned()
-            })
-            .collect();
-        let tx_hash_list = if txs.len() > 0 {
-            let mut proto_hash_list = TxHashList::default();
-            proto_hash_list.set_hash_set(protobuf::RepeatedField::from(txs_list.entry.to_vec()));
-            Some(ProtocolParser::serialize::<TxHashList>(&proto_hash_list))
-        } else {
-            None
-        };
+    let txs_list = match LedgerStorage::load_ledger_tx_list(block_num) {
+        Ok(Some(v)) => v,
+        Ok(None) => return Ok(None),
+        Err(e) => bail!(e),
+    };
 
-        let previous_proof_data = match StateStorage::load_proof(block_num - 1) {
-            Ok(Some(v)) => Some(ProtocolParser::serialize::<BftProof>(&v)),
-            Ok(None) => None,
-            Err(e) => bail!(e),
-        };
+    let txs: Vec<TransactionSign> = txs_list
+        .get_entry()
+        .iter()
+        .map(|hash| {
+            let tx = match LedgerStorage::load_tx(&bytes_to_hex_str(hash)) {
+                Ok(Some(v)) => v.get_transaction_sign().to_owned(),
+                Ok(None) => TransactionSign::new(),
+                Err(e) => TransactionSign::new(),
+            };
+            tx.to_owned()
+        })
+        .collect();
+    let tx_hash_list = if txs.len() > 0 {
+        let mut proto_hash_list = TxHashList::default();
+        proto_hash_list.set_hash_set(protobuf::RepeatedField::from(txs_list.entry.to_vec()));
+        Some(ProtocolParser::serialize::<TxHashList>(&proto_hash_list))
+    } else {
+        None
+    };
 
-        let mut block = BlockExecutor::initialize_new_block(
-            header.get_height(),
-            Vec::from(header.get_previous_hash()),
-            header.get_timestamp(),
-            header.get_version(),
-            header.get_tx_count(),
-            header.get_total_tx_count(),
-            header.get_proposer().to_string(),
-            previous_proof_data,
-            tx_hash_list,
-        );
-        block.set_header(header);
-        block.set_transaction_signs(txs.into());
+    let previous_proof_data = match StateStorage::load_proof(block_num - 1) {
+        Ok(Some(v)) => Some(ProtocolParser::serialize::<BftProof>(&v)),
+        Ok(None) => None,
+        Err(e) => bail!(e),
+    };
+
+    let mut block = BlockExecutor::initialize_new_block(
+        header.get_height(),
+        Vec::from(header.get_previous_hash()),
+        header.get_timestamp(),
+        header.get_version(),
+        header.get_tx_count(),
+        header.get_total_tx_count(),
+        header.get_proposer().to_string(),
+        previous_proof_data,
+        tx_hash_list,
+    );
+    block.set_header(header);
+    block.set_transaction_signs(txs.into());
 
-        return Ok(Some(block));
+    // the proof lets the peer check the block was committed before executing it
+    if let Some(proof) = StateStorage::load_proof(block_num)? {
+        BlockExecutor::inject_current_proof(
+            &mut block,
+            ProtocolParser::serialize::<BftProof>(&proof),
+        );
+    }
+    if let Some(proof) = HotStuffStorage::load_proof(block_num)? {
+        BlockExecutor::inject_hotstuff_proof(
+            &mut block,
+            ProtocolParser::serialize::<HotStuffProof>(&proof),
+        );
     }
+
+    return Ok(Some(block));
 }
 
 pub struct StoreageExecutor {
@@ -82,16 +115,151 @@ impl StoreageExecutor {
         Self { block_executor }
     }
 
-    fn execute_verify_block(&self, block: Ledger) -> anyhow::Result<()> {
-        match self.block_executor.execute_verify_block(block) {
-            Ok(_) => Ok(()),
-            Err(e) => bail!(e),
+    /// Check the block carries a commit proof signed by a quorum of the validators in
+    /// effect at its height, the ones set by the block before it.
+    fn verify_block_proof(&self, block: &Ledger) -> anyhow::Result<()> {
+        let header = block.get_header();
+        if header.get_height() <= GENESIS_HEIGHT {
+            bail!("block {} is not synced", header.get_height());
         }
+
+        let validators = match BftStorage::load_validators(header.get_height() - 1)? {
+            Some(v) => v,
+            None => {
+                let last_committed = LAST_COMMITTED_BLOCK_INFO_REF.read();
+                if last_committed.get_header().get_height() + 1 != header.get_height() {
+                    bail!("no validators of block {}", header.get_height() - 1);
+                }
+                last_committed.get_validators().clone()
+            }
+        };
+        check_block_proof(&validators, block)
     }
 }
 
+/// Check the proof of `block` is signed by `validators` for the value the block is built from.
+/// The value is rebuilt from the block itself, its transactions included, so a proof cannot be
+/// reused for another block at the same height.
+pub fn check_block_proof(validators: &ValidatorSet, block: &Ledger) -> anyhow::Result<()> {
+    let height = block.get_header().get_height();
+    // blocks closed by hotstuff carry its proof instead, which checks the transactions too
+    if let Some(data) = BlockExecutor::extract_hotstuff_proof(block) {
+        let proof = ProtocolParser::deserialize::<HotStuffProof>(&data)?;
+        return check_commit_proof(validators, block, &proof);
+    }
+
+    BlockExecutor::verify_tx_hash_list(block)?;
+
+    let proof = match BlockExecutor::extract_proof(block) {
+        Some(data) => ProtocolParser::deserialize::<BftProof>(&data)?,
+        None => bail!("block {} has no consensus proof", height),
+    };
+    let consensus_value_hash = BlockExecutor::caculate_consensus_value_hash(block);
+    if !CheckValue::check_proof(validators, &consensus_value_hash, &proof, &Span::current()) {
+        bail!("block {} consensus proof is not valid", height);
+    }
+    Ok(())
+}
+
 impl StorageExecutorInterface for StoreageExecutor {
     fn execute_verify_block(&self, block: Ledger) -> anyhow::Result<()> {
-        self.block_executor.execute_verify_block(block)
+        let height = block.get_header().get_height();
+        self.verify_block_proof(&block)?;
+        let hotstuff_proof = BlockExecutor::extract_hotstuff_proof(&block);
+        self.block_executor.execute_verify_block(block)?;
+
+        // kept to serve the block on to other peers
+        if let Some(data) = hotstuff_proof {
+            let proof = ProtocolParser::deserialize::<HotStuffProof>(&data)?;
+            HotStuffStorage::store_proof(height, &proof)?;
+        }
+
+        // the next block is checked against the validators this one leaves
+        let validators = LAST_COMMITTED_BLOCK_INFO_REF.read().get_validators().clone();
+        BftStorage::store_validators(height, &validators);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::TestValidators;
+    use protos::ledger::{Transaction, TransactionSign};
+    use types::transaction::SignedTransaction;
+
+    fn transaction(nonce: u64) -> TransactionSign {
+        let mut tx = Transaction::new();
+        tx.set_nonce(nonce);
+        tx.set_value("0".to_string());
+        tx.set_gas_price("1".to_string());
+        let mut tx_sign = TransactionSign::new();
+        tx_sign.set_transaction(tx);
+        tx_sign
+    }
+
+    // a block of `txs` committed by `validators`
+    fn block(validators: &TestValidators, txs: Vec<TransactionSign>) -> Ledger {
+        let mut hash_list = TxHashList::new();
+        for tx in txs.iter() {
+            let tx = SignedTransaction::try_from(tx.clone()).unwrap();
+            hash_list.mut_hash_set().push(tx.hash().to_vec());
+        }
+        let mut block = BlockExecutor::initialize_new_block(
+            9,
+            vec![1; 32],
+            1,
+            1,
+            txs.len() as u64,
+            txs.len() as u64,
+            String::new(),
+            None,
+            Some(ProtocolParser::serialize::<TxHashList>(&hash_list)),
+        );
+        block.set_transaction_signs(txs.into());
+        let value_hash = BlockExecutor::caculate_consensus_value_hash(&block);
+        BlockExecutor::inject_current_proof(
+            &mut block,
+            ProtocolParser::serialize::<BftProof>(&validators.proof(&value_hash, 3)),
+        );
+        block
+    }
+
+    #[test]
+    fn block_proof_test() {
+        configure::init_test_config();
+        let validators = TestValidators::new(4);
+        let block = block(&validators, vec![transaction(1), transaction(2)]);
+        assert!(check_block_proof(&validators.set, &block).is_ok());
+
+        let strangers = TestValidators::new(4);
+        assert!(check_block_proof(&strangers.set, &block).is_err());
+    }
+
+    #[test]
+    fn swapped_transactions_test() {
+        configure::init_test_config();
+        let validators = TestValidators::new(4);
+        let block = block(&validators, vec![transaction(1), transaction(2)]);
+
+        // another transaction under the valid proof
+        let mut swapped = block.clone();
+        swapped.mut_transaction_signs()[1] = transaction(3);
+        assert!(check_block_proof(&validators.set, &swapped).is_err());
+
+        // the proposed transactions out of order
+        let mut reordered = block.clone();
+        reordered.mut_transaction_signs().swap(0, 1);
+        assert!(check_block_proof(&validators.set, &reordered).is_err());
+
+        // a dropped transaction
+        let mut dropped = block.clone();
+        dropped.mut_transaction_signs().pop();
+        assert!(check_block_proof(&validators.set, &dropped).is_err());
+
+        // a forged header field under the reused proof
+        let mut forged = block;
+        forged.mut_header().set_timestamp(2);
+        assert!(check_block_proof(&validators.set, &forged).is_err());
     }
 }
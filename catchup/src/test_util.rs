@@ -0,0 +1,62 @@
+//! Validators with keys and the commit proofs they sign, for the tests of synced blocks.
+use msp::signing::{create_secret_key, PrivateKey};
+use protos::{
+    common::{Validator, ValidatorSet},
+    consensus::{Bft, BftCommit, BftMessageType, BftProof, BftSign},
+};
+use utils::{
+    general::{self_chain_hub, self_chain_id},
+    parse::ProtocolParser,
+    verify_sign::sign,
+};
+
+const ENCRYPTION_TYPE: &str = "eddsa_ed25519";
+
+pub struct TestValidators {
+    pub keys: Vec<Box<dyn PrivateKey>>,
+    pub set: ValidatorSet,
+}
+
+impl TestValidators {
+    pub fn new(count: usize) -> Self {
+        let keys: Vec<Box<dyn PrivateKey>> = (0..count)
+            .map(|_| create_secret_key(ENCRYPTION_TYPE).unwrap())
+            .collect();
+        let mut set = ValidatorSet::new();
+        for key in keys.iter() {
+            let mut validator = Validator::new();
+            validator.set_address(key.get_address());
+            set.mut_validators().push(validator);
+        }
+        Self { keys, set }
+    }
+
+    /// The commits of the first `signers` validators on `value_hash`.
+    pub fn proof(&self, value_hash: &[u8], signers: usize) -> BftProof {
+        let mut proof = BftProof::new();
+        for (replica_id, key) in self.keys.iter().take(signers).enumerate() {
+            let mut commit = BftCommit::new();
+            commit.mut_base().set_replica_id(replica_id as i64);
+            commit.set_value_digest(value_hash.to_vec());
+            let mut bft = Bft::new();
+            bft.set_commit(commit);
+            bft.set_round_number(1);
+            bft.set_msg_type(BftMessageType::COMMIT);
+
+            let mut bft_sign = BftSign::new();
+            bft_sign.set_signature(
+                sign(
+                    &key.as_hex(),
+                    &ProtocolParser::serialize::<Bft>(&bft),
+                    ENCRYPTION_TYPE,
+                )
+                .unwrap(),
+            );
+            bft_sign.set_bft(bft);
+            bft_sign.set_chain_id(self_chain_id());
+            bft_sign.set_chain_hub(self_chain_hub());
+            proof.mut_commits().push(bft_sign);
+        }
+        proof
+    }
+}
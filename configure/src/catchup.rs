@@ -0,0 +1,40 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+#[serde(default)]
+pub struct CatchupConfig {
+    /// blocks asked from one peer in one request
+    pub batch_size: u64,
+    /// bytes of blocks a node answers one request with, at least one block is sent
+    pub response_bytes: u64,
+    /// requests in flight at once, each to a different peer
+    pub max_inflight: usize,
+    /// blocks downloaded ahead of the last executed one
+    pub window: u64,
+    /// seconds a peer has to answer a request before its range goes to another peer
+    pub request_timeout_secs: u64,
+}
+
+impl Default for CatchupConfig {
+    fn default() -> Self {
+        Self {
+            batch_size: 64,
+            response_bytes: 4 * 1024 * 1024,
+            max_inflight: 8,
+            window: 1024,
+            request_timeout_secs: 15,
+        }
+    }
+}
+
+impl Clone for CatchupConfig {
+    fn clone(&self) -> Self {
+        Self {
+            batch_size: self.batch_size,
+            response_bytes: self.response_bytes,
+            max_inflight: self.max_inflight,
+            window: self.window,
+            request_timeout_secs: self.request_timeout_secs,
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{Consensus, Db, GenesisBlock, JsonRpcConfig, P2PNetwork, TxPoolConfig, SSL};
+use crate::{
+    CatchupConfig, Consensus, Db, GenesisBlock, JsonRpcConfig, P2PNetwork, SignerConfig,
+    TxPoolConfig, SSL,
+};
 use serde;
 use serde::Deserialize;
 #[derive(Debug, Deserialize, Clone)]
@@ -8,6 +11,8 @@ pub struct Configure {
     pub chain_hub: String,
     pub ssl_enable: bool,
     pub node_address: String,
+    // empty with an external signer
+    #[serde(default)]
     pub node_private_key: String,
     pub key_version: u64,
     pub p2p_network: P2PNetwork,
@@ -17,4 +22,8 @@ pub struct Configure {
     pub consensus: Consensus,
     pub tx_pool: TxPoolConfig,
     pub json_rpc: JsonRpcConfig,
+    #[serde(default)]
+    pub catchup: CatchupConfig,
+    #[serde(default)]
+    pub signer: SignerConfig,
 }
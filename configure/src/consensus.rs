@@ -1,20 +1,59 @@
 use serde::{Deserialize, Serialize};
 
+pub const DEFAULT_BLOCK_MAX_GAS_LIMIT: u64 = 100_000_000;
+pub const DEFAULT_TX_FETCH_TIMEOUT: i64 = 3000;
+pub const DEFAULT_VIEW_TIMEOUT: i64 = 5000;
+
+pub const CONSENSUS_TYPE_PBFT: &str = "pbft";
+// chained hotstuff, votes go to the next leader only
+pub const CONSENSUS_TYPE_HOTSTUFF: &str = "hotstuff";
+// a single node sealing every commit_interval, no networking, for development and tests
+pub const CONSENSUS_TYPE_SOLO: &str = "solo";
+// as solo, but sealing as soon as the tx-pool holds transactions
+pub const CONSENSUS_TYPE_INSTANT: &str = "instant";
+
+fn default_block_max_gas_limit() -> u64 {
+    DEFAULT_BLOCK_MAX_GAS_LIMIT
+}
+
+fn default_tx_fetch_timeout() -> i64 {
+    DEFAULT_TX_FETCH_TIMEOUT
+}
+
+fn default_view_timeout() -> i64 {
+    DEFAULT_VIEW_TIMEOUT
+}
+
 #[derive(Deserialize, Debug)]
 pub struct Consensus {
     pub consensus_type: String,
     pub block_max_tx_size: u64,
     pub block_max_contract_size: u64,
     pub commit_interval: i64,
+    #[serde(default = "default_block_max_gas_limit")]
+    pub block_max_gas_limit: u64,
+    // fees are credited to the block proposer when empty
+    #[serde(default)]
+    pub fee_treasury: String,
+    // milliseconds a validator gets to send the missing transactions of a proposal
+    #[serde(default = "default_tx_fetch_timeout")]
+    pub tx_fetch_timeout: i64,
+    // milliseconds a hotstuff view waits past commit_interval, doubled per timeout in a row
+    #[serde(default = "default_view_timeout")]
+    pub view_timeout: i64,
 }
 
 impl Default for Consensus {
     fn default() -> Self {
         Self {
-            consensus_type: "pbft".to_string(),
+            consensus_type: CONSENSUS_TYPE_PBFT.to_string(),
             block_max_tx_size: 100000,
             block_max_contract_size: 2500,
             commit_interval: 10000,
+            block_max_gas_limit: DEFAULT_BLOCK_MAX_GAS_LIMIT,
+            fee_treasury: String::new(),
+            tx_fetch_timeout: DEFAULT_TX_FETCH_TIMEOUT,
+            view_timeout: DEFAULT_VIEW_TIMEOUT,
         }
     }
 }
@@ -26,6 +65,10 @@ impl Clone for Consensus {
             block_max_tx_size: self.block_max_tx_size,
             block_max_contract_size: self.block_max_contract_size,
             commit_interval: self.commit_interval,
+            block_max_gas_limit: self.block_max_gas_limit,
+            fee_treasury: self.fee_treasury.clone(),
+            tx_fetch_timeout: self.tx_fetch_timeout,
+            view_timeout: self.view_timeout,
         }
     }
 }
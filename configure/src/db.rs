@@ -1,4 +1,66 @@
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
+use std::{fmt, str::FromStr};
+
+/// How much state history the node keeps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NodeMode {
+    /// every state root stays readable
+    Archive,
+    /// only the state roots of the last N blocks stay readable
+    Pruned(u64),
+    /// no state past genesis, only the block headers and their proofs are synced
+    Light,
+}
+
+impl Default for NodeMode {
+    fn default() -> Self {
+        NodeMode::Archive
+    }
+}
+
+impl FromStr for NodeMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s == "archive" {
+            return Ok(NodeMode::Archive);
+        }
+        if s == "light" {
+            return Ok(NodeMode::Light);
+        }
+        match s
+            .strip_prefix("pruned(")
+            .and_then(|s| s.strip_suffix(')'))
+            .map(|n| n.trim().parse::<u64>())
+        {
+            Some(Ok(n)) if n > 0 => Ok(NodeMode::Pruned(n)),
+            _ => Err(format!(
+                "unknown node mode {}, expected archive, light or pruned(N) with N > 0",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for NodeMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NodeMode::Archive => write!(f, "archive"),
+            NodeMode::Pruned(n) => write!(f, "pruned({})", n),
+            NodeMode::Light => write!(f, "light"),
+        }
+    }
+}
+
+fn deserialize_node_mode<'de, D>(deserializer: D) -> Result<NodeMode, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    s.parse().map_err(serde::de::Error::custom)
+}
+
 #[derive(Deserialize, Debug)]
 pub struct Db {
     pub db_type: String,
@@ -6,6 +68,24 @@ pub struct Db {
     pub key_value_db_path: String,
     pub ledger_db_path: String,
     pub account_db_path: String,
+    /// `archive`, `pruned(N)` or `light`
+    #[serde(default, deserialize_with = "deserialize_node_mode")]
+    pub node_mode: NodeMode,
+    /// directory of the exported state snapshots, also served to syncing peers
+    #[serde(default = "default_snapshot_path")]
+    pub snapshot_path: String,
+    /// a node still at genesis syncs the state from a peer's snapshot instead of
+    /// replaying every block
+    #[serde(default)]
+    pub snapshot_sync: bool,
+    /// export a snapshot of the state every 10000 blocks in the background, syncing peers
+    /// are served the newest snapshot under `snapshot_path`
+    #[serde(default)]
+    pub snapshot_export: bool,
+}
+
+fn default_snapshot_path() -> String {
+    "./data/snapshot".to_string()
 }
 
 impl Clone for Db {
@@ -16,6 +96,10 @@ impl Clone for Db {
             key_value_db_path: self.key_value_db_path.clone(),
             ledger_db_path: self.ledger_db_path.clone(),
             account_db_path: self.account_db_path.clone(),
+            node_mode: self.node_mode,
+            snapshot_path: self.snapshot_path.clone(),
+            snapshot_sync: self.snapshot_sync,
+            snapshot_export: self.snapshot_export,
         }
     }
 }
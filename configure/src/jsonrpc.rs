@@ -9,6 +9,17 @@ pub struct JsonRpcConfig {
     pub content_length_limit: usize,
     pub tls_cert_path: Option<String>,
     pub tls_key_path: Option<String>,
+    /// PEM bundle of the CAs trusted to sign client certificates, enables client auth
+    pub tls_client_ca_path: Option<String>,
+    /// reject clients without a certificate instead of only verifying presented ones
+    #[serde(default)]
+    pub tls_client_auth_required: bool,
+    /// `file` or `db`, none serves every client without an api key
+    pub api_key_store: Option<String>,
+    /// json array of the api keys of the file store
+    pub api_key_file: Option<String>,
+    /// http json-rpc url of the full node a light node forwards the state queries to
+    pub light_forward_url: Option<String>,
 }
 
 pub const DEFAULT_JSON_RPC_ADDRESS: &str = "0.0.0.0";
@@ -24,8 +35,13 @@ impl Clone for JsonRpcConfig {
             batch_size_limit: self.batch_size_limit,
             page_size_limit: self.page_size_limit,
             content_length_limit: self.content_length_limit,
-            tls_cert_path: None,
-            tls_key_path: None,
+            tls_cert_path: self.tls_cert_path.clone(),
+            tls_key_path: self.tls_key_path.clone(),
+            tls_client_ca_path: self.tls_client_ca_path.clone(),
+            tls_client_auth_required: self.tls_client_auth_required,
+            api_key_store: self.api_key_store.clone(),
+            api_key_file: self.api_key_file.clone(),
+            light_forward_url: self.light_forward_url.clone(),
         }
     }
 }
@@ -41,6 +57,11 @@ impl Default for JsonRpcConfig {
             content_length_limit: DEFAULT_CONTENT_LENGTH_LIMIT,
             tls_cert_path: None,
             tls_key_path: None,
+            tls_client_ca_path: None,
+            tls_client_auth_required: false,
+            api_key_store: None,
+            api_key_file: None,
+            light_forward_url: None,
         }
     }
 }
@@ -5,24 +5,31 @@ extern crate serde_derive;
 #[macro_use]
 extern crate config;
 
+mod catchup;
 mod configure;
 mod consensus;
 mod db;
 mod genesis_block;
 mod jsonrpc;
 mod p2p_network;
+mod signer;
 mod ssl;
 mod tx_pool;
 
+pub use catchup::CatchupConfig;
 use config::*;
 pub use configure::Configure;
-pub use consensus::Consensus;
-pub use db::Db;
+pub use consensus::{
+    Consensus, CONSENSUS_TYPE_HOTSTUFF, CONSENSUS_TYPE_INSTANT, CONSENSUS_TYPE_PBFT,
+    CONSENSUS_TYPE_SOLO,
+};
+pub use db::{Db, NodeMode};
 pub use genesis_block::GenesisBlock;
 pub use jsonrpc::JsonRpcConfig;
 use once_cell::sync::Lazy;
 pub use p2p_network::P2PNetwork;
 use parking_lot::RwLock;
+pub use signer::{SignerConfig, SIGNER_TYPE_EXTERNAL, SIGNER_TYPE_LOCAL};
 pub use ssl::SSL;
 pub use tx_pool::TxPoolConfig;
 
@@ -49,3 +56,23 @@ pub fn parse_config(file_path: &str) -> Configure {
 
 pub static CONFIGURE_INSTANCE_REF: Lazy<Configure> =
     Lazy::new(|| parse_config(CONFIG_FILE_PATH.read().as_str()));
+
+/// Points the configuration at `setting/config.toml` with its databases moved to a
+/// temporary directory of the process, for the tests of crates that reach the global
+/// storage. Has to run before the configuration is read first.
+pub fn init_test_config() {
+    static INIT: std::sync::Once = std::sync::Once::new();
+    INIT.call_once(|| {
+        let setting = concat!(env!("CARGO_MANIFEST_DIR"), "/../setting/config.toml");
+        let dir = std::env::temp_dir().join(format!("geno-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("create test data dir");
+        let content = std::fs::read_to_string(setting)
+            .expect("read setting/config.toml")
+            .replace("\"./data/", &format!("\"{}/", dir.display()));
+        let path = dir.join("config.toml");
+        std::fs::write(&path, content).expect("write test config");
+        CONFIG_FILE_PATH
+            .write()
+            .clone_from(&path.display().to_string());
+    });
+}
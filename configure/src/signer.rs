@@ -0,0 +1,45 @@
+use serde::Deserialize;
+
+/// Signs with `node_private_key` from the configuration.
+pub const SIGNER_TYPE_LOCAL: &str = "local";
+/// Asks the signer listening on `socket_path`, the key never enters the node.
+pub const SIGNER_TYPE_EXTERNAL: &str = "external";
+
+#[derive(Deserialize, Debug)]
+#[serde(default)]
+pub struct SignerConfig {
+    /// local or external
+    pub signer_type: String,
+    /// eddsa_ed25519, secp256k1 or sm2
+    pub encryption_type: String,
+    /// unix socket of the external signer, a key daemon or a bridge to a PKCS#11 module
+    pub socket_path: String,
+    /// the key the external signer signs with
+    pub key_id: String,
+    /// milliseconds the external signer has to answer a request
+    pub timeout: u64,
+}
+
+impl Default for SignerConfig {
+    fn default() -> Self {
+        Self {
+            signer_type: SIGNER_TYPE_LOCAL.to_string(),
+            encryption_type: "eddsa_ed25519".to_string(),
+            socket_path: String::new(),
+            key_id: String::new(),
+            timeout: 3000,
+        }
+    }
+}
+
+impl Clone for SignerConfig {
+    fn clone(&self) -> Self {
+        Self {
+            signer_type: self.signer_type.clone(),
+            encryption_type: self.encryption_type.clone(),
+            socket_path: self.socket_path.clone(),
+            key_id: self.key_id.clone(),
+            timeout: self.timeout,
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crossbeam_channel::{bounded, Receiver};
+use network::PeerNetwork;
+use protos::{common::ProtocolsMessageType, consensus::Consensus, ledger::Ledger};
+use tracing::error;
+use utils::{parse::ProtocolParser, timer_manager::TimterEventParam};
+
+use crate::engine::ConsensusEngine;
+
+/// Run `engine` on its own thread, feeding it the consensus messages of `network`, the rpc
+/// requests it registers, its timers and the blocks committed by catch-up.
+pub fn start_engine<E>(
+    mut engine: E,
+    network: PeerNetwork,
+    timer_receiver: Receiver<TimterEventParam>,
+    commit_notify: Receiver<Ledger>,
+) where
+    E: ConsensusEngine + Send + 'static,
+{
+    let subscriber = network.add_subscriber(ProtocolsMessageType::CONSENSUS);
+    let (rpc_sender, rpc_receiver) = bounded(1024);
+    for msg_type in engine.rpc_message_types() {
+        network.register_rpc_handler(msg_type, rpc_sender.clone());
+    }
+    let consensus_type = engine.consensus_type();
+
+    std::thread::spawn(move || {
+        engine.start();
+        loop {
+            crossbeam_channel::select! {
+                recv(timer_receiver) -> para =>{
+                    match para {
+                        Ok(param)=>{
+                            engine.handle_timer(param);
+                        }
+                        Err(e)=>{
+                            error!("{:?}", e);
+                        }
+                    }
+                }
+                recv(subscriber.inbox) -> msg =>{
+                    match msg {
+                        Ok((_, (_, proto_message)))=>{
+                            match ProtocolParser::deserialize::<Consensus>(proto_message.get_data()) {
+                                Ok(consensus_message) =>{
+                                    if consensus_message.get_consensus_type() == consensus_type {
+                                        engine.handle_message(consensus_message.get_msg());
+                                    }
+                                },
+                                Err(e) => {
+                                    error!("{:?}", e);
+                                }
+                            }
+                        }
+                        Err(e)=>{
+                            error!("{:?}", e);
+                        }
+                    }
+                }
+                recv(rpc_receiver) -> msg =>{
+                    match msg {
+                        Ok((peer, proto_message))=>{
+                            engine.handle_rpc(peer, &proto_message);
+                        }
+                        Err(e)=>{
+                            error!("{:?}", e);
+                        }
+                    }
+                }
+                recv(commit_notify) -> para =>{
+                    match para {
+                        Ok(ledger)=>{
+                            engine.handle_commit(ledger);
+                        }
+                        Err(e)=>{
+                            error!("{:?}", e);
+                        }
+                    }
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,45 @@
+use network::Endpoint;
+use protos::{
+    common::{ProtocolsMessage, ProtocolsMessageType, TransactionResult, ValidatorSet},
+    consensus::ConsensusType,
+    ledger::Ledger,
+};
+use utils::timer_manager::TimterEventParam;
+
+pub type WsPublishEventSender =
+    tokio::sync::mpsc::UnboundedSender<(Ledger, Vec<TransactionResult>)>;
+
+/// A consensus algorithm driven by `start_engine`. An engine is built with the
+/// `CommitNotificationSender` of the tx-pool, the ws publish sender and its timer sender, it
+/// hears of the blocks committed by catch-up through the driver, so it never touches those
+/// channels' other ends.
+pub trait ConsensusEngine {
+    /// The type tagging this engine's messages inside `Consensus`, others are dropped.
+    fn consensus_type(&self) -> ConsensusType;
+
+    /// Message types received as direct requests, next to the CONSENSUS broadcasts.
+    fn rpc_message_types(&self) -> Vec<ProtocolsMessageType> {
+        Vec::new()
+    }
+
+    /// Start the engine's timers, called once from the driver thread.
+    fn start(&mut self);
+
+    /// Propose the next block when this node leads, false when no proposal went out.
+    fn propose(&mut self) -> bool;
+
+    /// The `msg` of a `Consensus` message of this engine's type.
+    fn handle_message(&mut self, msg: &[u8]);
+
+    /// A message of one of `rpc_message_types`.
+    fn handle_rpc(&mut self, _peer: Endpoint, _msg: &ProtocolsMessage) {}
+
+    /// A block committed by catch-up.
+    fn handle_commit(&mut self, block: Ledger);
+
+    /// The validators in effect after the block at `height`.
+    fn apply_validators(&mut self, height: u64, validators: &ValidatorSet);
+
+    /// One of the engine's timers fired.
+    fn handle_timer(&mut self, param: TimterEventParam);
+}
@@ -0,0 +1,5 @@
+pub mod driver;
+pub mod engine;
+
+pub use driver::start_engine;
+pub use engine::{ConsensusEngine, WsPublishEventSender};
@@ -0,0 +1,115 @@
+use consensus_engine::{start_engine, ConsensusEngine, WsPublishEventSender};
+use crossbeam_channel::{bounded, Receiver};
+use executor::LAST_COMMITTED_BLOCK_INFO_REF;
+use network::{Endpoint, PeerNetwork};
+use protos::{
+    common::{ProtocolsMessage, ProtocolsMessageType, ValidatorSet},
+    consensus::{Consensus, ConsensusType},
+    ledger::Ledger,
+};
+use tracing::{error, info};
+use tx_pool::types::CommitNotificationSender;
+use utils::{
+    parse::ProtocolParser,
+    timer_manager::{TimerEventType, TimerManager, TimterEventParam},
+};
+
+use crate::hotstuff_consensus::HotStuffConsensus;
+
+pub fn start_hotstuff_consensus(
+    network_tx: PeerNetwork,
+    network_consensus: PeerNetwork,
+    commit_to_txpool_sender: CommitNotificationSender,
+    ws_publish_event_sender: WsPublishEventSender,
+    commit_notify: Receiver<Ledger>,
+) {
+    let (timer_sender, timer_receiver) = bounded::<TimterEventParam>(1024);
+    let consensus = HotStuffConsensus::new(
+        timer_sender,
+        network_tx,
+        network_consensus.clone(),
+        commit_to_txpool_sender,
+        ws_publish_event_sender,
+    );
+    start_engine(consensus, network_consensus, timer_receiver, commit_notify);
+}
+
+impl ConsensusEngine for HotStuffConsensus {
+    fn consensus_type(&self) -> ConsensusType {
+        ConsensusType::HOTSTUFF
+    }
+
+    // proposals, votes and new views go straight to their receivers as requests
+    fn rpc_message_types(&self) -> Vec<ProtocolsMessageType> {
+        vec![ProtocolsMessageType::CONSENSUS]
+    }
+
+    fn start(&mut self) {
+        info!(
+            "Start hotstuff consensus, validator({})",
+            self.is_validator()
+        );
+        let height = LAST_COMMITTED_BLOCK_INFO_REF
+            .read()
+            .get_header()
+            .get_height();
+        self.apply_committed_validators(height);
+        self.execute_commits();
+        self.start_view_timer();
+        self.schedule_propose();
+    }
+
+    fn propose(&mut self) -> bool {
+        self.propose_node()
+    }
+
+    fn handle_message(&mut self, msg: &[u8]) {
+        self.handle_hotstuff(msg);
+    }
+
+    fn handle_rpc(&mut self, _peer: Endpoint, msg: &ProtocolsMessage) {
+        match ProtocolParser::deserialize::<Consensus>(msg.get_data()) {
+            Ok(consensus) => {
+                if consensus.get_consensus_type() == ConsensusType::HOTSTUFF {
+                    self.handle_hotstuff(consensus.get_msg());
+                }
+            }
+            Err(e) => {
+                error!("{:?}", e);
+            }
+        }
+    }
+
+    fn handle_commit(&mut self, block: Ledger) {
+        self.handle_caught_up(block);
+    }
+
+    fn apply_validators(&mut self, height: u64, validators: &ValidatorSet) {
+        self.update_validators(height, validators);
+    }
+
+    fn handle_timer(&mut self, param: TimterEventParam) {
+        match param.event_type {
+            TimerEventType::HotStuffPropose => {
+                TimerManager::instance().delete_timer(param.id);
+                if param.id == self.propose_timer_id {
+                    self.propose();
+                }
+            }
+            TimerEventType::HotStuffViewTimeout => {
+                TimerManager::instance().delete_timer(param.id);
+                if param.id != self.view_timer_id {
+                    return;
+                }
+                if let Some(data) = param.data {
+                    let mut view = [0u8; 8];
+                    if data.len() == view.len() {
+                        view.copy_from_slice(&data);
+                        self.handle_view_timeout(i64::from_be_bytes(view));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,1300 @@
+use configure::CONFIGURE_INSTANCE_REF;
+use consensus_engine::WsPublishEventSender;
+use consensus_pbft::{utils::quorum_size, validators::Validators};
+use consensus_store::{bft_storage::BftStorage, hotstuff_storage::HotStuffStorage};
+use executor::{block_result::BlockResult, BlockExecutor, LAST_COMMITTED_BLOCK_INFO_REF};
+use msp::bytes_to_hex_str;
+use network::PeerNetwork;
+use protobuf::RepeatedField;
+use protos::{
+    common::{Signature, ValidatorSet},
+    consensus::{
+        HotStuff, HotStuffMessageType, HotStuffNewView, HotStuffNode, HotStuffProof, HotStuffQc,
+        HotStuffSign, HotStuffState, HotStuffVote, TxHashList,
+    },
+    ledger::{Ledger, TransactionSign},
+};
+use std::collections::{HashMap, HashSet, VecDeque};
+use tracing::{error, info};
+use tx_pool::{
+    types::{CommitNotificationSender, TxPoolCommitNotification, TxPoolCommitted},
+    TX_POOL_INSTANCE_REF,
+};
+use utils::{
+    general::{self_chain_hub, self_chain_id, LEDGER_VERSION},
+    parse::ProtocolParser,
+    signer::node_signer,
+    timer_manager::{TimerEventType, TimerManager, TimerSender},
+    verify_sign::{get_sign_address, verify_sign},
+};
+
+use crate::{
+    message::{check_sign, consensus_message, node_hash, vote_content},
+    proof::check_qc,
+};
+
+// a view timeout doubles at most this many times in a row
+const MAX_TIMEOUT_DOUBLING: u32 = 6;
+// votes and new views further ahead of the current view are dropped, they bound what a
+// validator can make the leader hold
+const MAX_VIEWS_AHEAD: i64 = 64;
+
+/// What the engine needs to know of a node to extend it. The committed one may be a ledger
+/// closed before hotstuff, then it is known by its ledger hash at view 0.
+#[derive(Clone)]
+struct NodeInfo {
+    hash: Vec<u8>,
+    height: u64,
+    view: i64,
+    timestamp: i64,
+}
+
+impl NodeInfo {
+    fn of(node: &HotStuffNode) -> Self {
+        Self {
+            hash: node_hash(node),
+            height: node.get_height(),
+            view: node.get_view(),
+            timestamp: node.get_timestamp(),
+        }
+    }
+}
+
+/// Chained HotStuff: every view one leader proposes a node extending the highest qc, the
+/// validators vote to the leader of the next view only, whose qc certifies the node and goes
+/// out with the next proposal. A node is committed once three nodes of consecutive heights
+/// are certified on top of each other. A view change is a single NEW_VIEW carrying the highest
+/// qc to the next leader, which proceeds as soon as a quorum of them arrived.
+pub struct HotStuffConsensus {
+    node_address: String,
+    validators: Validators,
+    // the view waiting for a proposal
+    view: i64,
+    last_voted_view: i64,
+    // the qc of the highest view, the next proposal extends its node
+    high_qc: HotStuffQc,
+    // votes only go to nodes extending it, unless their qc is newer
+    locked_hash: Vec<u8>,
+    committed: NodeInfo,
+    // the last node decided by a three-chain, maybe not executed yet
+    decided_hash: Vec<u8>,
+    decided_height: u64,
+    // the nodes above the committed one
+    nodes: HashMap<Vec<u8>, HotStuffNode>,
+    // the proofs of the decided nodes waiting for their execution, lowest first
+    commit_queue: VecDeque<HotStuffProof>,
+    // the votes collected as the next leader by (view, height, node hash)
+    votes: HashMap<(i64, u64, Vec<u8>), HashMap<String, Signature>>,
+    new_views: HashMap<i64, HashSet<String>>,
+    proposed_view: i64,
+    // view timeouts in a row
+    timeouts: u32,
+    pub(crate) propose_timer_id: i64,
+    pub(crate) view_timer_id: i64,
+    timer_sender: TimerSender,
+    network_tx: PeerNetwork,
+    network_consensus: PeerNetwork,
+    commit_to_txpool_sender: CommitNotificationSender,
+    ws_publish_event_sender: WsPublishEventSender,
+    last_commit_txs: HashMap<String, TxPoolCommitted>,
+}
+
+impl HotStuffConsensus {
+    pub(crate) fn new(
+        timer_sender: TimerSender,
+        network_tx: PeerNetwork,
+        network_consensus: PeerNetwork,
+        commit_to_txpool_sender: CommitNotificationSender,
+        ws_publish_event_sender: WsPublishEventSender,
+    ) -> Self {
+        let (lcl, validators_set) = {
+            let lcl = LAST_COMMITTED_BLOCK_INFO_REF.read();
+            (lcl.get_header().clone(), lcl.get_validators().clone())
+        };
+        let mut validators = Validators::default();
+        validators.update_validators(&validators_set);
+
+        let mut committed = NodeInfo {
+            hash: lcl.get_hash().to_vec(),
+            height: lcl.get_height(),
+            view: 0,
+            timestamp: lcl.get_timestamp(),
+        };
+        let proof = match HotStuffStorage::load_proof(lcl.get_height()) {
+            Ok(proof) => proof,
+            Err(e) => {
+                error!(
+                    "Failed to load the hotstuff proof of ledger({}): {}",
+                    lcl.get_height(),
+                    e
+                );
+                None
+            }
+        };
+        if let Some(node) = proof.as_ref().and_then(|p| p.get_chain().first()) {
+            committed = NodeInfo::of(node);
+        }
+
+        let mut consensus = Self::with_committed(
+            committed,
+            validators,
+            timer_sender,
+            network_tx,
+            network_consensus,
+            commit_to_txpool_sender,
+            ws_publish_event_sender,
+        );
+        // the nodes certified on top of the committed one
+        if let Some(proof) = proof {
+            consensus.insert_chain(&proof);
+        }
+        consensus.restore_state();
+        consensus
+    }
+
+    // the engine right above `committed`, knowing no node on top of it yet
+    fn with_committed(
+        committed: NodeInfo,
+        validators: Validators,
+        timer_sender: TimerSender,
+        network_tx: PeerNetwork,
+        network_consensus: PeerNetwork,
+        commit_to_txpool_sender: CommitNotificationSender,
+        ws_publish_event_sender: WsPublishEventSender,
+    ) -> Self {
+        let mut consensus = Self {
+            node_address: node_signer().address().to_string(),
+            validators,
+            view: committed.view + 1,
+            last_voted_view: committed.view,
+            high_qc: HotStuffQc::new(),
+            locked_hash: committed.hash.clone(),
+            decided_hash: committed.hash.clone(),
+            decided_height: committed.height,
+            committed,
+            nodes: HashMap::default(),
+            commit_queue: VecDeque::default(),
+            votes: HashMap::default(),
+            new_views: HashMap::default(),
+            proposed_view: 0,
+            timeouts: 0,
+            propose_timer_id: 0,
+            view_timer_id: 0,
+            timer_sender,
+            network_tx,
+            network_consensus,
+            commit_to_txpool_sender,
+            ws_publish_event_sender,
+            last_commit_txs: HashMap::default(),
+        };
+        consensus.high_qc = consensus.committed_qc();
+        consensus
+    }
+
+    pub(crate) fn is_validator(&self) -> bool {
+        self.validators.contains(&self.node_address)
+    }
+
+    pub(crate) fn quorum(&self) -> usize {
+        quorum_size(self.validators.len()) + 1
+    }
+
+    /// The leader of `view`, validators take turns by replica id.
+    pub(crate) fn leader(&self, view: i64) -> Option<String> {
+        let validators = self.validators.iter();
+        if validators.is_empty() {
+            return None;
+        }
+        let index = view.rem_euclid(validators.len() as i64) as usize;
+        validators.get(index).map(|(address, _)| address.clone())
+    }
+
+    fn is_leader(&self, view: i64) -> bool {
+        self.leader(view).as_deref() == Some(self.node_address.as_str())
+    }
+
+    fn node_info(&self, hash: &[u8]) -> Option<NodeInfo> {
+        if hash == self.committed.hash.as_slice() {
+            return Some(self.committed.clone());
+        }
+        self.nodes.get(hash).map(NodeInfo::of)
+    }
+
+    fn locked_view(&self) -> i64 {
+        self.node_info(&self.locked_hash)
+            .map(|info| info.view)
+            .unwrap_or(self.committed.view)
+    }
+
+    // the qc of the committed node, nobody needs its signatures any more
+    fn committed_qc(&self) -> HotStuffQc {
+        let mut qc = HotStuffQc::new();
+        qc.set_view(self.committed.view);
+        qc.set_height(self.committed.height);
+        qc.set_node_hash(self.committed.hash.clone());
+        qc
+    }
+
+    fn check_qc(&self, qc: &HotStuffQc) -> bool {
+        if qc.get_node_hash() == self.committed.hash.as_slice()
+            && qc.get_view() == self.committed.view
+            && qc.get_height() == self.committed.height
+        {
+            return true;
+        }
+        check_qc(&self.validators, qc)
+    }
+
+    // does the node `hash` descend from `ancestor`
+    fn extends(&self, mut hash: &[u8], ancestor: &[u8]) -> bool {
+        loop {
+            if hash == ancestor {
+                return true;
+            }
+            match self.nodes.get(hash) {
+                Some(node) => hash = node.get_parent_hash(),
+                None => return false,
+            }
+        }
+    }
+
+    fn insert_chain(&mut self, proof: &HotStuffProof) {
+        for node in proof.get_chain().iter() {
+            if node.get_height() > self.committed.height {
+                self.nodes.insert(node_hash(node), node.clone());
+            }
+        }
+        self.update_high_qc(proof.get_qc().clone());
+    }
+
+    fn restore_state(&mut self) {
+        let state = match HotStuffStorage::load_state() {
+            Ok(Some(state)) => state,
+            Ok(None) => return,
+            Err(e) => {
+                error!("Failed to load the hotstuff state: {}", e);
+                return;
+            }
+        };
+        for node in state.get_nodes().iter() {
+            if node.get_height() > self.committed.height {
+                self.nodes.insert(node_hash(node), node.clone());
+            }
+        }
+        self.view = self.view.max(state.get_view());
+        self.last_voted_view = self.last_voted_view.max(state.get_last_voted_view());
+        if self.nodes.contains_key(state.get_high_qc().get_node_hash()) {
+            self.update_high_qc(state.get_high_qc().clone());
+        }
+        if let Some(locked) = self.node_info(state.get_locked_hash()) {
+            if locked.view > self.locked_view() {
+                self.locked_hash = locked.hash;
+            }
+        }
+        info!(
+            "Restore hotstuff state: view({}), last voted view({}), nodes({})",
+            self.view,
+            self.last_voted_view,
+            self.nodes.len()
+        );
+    }
+
+    pub(crate) fn store_state(&self) {
+        let mut state = HotStuffState::new();
+        state.set_view(self.view);
+        state.set_last_voted_view(self.last_voted_view);
+        state.set_high_qc(self.high_qc.clone());
+        state.set_locked_hash(self.locked_hash.clone());
+        state.set_nodes(RepeatedField::from(
+            self.nodes.values().cloned().collect::<Vec<HotStuffNode>>(),
+        ));
+        if let Err(e) = HotStuffStorage::store_state(&state) {
+            error!("Failed to store the hotstuff state: {}", e);
+        }
+    }
+
+    fn sign_data(&self, data: &[u8]) -> Signature {
+        match node_signer().sign(data) {
+            Ok(signature) => signature,
+            Err(e) => {
+                error!("sign error:{}", e);
+                Signature::default()
+            }
+        }
+    }
+
+    fn sign_message(&self, hotstuff: HotStuff) -> HotStuffSign {
+        let mut hotstuff_sign = HotStuffSign::new();
+        hotstuff_sign.set_signature(self.sign_data(&ProtocolParser::serialize(&hotstuff)));
+        hotstuff_sign.set_hotstuff(hotstuff);
+        hotstuff_sign.set_chain_id(self_chain_id());
+        hotstuff_sign.set_chain_hub(self_chain_hub());
+        hotstuff_sign
+    }
+
+    fn send_to_validators(&self, hotstuff: HotStuff) {
+        let msg = consensus_message(&self.sign_message(hotstuff));
+        for (address, endpoint) in self.network_consensus.validator_conn_ids() {
+            if address != self.node_address {
+                self.network_consensus.send_msg(endpoint, msg.clone());
+            }
+        }
+    }
+
+    fn send_to_leader(&mut self, view: i64, hotstuff: HotStuff) {
+        let leader = match self.leader(view) {
+            Some(leader) => leader,
+            None => return,
+        };
+        if leader == self.node_address {
+            let address = self.node_address.clone();
+            self.dispatch(&address, &hotstuff);
+            return;
+        }
+        let msg = consensus_message(&self.sign_message(hotstuff));
+        match self.network_consensus.validator_conn_ids().get(&leader) {
+            Some(endpoint) => {
+                self.network_consensus.send_msg(*endpoint, msg);
+            }
+            None => {
+                info!("The leader {} of view {} is not connected", leader, view);
+            }
+        }
+    }
+
+    /// A `HotStuffSign` received from a validator.
+    pub(crate) fn handle_hotstuff(&mut self, msg: &[u8]) {
+        let hotstuff_sign = match ProtocolParser::deserialize::<HotStuffSign>(msg) {
+            Ok(hotstuff_sign) => hotstuff_sign,
+            Err(e) => {
+                error!("Failed to deserialize the hotstuff message: {}", e);
+                return;
+            }
+        };
+        if let Some(sender) = check_sign(&hotstuff_sign, &self.validators) {
+            self.dispatch(&sender, hotstuff_sign.get_hotstuff());
+        }
+    }
+
+    fn dispatch(&mut self, sender: &str, hotstuff: &HotStuff) {
+        match hotstuff.get_msg_type() {
+            HotStuffMessageType::PROPOSAL => {
+                self.handle_proposal(sender, hotstuff.get_proposal().clone())
+            }
+            HotStuffMessageType::VOTE => self.handle_vote(sender, hotstuff.get_vote()),
+            HotStuffMessageType::NEW_VIEW => self.handle_new_view(sender, hotstuff.get_new_view()),
+        }
+    }
+
+    /// Propose a node extending the highest qc when this node leads the current view.
+    pub(crate) fn propose_node(&mut self) -> bool {
+        if !self.is_validator() || !self.is_leader(self.view) || self.proposed_view >= self.view {
+            return false;
+        }
+        let parent_hash = self.high_qc.get_node_hash().to_vec();
+        let parent = match self.node_info(&parent_hash) {
+            Some(parent) => parent,
+            None => return false,
+        };
+
+        let hash_list = TX_POOL_INSTANCE_REF.read().get_block_hash_list(
+            CONFIGURE_INSTANCE_REF.consensus.block_max_tx_size,
+            CONFIGURE_INSTANCE_REF.consensus.block_max_contract_size,
+            CONFIGURE_INSTANCE_REF.consensus.block_max_gas_limit,
+            &self.pending_txs(&parent_hash),
+        );
+        let now = chrono::Local::now().timestamp_millis();
+        let mut node = HotStuffNode::new();
+        node.set_view(self.view);
+        node.set_height(parent.height + 1);
+        node.set_parent_hash(parent_hash);
+        node.set_justify(self.high_qc.clone());
+        node.set_timestamp(now.max(parent.timestamp + 1));
+        node.set_proposer(self.node_address.clone());
+        node.set_version(
+            LAST_COMMITTED_BLOCK_INFO_REF
+                .read()
+                .get_header()
+                .get_version(),
+        );
+        node.set_tx_hashes(RepeatedField::from(hash_list));
+        self.proposed_view = self.view;
+
+        info!(
+            "Propose node({}) at view {}, txs({}), parent({})",
+            node.get_height(),
+            node.get_view(),
+            node.get_tx_hashes().len(),
+            bytes_to_hex_str(node.get_parent_hash())
+        );
+        let mut hotstuff = HotStuff::new();
+        hotstuff.set_msg_type(HotStuffMessageType::PROPOSAL);
+        hotstuff.set_proposal(node.clone());
+        self.send_to_validators(hotstuff);
+        let address = self.node_address.clone();
+        self.handle_proposal(&address, node);
+        true
+    }
+
+    // the transactions of the uncommitted ancestors from `hash` on, on top of the last block's
+    fn pending_txs(&self, mut hash: &[u8]) -> HashMap<String, TxPoolCommitted> {
+        let mut transactions = self.last_commit_txs.clone();
+        let pool = TX_POOL_INSTANCE_REF.read();
+        while let Some(node) = self.nodes.get(hash) {
+            for tx_hash in node.get_tx_hashes().iter() {
+                if let Some(tx) = pool.get_by_hash(tx_hash) {
+                    let committed =
+                        transactions
+                            .entry(tx.sender().to_string())
+                            .or_insert_with(|| TxPoolCommitted {
+                                sender: tx.sender().to_string(),
+                                max_seq: tx.nonce(),
+                                seqs: HashSet::default(),
+                            });
+                    committed.max_seq = committed.max_seq.max(tx.nonce());
+                    committed.seqs.insert(tx.nonce());
+                }
+            }
+            hash = node.get_parent_hash();
+        }
+        transactions
+    }
+
+    fn handle_proposal(&mut self, sender: &str, node: HotStuffNode) {
+        let view = node.get_view();
+        if self.leader(view).as_deref() != Some(sender) {
+            error!(
+                "The proposal of view {} is not from its leader but {}",
+                view, sender
+            );
+            return;
+        }
+        let hash = node_hash(&node);
+        if self.nodes.contains_key(&hash) {
+            return;
+        }
+        let parent = match self.node_info(node.get_parent_hash()) {
+            Some(parent) => parent,
+            None => {
+                info!(
+                    "The parent of node({}) at view {} is unknown",
+                    node.get_height(),
+                    view
+                );
+                return;
+            }
+        };
+        let justify = node.get_justify();
+        if justify.get_node_hash() != node.get_parent_hash()
+            || justify.get_view() != parent.view
+            || justify.get_height() != parent.height
+            || view <= justify.get_view()
+            || node.get_height() != parent.height + 1
+            || node.get_timestamp() <= parent.timestamp
+        {
+            error!(
+                "The proposal of view {} does not extend the node of its qc",
+                view
+            );
+            return;
+        }
+        if !self.check_qc(justify) {
+            return;
+        }
+
+        info!(
+            "Receive node({}) proposal of view {} from {}, txs({})",
+            node.get_height(),
+            view,
+            sender,
+            node.get_tx_hashes().len()
+        );
+        let justify = justify.clone();
+        self.nodes.insert(hash.clone(), node.clone());
+        self.update_high_qc(justify);
+        self.try_form_qc(&hash);
+        if view < self.view {
+            return;
+        }
+        if self.can_vote(&node) {
+            self.vote(&hash, &node);
+        }
+        self.enter_view(view + 1, true);
+        self.schedule_propose();
+    }
+
+    fn can_vote(&self, node: &HotStuffNode) -> bool {
+        if !self.is_validator() || node.get_view() <= self.last_voted_view {
+            return false;
+        }
+        // safe when the node extends the locked one, or its qc is newer than the lock
+        if node.get_justify().get_view() <= self.locked_view()
+            && !self.extends(node.get_parent_hash(), &self.locked_hash)
+        {
+            info!(
+                "Node({}) at view {} conflicts with the locked node, no vote",
+                node.get_height(),
+                node.get_view()
+            );
+            return false;
+        }
+        let now = chrono::Local::now().timestamp_millis();
+        if node.get_timestamp() > now + CONFIGURE_INSTANCE_REF.consensus.commit_interval
+            || node.get_version() > LEDGER_VERSION
+            || node.get_tx_hashes().len() as u64
+                > CONFIGURE_INSTANCE_REF.consensus.block_max_tx_size
+        {
+            error!(
+                "Node({}) at view {} is not valid, no vote",
+                node.get_height(),
+                node.get_view()
+            );
+            return false;
+        }
+        let (tx_list, lack_txs) = TX_POOL_INSTANCE_REF
+            .read()
+            .get_block_by_hashs(node.get_tx_hashes());
+        if !lack_txs.is_empty() {
+            info!(
+                "{} transactions of node({}) are not in the tx-pool, no vote",
+                lack_txs.len(),
+                node.get_height()
+            );
+            return false;
+        }
+        if !BlockExecutor::within_gas_limit(&tx_list) {
+            error!(
+                "Transactions of node({}) are over the block gas limit, no vote",
+                node.get_height()
+            );
+            return false;
+        }
+        true
+    }
+
+    fn vote(&mut self, hash: &[u8], node: &HotStuffNode) {
+        // never vote twice in a view, even across a restart
+        self.last_voted_view = node.get_view();
+        self.store_state();
+
+        let mut vote = HotStuffVote::new();
+        vote.set_view(node.get_view());
+        vote.set_height(node.get_height());
+        vote.set_node_hash(hash.to_vec());
+        vote.set_chain_id(self_chain_id());
+        vote.set_signature(self.sign_data(&vote_content(
+            node.get_view(),
+            node.get_height(),
+            hash,
+            &self_chain_id(),
+        )));
+        let mut hotstuff = HotStuff::new();
+        hotstuff.set_msg_type(HotStuffMessageType::VOTE);
+        hotstuff.set_vote(vote);
+        self.send_to_leader(node.get_view() + 1, hotstuff);
+    }
+
+    fn handle_vote(&mut self, sender: &str, vote: &HotStuffVote) {
+        if vote.get_view() + 1 < self.view
+            || vote.get_view() > self.view + MAX_VIEWS_AHEAD
+            || vote.get_height() <= self.committed.height
+            || !self.is_leader(vote.get_view() + 1)
+            || vote.get_chain_id() != self_chain_id()
+        {
+            return;
+        }
+        match get_sign_address(vote.get_signature()) {
+            Ok(address) if address == sender => {}
+            _ => {
+                error!(
+                    "The vote of view {} is not signed by {}",
+                    vote.get_view(),
+                    sender
+                );
+                return;
+            }
+        }
+        let content = vote_content(
+            vote.get_view(),
+            vote.get_height(),
+            vote.get_node_hash(),
+            vote.get_chain_id(),
+        );
+        match verify_sign(vote.get_signature(), &content) {
+            Ok(true) => {}
+            _ => {
+                error!("Failed to check the vote signature of {}", sender);
+                return;
+            }
+        }
+        // a validator votes once in a view
+        let voted = self.votes.iter().any(|((view, _, hash), votes)| {
+            *view == vote.get_view()
+                && hash.as_slice() != vote.get_node_hash()
+                && votes.contains_key(sender)
+        });
+        if voted {
+            error!("{} voted for two nodes at view {}", sender, vote.get_view());
+            return;
+        }
+
+        self.votes
+            .entry((
+                vote.get_view(),
+                vote.get_height(),
+                vote.get_node_hash().to_vec(),
+            ))
+            .or_default()
+            .insert(sender.to_string(), vote.get_signature().clone());
+        self.try_form_qc(vote.get_node_hash());
+    }
+
+    fn try_form_qc(&mut self, hash: &[u8]) {
+        let node = match self.nodes.get(hash) {
+            Some(node) => node.clone(),
+            None => return,
+        };
+        let key = (node.get_view(), node.get_height(), hash.to_vec());
+        match self.votes.get(&key) {
+            Some(votes) if votes.len() >= self.quorum() => {}
+            _ => return,
+        }
+        let signatures = match self.votes.remove(&key) {
+            Some(votes) => votes.into_values().collect::<Vec<Signature>>(),
+            None => return,
+        };
+
+        let mut qc = HotStuffQc::new();
+        qc.set_view(node.get_view());
+        qc.set_height(node.get_height());
+        qc.set_node_hash(hash.to_vec());
+        qc.set_signatures(RepeatedField::from(signatures));
+        info!(
+            "Form the qc of node({}) at view {}",
+            node.get_height(),
+            node.get_view()
+        );
+        self.update_high_qc(qc);
+        self.enter_view(node.get_view() + 1, true);
+        self.schedule_propose();
+    }
+
+    fn handle_new_view(&mut self, sender: &str, new_view: &HotStuffNewView) {
+        let view = new_view.get_view();
+        if view < self.view || view > self.view + MAX_VIEWS_AHEAD || !self.is_leader(view) {
+            return;
+        }
+        let qc = new_view.get_high_qc();
+        if qc.get_view() > self.high_qc.get_view()
+            && self.node_info(qc.get_node_hash()).is_some()
+            && self.check_qc(qc)
+        {
+            self.update_high_qc(qc.clone());
+        }
+
+        let quorum = self.quorum();
+        let senders = self.new_views.entry(view).or_default();
+        senders.insert(sender.to_string());
+        if senders.len() >= quorum {
+            info!("A quorum moved to view {} led by this node", view);
+            self.enter_view(view, false);
+            self.schedule_propose();
+        }
+    }
+
+    /// Extend the highest qc with `qc`, lock the node its node certifies, and commit the
+    /// grandparent when the three form a direct chain.
+    fn update_high_qc(&mut self, qc: HotStuffQc) {
+        if !self.nodes.contains_key(qc.get_node_hash()) {
+            return;
+        }
+        if qc.get_view() > self.high_qc.get_view() {
+            self.high_qc = qc.clone();
+        }
+
+        let b2 = match self.nodes.get(qc.get_node_hash()) {
+            Some(node) => node.clone(),
+            None => return,
+        };
+        let b1_hash = b2.get_justify().get_node_hash().to_vec();
+        let b1 = match self.nodes.get(&b1_hash) {
+            Some(node) => node.clone(),
+            None => return,
+        };
+        if b1.get_view() > self.locked_view() {
+            self.locked_hash = b1_hash.clone();
+        }
+        let b0_hash = b1.get_justify().get_node_hash().to_vec();
+        let b0 = match self.nodes.get(&b0_hash) {
+            Some(node) => node.clone(),
+            None => return,
+        };
+        if b2.get_parent_hash() == b1_hash.as_slice()
+            && b1.get_parent_hash() == b0_hash.as_slice()
+            && b0.get_height() > self.decided_height
+        {
+            self.decide(b0_hash, vec![b1, b2], qc);
+        }
+    }
+
+    // queue `hash` and its undecided ancestors, each with its proof up to `qc`
+    fn decide(&mut self, hash: Vec<u8>, tail: Vec<HotStuffNode>, qc: HotStuffQc) {
+        let mut path = Vec::new();
+        let mut ancestor = hash.clone();
+        while ancestor != self.decided_hash {
+            let node = match self.nodes.get(&ancestor) {
+                Some(node) => node.clone(),
+                None => {
+                    error!(
+                        "An ancestor of the decided node({}) is missing",
+                        bytes_to_hex_str(&hash)
+                    );
+                    return;
+                }
+            };
+            if node.get_height() <= self.decided_height {
+                error!(
+                    "The decided node({}) conflicts with the decided node({}) at height {}",
+                    bytes_to_hex_str(&hash),
+                    bytes_to_hex_str(&self.decided_hash),
+                    self.decided_height
+                );
+                return;
+            }
+            let parent_hash = node.get_parent_hash().to_vec();
+            path.push((ancestor, node));
+            ancestor = parent_hash;
+        }
+        path.reverse();
+
+        let mut chain: Vec<HotStuffNode> = path.iter().map(|(_, node)| node.clone()).collect();
+        chain.extend(tail);
+        for (index, (decided_hash, node)) in path.into_iter().enumerate() {
+            let mut proof = HotStuffProof::new();
+            proof.set_chain(RepeatedField::from(chain[index..].to_vec()));
+            proof.set_qc(qc.clone());
+            self.commit_queue.push_back(proof);
+            self.decided_hash = decided_hash;
+            self.decided_height = node.get_height();
+        }
+        self.execute_commits();
+    }
+
+    /// Execute the decided nodes in height order, stopping at the first missing transactions
+    /// or ledger gap, which catch-up fills.
+    pub(crate) fn execute_commits(&mut self) {
+        while let Some(proof) = self.commit_queue.front().cloned() {
+            let node = match proof.get_chain().first() {
+                Some(node) => node.clone(),
+                None => {
+                    self.commit_queue.pop_front();
+                    continue;
+                }
+            };
+            let lcl_height = LAST_COMMITTED_BLOCK_INFO_REF
+                .read()
+                .get_header()
+                .get_height();
+            if node.get_height() <= lcl_height {
+                self.commit_queue.pop_front();
+                continue;
+            }
+            if node.get_height() != lcl_height + 1 {
+                break;
+            }
+            match self.commit_node(&node, &proof) {
+                Ok(true) => {
+                    self.commit_queue.pop_front();
+                }
+                Ok(false) => break,
+                Err(e) => {
+                    error!("Failed to commit node({}): {}", node.get_height(), e);
+                    break;
+                }
+            }
+        }
+    }
+
+    fn commit_node(&mut self, node: &HotStuffNode, proof: &HotStuffProof) -> anyhow::Result<bool> {
+        let (tx_list, lack_txs) = TX_POOL_INSTANCE_REF
+            .read()
+            .get_block_by_hashs(node.get_tx_hashes());
+        if !lack_txs.is_empty() {
+            info!(
+                "{} transactions of the decided node({}) are not in the tx-pool",
+                lack_txs.len(),
+                node.get_height()
+            );
+            return Ok(false);
+        }
+
+        let lcl = LAST_COMMITTED_BLOCK_INFO_REF.read().get_header().clone();
+        let tx_count = node.get_tx_hashes().len() as u64;
+        let tx_hash_list = if tx_count > 0 {
+            let mut proto_hash_list = TxHashList::default();
+            proto_hash_list.set_hash_set(RepeatedField::from(node.get_tx_hashes().to_vec()));
+            Some(ProtocolParser::serialize::<TxHashList>(&proto_hash_list))
+        } else {
+            None
+        };
+        let mut block = BlockExecutor::initialize_new_block(
+            node.get_height(),
+            lcl.get_hash().to_vec(),
+            node.get_timestamp(),
+            node.get_version(),
+            tx_count,
+            lcl.get_total_tx_count() + tx_count,
+            node.get_proposer().to_string(),
+            None,
+            tx_hash_list,
+        );
+        let tx_signs: Vec<TransactionSign> = tx_list.iter().map(|t| t.convert_into()).collect();
+        block.set_transaction_signs(RepeatedField::from(tx_signs));
+
+        let (tx_list, block_result) = BlockExecutor::execute_block(&block)?;
+        BlockExecutor::commit_block(&mut block, tx_list, &block_result)?;
+        HotStuffStorage::store_proof(node.get_height(), proof)?;
+        self.after_commit(node, &block, &block_result);
+        Ok(true)
+    }
+
+    fn after_commit(&mut self, node: &HotStuffNode, block: &Ledger, result: &BlockResult) {
+        info!(
+            "Ledger({}) closed successfully by hotstuff, view({}), txs({})",
+            block.get_header().get_height(),
+            node.get_view(),
+            block.get_transaction_signs().len()
+        );
+        if let Err(e) = self
+            .ws_publish_event_sender
+            .send((block.clone(), result.tx_result_set.clone()))
+        {
+            error!("ws_publish_event_sender send error:{:?}", e);
+        }
+        self.delete_commit_tx(block);
+
+        self.committed = NodeInfo::of(node);
+        self.prune();
+        self.apply_committed_validators(block.get_header().get_height());
+        self.store_state();
+    }
+
+    /// A block committed by catch-up, it carries its hotstuff proof when it was closed by
+    /// this engine.
+    pub(crate) fn handle_caught_up(&mut self, block: Ledger) {
+        let height = block.get_header().get_height();
+        if height <= self.committed.height {
+            return;
+        }
+        self.committed = NodeInfo {
+            hash: block.get_header().get_hash().to_vec(),
+            height,
+            view: 0,
+            timestamp: block.get_header().get_timestamp(),
+        };
+        let proof = BlockExecutor::extract_hotstuff_proof(&block)
+            .and_then(|data| ProtocolParser::deserialize::<HotStuffProof>(&data).ok());
+        if let Some(node) = proof.as_ref().and_then(|p| p.get_chain().first()) {
+            if node.get_height() == height {
+                self.committed = NodeInfo::of(node);
+            }
+        }
+        if self.decided_height < height {
+            self.decided_hash = self.committed.hash.clone();
+            self.decided_height = height;
+        }
+        self.commit_queue
+            .retain(|proof| proof.get_chain()[0].get_height() > height);
+        self.prune();
+        // the nodes certified past the caught-up block let this node vote again
+        if let Some(proof) = proof {
+            self.insert_chain(&proof);
+        }
+        info!(
+            "Ledger({}) caught up, next hotstuff height {}",
+            height,
+            height + 1
+        );
+
+        self.delete_commit_tx(&block);
+        self.apply_committed_validators(height);
+        self.store_state();
+        self.execute_commits();
+        self.schedule_propose();
+    }
+
+    // drop the nodes and votes at or below the committed height, which can never be extended
+    fn prune(&mut self) {
+        let committed = self.committed.clone();
+        self.nodes
+            .retain(|_, node| node.get_height() > committed.height);
+        self.votes
+            .retain(|(_, height, _), _| *height > committed.height);
+        if self.node_info(&self.locked_hash).is_none() {
+            self.locked_hash = committed.hash.clone();
+        }
+        if self.node_info(self.high_qc.get_node_hash()).is_none() {
+            self.high_qc = self.committed_qc();
+        }
+    }
+
+    /// Follow the validators of the last committed ledger at `height`.
+    pub(crate) fn apply_committed_validators(&mut self, height: u64) {
+        let validators = LAST_COMMITTED_BLOCK_INFO_REF
+            .read()
+            .get_validators()
+            .clone();
+        self.update_validators(height, &validators);
+    }
+
+    pub(crate) fn update_validators(&mut self, height: u64, validators: &ValidatorSet) {
+        if self.validators.changed(validators) {
+            info!("Validators changed at ledger({})", height);
+        }
+        self.validators.update_validators(validators);
+        BftStorage::store_validators(height, validators);
+        if height > 3 {
+            let _ = BftStorage::delete_validators(height - 3);
+        }
+
+        let addresses: Vec<String> = validators
+            .get_validators()
+            .iter()
+            .map(|v| v.get_address().to_string())
+            .collect();
+        self.network_consensus.update_validators(&addresses);
+        self.network_tx.update_validators(&addresses);
+    }
+
+    fn delete_commit_tx(&mut self, block: &Ledger) {
+        if block.get_transaction_signs().is_empty() {
+            return;
+        }
+        let mut transactions: HashMap<String, TxPoolCommitted> = HashMap::new();
+        for tx in block.get_transaction_signs().iter() {
+            let sender = tx.get_transaction().get_source();
+            let nonce = tx.get_transaction().get_nonce();
+            let committed =
+                transactions
+                    .entry(sender.to_string())
+                    .or_insert_with(|| TxPoolCommitted {
+                        sender: sender.to_string(),
+                        max_seq: nonce,
+                        seqs: HashSet::default(),
+                    });
+            committed.max_seq = committed.max_seq.max(nonce);
+            committed.seqs.insert(nonce);
+        }
+        self.last_commit_txs.clone_from(&transactions);
+        let notify = TxPoolCommitNotification {
+            transactions,
+            count: block.get_transaction_signs().len() as u64,
+        };
+        if let Err(e) = self.commit_to_txpool_sender.try_send(notify) {
+            error!("hotstuff to_tx_pool_commit_sender send error({})", e);
+        }
+    }
+
+    fn enter_view(&mut self, view: i64, progress: bool) {
+        if progress {
+            self.timeouts = 0;
+        }
+        if view <= self.view {
+            return;
+        }
+        self.view = view;
+        self.votes
+            .retain(|(vote_view, _, _), _| *vote_view + 1 >= view);
+        self.new_views.retain(|new_view, _| *new_view >= view);
+        self.start_view_timer();
+    }
+
+    pub(crate) fn start_view_timer(&mut self) {
+        TimerManager::instance().delete_timer(self.view_timer_id);
+        let timeout = CONFIGURE_INSTANCE_REF.consensus.commit_interval
+            + (CONFIGURE_INSTANCE_REF.consensus.view_timeout
+                << self.timeouts.min(MAX_TIMEOUT_DOUBLING));
+        self.view_timer_id = TimerManager::instance().new_delay_timer(
+            chrono::Duration::milliseconds(timeout),
+            self.timer_sender.clone(),
+            TimerEventType::HotStuffViewTimeout,
+            Some(self.view.to_be_bytes().to_vec()),
+        );
+    }
+
+    /// The proposal of the current view is due once commit_interval passed since its parent.
+    pub(crate) fn schedule_propose(&mut self) {
+        if !self.is_validator() || !self.is_leader(self.view) || self.proposed_view >= self.view {
+            return;
+        }
+        let parent = match self.node_info(self.high_qc.get_node_hash()) {
+            Some(parent) => parent,
+            None => return,
+        };
+        let waiting_time = parent.timestamp + CONFIGURE_INSTANCE_REF.consensus.commit_interval
+            - chrono::Local::now().timestamp_millis();
+        TimerManager::instance().delete_timer(self.propose_timer_id);
+        self.propose_timer_id = TimerManager::instance().new_delay_timer(
+            chrono::Duration::milliseconds(waiting_time.max(1)),
+            self.timer_sender.clone(),
+            TimerEventType::HotStuffPropose,
+            None,
+        );
+    }
+
+    /// Nothing certified in the view, move on and hand the highest qc to the next leader.
+    pub(crate) fn handle_view_timeout(&mut self, view: i64) {
+        if view != self.view {
+            return;
+        }
+        self.timeouts += 1;
+        let next_view = view + 1;
+        info!(
+            "View {} timed out {} times in a row, move to view {}",
+            view, self.timeouts, next_view
+        );
+        self.enter_view(next_view, false);
+        self.store_state();
+
+        let mut new_view = HotStuffNewView::new();
+        new_view.set_view(next_view);
+        new_view.set_high_qc(self.high_qc.clone());
+        let mut hotstuff = HotStuff::new();
+        hotstuff.set_msg_type(HotStuffMessageType::NEW_VIEW);
+        hotstuff.set_new_view(new_view);
+        if self.is_validator() {
+            self.send_to_leader(next_view, hotstuff);
+        }
+        // transactions of the decided nodes may have arrived meanwhile
+        self.execute_commits();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::TestValidators;
+    use crossbeam_channel::bounded;
+    use network::NetworkConfigType;
+    use utils::{
+        general::hash_crypto_byte, signer::initialize_node_signer,
+        timer_manager::initialize_timer_manager,
+    };
+
+    // far above the ledger of the tests, the decided nodes wait in the commit queue
+    const COMMITTED_HEIGHT: u64 = 1_000_000;
+
+    // the node under test is the first of `validators`
+    fn consensus(validators: &TestValidators) -> HotStuffConsensus {
+        initialize_node_signer(&CONFIGURE_INSTANCE_REF).unwrap();
+        initialize_timer_manager();
+        let mut set = Validators::default();
+        set.update_validators(&validators.set);
+        let network = PeerNetwork::start(
+            "127.0.0.1:0",
+            "hotstuff-test",
+            "hotstuff_test_peer",
+            NetworkConfigType::Consensus,
+        );
+        let (timer_sender, _) = bounded(1024);
+        let (commit_sender, _) = futures::channel::mpsc::channel(1024);
+        let (ws_sender, _) = tokio::sync::mpsc::unbounded_channel();
+        let committed = NodeInfo {
+            hash: hash_crypto_byte(b"committed"),
+            height: COMMITTED_HEIGHT,
+            view: 0,
+            timestamp: 0,
+        };
+        HotStuffConsensus::with_committed(
+            committed,
+            set,
+            timer_sender,
+            network.clone(),
+            network,
+            commit_sender,
+            ws_sender,
+        )
+    }
+
+    // the leader of `view` proposes a node on top of the node `justify` certifies
+    fn propose(consensus: &mut HotStuffConsensus, justify: &HotStuffQc, view: i64) -> HotStuffNode {
+        let leader = consensus.leader(view).unwrap();
+        let mut node = HotStuffNode::new();
+        node.set_view(view);
+        node.set_height(justify.get_height() + 1);
+        node.set_parent_hash(justify.get_node_hash().to_vec());
+        node.set_justify(justify.clone());
+        node.set_timestamp(node.get_height() as i64);
+        node.set_proposer(leader.clone());
+        node.set_version(LEDGER_VERSION);
+        let mut hotstuff = HotStuff::new();
+        hotstuff.set_msg_type(HotStuffMessageType::PROPOSAL);
+        hotstuff.set_proposal(node.clone());
+        consensus.dispatch(&leader, &hotstuff);
+        node
+    }
+
+    fn vote(validators: &TestValidators, index: usize, node: &HotStuffNode) -> HotStuff {
+        let mut vote = HotStuffVote::new();
+        vote.set_view(node.get_view());
+        vote.set_height(node.get_height());
+        vote.set_node_hash(node_hash(node));
+        vote.set_chain_id(self_chain_id());
+        vote.set_signature(validators.vote(index, node));
+        let mut hotstuff = HotStuff::new();
+        hotstuff.set_msg_type(HotStuffMessageType::VOTE);
+        hotstuff.set_vote(vote);
+        hotstuff
+    }
+
+    fn new_view(view: i64, high_qc: &HotStuffQc) -> HotStuff {
+        let mut new_view = HotStuffNewView::new();
+        new_view.set_view(view);
+        new_view.set_high_qc(high_qc.clone());
+        let mut hotstuff = HotStuff::new();
+        hotstuff.set_msg_type(HotStuffMessageType::NEW_VIEW);
+        hotstuff.set_new_view(new_view);
+        hotstuff
+    }
+
+    #[test]
+    fn locked_qc_test() {
+        configure::init_test_config();
+        let validators = TestValidators::new(4);
+        let mut consensus = consensus(&validators);
+        let root = consensus.committed_qc();
+        let b1 = propose(&mut consensus, &root, 1);
+        let b2 = propose(&mut consensus, &validators.qc(&b1, 3), 2);
+        propose(&mut consensus, &validators.qc(&b2, 3), 3);
+        // the qc of b2 locks its parent
+        assert_eq!(consensus.locked_hash, node_hash(&b1));
+        assert_eq!(consensus.last_voted_view, 3);
+
+        // a fork under a qc no newer than the lock gets no vote
+        let fork = propose(&mut consensus, &root, 5);
+        assert!(consensus.nodes.contains_key(&node_hash(&fork)));
+        assert_eq!(consensus.last_voted_view, 3);
+
+        // a qc newer than the lock is safe to follow even on a fork
+        propose(&mut consensus, &validators.qc(&fork, 3), 6);
+        assert_eq!(consensus.last_voted_view, 6);
+        assert_eq!(consensus.locked_hash, node_hash(&b1));
+
+        // never a second vote in a view
+        let mut twin = fork;
+        twin.set_view(6);
+        assert!(!consensus.can_vote(&twin));
+    }
+
+    #[test]
+    fn commit_rule_test() {
+        configure::init_test_config();
+        let validators = TestValidators::new(4);
+        let mut consensus = consensus(&validators);
+        let root = consensus.committed_qc();
+        let b1 = propose(&mut consensus, &root, 1);
+        let b2 = propose(&mut consensus, &validators.qc(&b1, 3), 2);
+        let b3 = propose(&mut consensus, &validators.qc(&b2, 3), 3);
+        // a two-chain on b1 locks it without deciding it
+        assert_eq!(consensus.decided_height, COMMITTED_HEIGHT);
+        assert!(consensus.commit_queue.is_empty());
+
+        // the three-chain decides b1, which waits for the ledger below it
+        let qc3 = validators.qc(&b3, 3);
+        propose(&mut consensus, &qc3, 4);
+        assert_eq!(consensus.decided_hash, node_hash(&b1));
+        assert_eq!(consensus.decided_height, COMMITTED_HEIGHT + 1);
+        assert_eq!(consensus.locked_hash, node_hash(&b2));
+        assert_eq!(consensus.commit_queue.len(), 1);
+        let proof = consensus.commit_queue.front().unwrap();
+        assert_eq!(proof.get_chain().to_vec(), vec![b1, b2, b3]);
+        assert_eq!(proof.get_qc(), &qc3);
+    }
+
+    #[test]
+    fn vote_quorum_test() {
+        configure::init_test_config();
+        let validators = TestValidators::new(4);
+        let mut consensus = consensus(&validators);
+        let root = consensus.committed_qc();
+        let b1 = propose(&mut consensus, &root, 1);
+        let b2 = propose(&mut consensus, &validators.qc(&b1, 3), 2);
+        let b3 = propose(&mut consensus, &validators.qc(&b2, 3), 3);
+        // this node leads view 4, its own vote for b3 came to itself
+        let key = (3, b3.get_height(), node_hash(&b3));
+        assert_eq!(consensus.votes[&key].len(), 1);
+
+        consensus.dispatch(&validators.address(1), &vote(&validators, 1, &b3));
+        assert_eq!(consensus.high_qc.get_view(), 2);
+
+        // a second vote of a validator in the view is refused
+        let mut twin = b3.clone();
+        twin.set_timestamp(b3.get_timestamp() + 1);
+        consensus.dispatch(&validators.address(1), &vote(&validators, 1, &twin));
+        assert!(!consensus
+            .votes
+            .contains_key(&(3, twin.get_height(), node_hash(&twin))));
+
+        // the quorum forms the qc
+        consensus.dispatch(&validators.address(2), &vote(&validators, 2, &b3));
+        assert_eq!(consensus.high_qc.get_view(), 3);
+        assert_eq!(consensus.high_qc.get_signatures().len(), 3);
+        assert!(check_qc(&consensus.validators, &consensus.high_qc));
+        assert!(!consensus.votes.contains_key(&key));
+
+        // votes too far ahead of the view are dropped
+        let mut ahead = b3.clone();
+        ahead.set_view(consensus.view + MAX_VIEWS_AHEAD + 3);
+        assert!(consensus.is_leader(ahead.get_view() + 1));
+        consensus.dispatch(&validators.address(1), &vote(&validators, 1, &ahead));
+        assert!(consensus.votes.is_empty());
+
+        // a late vote is kept until its node is committed
+        consensus.dispatch(&validators.address(3), &vote(&validators, 3, &b3));
+        assert_eq!(consensus.votes[&key].len(), 1);
+        consensus.committed = NodeInfo::of(&b3);
+        consensus.prune();
+        assert!(consensus.votes.is_empty());
+        consensus.dispatch(&validators.address(3), &vote(&validators, 3, &b3));
+        assert!(consensus.votes.is_empty());
+    }
+
+    #[test]
+    fn view_change_test() {
+        configure::init_test_config();
+        let validators = TestValidators::new(4);
+        let mut consensus = consensus(&validators);
+        let root = consensus.committed_qc();
+        let b1 = propose(&mut consensus, &root, 1);
+        assert_eq!(consensus.view, 2);
+
+        // nothing certified in view 2, the node moves on
+        consensus.handle_view_timeout(2);
+        assert_eq!((consensus.view, consensus.timeouts), (3, 1));
+        // a stale timer changes nothing
+        consensus.handle_view_timeout(2);
+        assert_eq!((consensus.view, consensus.timeouts), (3, 1));
+
+        // this node leads view 4 once a quorum handed it their highest qc
+        let qc1 = validators.qc(&b1, 3);
+        for index in 1..3 {
+            consensus.dispatch(&validators.address(index), &new_view(4, &qc1));
+            assert_eq!(consensus.view, 3);
+        }
+        assert_eq!(consensus.high_qc, qc1);
+        consensus.dispatch(&validators.address(3), &new_view(4, &qc1));
+        assert_eq!(consensus.view, 4);
+
+        // and proposes on top of the highest qc
+        assert!(consensus.propose_node());
+        assert!(!consensus.propose_node());
+        let proposal = consensus
+            .nodes
+            .values()
+            .find(|node| node.get_view() == 4)
+            .unwrap();
+        assert_eq!(proposal.get_parent_hash(), node_hash(&b1).as_slice());
+        assert_eq!(proposal.get_justify(), &qc1);
+        assert_eq!(consensus.last_voted_view, 4);
+    }
+}
@@ -0,0 +1,6 @@
+pub mod bootstrap;
+pub mod hotstuff_consensus;
+pub mod message;
+pub mod proof;
+#[cfg(test)]
+mod test_util;
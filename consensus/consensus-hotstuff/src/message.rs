@@ -0,0 +1,74 @@
+use consensus_pbft::validators::Validators;
+use protos::{
+    common::{ProtocolsActionMessageType, ProtocolsMessage, ProtocolsMessageType},
+    consensus::{Consensus, ConsensusType, HotStuffNode, HotStuffSign, HotStuffVote},
+};
+use tracing::error;
+use utils::{
+    general::{hash_crypto_byte, self_chain_hub, self_chain_id},
+    parse::ProtocolParser,
+    verify_sign::{get_sign_address, verify_sign},
+};
+
+pub fn node_hash(node: &HotStuffNode) -> Vec<u8> {
+    hash_crypto_byte(&ProtocolParser::serialize::<HotStuffNode>(node))
+}
+
+/// The bytes a vote signature covers, the vote without its signature.
+pub fn vote_content(view: i64, height: u64, node_hash: &[u8], chain_id: &str) -> Vec<u8> {
+    let mut vote = HotStuffVote::new();
+    vote.set_view(view);
+    vote.set_height(height);
+    vote.set_node_hash(node_hash.to_vec());
+    vote.set_chain_id(chain_id.to_string());
+    ProtocolParser::serialize::<HotStuffVote>(&vote)
+}
+
+/// The validator address `sign` comes from, None when it is not a valid message of this chain.
+pub fn check_sign(sign: &HotStuffSign, validators: &Validators) -> Option<String> {
+    if sign.get_chain_id() != self_chain_id() || sign.get_chain_hub() != self_chain_hub() {
+        error!(
+            "Failed to check hotstuff message of chain {} hub {}",
+            sign.get_chain_id(),
+            sign.get_chain_hub()
+        );
+        return None;
+    }
+
+    let address = match get_sign_address(sign.get_signature()) {
+        Ok(address) => address,
+        Err(e) => {
+            error!("Failed to get hotstuff message sign address: {}", e);
+            return None;
+        }
+    };
+    if !validators.contains(&address) {
+        error!("Unable to find validator {} from validators", address);
+        return None;
+    }
+
+    match verify_sign(
+        sign.get_signature(),
+        &ProtocolParser::serialize(sign.get_hotstuff()),
+    ) {
+        Ok(true) => Some(address),
+        _ => {
+            error!("Failed to check hotstuff message signature of {}", address);
+            None
+        }
+    }
+}
+
+/// `sign` sent straight to one validator. Requests reach the consensus rpc handler without
+/// being relayed, which keeps a view at a linear number of messages.
+pub fn consensus_message(sign: &HotStuffSign) -> ProtocolsMessage {
+    let mut consensus = Consensus::default();
+    consensus.set_consensus_type(ConsensusType::HOTSTUFF);
+    consensus.set_msg(ProtocolParser::serialize::<HotStuffSign>(sign));
+    let mut msg = ProtocolsMessage::new();
+    msg.set_msg_type(ProtocolsMessageType::CONSENSUS);
+    msg.set_action(ProtocolsActionMessageType::REQUEST);
+    msg.set_data(ProtocolParser::serialize::<Consensus>(&consensus));
+    msg.set_timestamp(chrono::Local::now().timestamp_millis());
+    msg
+}
@@ -0,0 +1,285 @@
+use anyhow::bail;
+use consensus_pbft::{utils::quorum_size, validators::Validators};
+use executor::BlockExecutor;
+use protos::{
+    common::ValidatorSet,
+    consensus::{HotStuffNode, HotStuffProof, HotStuffQc, TxHashList},
+    ledger::Ledger,
+};
+use std::collections::HashSet;
+use tracing::error;
+use utils::{
+    general::self_chain_id,
+    parse::ProtocolParser,
+    verify_sign::{get_sign_address, verify_sign},
+};
+
+use crate::message::{node_hash, vote_content};
+
+/// Whether `qc` holds the votes of a quorum of `validators`.
+pub fn check_qc(validators: &Validators, qc: &HotStuffQc) -> bool {
+    let content = vote_content(
+        qc.get_view(),
+        qc.get_height(),
+        qc.get_node_hash(),
+        &self_chain_id(),
+    );
+    let mut signers = HashSet::new();
+    for signature in qc.get_signatures().iter() {
+        let address = match get_sign_address(signature) {
+            Ok(address) => address,
+            Err(e) => {
+                error!("Failed to get qc signature address: {}", e);
+                return false;
+            }
+        };
+        if !validators.contains(&address) || !signers.insert(address.clone()) {
+            error!("qc signature({}) is not a validator or duplicated", address);
+            return false;
+        }
+        match verify_sign(signature, &content) {
+            Ok(true) => {}
+            _ => {
+                error!("Failed to check qc signature of {}", address);
+                return false;
+            }
+        }
+    }
+
+    let q_size = quorum_size(validators.len()) + 1;
+    if signers.len() < q_size {
+        error!(
+            "qc quorum size({}) < quorum size({}) at view {}",
+            signers.len(),
+            q_size,
+            qc.get_view()
+        );
+        return false;
+    }
+    true
+}
+
+/// Check that `proof` commits `block` under `validators`: the first node of the chain is the
+/// block, and the last three nodes form a three-chain certified by the proof's qc.
+pub fn check_commit_proof(
+    validators: &ValidatorSet,
+    block: &Ledger,
+    proof: &HotStuffProof,
+) -> anyhow::Result<()> {
+    let height = block.get_header().get_height();
+    let chain = proof.get_chain();
+    if chain.len() < 3 {
+        bail!("block {} hotstuff proof chain is too short", height);
+    }
+    for pair in chain.windows(2) {
+        if pair[1].get_parent_hash() != node_hash(&pair[0]).as_slice()
+            || pair[1].get_height() != pair[0].get_height() + 1
+        {
+            bail!("block {} hotstuff proof chain is not linked", height);
+        }
+    }
+
+    let mut temp_vs = Validators::default();
+    temp_vs.update_validators(validators);
+    let n = chain.len();
+    let certified = [
+        (&chain[n - 3], chain[n - 2].get_justify()),
+        (&chain[n - 2], chain[n - 1].get_justify()),
+        (&chain[n - 1], proof.get_qc()),
+    ];
+    for (node, qc) in certified {
+        if !certifies(qc, node) || !check_qc(&temp_vs, qc) {
+            bail!(
+                "block {} hotstuff qc of view {} is not valid",
+                height,
+                qc.get_view()
+            );
+        }
+    }
+
+    check_block_node(block, &chain[0])
+}
+
+fn certifies(qc: &HotStuffQc, node: &HotStuffNode) -> bool {
+    qc.get_node_hash() == node_hash(node).as_slice()
+        && qc.get_view() == node.get_view()
+        && qc.get_height() == node.get_height()
+}
+
+// the block is the one built from `node`, with the transactions it lists
+fn check_block_node(block: &Ledger, node: &HotStuffNode) -> anyhow::Result<()> {
+    let header = block.get_header();
+    if header.get_height() != node.get_height()
+        || header.get_timestamp() != node.get_timestamp()
+        || header.get_proposer() != node.get_proposer()
+        || header.get_version() != node.get_version()
+    {
+        bail!(
+            "block {} does not match its hotstuff node",
+            header.get_height()
+        );
+    }
+    let tx_hashes = match BlockExecutor::extract_tx_hash_list(block) {
+        Some(data) => ProtocolParser::deserialize::<TxHashList>(&data)?
+            .get_hash_set()
+            .to_vec(),
+        None => Vec::new(),
+    };
+    if tx_hashes.as_slice() != node.get_tx_hashes() {
+        bail!(
+            "block {} transactions do not match its hotstuff node",
+            header.get_height()
+        );
+    }
+    BlockExecutor::verify_tx_hash_list(block)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::TestValidators;
+    use protobuf::RepeatedField;
+    use protos::ledger::{Transaction, TransactionSign};
+    use types::transaction::SignedTransaction;
+    use utils::general::hash_crypto_byte;
+
+    fn transaction(nonce: u64) -> TransactionSign {
+        let mut tx = Transaction::new();
+        tx.set_nonce(nonce);
+        tx.set_value("0".to_string());
+        tx.set_gas_price("1".to_string());
+        let mut tx_sign = TransactionSign::new();
+        tx_sign.set_transaction(tx);
+        tx_sign
+    }
+
+    // the node of `view` on top of the node `justify` certifies
+    fn node(justify: HotStuffQc, view: i64, tx_hashes: Vec<Vec<u8>>) -> HotStuffNode {
+        let mut node = HotStuffNode::new();
+        node.set_view(view);
+        node.set_height(justify.get_height() + 1);
+        node.set_parent_hash(justify.get_node_hash().to_vec());
+        node.set_timestamp(view);
+        node.set_version(1);
+        node.set_tx_hashes(RepeatedField::from(tx_hashes));
+        node.set_justify(justify);
+        node
+    }
+
+    // a block of `txs` and the proof of the three-chain on top of it
+    fn committed_block(
+        validators: &TestValidators,
+        txs: Vec<TransactionSign>,
+    ) -> (Ledger, HotStuffProof) {
+        let tx_hashes: Vec<Vec<u8>> = txs
+            .iter()
+            .map(|tx| {
+                SignedTransaction::try_from(tx.clone())
+                    .unwrap()
+                    .hash()
+                    .to_vec()
+            })
+            .collect();
+        let mut root = HotStuffQc::new();
+        root.set_height(9);
+        root.set_node_hash(hash_crypto_byte(b"root"));
+        let b0 = node(root, 1, tx_hashes.clone());
+        let b1 = node(validators.qc(&b0, 3), 2, Vec::new());
+        let b2 = node(validators.qc(&b1, 3), 3, Vec::new());
+        let mut proof = HotStuffProof::new();
+        proof.set_qc(validators.qc(&b2, 3));
+        proof.set_chain(RepeatedField::from(vec![b0.clone(), b1, b2]));
+
+        let mut hash_list = TxHashList::new();
+        hash_list.set_hash_set(RepeatedField::from(tx_hashes));
+        let mut block = BlockExecutor::initialize_new_block(
+            b0.get_height(),
+            vec![1; 32],
+            b0.get_timestamp(),
+            b0.get_version(),
+            txs.len() as u64,
+            txs.len() as u64,
+            b0.get_proposer().to_string(),
+            None,
+            Some(ProtocolParser::serialize::<TxHashList>(&hash_list)),
+        );
+        block.set_transaction_signs(txs.into());
+        (block, proof)
+    }
+
+    #[test]
+    fn qc_quorum_test() {
+        configure::init_test_config();
+        let validators = TestValidators::new(4);
+        let mut set = Validators::default();
+        set.update_validators(&validators.set);
+        let mut root = HotStuffQc::new();
+        root.set_node_hash(hash_crypto_byte(b"root"));
+        let node = node(root, 1, Vec::new());
+
+        assert!(check_qc(&set, &validators.qc(&node, 3)));
+        assert!(check_qc(&set, &validators.qc(&node, 4)));
+        // one vote short of a quorum
+        assert!(!check_qc(&set, &validators.qc(&node, 2)));
+
+        // a vote counted twice
+        let mut duplicated = validators.qc(&node, 2);
+        duplicated.mut_signatures().push(validators.vote(1, &node));
+        assert!(!check_qc(&set, &duplicated));
+
+        // a vote of a stranger
+        let strangers = TestValidators::new(2);
+        let mut stranger = validators.qc(&node, 2);
+        stranger.mut_signatures().push(strangers.vote(1, &node));
+        assert!(!check_qc(&set, &stranger));
+
+        // the votes moved to another view
+        let mut moved = validators.qc(&node, 3);
+        moved.set_view(2);
+        assert!(!check_qc(&set, &moved));
+    }
+
+    #[test]
+    fn commit_proof_test() {
+        configure::init_test_config();
+        let validators = TestValidators::new(4);
+        let (block, proof) = committed_block(&validators, vec![transaction(1), transaction(2)]);
+        assert!(check_commit_proof(&validators.set, &block, &proof).is_ok());
+
+        let strangers = TestValidators::new(4);
+        assert!(check_commit_proof(&strangers.set, &block, &proof).is_err());
+
+        // a two-chain commits nothing
+        let mut short = proof.clone();
+        short.mut_chain().pop();
+        assert!(check_commit_proof(&validators.set, &block, &short).is_err());
+
+        // the last node certified by less than a quorum
+        let mut weak = proof.clone();
+        let last = weak.get_chain()[2].clone();
+        weak.set_qc(validators.qc(&last, 2));
+        assert!(check_commit_proof(&validators.set, &block, &weak).is_err());
+    }
+
+    #[test]
+    fn swapped_transactions_test() {
+        configure::init_test_config();
+        let validators = TestValidators::new(4);
+        let (block, proof) = committed_block(&validators, vec![transaction(1), transaction(2)]);
+
+        // another transaction under the valid qc
+        let mut swapped = block.clone();
+        swapped.mut_transaction_signs()[1] = transaction(3);
+        assert!(check_commit_proof(&validators.set, &swapped, &proof).is_err());
+
+        // the listed transactions out of order
+        let mut reordered = block.clone();
+        reordered.mut_transaction_signs().swap(0, 1);
+        assert!(check_commit_proof(&validators.set, &reordered, &proof).is_err());
+
+        // a dropped transaction
+        let mut dropped = block;
+        dropped.mut_transaction_signs().pop();
+        assert!(check_commit_proof(&validators.set, &dropped, &proof).is_err());
+    }
+}
@@ -0,0 +1,64 @@
+//! Validators with keys and the votes they sign, for the tests of the hotstuff rules.
+use configure::CONFIGURE_INSTANCE_REF;
+use msp::signing::{create_private_key, create_secret_key, PrivateKey};
+use protobuf::RepeatedField;
+use protos::{
+    common::{Signature, Validator, ValidatorSet},
+    consensus::{HotStuffNode, HotStuffQc},
+};
+use utils::{general::self_chain_id, verify_sign::sign};
+
+use crate::message::{node_hash, vote_content};
+
+const ENCRYPTION_TYPE: &str = "eddsa_ed25519";
+
+pub struct TestValidators {
+    pub keys: Vec<Box<dyn PrivateKey>>,
+    pub set: ValidatorSet,
+}
+
+impl TestValidators {
+    /// `count` validators, the first one holds the key of the node under test.
+    pub fn new(count: usize) -> Self {
+        let node_key =
+            create_private_key(ENCRYPTION_TYPE, &CONFIGURE_INSTANCE_REF.node_private_key).unwrap();
+        let mut keys: Vec<Box<dyn PrivateKey>> = vec![node_key];
+        keys.extend((1..count).map(|_| create_secret_key(ENCRYPTION_TYPE).unwrap()));
+        let mut set = ValidatorSet::new();
+        for key in keys.iter() {
+            let mut validator = Validator::new();
+            validator.set_address(key.get_address());
+            set.mut_validators().push(validator);
+        }
+        Self { keys, set }
+    }
+
+    pub fn address(&self, index: usize) -> String {
+        self.keys[index].get_address()
+    }
+
+    /// The vote of the validator `index` for `node`.
+    pub fn vote(&self, index: usize, node: &HotStuffNode) -> Signature {
+        let content = vote_content(
+            node.get_view(),
+            node.get_height(),
+            &node_hash(node),
+            &self_chain_id(),
+        );
+        sign(&self.keys[index].as_hex(), &content, ENCRYPTION_TYPE).unwrap()
+    }
+
+    /// The qc of the first `signers` validators for `node`.
+    pub fn qc(&self, node: &HotStuffNode, signers: usize) -> HotStuffQc {
+        let mut qc = HotStuffQc::new();
+        qc.set_view(node.get_view());
+        qc.set_height(node.get_height());
+        qc.set_node_hash(node_hash(node));
+        qc.set_signatures(RepeatedField::from(
+            (0..signers)
+                .map(|index| self.vote(index, node))
+                .collect::<Vec<Signature>>(),
+        ));
+        qc
+    }
+}
@@ -1,15 +1,21 @@
 use ::utils::timer_manager::TimerSender;
 use configure::CONFIGURE_INSTANCE_REF;
+use consensus_engine::ConsensusEngine;
 use consensus_store::bft_storage::BftStorage;
 use executor::{block_result::BlockResult, BlockExecutor, LAST_COMMITTED_BLOCK_INFO_REF};
+use ledger_store::LedgerStorage;
 use ledger_upgrade::ledger_upgrade::LedgerUpgradeInstance;
 use msp::bytes_to_hex_str;
-use network::PeerNetwork;
+use network::{Endpoint, PeerNetwork};
 use parking_lot::RwLock;
+use protobuf::{Message, RepeatedField};
 use protos::{
-    common::{TransactionResult, ValidatorSet},
+    common::{
+        ProtocolsActionMessageType, ProtocolsMessage, ProtocolsMessageType, TransactionResult,
+        ValidatorSet,
+    },
     consensus::{BftMessageType, BftProof, BftSign, LedgerUpgrade, NewViewRepondParam, TxHashList},
-    ledger::Ledger,
+    ledger::{Ledger, SyncTransactionRequest, SyncTransactionResponse},
 };
 use std::{
     collections::{HashMap, HashSet},
@@ -20,8 +26,9 @@ use tx_pool::{
     types::{CommitNotificationSender, TxPoolCommitNotification, TxPoolCommitted},
     TX_POOL_INSTANCE_REF,
 };
+use types::SignedTransaction;
 use utils::{
-    general::{hash_crypto_byte, node_address, LEDGER_VERSION, MILLI_UNITS_PER_SEC},
+    general::{hash_crypto_byte, node_address, self_chain_id, LEDGER_VERSION, MILLI_UNITS_PER_SEC},
     parse::ProtocolParser,
     timer_manager::{TimerEventType, TimerManager},
 };
@@ -35,8 +42,13 @@ use crate::{
     },
     instance::{bft_instance::BftInstance, bft_instance_index::BftInstanceIndex},
     new_bft_message::NewBftMessage,
+    tx_fetcher::TxFetcher,
 };
 
+// view changes and new views further ahead of the current view are dropped, they bound what
+// a validator can make the node log
+const MAX_VIEWS_AHEAD: i64 = 64;
+
 pub struct BftConsensus {
     pub(crate) logs: BftLog,
     pub(crate) state: BftState,
@@ -52,6 +64,7 @@ pub struct BftConsensus {
     pub(crate) ws_publish_event_sender:
         tokio::sync::mpsc::UnboundedSender<(Ledger, Vec<TransactionResult>)>,
     pub(crate) last_commit_txs: HashMap<String, TxPoolCommitted>,
+    pub(crate) tx_fetcher: TxFetcher,
 }
 
 impl BftConsensus {
@@ -98,7 +111,7 @@ impl BftConsensus {
         state.view_number = view_number;
         state.update_validators(validators_set);
 
-        BftConsensus {
+        let mut consensus = BftConsensus {
             logs: BftLog::default(),
             state,
             timer_sender,
@@ -112,7 +125,56 @@ impl BftConsensus {
             new_view_repond_timer_id: 0,
             last_commit_txs: HashMap::default(),
             ws_publish_event_sender,
+            tx_fetcher: TxFetcher::default(),
+        };
+        consensus.replay_messages();
+        consensus
+    }
+
+    // rebuild the instances and view changes in flight before a restart from the message log,
+    // nothing is sent or proposed on the way
+    fn replay_messages(&mut self) {
+        let messages = BftStorage::load_messages().expect("load bft messages error");
+        if messages.is_empty() {
+            return;
+        }
+        info!(parent:self.span(), "Replay {} logged bft messages", messages.len());
+
+        self.state.replaying = true;
+        for bft_sign in messages.iter() {
+            let bft = bft_sign.get_bft();
+            if bft.get_msg_type() == BftMessageType::PRE_PREPARE
+                && bft.get_pre_prepare().get_base().get_replica_id() == self.replica_id()
+            {
+                // the primary does not handle its own pre-prepare, it is restored as proposed
+                let base = bft.get_pre_prepare().get_base();
+                if self.view_active()
+                    && base.get_view_number() == self.view_number()
+                    && base.get_sequence() > self.last_exe_sequence()
+                {
+                    self.insert_proposal(bft_sign);
+                }
+                continue;
+            }
+            let _ = self.handle_receive_consensus(bft_sign);
         }
+        self.state.replaying = false;
+
+        // a view change this node voted for has not completed yet
+        let view_number = self.view_number();
+        if self.logs.vc_instances.values().any(|vc_instance| {
+            vc_instance.view_number > view_number
+                && vc_instance.end_time == 0
+                && vc_instance.view_change_msg.has_bft()
+        }) {
+            self.set_view_active(false);
+        }
+        info!(parent:self.span(),
+            "Replayed bft messages, view number({}), view active({}), last execution sequence({})",
+            self.view_number(),
+            self.view_active(),
+            self.last_exe_sequence()
+        );
     }
 
     pub fn span(&self) -> &Span {
@@ -228,6 +290,14 @@ impl BftConsensus {
             //Enter to new view
             if new_view_number > 0 {
                 self.set_view_number(new_view_number);
+                // the values left are committed, their fetches go on
+                let needed = self
+                    .logs
+                    .instances
+                    .values()
+                    .flat_map(|instance| value_tx_hashes(instance.pre_prepare.get_value()))
+                    .collect();
+                self.tx_fetcher.retain(&needed);
             }
 
             self.set_view_active(true);
@@ -326,18 +396,16 @@ impl BftConsensus {
         self.start_ledgerclose_check_timer();
     }
 
-    pub fn execute_value(&mut self, bft_sign: &BftSign) -> bool {
+    pub fn execute_value(&mut self) -> bool {
         let mut exe_vec = Vec::new();
         for (index, instance) in self.logs.instances() {
             if index.sequence <= self.last_exe_sequence() {
                 continue;
             }
 
-            if index.sequence == (self.last_exe_sequence() + 1)
-                && (instance.phase.clone() as i64) >= (BftInstancePhase::COMMITTED as i64)
+            if index.sequence != (self.last_exe_sequence() + 1)
+                || (instance.phase.clone() as i64) < (BftInstancePhase::COMMITTED as i64)
             {
-                self.inc_last_exe_sequence();
-            } else {
                 break;
             }
 
@@ -346,13 +414,23 @@ impl BftConsensus {
                 instance.get_bft_sign_vec(&BftInstancePhase::as_phase(&BftMessageType::COMMIT));
             let proof = NewBftMessage::get_commited_proof(vec);
 
-            self.handle_value_commit(index.sequence, instance.pre_prepare.get_value(), proof);
+            // the value waiting for its transactions is executed once they are fetched
+            if !self.handle_value_commit(index.sequence, instance.pre_prepare.get_value(), proof) {
+                break;
+            }
+            self.inc_last_exe_sequence();
 
             exe_vec.push(index.sequence);
         }
 
         let v = self.ckp_interval() / 2;
         for sequence in exe_vec {
+            //Drop the logged messages at the check point
+            if sequence % self.ckp_interval() == 0 {
+                if let Err(e) = BftStorage::delete_messages(sequence, self.view_number()) {
+                    error!(parent:self.span(),"Failed to delete the logged bft messages up to sequence({}): {}", sequence, e);
+                }
+            }
             //Delete the old check point
             if sequence >= v {
                 self.logs
@@ -363,12 +441,13 @@ impl BftConsensus {
         return true;
     }
 
-    pub fn handle_value_commit(&mut self, request_seq: u64, value: &[u8], proof: BftProof) {
+    /// Execute and commit a committed value, false when it waits for missing transactions.
+    pub fn handle_value_commit(&mut self, request_seq: u64, value: &[u8], proof: BftProof) -> bool {
         let mut block = match ProtocolParser::deserialize::<Ledger>(value) {
             Ok(block) => block,
             Err(e) => {
                 error!(parent:self.span(),"handle_value_committed deserialize Ledger error {}",e);
-                return;
+                return true;
             }
         };
 
@@ -380,26 +459,21 @@ impl BftConsensus {
                 Ok(hash_list) => hash_list,
                 Err(e) => {
                     error!(parent:self.span(),"handle_value_committed deserialize TxHashList error {}",e);
-                    return;
+                    return true;
                 }
             },
             None => TxHashList::default(),
         };
 
-        // get tx from tx-pool
+        // get tx from tx-pool, or from the validators when some are missing
         if hash_list.get_hash_set().len() > 0 {
-            let (tx_list, lacktx_hash_set) = TX_POOL_INSTANCE_REF
-                .read()
-                .get_block_by_hashs(hash_list.get_hash_set());
-            if lacktx_hash_set.len() > 0 {
-                for (hash, index) in lacktx_hash_set.iter() {
-                    error!(parent:self.span(),"lacktx hash({}) index({}) in block({})",bytes_to_hex_str(hash),index,block.get_header().get_height());
-                }
-                return;
-            } else {
-                let value: Vec<_> = tx_list.iter().map(|t| t.convert_into()).collect();
-                block.set_transaction_signs(protobuf::RepeatedField::from(value));
-            }
+            let tx_list = match self.block_transactions(&block, hash_list.get_hash_set()) {
+                Some(tx_list) => tx_list,
+                None => return false,
+            };
+            let value: Vec<_> = tx_list.iter().map(|t| t.convert_into()).collect();
+            block.set_transaction_signs(protobuf::RepeatedField::from(value));
+            self.tx_fetcher.remove(hash_list.get_hash_set());
         }
 
         // add current proof into block
@@ -413,7 +487,6 @@ impl BftConsensus {
             Ok((tx_list, block_result)) => {
                 if let Err(e) = BlockExecutor::commit_block(&mut block, tx_list, &block_result) {
                     error!(parent:self.span(),"handle_value_committed commit_block error {}",e);
-                    return;
                 } else {
                     // publish event
                     self.send_to_ws(&block, &block_result);
@@ -421,9 +494,197 @@ impl BftConsensus {
             }
             Err(e) => {
                 error!(parent:self.span(),"handle_value_committed execute_block error {}",e);
+            }
+        };
+        true
+    }
+
+    // the transactions of `hash_list`, None while some are fetched from the validators
+    fn block_transactions(
+        &mut self,
+        block: &Ledger,
+        hash_list: &[Vec<u8>],
+    ) -> Option<Vec<SignedTransaction>> {
+        let (tx_list, missing) = self.tx_fetcher.collect(hash_list);
+        if missing.is_empty() {
+            return Some(tx_list);
+        }
+        for hash in missing.iter() {
+            info!(parent:self.span(),"lacktx hash({}) in block({}), fetching it",
+                bytes_to_hex_str(hash), block.get_header().get_height());
+        }
+        self.tx_fetcher
+            .fetch(missing, block.get_header().get_proposer());
+        self.check_tx_fetch(chrono::Local::now().timestamp_millis());
+        None
+    }
+
+    // whether this node holds every transaction of a proposed value, fetching those it lacks,
+    // and they fit in the block gas limit
+    fn has_value_transactions(&mut self, value: &[u8]) -> bool {
+        let block = match ProtocolParser::deserialize::<Ledger>(value) {
+            Ok(block) => block,
+            Err(_) => return true,
+        };
+        match BlockExecutor::extract_tx_hash_list(&block)
+            .and_then(|value| ProtocolParser::deserialize::<TxHashList>(&value).ok())
+        {
+            Some(hash_list) => match self.block_transactions(&block, hash_list.get_hash_set()) {
+                Some(tx_list) if !BlockExecutor::within_gas_limit(&tx_list) => {
+                    error!(parent:self.span(),"Transactions of block({}) are over the block gas limit",
+                        block.get_header().get_height());
+                    false
+                }
+                Some(_) => true,
+                None => false,
+            },
+            None => true,
+        }
+    }
+
+    pub fn check_tx_fetch(&mut self, current_time: i64) {
+        if !self
+            .tx_fetcher
+            .need_request(current_time, CONFIGURE_INSTANCE_REF.consensus.tx_fetch_timeout)
+        {
+            return;
+        }
+        let connected = self.network_consensus.validator_conn_ids();
+        match self.tx_fetcher.next_request(&connected, current_time) {
+            Some((endpoint, req)) => {
+                info!(parent:self.span(),"Request {} missing transactions from {}",
+                    req.get_hashes().len(), endpoint.addr());
+                self.network_consensus.send_msg(
+                    endpoint,
+                    tx_sync_message(
+                        ProtocolsActionMessageType::REQUEST,
+                        req.write_to_bytes().unwrap(),
+                    ),
+                );
+            }
+            None => {
+                error!(parent:self.span(),"No validator connected to fetch the missing transactions");
+            }
+        }
+    }
+
+    pub fn handle_tx_sync(&mut self, peer: Endpoint, msg: &ProtocolsMessage) {
+        match msg.get_action() {
+            ProtocolsActionMessageType::REQUEST => self.handle_tx_sync_request(peer, msg),
+            ProtocolsActionMessageType::RESPONSE => self.handle_tx_sync_response(peer, msg),
+            _ => {}
+        }
+    }
+
+    fn handle_tx_sync_request(&self, peer: Endpoint, msg: &ProtocolsMessage) {
+        let req = match ProtocolParser::deserialize::<SyncTransactionRequest>(msg.get_data()) {
+            Ok(req) => req,
+            Err(e) => {
+                error!(parent:self.span(),"handle_tx_sync_request deserialize error {}",e);
                 return;
             }
         };
+        if req.get_chain_id() != self_chain_id() {
+            return;
+        }
+
+        // the pool, what this node fetched itself, then the committed transactions
+        let mut transactions = Vec::new();
+        for hash in req
+            .get_hashes()
+            .iter()
+            .take(CONFIGURE_INSTANCE_REF.consensus.block_max_tx_size as usize)
+        {
+            if let Some(tx) = TX_POOL_INSTANCE_REF.read().get_by_hash(hash) {
+                transactions.push(tx.convert_into());
+            } else if let Some(tx) = self.tx_fetcher.get(hash) {
+                transactions.push(tx.convert_into());
+            } else if let Ok(Some(tx_store)) = LedgerStorage::load_tx(&bytes_to_hex_str(hash)) {
+                transactions.push(tx_store.get_transaction_sign().clone());
+            }
+        }
+
+        let mut resp = SyncTransactionResponse::new();
+        resp.set_chain_id(self_chain_id());
+        resp.set_requestid(req.get_requestid());
+        resp.set_transactions(RepeatedField::from(transactions));
+        self.network_consensus.send_msg(
+            peer,
+            tx_sync_message(
+                ProtocolsActionMessageType::RESPONSE,
+                resp.write_to_bytes().unwrap(),
+            ),
+        );
+    }
+
+    fn handle_tx_sync_response(&mut self, peer: Endpoint, msg: &ProtocolsMessage) {
+        let resp = match ProtocolParser::deserialize::<SyncTransactionResponse>(msg.get_data()) {
+            Ok(resp) => resp,
+            Err(e) => {
+                error!(parent:self.span(),"handle_tx_sync_response deserialize error {}",e);
+                return;
+            }
+        };
+        if resp.get_chain_id() != self_chain_id() || !self.tx_fetcher.receive(peer, &resp) {
+            return;
+        }
+
+        info!(parent:self.span(),"The missing transactions are fetched, resume consensus");
+        self.resume_prepares();
+        self.execute_value();
+    }
+
+    // vote for the values held back while their transactions were missing
+    fn resume_prepares(&mut self) {
+        let last_exe_sequence = self.last_exe_sequence();
+        let mut indexes: Vec<_> = self
+            .logs
+            .instances
+            .iter()
+            .filter(|(index, instance)| {
+                index.sequence > last_exe_sequence
+                    && instance.check_value != CheckValueResult::Valid
+                    && (instance.phase == BftInstancePhase::PRE_PREPARED
+                        || instance.phase == BftInstancePhase::PREPARED)
+            })
+            .map(|(index, _)| index.clone())
+            .collect();
+        indexes.sort_by(|a, b| BftInstanceIndex::cmp(a, b));
+
+        for index in indexes {
+            let value = match self.logs.instances.get(&index) {
+                Some(instance) => instance.pre_prepare.get_value().to_vec(),
+                None => continue,
+            };
+            if CheckValue::check_value_bytes(&value, self.span()) != CheckValueResult::Valid
+                || !self.has_value_transactions(&value)
+            {
+                continue;
+            }
+            let instance = match self.logs.instances.get_mut(&index) {
+                Some(instance) => instance,
+                None => continue,
+            };
+            instance.check_value = CheckValueResult::Valid;
+            let msg = if instance.phase == BftInstancePhase::PRE_PREPARED {
+                NewBftMessage::new_prepare(&self.state, &instance.pre_prepare, 1)
+            } else {
+                match instance.prepares_begin() {
+                    Some(prepare) => {
+                        instance.set_last_commit_send_time(chrono::Local::now().timestamp_millis());
+                        NewBftMessage::new_commit(&self.state, &prepare, 1)
+                    }
+                    None => continue,
+                }
+            };
+            info!(parent:self.span(),
+                "Send the held back {:?} message: view number({}), sequence({})",
+                msg.get_bft().get_msg_type(),
+                index.view_number,
+                index.sequence
+            );
+            self.state.broadcast_message(&msg);
+        }
     }
 
     pub fn handle_value_commit_after(&mut self, block: Ledger) {
@@ -443,16 +704,7 @@ impl BftConsensus {
                 .get_validators()
                 .clone()
         };
-        let proof = { LAST_COMMITTED_BLOCK_INFO_REF.read().get_proof() };
-        self.update_validators(block.get_header().get_height(), &validator_set, proof);
-
-        let validators: Vec<_> = validator_set
-            .get_validators()
-            .iter()
-            .map(|x| x.get_address().to_string())
-            .collect();
-        self.network_consensus.update_validators(&validators);
-        self.network_tx.update_validators(&validators);
+        self.apply_validators(block.get_header().get_height(), &validator_set);
 
         if self.is_validator() {
             self.ledger_upgrade_instance
@@ -547,7 +799,7 @@ impl BftConsensus {
     }
 
     pub fn publish(&mut self, last_value: &Option<Ledger>) -> bool {
-        if !self.view_active() {
+        if !self.view_active() || self.state.replaying {
             return true;
         }
         if !self.is_primary() {
@@ -606,6 +858,7 @@ impl BftConsensus {
             TX_POOL_INSTANCE_REF.read().get_block_hash_list(
                 CONFIGURE_INSTANCE_REF.consensus.block_max_tx_size,
                 CONFIGURE_INSTANCE_REF.consensus.block_max_contract_size,
+                CONFIGURE_INSTANCE_REF.consensus.block_max_gas_limit,
                 &self.last_commit_txs,
             )
         };
@@ -652,8 +905,22 @@ impl BftConsensus {
             return false;
         }
 
-        //Delete the last uncommitted logs
+        //Send the value proposed for this sequence again rather than a different one
         let last_exe_seququece = self.last_exe_sequence();
+        let index = BftInstanceIndex::new(self.view_number(), last_exe_seququece + 1);
+        if let Some(instance) = self.logs.instances.get(&index) {
+            if instance.pre_prepare_msg.has_bft() {
+                info!(parent:self.span(),
+                    "The sequence({}) is proposed in view number({}), send the pre-prepare message again",
+                    index.sequence,
+                    index.view_number
+                );
+                self.state.broadcast_message(&instance.pre_prepare_msg);
+                return true;
+            }
+        }
+
+        //Delete the last uncommitted logs
         self.logs.instances.retain(|key, value| {
             !((key.sequence > last_exe_seququece)
                 && ((value.phase.clone() as i64) < (BftInstancePhase::COMMITTED as i64)))
@@ -666,18 +933,7 @@ impl BftConsensus {
             next_sequence,
         );
 
-        //Check the index
-        let index = BftInstanceIndex::new(self.view_number(), next_sequence);
-        //Insert the instance to map
-        let mut instance = BftInstance::new();
-        instance.pre_prepare_msg.clone_from(&bft_sign);
-        instance.phase.clone_from(&BftInstancePhase::PRE_PREPARED);
-        instance
-            .pre_prepare
-            .clone_from(bft_sign.get_bft().get_pre_prepare());
-        let phase = BftInstancePhase::as_phase(&bft_sign.get_bft().get_msg_type());
-        instance.update_msg_buf(&phase, &bft_sign);
-        self.logs.instances.insert(index, instance);
+        self.insert_proposal(&bft_sign);
 
         info!(parent:self.span(),
             "Send pre-prepare message: view number({}), sequence({}), consensus value({}) value_digest({})",
@@ -692,6 +948,24 @@ impl BftConsensus {
         true
     }
 
+    // the instance of a pre-prepare this node sends as the primary
+    fn insert_proposal(&mut self, bft_sign: &BftSign) {
+        let pre_prepare = bft_sign.get_bft().get_pre_prepare();
+        //Check the index
+        let index = BftInstanceIndex::new(
+            pre_prepare.get_base().get_view_number(),
+            pre_prepare.get_base().get_sequence(),
+        );
+        //Insert the instance to map
+        let mut instance = BftInstance::new();
+        instance.pre_prepare_msg.clone_from(bft_sign);
+        instance.phase.clone_from(&BftInstancePhase::PRE_PREPARED);
+        instance.pre_prepare.clone_from(pre_prepare);
+        let phase = BftInstancePhase::as_phase(&bft_sign.get_bft().get_msg_type());
+        instance.update_msg_buf(&phase, bft_sign);
+        self.logs.instances.insert(index, instance);
+    }
+
     pub fn validators_set_and_quorum(&self) -> (ValidatorSet, usize) {
         (
             self.state.validators.validators_set(),
@@ -714,6 +988,7 @@ impl BftConsensus {
             .check_instances_timeout(&mut self.state, current_time);
         self.logs
             .check_vc_instances_timeout(&mut self.state, current_time);
+        self.check_tx_fetch(current_time);
     }
 
     pub fn start_view_change(&mut self) {
@@ -775,10 +1050,20 @@ impl BftConsensus {
                         bft.get_pre_prepare().get_value(),
                         self.span(),
                     );
+                    // no vote on a value this node cannot execute yet, it is sent once
+                    // the missing transactions are fetched
+                    if check_value == CheckValueResult::Valid
+                        && !self.has_value_transactions(bft.get_pre_prepare().get_value())
+                    {
+                        check_value = CheckValueResult::MayValid;
+                    }
                 }
                 if !BftHandler::create_instance(self, bft_sign) {
                     return false;
                 };
+                if !self.log_message(bft_sign) {
+                    return false;
+                }
 
                 let mut trigger_committed = false;
                 let ret = self.logs.handle_instance(
@@ -788,14 +1073,30 @@ impl BftConsensus {
                     &mut trigger_committed,
                 );
                 if trigger_committed {
-                    self.execute_value(bft_sign);
+                    self.execute_value();
                 }
             }
             BftMessageType::VIEW_CHANGE_VALUE => {
-                ret = HandlerViewChange::handler_view_change_value(self, &bft_sign);
+                let view_change = bft
+                    .get_view_change_value()
+                    .get_view_change_env()
+                    .get_bft()
+                    .get_view_change();
+                if view_change.get_base().get_view_number() > self.view_number() + MAX_VIEWS_AHEAD {
+                    return false;
+                }
+                // logged once accepted, a refused one never reaches the log
+                ret = HandlerViewChange::handler_view_change_value(self, &bft_sign)
+                    && self.log_message(bft_sign);
             }
             BftMessageType::NEW_VIEW => {
-                ret = HandlerNewView::handler_new_view(self, &bft_sign);
+                if bft.get_new_view().get_base().get_view_number()
+                    > self.view_number() + MAX_VIEWS_AHEAD
+                {
+                    return false;
+                }
+                ret =
+                    HandlerNewView::handler_new_view(self, &bft_sign) && self.log_message(bft_sign);
             }
             _ => {
                 ret = false;
@@ -804,4 +1105,439 @@ impl BftConsensus {
 
         ret
     }
+
+    // log a received instance message ahead of handling it and a view change or new view once
+    // it is accepted, a replayed one is in the log already. An instance message that cannot be
+    // logged is dropped like an unsent one
+    fn log_message(&self, bft_sign: &BftSign) -> bool {
+        if !self.is_validator() || self.state.replaying {
+            return true;
+        }
+        // a pre-prepare conflicting with the accepted one is refused, it must not replace
+        // the logged one of its instance
+        let bft = bft_sign.get_bft();
+        if bft.get_msg_type() == BftMessageType::PRE_PREPARE {
+            if let Some(instance) = self.logs.instances.get(&BftInstanceIndex::index(bft_sign)) {
+                if instance.phase != BftInstancePhase::NONE
+                    && instance.pre_prepare.get_value() != bft.get_pre_prepare().get_value()
+                {
+                    return true;
+                }
+            }
+        }
+        if let Err(e) = BftStorage::store_message(bft_sign) {
+            error!(parent:self.span(), "Failed to log bft message, not handling it: {}", e);
+            return false;
+        }
+        true
+    }
+}
+
+fn tx_sync_message(action: ProtocolsActionMessageType, data: Vec<u8>) -> ProtocolsMessage {
+    let mut message = ProtocolsMessage::new();
+    message.set_msg_type(ProtocolsMessageType::TRANSACTION_SYNC);
+    message.set_action(action);
+    message.set_data(data);
+    message.set_timestamp(chrono::Local::now().timestamp_millis());
+    message
+}
+
+// the transaction hashes a proposed value references
+fn value_tx_hashes(value: &[u8]) -> Vec<Vec<u8>> {
+    ProtocolParser::deserialize::<Ledger>(value)
+        .ok()
+        .and_then(|block| BlockExecutor::extract_tx_hash_list(&block))
+        .and_then(|data| ProtocolParser::deserialize::<TxHashList>(&data).ok())
+        .map(|hash_list| hash_list.get_hash_set().to_vec())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossbeam_channel::bounded;
+    use msp::signing::{create_private_key, create_secret_key, PrivateKey};
+    use network::NetworkConfigType;
+    use protos::{common::Validator, consensus::Bft};
+    use std::sync::{Mutex, MutexGuard};
+    use utils::{
+        general::self_chain_hub, signer::initialize_node_signer,
+        timer_manager::initialize_timer_manager, verify_sign::sign,
+    };
+
+    const ENCRYPTION_TYPE: &str = "eddsa_ed25519";
+    // the sequence after the ledger of the tests
+    const SEQUENCE: u64 = 2;
+
+    // the replicas of the tests share the message log of the node
+    static MESSAGE_LOG: Mutex<()> = Mutex::new(());
+
+    // an empty message log in `view_number`, held until the test ends
+    fn reset(view_number: i64) -> MutexGuard<'static, ()> {
+        let guard = MESSAGE_LOG.lock().unwrap_or_else(|e| e.into_inner());
+        configure::init_test_config();
+        initialize_node_signer(&CONFIGURE_INSTANCE_REF).unwrap();
+        initialize_timer_manager();
+        BftStorage::delete_messages(u64::MAX, i64::MAX).unwrap();
+        BftStorage::store_view_number(view_number);
+        guard
+    }
+
+    // four validators, the node under test is the first one
+    struct Replicas {
+        keys: Vec<Box<dyn PrivateKey>>,
+        set: ValidatorSet,
+    }
+
+    impl Replicas {
+        fn new() -> Self {
+            let node_key =
+                create_private_key(ENCRYPTION_TYPE, &CONFIGURE_INSTANCE_REF.node_private_key)
+                    .unwrap();
+            let mut keys: Vec<Box<dyn PrivateKey>> = vec![node_key];
+            keys.extend((1..4).map(|_| create_secret_key(ENCRYPTION_TYPE).unwrap()));
+            let mut set = ValidatorSet::new();
+            for key in keys.iter() {
+                let mut validator = Validator::new();
+                validator.set_address(key.get_address());
+                set.mut_validators().push(validator);
+            }
+            Self { keys, set }
+        }
+
+        // the node (re)started on the messages it logged
+        fn start(&self) -> BftConsensus {
+            let network = PeerNetwork::start(
+                "127.0.0.1:0",
+                "pbft-test",
+                "pbft_test_peer",
+                NetworkConfigType::Consensus,
+            );
+            let (_, upgrade_timer) = bounded(1024);
+            let ledger_upgrade = LedgerUpgradeInstance::new(
+                network.clone(),
+                network.publisher(),
+                network.add_subscribers(&[]),
+                LEDGER_VERSION,
+                upgrade_timer,
+            );
+            let (timer_sender, _) = bounded(1024);
+            let (commit_sender, _) = futures::channel::mpsc::channel(1024);
+            let (ws_sender, _) = tokio::sync::mpsc::unbounded_channel();
+            BftConsensus::new(
+                timer_sender,
+                &self.set,
+                SEQUENCE - 1,
+                Arc::new(RwLock::new(ledger_upgrade)),
+                network.clone(),
+                network,
+                commit_sender,
+                ws_sender,
+            )
+        }
+
+        // the message `build` makes, as sent by the validator `replica_id`
+        fn sent_by(
+            &self,
+            node: &BftConsensus,
+            replica_id: i64,
+            build: impl Fn(&BftState) -> BftSign,
+        ) -> BftSign {
+            let mut state = node.state.clone();
+            state.replica_id = replica_id;
+            self.signed(replica_id, build(&state).get_bft().clone())
+        }
+
+        // `bft` signed by the validator `replica_id`
+        fn signed(&self, replica_id: i64, bft: Bft) -> BftSign {
+            let key = self.keys[replica_id as usize].as_hex();
+            let data = ProtocolParser::serialize::<Bft>(&bft);
+            let mut bft_sign = BftSign::new();
+            bft_sign.set_signature(sign(&key, &data, ENCRYPTION_TYPE).unwrap());
+            bft_sign.set_bft(bft);
+            bft_sign.set_chain_id(self_chain_id());
+            bft_sign.set_chain_hub(self_chain_hub());
+            bft_sign
+        }
+
+        // the pre-prepare of the primary of the node's view
+        fn pre_prepare(&self, node: &BftConsensus, value: &[u8]) -> BftSign {
+            let primary = node.view_number() % self.keys.len() as i64;
+            self.sent_by(node, primary, |state| {
+                NewBftMessage::new_pre_prepare(state, value, SEQUENCE)
+            })
+        }
+
+        fn prepare(&self, node: &BftConsensus, replica_id: i64, pre_prepare: &BftSign) -> BftSign {
+            self.sent_by(node, replica_id, |state| {
+                NewBftMessage::new_prepare(state, pre_prepare.get_bft().get_pre_prepare(), 1)
+            })
+        }
+
+        fn commit(&self, node: &BftConsensus, replica_id: i64, prepare: &BftSign) -> BftSign {
+            self.sent_by(node, replica_id, |state| {
+                NewBftMessage::new_commit(state, prepare.get_bft().get_prepare(), 1)
+            })
+        }
+
+        // the view change of the validator `replica_id` to `view_number`, nothing prepared
+        fn view_change(&self, replica_id: i64, view_number: i64, sequence: u64) -> BftSign {
+            let mut inner = Bft::new();
+            inner.set_msg_type(BftMessageType::VIEW_CHANGE);
+            inner
+                .mut_view_change()
+                .set_base(NewBftMessage::new_base_info(
+                    view_number,
+                    sequence,
+                    replica_id,
+                ));
+            let mut bft = Bft::new();
+            bft.set_msg_type(BftMessageType::VIEW_CHANGE_VALUE);
+            bft.mut_view_change_value()
+                .set_view_change_env(self.signed(replica_id, inner));
+            self.signed(replica_id, bft)
+        }
+    }
+
+    // a value on top of the ledger of the tests
+    fn value(timestamp: i64) -> Vec<u8> {
+        let lcl = LAST_COMMITTED_BLOCK_INFO_REF.read().get_header().clone();
+        ProtocolParser::serialize::<Ledger>(&BlockExecutor::initialize_new_block(
+            lcl.get_height() + 1,
+            lcl.get_hash().to_vec(),
+            timestamp,
+            lcl.get_version(),
+            0,
+            lcl.get_total_tx_count(),
+            String::new(),
+            None,
+            None,
+        ))
+    }
+
+    // the logged messages of `msg_type` the node sent
+    fn sent(msg_type: BftMessageType) -> Vec<BftSign> {
+        BftStorage::load_messages()
+            .unwrap()
+            .into_iter()
+            .filter(|bft_sign| {
+                let bft = bft_sign.get_bft();
+                let base = match msg_type {
+                    BftMessageType::PRE_PREPARE => bft.get_pre_prepare().get_base(),
+                    BftMessageType::PREPARE => bft.get_prepare().get_base(),
+                    BftMessageType::COMMIT => bft.get_commit().get_base(),
+                    _ => bft
+                        .get_view_change_value()
+                        .get_view_change_env()
+                        .get_bft()
+                        .get_view_change()
+                        .get_base(),
+                };
+                bft.get_msg_type() == msg_type && base.get_replica_id() == 0
+            })
+            .collect()
+    }
+
+    fn instance(node: &BftConsensus, view_number: i64) -> &BftInstance {
+        &node.logs.instances[&BftInstanceIndex::new(view_number, SEQUENCE)]
+    }
+
+    #[test]
+    fn restart_primary_after_pre_prepare_test() {
+        let _log = reset(0);
+        let replicas = Replicas::new();
+        let mut node = replicas.start();
+        assert!(node.is_primary());
+        assert!(node.publish(&None));
+        let proposed = sent(BftMessageType::PRE_PREPARE);
+        assert_eq!(proposed.len(), 1);
+
+        // killed before any prepare arrives
+        drop(node);
+        let mut node = replicas.start();
+        assert_eq!(instance(&node, 0).phase, BftInstancePhase::PRE_PREPARED);
+        assert_eq!(instance(&node, 0).pre_prepare_msg, proposed[0]);
+
+        // the value is proposed again rather than a new one
+        assert!(node.publish(&None));
+        assert_eq!(sent(BftMessageType::PRE_PREPARE), proposed);
+        assert_eq!(node.logs.instances.len(), 1);
+
+        for replica_id in 1..3 {
+            node.handle_receive_consensus(&replicas.prepare(&node, replica_id, &proposed[0]));
+        }
+        assert_eq!(instance(&node, 0).phase, BftInstancePhase::PREPARED);
+        let commits = sent(BftMessageType::COMMIT);
+        assert_eq!(commits.len(), 1);
+        assert_eq!(
+            commits[0].get_bft().get_commit().get_value_digest(),
+            proposed[0].get_bft().get_pre_prepare().get_value_digest()
+        );
+    }
+
+    #[test]
+    fn restart_after_pre_prepare_test() {
+        let _log = reset(1);
+        let replicas = Replicas::new();
+        let mut node = replicas.start();
+        let pre_prepare = replicas.pre_prepare(&node, &value(1));
+        node.handle_receive_consensus(&pre_prepare);
+        let prepares = sent(BftMessageType::PREPARE);
+        assert_eq!(prepares.len(), 1);
+
+        // killed before the prepares of the others arrive, nothing is sent on the way back
+        drop(node);
+        let logged = BftStorage::load_messages().unwrap();
+        let mut node = replicas.start();
+        assert_eq!(BftStorage::load_messages().unwrap(), logged);
+        assert!(node.view_active());
+        let restored = instance(&node, 1);
+        assert_eq!(restored.phase, BftInstancePhase::PRE_PREPARED);
+        assert_eq!(
+            &restored.pre_prepare,
+            pre_prepare.get_bft().get_pre_prepare()
+        );
+        assert!(restored.prepares.contains_key(&0));
+
+        // another value for the same sequence is refused and does not replace the logged one
+        node.handle_receive_consensus(&replicas.pre_prepare(&node, &value(2)));
+        assert_eq!(BftStorage::load_messages().unwrap(), logged);
+        assert_eq!(sent(BftMessageType::PREPARE), prepares);
+
+        // the prepare sent before the restart counts toward the quorum
+        node.handle_receive_consensus(&replicas.prepare(&node, 2, &pre_prepare));
+        assert_eq!(instance(&node, 1).phase, BftInstancePhase::PREPARED);
+        let commits = sent(BftMessageType::COMMIT);
+        assert_eq!(commits.len(), 1);
+        assert_eq!(
+            commits[0].get_bft().get_commit().get_value_digest(),
+            pre_prepare.get_bft().get_pre_prepare().get_value_digest()
+        );
+    }
+
+    #[test]
+    fn restart_after_prepare_test() {
+        let _log = reset(1);
+        let replicas = Replicas::new();
+        let mut node = replicas.start();
+        let pre_prepare = replicas.pre_prepare(&node, &value(1));
+        node.handle_receive_consensus(&pre_prepare);
+        for replica_id in 2..4 {
+            node.handle_receive_consensus(&replicas.prepare(&node, replica_id, &pre_prepare));
+        }
+        let commits = sent(BftMessageType::COMMIT);
+        assert_eq!(commits.len(), 1);
+
+        drop(node);
+        let logged = BftStorage::load_messages().unwrap();
+        let mut node = replicas.start();
+        assert_eq!(BftStorage::load_messages().unwrap(), logged);
+        let restored = instance(&node, 1);
+        assert_eq!(restored.phase, BftInstancePhase::PREPARED);
+        assert_eq!(restored.prepares.len(), 3);
+        assert!(restored.commits.contains_key(&0));
+
+        node.handle_receive_consensus(&replicas.pre_prepare(&node, &value(2)));
+        assert_eq!(BftStorage::load_messages().unwrap(), logged);
+
+        // the prepared value is carried into the next view
+        node.start_view_change();
+        let view_changes = sent(BftMessageType::VIEW_CHANGE_VALUE);
+        assert_eq!(view_changes.len(), 1);
+        let view_change = view_changes[0]
+            .get_bft()
+            .get_view_change_value()
+            .get_view_change_env()
+            .get_bft()
+            .get_view_change();
+        assert_eq!(
+            view_change.get_prepared_value_digest(),
+            pre_prepare.get_bft().get_pre_prepare().get_value_digest()
+        );
+    }
+
+    #[test]
+    fn restart_after_commit_test() {
+        let _log = reset(1);
+        let replicas = Replicas::new();
+        let mut node = replicas.start();
+        let pre_prepare = replicas.pre_prepare(&node, &value(1));
+        node.handle_receive_consensus(&pre_prepare);
+        let mut prepares = Vec::new();
+        for replica_id in 2..4 {
+            prepares.push(replicas.prepare(&node, replica_id, &pre_prepare));
+            node.handle_receive_consensus(&prepares[prepares.len() - 1]);
+        }
+        node.handle_receive_consensus(&replicas.commit(&node, 2, &prepares[0]));
+        assert_eq!(instance(&node, 1).commits.len(), 1);
+
+        // killed one commit short of the quorum
+        drop(node);
+        let mut node = replicas.start();
+        let restored = instance(&node, 1);
+        assert_eq!(restored.phase, BftInstancePhase::PREPARED);
+        let mut committed: Vec<i64> = restored.commits.keys().cloned().collect();
+        committed.sort_unstable();
+        assert_eq!(committed, vec![0, 2]);
+        assert_eq!(node.last_exe_sequence(), SEQUENCE - 1);
+
+        // a commit for another value does not complete the quorum
+        let other = replicas.pre_prepare(&node, &value(2));
+        let other_prepare = replicas.prepare(&node, 3, &other);
+        node.handle_receive_consensus(&replicas.commit(&node, 3, &other_prepare));
+        assert_eq!(instance(&node, 1).commits.len(), 2);
+        assert_eq!(instance(&node, 1).phase, BftInstancePhase::PREPARED);
+        assert_eq!(node.last_exe_sequence(), SEQUENCE - 1);
+    }
+
+    #[test]
+    fn restart_after_view_change_test() {
+        let _log = reset(1);
+        let replicas = Replicas::new();
+        let mut node = replicas.start();
+        let pre_prepare = replicas.pre_prepare(&node, &value(1));
+        node.handle_receive_consensus(&pre_prepare);
+        node.start_view_change();
+        assert!(!node.view_active());
+        let view_changes = sent(BftMessageType::VIEW_CHANGE_VALUE);
+        assert_eq!(view_changes.len(), 1);
+
+        // still waiting for the new view after the restart
+        drop(node);
+        let logged = BftStorage::load_messages().unwrap();
+        let mut node = replicas.start();
+        assert_eq!(BftStorage::load_messages().unwrap(), logged);
+        assert!(!node.view_active());
+        assert_eq!(node.view_number(), 1);
+        assert_eq!(node.logs.vc_instances[&2].view_change_msg, view_changes[0]);
+        assert_eq!(instance(&node, 1).phase, BftInstancePhase::PRE_PREPARED);
+
+        // the old view takes no more votes
+        node.handle_receive_consensus(&replicas.prepare(&node, 2, &pre_prepare));
+        assert_eq!(BftStorage::load_messages().unwrap(), logged);
+        assert_eq!(instance(&node, 1).prepares.len(), 1);
+    }
+
+    #[test]
+    fn view_change_log_bound_test() {
+        let _log = reset(1);
+        let replicas = Replicas::new();
+        let mut node = replicas.start();
+
+        // a faulty validator's view changes to distant or past views are not logged
+        let ahead = 1 + MAX_VIEWS_AHEAD + 1;
+        assert!(!node.handle_receive_consensus(&replicas.view_change(2, ahead, SEQUENCE - 1)));
+        assert!(!node.logs.vc_instances.contains_key(&ahead));
+        assert!(!node.handle_receive_consensus(&replicas.view_change(2, 0, SEQUENCE - 1)));
+        assert!(BftStorage::load_messages().unwrap().is_empty());
+
+        // one in the window is logged once whatever sequence it is resent with
+        for sequence in SEQUENCE - 1..SEQUENCE + 3 {
+            assert!(node.handle_receive_consensus(&replicas.view_change(2, 3, sequence)));
+        }
+        let logged = BftStorage::load_messages().unwrap();
+        assert_eq!(logged.len(), 1);
+        assert_eq!(node.logs.vc_instances[&3].view_changes.len(), 1);
+        assert_eq!(node.view_number(), 1);
+    }
 }
@@ -1,4 +1,4 @@
-use msp::signing::{eddsa_ed25519::EddsaEd25519PrivateKey, PrivateKey};
+use consensus_store::bft_storage::BftStorage;
 use network::{LocalBusPublisher, PeerNetwork, ReturnableProtocolsMessage};
 use protos::{
     common::{
@@ -8,9 +8,9 @@ use protos::{
 };
 use tracing::*;
 use utils::{
-    general::{node_address, node_private_key, self_chain_hub, self_chain_id},
+    general::{self_chain_hub, self_chain_id},
     parse::ProtocolParser,
-    verify_sign::sign,
+    signer::node_signer,
     LogUtil,
 };
 
@@ -53,7 +53,6 @@ impl Eq for BftInstancePhase {}
 #[derive(Clone)]
 pub struct BftState {
     pub(crate) is_validator: bool,
-    pub(crate) private_key: EddsaEd25519PrivateKey,
     pub(crate) chain_id: String,
     pub(crate) chain_hub: String,
     pub(crate) node_address: String,
@@ -65,6 +64,8 @@ pub struct BftState {
     pub(crate) last_exe_sequence: u64,
     pub(crate) fault_number: u64,
     pub(crate) view_active: bool,
+    //Set while the logged messages are replayed after a restart
+    pub(crate) replaying: bool,
     pub(crate) network: PeerNetwork,
     pub(crate) span: Span,
     pub(crate) publisher: LocalBusPublisher<ProtocolsMessageType, ReturnableProtocolsMessage>,
@@ -73,14 +74,11 @@ pub struct BftState {
 impl BftState {
     pub fn new(last_sequence: u64, network: PeerNetwork) -> Self {
         let publisher = network.publisher();
-        let private_key =
-            EddsaEd25519PrivateKey::from_hex(&node_private_key()).expect("private key error");
         Self {
             is_validator: false,
-            private_key,
             chain_id: self_chain_id(),
             chain_hub: self_chain_hub(),
-            node_address: node_address(),
+            node_address: node_signer().address().to_string(),
             replica_id: -1,
             validators: Validators::default(),
             view_number: 0,
@@ -88,6 +86,7 @@ impl BftState {
             last_exe_sequence: last_sequence,
             fault_number: 0,
             view_active: true,
+            replaying: false,
             network,
             span: LogUtil::create_span("consensus"),
             publisher,
@@ -103,7 +102,12 @@ impl BftState {
     }
 
     pub fn broadcast_message(&self, message: &BftSign) {
-        if !self.is_validator() {
+        if !self.is_validator() || self.replaying {
+            return;
+        }
+        //Logged before it leaves the node, so a restart does not vote differently
+        if let Err(e) = BftStorage::store_message(message) {
+            error!(parent:self.span(), "Failed to log bft message, not sending it: {}", e);
             return;
         }
         let mut consensus = Consensus::default();
@@ -134,7 +138,7 @@ impl BftState {
     }
 
     pub fn sign_data(&self, data: &[u8]) -> Signature {
-        match sign(&self.private_key.as_hex(), data, "eddsa_ed25519") {
+        match node_signer().sign(data) {
             Ok(sign_ret) => sign_ret,
             Err(e) => {
                 error!("sign error:{}", e);
@@ -146,8 +150,7 @@ impl BftState {
     pub fn update_validators(&mut self, validators_set: &ValidatorSet) {
         self.validators.update_validators(&validators_set);
 
-        let node_address = self.private_key.get_address();
-        if let Some(replica_id) = self.validators.replica_id(node_address.as_str()) {
+        if let Some(replica_id) = self.validators.replica_id(self.node_address.as_str()) {
             self.is_validator = true;
             self.replica_id = replica_id;
         } else {
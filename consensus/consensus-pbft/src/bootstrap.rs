@@ -1,15 +1,14 @@
 use configure::CONFIGURE_INSTANCE_REF;
+use consensus_engine::{start_engine, ConsensusEngine, WsPublishEventSender};
 use crossbeam_channel::{bounded, Receiver};
 use executor::LAST_COMMITTED_BLOCK_INFO_REF;
 use ledger_upgrade::ledger_upgrade::LedgerUpgradeService;
-use network::PeerNetwork;
-use parking_lot::RwLock;
+use network::{Endpoint, PeerNetwork};
 use protos::{
-    common::{ProtocolsMessageType, TransactionResult},
-    consensus::{BftSign, Consensus, ConsensusType},
+    common::{ProtocolsMessage, ProtocolsMessageType, ValidatorSet},
+    consensus::{BftSign, ConsensusType},
     ledger::Ledger,
 };
-use std::sync::Arc;
 use tracing::error;
 use tx_pool::types::CommitNotificationSender;
 use utils::{
@@ -24,7 +23,7 @@ pub fn start_consensus(
     network_tx: PeerNetwork,
     network_consensus: PeerNetwork,
     commit_to_txpool_sender: CommitNotificationSender,
-    ws_publish_event_sender: tokio::sync::mpsc::UnboundedSender<(Ledger, Vec<TransactionResult>)>,
+    ws_publish_event_sender: WsPublishEventSender,
     commit_notify: Receiver<Ledger>,
 ) {
     let (timer_sender, timer_receiver) = bounded::<TimterEventParam>(1024);
@@ -40,7 +39,7 @@ pub fn start_consensus(
         LedgerUpgradeService::start(network_consensus.clone(), lcl.get_height());
     let ledger_upgrade_instance = ledger_upgrade_service.ledger_upgrade.clone();
 
-    let consensus = Arc::new(RwLock::new(BftConsensus::new(
+    let consensus = BftConsensus::new(
         timer_sender,
         &validator_set,
         lcl.get_height(),
@@ -49,122 +48,93 @@ pub fn start_consensus(
         network_consensus.clone(),
         commit_to_txpool_sender,
         ws_publish_event_sender,
-    )));
+    );
 
     if lcl.get_version() < LEDGER_VERSION {
         ledger_upgrade_instance
             .write()
             .set_new_version(LEDGER_VERSION);
     }
-    if consensus.read().is_validator() {
+    if consensus.is_validator() {
         ledger_upgrade_instance
             .write()
             .set_info(lcl.get_version(), true);
     }
 
-    process(
-        consensus.clone(),
-        timer_receiver,
-        network_consensus,
-        commit_notify,
-    );
-    start_consensus_check_timer(consensus.clone());
-    start_consensus_publish_timer(consensus.clone());
-    start_ledgerclose_check_timer(consensus.clone());
+    start_engine(consensus, network_consensus, timer_receiver, commit_notify);
 }
 
-fn process(
-    consensus: Arc<RwLock<BftConsensus>>,
-    timer_receiver: Receiver<TimterEventParam>,
-    network: PeerNetwork,
-    commit_notify: Receiver<Ledger>,
-) {
-    let subscriber = network.add_subscriber(ProtocolsMessageType::CONSENSUS);
-    std::thread::spawn(move || loop {
-        crossbeam_channel::select! {
-            recv(timer_receiver) -> para =>{
-                match para {
-                    Ok(param)=>{
-                        match param.event_type{
-                            TimerEventType::PbftConsensusCheck => {
-                                consensus.write().check_consensus_timeout(param.timestamp);
-                            }
-                            TimerEventType::PbftConsensusPublish => {
-                                TimerManager::instance().delete_timer(param.id);
-                                consensus.write().publish(&None);
-                            }
-                            TimerEventType::PbftLedgerCloseCheck => {
-                                TimerManager::instance().delete_timer(param.id);
-                                consensus.write().start_view_change();
-                            }
-                            TimerEventType::PbftNewViewRepond => {
-                                TimerManager::instance().delete_timer(param.id);
-                                if let Some(data) = param.data {
-                                    consensus
-                                        .write()
-                                        .handle_new_view_repond_timer(data.as_slice());
-                                }
-                            }
-                            _ => {}
-                        }
-                    }
-                    Err(e)=>{
-                        error!("{:?}", e);
-                    }
-                }
-            }
-            recv(subscriber.inbox) -> msg =>{
-                match msg {
-                    Ok((_, msg))=>{
-                        let (_, proto_message) = msg;
-                        match ProtocolParser::deserialize::<Consensus>(proto_message.get_data()) {
-                            Ok(consensus_message) =>{
-                                match consensus_message.get_consensus_type() {
-                                    ConsensusType::PBFT => {
-                                        match ProtocolParser::deserialize::<BftSign>(consensus_message.get_msg()) {
-                                            Ok(bft_sign) => {
-                                                let _ = consensus.write().handle_receive_consensus(&bft_sign);
-                                            }
-                                            Err(e) => {
-                                                error!("{:?}", e);
-                                            }
-                                        }
-                                    }
-                                }
-                            },
-                            Err(e) => {
-                                error!("{:?}", e);
-                            }
-                        }
-                    }
-                    Err(e)=>{
-                        error!("{:?}", e);
-                    }
-                }
+impl ConsensusEngine for BftConsensus {
+    fn consensus_type(&self) -> ConsensusType {
+        ConsensusType::PBFT
+    }
+
+    fn rpc_message_types(&self) -> Vec<ProtocolsMessageType> {
+        vec![ProtocolsMessageType::TRANSACTION_SYNC]
+    }
+
+    fn start(&mut self) {
+        self.start_consensus_check_timer();
+        self.start_consensus_publish_timer(CONFIGURE_INSTANCE_REF.consensus.commit_interval);
+        self.start_ledgerclose_check_timer();
+    }
+
+    fn propose(&mut self) -> bool {
+        self.publish(&None)
+    }
+
+    fn handle_message(&mut self, msg: &[u8]) {
+        match ProtocolParser::deserialize::<BftSign>(msg) {
+            Ok(bft_sign) => {
+                let _ = self.handle_receive_consensus(&bft_sign);
             }
-            recv(commit_notify) -> para =>{
-                match para {
-                    Ok(ledger)=>{
-                        consensus.write().handle_value_commit_after(ledger);
-                    }
-                    Err(e)=>{
-                        error!("{:?}", e);
-                    }
-                }
+            Err(e) => {
+                error!("{:?}", e);
             }
         }
-    });
-}
+    }
 
-fn start_consensus_check_timer(consensus: Arc<RwLock<BftConsensus>>) {
-    consensus.write().start_consensus_check_timer();
-}
+    fn handle_rpc(&mut self, peer: Endpoint, msg: &ProtocolsMessage) {
+        self.handle_tx_sync(peer, msg);
+    }
 
-fn start_consensus_publish_timer(consensus: Arc<RwLock<BftConsensus>>) {
-    consensus
-        .write()
-        .start_consensus_publish_timer(CONFIGURE_INSTANCE_REF.consensus.commit_interval);
-}
-fn start_ledgerclose_check_timer(consensus: Arc<RwLock<BftConsensus>>) {
-    consensus.write().start_ledgerclose_check_timer();
+    fn handle_commit(&mut self, block: Ledger) {
+        self.handle_value_commit_after(block);
+    }
+
+    fn apply_validators(&mut self, height: u64, validators: &ValidatorSet) {
+        let proof = { LAST_COMMITTED_BLOCK_INFO_REF.read().get_proof() };
+        self.update_validators(height, validators, proof);
+
+        let addresses: Vec<_> = validators
+            .get_validators()
+            .iter()
+            .map(|x| x.get_address().to_string())
+            .collect();
+        self.network_consensus.update_validators(&addresses);
+        self.network_tx.update_validators(&addresses);
+    }
+
+    fn handle_timer(&mut self, param: TimterEventParam) {
+        match param.event_type {
+            TimerEventType::PbftConsensusCheck => {
+                self.check_consensus_timeout(param.timestamp);
+            }
+            TimerEventType::PbftConsensusPublish => {
+                TimerManager::instance().delete_timer(param.id);
+                self.propose();
+            }
+            TimerEventType::PbftLedgerCloseCheck => {
+                TimerManager::instance().delete_timer(param.id);
+                self.start_view_change();
+            }
+            TimerEventType::PbftNewViewRepond => {
+                TimerManager::instance().delete_timer(param.id);
+                if let Some(data) = param.data {
+                    self.handle_new_view_repond_timer(data.as_slice());
+                }
+            }
+            _ => {}
+        }
+    }
 }
@@ -155,7 +155,7 @@ impl BftHandler {
                 return result;
             } else {
                 if trigger_committed {
-                    return bft_consensus.execute_value(bft_sign);
+                    return bft_consensus.execute_value();
                 }
                 return result;
             }
@@ -6,5 +6,6 @@ pub mod bootstrap;
 pub mod handler;
 pub mod instance;
 pub mod new_bft_message;
+pub mod tx_fetcher;
 pub mod utils;
 pub mod validators;
@@ -0,0 +1,294 @@
+use executor::block_verify::Verify;
+use network::Endpoint;
+use protobuf::RepeatedField;
+use protos::ledger::{SyncTransactionRequest, SyncTransactionResponse};
+use std::{
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+};
+use tx_pool::TX_POOL_INSTANCE_REF;
+use types::SignedTransaction;
+use utils::general::self_chain_id;
+
+// the request in flight: the endpoint of the validator asked, the request id and when it was sent
+struct TxFetchRequest {
+    endpoint: Endpoint,
+    requestid: i64,
+    send_time: i64,
+}
+
+/// Fetches the transactions a proposal references by hash but the tx-pool does not hold.
+/// The proposer is asked first, then the other validators in turn until all arrived.
+#[derive(Default)]
+pub struct TxFetcher {
+    missing: HashSet<Vec<u8>>,
+    fetched: HashMap<Vec<u8>, SignedTransaction>,
+    proposer: String,
+    // validators asked in the current round
+    asked: HashSet<String>,
+    request: Option<TxFetchRequest>,
+    next_id: i64,
+}
+
+impl TxFetcher {
+    /// The transactions of `hash_list` in order, from the tx-pool or fetched, and the hashes
+    /// found nowhere.
+    pub fn collect(&self, hash_list: &[Vec<u8>]) -> (Vec<SignedTransaction>, Vec<Vec<u8>>) {
+        let (mut tx_list, lack_txs) = TX_POOL_INSTANCE_REF.read().get_block_by_hashs(hash_list);
+        let mut missing = Vec::new();
+        for (hash, index) in lack_txs {
+            match self.fetched.get(&hash) {
+                Some(tx) => tx_list[index] = tx.clone(),
+                None => missing.push(hash),
+            }
+        }
+        (tx_list, missing)
+    }
+
+    /// A fetched transaction, to serve the validators missing it as well.
+    pub fn get(&self, hash: &[u8]) -> Option<&SignedTransaction> {
+        self.fetched.get(hash)
+    }
+
+    /// Add `hashes` to fetch, the proposer of the value referencing them is asked first.
+    pub fn fetch(&mut self, hashes: Vec<Vec<u8>>, proposer: &str) {
+        if self.missing.is_empty() {
+            self.proposer = proposer.to_string();
+            self.asked.clear();
+        }
+        self.missing.extend(hashes);
+    }
+
+    pub fn is_fetching(&self) -> bool {
+        !self.missing.is_empty()
+    }
+
+    /// Whether a request is due, none in flight or the one in flight timed out.
+    pub fn need_request(&self, now: i64, timeout: i64) -> bool {
+        self.is_fetching()
+            && self
+                .request
+                .as_ref()
+                .map_or(true, |req| now - req.send_time >= timeout)
+    }
+
+    /// Pick the next validator among `connected` and build the request for it, the proposer
+    /// first. A new round starts once every validator was asked.
+    pub fn next_request(
+        &mut self,
+        connected: &HashMap<String, Endpoint>,
+        now: i64,
+    ) -> Option<(Endpoint, SyncTransactionRequest)> {
+        self.request = None;
+        if !self.is_fetching() {
+            return None;
+        }
+        let pick = |asked: &HashSet<String>, proposer: &str| {
+            if !asked.contains(proposer) && connected.contains_key(proposer) {
+                return Some(proposer.to_string());
+            }
+            let mut candidates: Vec<_> = connected
+                .keys()
+                .filter(|validator| !asked.contains(*validator))
+                .collect();
+            candidates.sort();
+            candidates.first().map(|validator| validator.to_string())
+        };
+        let validator = match pick(&self.asked, &self.proposer) {
+            Some(validator) => validator,
+            None => {
+                self.asked.clear();
+                pick(&self.asked, &self.proposer)?
+            }
+        };
+        let endpoint = connected[&validator];
+        self.asked.insert(validator);
+
+        self.next_id += 1;
+        let mut req = SyncTransactionRequest::new();
+        req.set_chain_id(self_chain_id());
+        req.set_requestid(self.next_id);
+        req.set_hashes(RepeatedField::from(
+            self.missing.iter().cloned().collect::<Vec<_>>(),
+        ));
+        self.request = Some(TxFetchRequest {
+            endpoint,
+            requestid: self.next_id,
+            send_time: now,
+        });
+        Some((endpoint, req))
+    }
+
+    /// Take the transactions of a response, those not asked for or not properly signed are
+    /// dropped. Returns whether nothing is missing anymore.
+    pub fn receive(&mut self, endpoint: Endpoint, resp: &SyncTransactionResponse) -> bool {
+        match &self.request {
+            Some(req) if req.endpoint == endpoint && req.requestid == resp.get_requestid() => {}
+            _ => return false,
+        }
+        for tx_sign in resp.get_transactions() {
+            // the hash does not cover the signatures
+            if !matches!(tx_sign.verify_tx(), Ok(true)) {
+                continue;
+            }
+            let tx = match SignedTransaction::try_from(tx_sign.clone()) {
+                Ok(tx) => tx,
+                Err(_) => continue,
+            };
+            if self.missing.remove(tx.hash()) {
+                self.fetched.insert(tx.hash().to_vec(), tx);
+            }
+        }
+        // the validator sent what it had, the rest is asked elsewhere on the next check
+        self.request = None;
+        !self.is_fetching()
+    }
+
+    /// Drop the fetched transactions of a committed value.
+    pub fn remove(&mut self, hash_list: &[Vec<u8>]) {
+        for hash in hash_list {
+            self.fetched.remove(hash);
+        }
+    }
+
+    /// Keep the transactions of `hashes` only, those of the values still to execute, so the
+    /// fetches in progress for them go on.
+    pub fn retain(&mut self, hashes: &HashSet<Vec<u8>>) {
+        self.missing.retain(|hash| hashes.contains(hash));
+        self.fetched.retain(|hash, _| hashes.contains(hash));
+        if self.missing.is_empty() {
+            self.asked.clear();
+            self.request = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use msp::signing::create_secret_key;
+    use protobuf::Message;
+    use protos::ledger::{Transaction, TransactionSign};
+    use utils::{general::hash_crypto_byte, verify_sign::sign};
+
+    fn transaction(nonce: u64, signed: bool) -> TransactionSign {
+        let mut tx = Transaction::new();
+        tx.set_nonce(nonce);
+        tx.set_value("0".to_string());
+        tx.set_gas_price("1".to_string());
+        let hash = hash_crypto_byte(&tx.write_to_bytes().unwrap());
+        let key = create_secret_key("eddsa_ed25519").unwrap();
+        // signed over another transaction when not properly signed
+        let content = if signed { hash } else { vec![0; 32] };
+        let signature = sign(&key.as_hex(), &content, "eddsa_ed25519").unwrap();
+        let mut tx_sign = TransactionSign::new();
+        tx_sign.set_transaction(tx);
+        tx_sign.mut_signatures().push(signature);
+        tx_sign
+    }
+
+    fn hash(tx_sign: &TransactionSign) -> Vec<u8> {
+        SignedTransaction::try_from(tx_sign.clone())
+            .unwrap()
+            .hash()
+            .to_vec()
+    }
+
+    fn response(requestid: i64, txs: Vec<TransactionSign>) -> SyncTransactionResponse {
+        let mut resp = SyncTransactionResponse::new();
+        resp.set_chain_id(self_chain_id());
+        resp.set_requestid(requestid);
+        resp.set_transactions(RepeatedField::from(txs));
+        resp
+    }
+
+    fn validators() -> HashMap<String, Endpoint> {
+        let mut connected = HashMap::new();
+        connected.insert(
+            "proposer".to_string(),
+            network::Node::generate_listen_endpoint("127.0.0.1:0"),
+        );
+        connected.insert(
+            "replica".to_string(),
+            network::Node::generate_listen_endpoint("127.0.0.1:0"),
+        );
+        connected
+    }
+
+    #[test]
+    fn fetch_signed_transactions_test() {
+        configure::init_test_config();
+        let connected = validators();
+        let signed = transaction(1, true);
+        let forged = transaction(2, false);
+
+        let mut fetcher = TxFetcher::default();
+        fetcher.fetch(vec![hash(&signed), hash(&forged)], "proposer");
+        assert!(fetcher.need_request(0, 100));
+        let (endpoint, req) = fetcher.next_request(&connected, 0).unwrap();
+        assert_eq!(endpoint, connected["proposer"]);
+        assert_eq!(req.get_hashes().len(), 2);
+        assert!(!fetcher.need_request(50, 100));
+
+        // the badly signed transaction is still missing
+        let resp = response(req.get_requestid(), vec![signed.clone(), forged.clone()]);
+        assert!(!fetcher.receive(endpoint, &resp));
+        assert!(fetcher.get(&hash(&signed)).is_some());
+        assert!(fetcher.get(&hash(&forged)).is_none());
+
+        // asked from the next validator
+        let (endpoint, req) = fetcher.next_request(&connected, 10).unwrap();
+        assert_eq!(endpoint, connected["replica"]);
+        assert_eq!(req.get_hashes().to_vec(), vec![hash(&forged)]);
+    }
+
+    #[test]
+    fn unrequested_response_test() {
+        configure::init_test_config();
+        let connected = validators();
+        let tx = transaction(3, true);
+
+        let mut fetcher = TxFetcher::default();
+        fetcher.fetch(vec![hash(&tx)], "proposer");
+        let (endpoint, req) = fetcher.next_request(&connected, 0).unwrap();
+
+        // another request id, or another validator
+        assert!(!fetcher.receive(
+            endpoint,
+            &response(req.get_requestid() + 1, vec![tx.clone()])
+        ));
+        let other = connected["replica"];
+        assert!(!fetcher.receive(other, &response(req.get_requestid(), vec![tx.clone()])));
+        assert!(fetcher.is_fetching());
+
+        assert!(fetcher.receive(endpoint, &response(req.get_requestid(), vec![tx.clone()])));
+        assert!(fetcher.get(&hash(&tx)).is_some());
+    }
+
+    #[test]
+    fn retain_fetches_test() {
+        configure::init_test_config();
+        let connected = validators();
+        let kept = transaction(4, true);
+        let dropped = transaction(5, true);
+
+        let mut fetcher = TxFetcher::default();
+        fetcher.fetch(vec![hash(&kept), hash(&dropped)], "proposer");
+        let (endpoint, req) = fetcher.next_request(&connected, 0).unwrap();
+
+        // a view change keeps fetching for the committed value only
+        let needed: HashSet<Vec<u8>> = vec![hash(&kept)].into_iter().collect();
+        fetcher.retain(&needed);
+        assert!(fetcher.is_fetching());
+        assert!(fetcher.receive(
+            endpoint,
+            &response(req.get_requestid(), vec![kept.clone(), dropped.clone()])
+        ));
+        assert!(fetcher.get(&hash(&kept)).is_some());
+        assert!(fetcher.get(&hash(&dropped)).is_none());
+
+        fetcher.retain(&HashSet::new());
+        assert!(fetcher.get(&hash(&kept)).is_none());
+        assert!(!fetcher.is_fetching());
+    }
+}
@@ -0,0 +1,162 @@
+use configure::CONFIGURE_INSTANCE_REF;
+use executor::LAST_COMMITTED_BLOCK_INFO_REF;
+use protos::{common::TransactionResult, ledger::Ledger};
+use std::{sync::mpsc::sync_channel, thread, time::Duration};
+use tracing::error;
+use tx_pool::{types::CommitNotificationSender, TX_POOL_INSTANCE_REF};
+
+use crate::solo::SoloConsensus;
+
+// the shortest wait for the next block when sealing every commit_interval
+const MIN_CHECK_INTERVAL: u64 = 10;
+
+/// Start sealing blocks alone, every commit_interval or as soon as the tx-pool holds
+/// transactions when `instant`.
+pub fn start_solo_consensus(
+    commit_to_txpool_sender: CommitNotificationSender,
+    ws_publish_event_sender: tokio::sync::mpsc::UnboundedSender<(Ledger, Vec<TransactionResult>)>,
+    instant: bool,
+) {
+    // the tx-pool wakes the instant mode once transactions are ready for a block
+    let tx_ready = if instant {
+        let (sender, receiver) = sync_channel(1);
+        TX_POOL_INSTANCE_REF.write().tx_ready_sender = Some(sender);
+        Some(receiver)
+    } else {
+        None
+    };
+    let mut consensus =
+        SoloConsensus::new(instant, commit_to_txpool_sender, ws_publish_event_sender);
+    thread::Builder::new()
+        .name("solo-consensus".to_string())
+        .spawn(move || loop {
+            let sealed = match consensus.try_seal() {
+                Ok(block) => block.is_some(),
+                Err(e) => {
+                    error!("solo seal error {}", e);
+                    false
+                }
+            };
+            match &tx_ready {
+                // more may be ready than one block holds
+                Some(_) if sealed => {}
+                Some(tx_ready) => {
+                    if tx_ready.recv().is_err() {
+                        return;
+                    }
+                }
+                None => thread::sleep(Duration::from_millis(next_check())),
+            }
+        })
+        .expect("failed to spawn solo consensus thread");
+}
+
+// milliseconds until the next block is due
+fn next_check() -> u64 {
+    let lcl_timestamp = LAST_COMMITTED_BLOCK_INFO_REF
+        .read()
+        .get_header()
+        .get_timestamp();
+    let next_commit_time = lcl_timestamp + CONFIGURE_INSTANCE_REF.consensus.commit_interval;
+    let now = chrono::Local::now().timestamp_millis();
+    (next_commit_time - now).clamp(MIN_CHECK_INTERVAL as i64, i64::MAX) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use executor::BlockExecutor;
+    use protobuf::Message;
+    use protos::ledger::{Transaction, TransactionSign};
+    use std::{convert::TryFrom, time::Instant};
+    use syscontract::{
+        contract_factory::initialize_system_contract_factory,
+        system_address::initialize_syscontract_address,
+    };
+    use tx_pool::{
+        start_txpool_service,
+        types::{get_account_nonce_banace, TxPoolStatusCode},
+    };
+    use types::SignedTransaction;
+    use utils::{
+        general::{genesis_block_config, hash_crypto_byte, self_chain_id, GENESIS_HEIGHT},
+        verify_sign::sign,
+    };
+
+    // the key of the genesis account of setting/config.toml
+    const GENESIS_KEY: &str = "fc5a55e22797ed20e78b438d9e3ca873877a7b55a604dfa7531c300e743c5ef1";
+
+    fn transfer() -> SignedTransaction {
+        let genesis = genesis_block_config();
+        let (nonce, _) = get_account_nonce_banace(&genesis.genesis_account).unwrap();
+        let mut tx = Transaction::new();
+        tx.set_source(genesis.genesis_account.clone());
+        tx.set_nonce(nonce);
+        tx.set_to(genesis.validators[0].clone());
+        tx.set_value("1".to_string());
+        tx.set_gas_limit(100_000);
+        tx.set_gas_price("1".to_string());
+        tx.set_chain_id(self_chain_id());
+        let hash = hash_crypto_byte(&tx.write_to_bytes().unwrap());
+
+        let mut tx_sign = TransactionSign::new();
+        tx_sign.set_transaction(tx);
+        tx_sign
+            .mut_signatures()
+            .push(sign(GENESIS_KEY, &hash, "eddsa_ed25519").unwrap());
+        SignedTransaction::try_from(tx_sign).unwrap()
+    }
+
+    #[test]
+    fn instant_seal_test() {
+        configure::init_test_config();
+        initialize_syscontract_address();
+        initialize_system_contract_factory();
+        BlockExecutor::block_initialize().unwrap();
+
+        let (client_sender, client_receiver) = futures::channel::mpsc::unbounded();
+        let (pending_tx_sender, _pending_tx_receiver) = futures::channel::mpsc::unbounded();
+        let (_runtime, _broadcast_tx_sender, committed_sender) = start_txpool_service(
+            &CONFIGURE_INSTANCE_REF.tx_pool,
+            client_receiver,
+            pending_tx_sender,
+            None,
+        );
+        let (ws_sender, mut ws_receiver) = tokio::sync::mpsc::unbounded_channel();
+        start_solo_consensus(committed_sender, ws_sender, true);
+
+        let tx = transfer();
+        let (callback, status) = futures::channel::oneshot::channel();
+        client_sender
+            .unbounded_send((tx.clone(), callback))
+            .unwrap();
+        let (status, _) = futures::executor::block_on(status).unwrap().unwrap();
+        assert_eq!(status.code, TxPoolStatusCode::Accepted);
+
+        // sealed once the tx-pool has the transaction ready, within its broadcast interval
+        let deadline = Instant::now() + Duration::from_secs(10);
+        let (block, results) = loop {
+            match ws_receiver.try_recv() {
+                Ok(sealed) => break sealed,
+                Err(_) if Instant::now() < deadline => thread::sleep(Duration::from_millis(10)),
+                Err(e) => panic!("no block sealed: {}", e),
+            }
+        };
+        assert_eq!(block.get_header().get_height(), GENESIS_HEIGHT + 1);
+        assert_eq!(block.get_transaction_signs().len(), 1);
+        assert_eq!(results.len(), 1);
+        let sealed = SignedTransaction::try_from(block.get_transaction_signs()[0].clone()).unwrap();
+        assert_eq!(sealed.hash(), tx.hash());
+        assert_eq!(
+            LAST_COMMITTED_BLOCK_INFO_REF
+                .read()
+                .get_header()
+                .get_height(),
+            GENESIS_HEIGHT + 1
+        );
+
+        // nothing more to seal, the node waits for the tx-pool
+        thread::sleep(Duration::from_millis(100));
+        assert!(ws_receiver.try_recv().is_err());
+    }
+}
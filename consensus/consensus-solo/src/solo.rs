@@ -0,0 +1,151 @@
+use anyhow::{bail, Result};
+use configure::CONFIGURE_INSTANCE_REF;
+use executor::{block_result::BlockResult, BlockExecutor, LAST_COMMITTED_BLOCK_INFO_REF};
+use protobuf::RepeatedField;
+use protos::{
+    common::TransactionResult,
+    consensus::TxHashList,
+    ledger::{Ledger, TransactionSign},
+};
+use std::collections::{HashMap, HashSet};
+use tracing::{error, info};
+use tx_pool::{
+    types::{CommitNotificationSender, TxPoolCommitNotification, TxPoolCommitted},
+    TX_POOL_INSTANCE_REF,
+};
+use utils::{general::node_address, parse::ProtocolParser};
+
+/// Seals blocks alone from the tx-pool, without proofs nor networking. Only meant for a
+/// single development node or an in-process test chain.
+pub struct SoloConsensus {
+    // seal as soon as the tx-pool holds transactions instead of every commit_interval
+    instant: bool,
+    commit_to_txpool_sender: CommitNotificationSender,
+    ws_publish_event_sender: tokio::sync::mpsc::UnboundedSender<(Ledger, Vec<TransactionResult>)>,
+    // committed but maybe not yet removed from the tx-pool
+    last_commit_txs: HashMap<String, TxPoolCommitted>,
+}
+
+impl SoloConsensus {
+    pub fn new(
+        instant: bool,
+        commit_to_txpool_sender: CommitNotificationSender,
+        ws_publish_event_sender: tokio::sync::mpsc::UnboundedSender<(
+            Ledger,
+            Vec<TransactionResult>,
+        )>,
+    ) -> Self {
+        Self {
+            instant,
+            commit_to_txpool_sender,
+            ws_publish_event_sender,
+            last_commit_txs: HashMap::default(),
+        }
+    }
+
+    /// Seal the next block when it is due, returning it.
+    pub fn try_seal(&mut self) -> Result<Option<Ledger>> {
+        let now = chrono::Local::now().timestamp_millis();
+        if !self.instant {
+            let lcl_timestamp = LAST_COMMITTED_BLOCK_INFO_REF
+                .read()
+                .get_header()
+                .get_timestamp();
+            if now < lcl_timestamp + CONFIGURE_INSTANCE_REF.consensus.commit_interval {
+                return Ok(None);
+            }
+        }
+
+        let hash_list = TX_POOL_INSTANCE_REF.read().get_block_hash_list(
+            CONFIGURE_INSTANCE_REF.consensus.block_max_tx_size,
+            CONFIGURE_INSTANCE_REF.consensus.block_max_contract_size,
+            CONFIGURE_INSTANCE_REF.consensus.block_max_gas_limit,
+            &self.last_commit_txs,
+        );
+        if self.instant && hash_list.is_empty() {
+            return Ok(None);
+        }
+        self.seal(hash_list, now).map(Some)
+    }
+
+    /// Seal a block of the transactions of `hash_list`, at `timestamp`.
+    pub fn seal(&mut self, hash_list: Vec<Vec<u8>>, timestamp: i64) -> Result<Ledger> {
+        let lcl = LAST_COMMITTED_BLOCK_INFO_REF.read().get_header().clone();
+        let (tx_list, lack_txs) = TX_POOL_INSTANCE_REF.read().get_block_by_hashs(&hash_list);
+        if !lack_txs.is_empty() {
+            bail!(
+                "{} transactions left the tx-pool while sealing",
+                lack_txs.len()
+            );
+        }
+
+        let tx_count = hash_list.len() as u64;
+        let tx_hash_list = if tx_count > 0 {
+            let mut proto_hash_list = TxHashList::default();
+            proto_hash_list.set_hash_set(RepeatedField::from(hash_list));
+            Some(ProtocolParser::serialize::<TxHashList>(&proto_hash_list))
+        } else {
+            None
+        };
+        let mut block = BlockExecutor::initialize_new_block(
+            lcl.get_height() + 1,
+            lcl.get_hash().to_vec(),
+            timestamp.max(lcl.get_timestamp() + 1),
+            lcl.get_version(),
+            tx_count,
+            lcl.get_total_tx_count() + tx_count,
+            node_address(),
+            None,
+            tx_hash_list,
+        );
+        let tx_signs: Vec<TransactionSign> = tx_list.iter().map(|t| t.convert_into()).collect();
+        block.set_transaction_signs(RepeatedField::from(tx_signs));
+
+        let (tx_list, block_result) = BlockExecutor::execute_block(&block)?;
+        BlockExecutor::commit_block(&mut block, tx_list, &block_result)?;
+        self.after_commit(&block, &block_result);
+        Ok(block)
+    }
+
+    fn after_commit(&mut self, block: &Ledger, result: &BlockResult) {
+        info!(
+            "Ledger({}) sealed alone, txs({})",
+            block.get_header().get_height(),
+            block.get_transaction_signs().len()
+        );
+        if let Err(e) = self
+            .ws_publish_event_sender
+            .send((block.clone(), result.tx_result_set.clone()))
+        {
+            error!("ws_publish_event_sender send error:{:?}", e);
+        }
+
+        // the tx-pool drops the committed transactions
+        if block.get_transaction_signs().is_empty() {
+            return;
+        }
+        let mut transactions: HashMap<String, TxPoolCommitted> = HashMap::new();
+        for tx in block.get_transaction_signs().iter() {
+            let sender = tx.get_transaction().get_source();
+            let nonce = tx.get_transaction().get_nonce();
+            let committed =
+                transactions
+                    .entry(sender.to_string())
+                    .or_insert_with(|| TxPoolCommitted {
+                        sender: sender.to_string(),
+                        max_seq: nonce,
+                        seqs: HashSet::default(),
+                    });
+            committed.max_seq = committed.max_seq.max(nonce);
+            committed.seqs.insert(nonce);
+        }
+        self.last_commit_txs.clone_from(&transactions);
+        let notify = TxPoolCommitNotification {
+            transactions,
+            count: block.get_transaction_signs().len() as u64,
+        };
+        if let Err(e) = self.commit_to_txpool_sender.try_send(notify) {
+            error!("solo to_tx_pool_commit_sender send error({})", e);
+        }
+    }
+}
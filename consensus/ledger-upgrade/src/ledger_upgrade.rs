@@ -15,6 +15,7 @@ use tracing::*;
 use utils::{
     general::*,
     parse::ProtocolParser,
+    signer::node_signer,
     timer_manager::{TimerEventType, TimerManager, TimterEventParam},
     verify_sign::{get_sign_address, verify_sign},
 };
@@ -165,11 +166,7 @@ impl LedgerUpgradeInstance {
         {
             notify.set_nonce(current_time);
             notify.set_upgrade(self.local_state.clone());
-            let sig = match utils::verify_sign::sign(
-                &node_private_key(),
-                ProtocolParser::serialize(&notify).as_slice(),
-                "eddsa_ed25519",
-            ) {
+            let sig = match node_signer().sign(ProtocolParser::serialize(&notify).as_slice()) {
                 Ok(value) => value,
                 Err(e) => {
                     error!("{}", e);
@@ -7,12 +7,14 @@ use merkletree::Tree;
 use msp::bytes_to_hex_str;
 use protos::{
     common::{KeyValuePair, TransactionResult, Validator, ValidatorSet},
-    consensus::BftProof,
+    consensus::{BftProof, TxHashList},
     ledger::*,
 };
 use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
-use state::{cache_state::StateMapActionType, AccountFrame, CacheState, TrieHash, TrieWriter};
-use state_store::StateStorage;
+use state::{
+    cache_state::StateMapActionType, AccountFrame, CacheState, TrieHash, TrieJournal, TrieWriter,
+};
+use state_store::{pruner::StatePruner, StateStorage};
 use std::collections::HashMap;
 use storage_db::{MemWriteBatch, WriteBatchTrait, STORAGE_INSTANCE_REF};
 use syscontract::{
@@ -24,7 +26,10 @@ use tracing::{error, info};
 use types::error::BlockExecutionError;
 use types::transaction::SignedTransaction;
 use utils::{
-    general::{genesis_block_config, hash_crypto_byte, hash_zero, self_chain_hub, self_chain_id},
+    general::{
+        consensus_config, genesis_block_config, hash_crypto_byte, hash_zero, self_chain_hub,
+        self_chain_id,
+    },
     parse::ProtocolParser,
 };
 
@@ -85,21 +90,19 @@ impl BlockExecutor {
                     continue;
                 }
             };
-            if is_system_contract(&tx_raw.to().to_string()) {
-                if let Err(e) = vm.sysvm_execute(index, &tx_raw, &mut post_state) {
-                    let error = BlockExecutionError::VmError {
-                        error: format!("sysvm execute error {e:?}"),
-                    };
-                    continue;
-                }
+            let ret = if is_system_contract(&tx_raw.to().to_string()) {
+                vm.sysvm_execute(index, &tx_raw, &mut post_state)
+            } else if tx_raw.tx_type() == TransactionType::WASM {
+                vm.wasm_execute(index, &tx_raw, &mut post_state)
             } else {
-                if let Err(e) = vm.evm_execute(index, &tx_raw, &mut post_state) {
-                    let error = BlockExecutionError::VmError {
-                        error: format!("vm execute error {e:?}"),
-                    };
-                    error!("vm execute error {e:?}");
-                    continue;
-                }
+                vm.evm_execute(index, &tx_raw, &mut post_state)
+            };
+            if let Err(e) = ret {
+                error!("vm execute error {e:?}");
+                post_state.add_receipt(
+                    header.get_height(),
+                    Self::failed_receipt(index, e.to_string()),
+                );
             }
 
             tx_array.push(tx_raw);
@@ -137,10 +140,13 @@ impl BlockExecutor {
 
         // state commit and storage
         let mut state_batch = MemWriteBatch::new();
+        let mut state_journal = TrieJournal::default();
         let mut state_datas = HashMap::new();
         let state_changes = result.state.get_commit_changes();
         for (address, mut value) in state_changes {
-            value.data.commit_metadata_trie(&mut state_batch)?;
+            value
+                .data
+                .commit_metadata_trie(&mut state_batch, &mut state_journal)?;
             match value.action {
                 StateMapActionType::UPSERT => {
                     state_datas.insert(address.as_bytes().to_vec(), Some(value.data.serialize()));
@@ -155,6 +161,11 @@ impl BlockExecutor {
                     }
                 }
                 StateMapActionType::DELETE => {
+                    StatePruner::journal_deleted_account(
+                        &address,
+                        last_state_root_hash,
+                        &mut state_journal,
+                    )?;
                     state_datas.insert(address.as_bytes().to_vec(), None);
                 }
                 _ => {}
@@ -167,6 +178,13 @@ impl BlockExecutor {
             last_state_root_hash,
             &state_datas,
             &mut state_batch,
+            &mut state_journal,
+        )?;
+        StatePruner::commit(
+            header.get_height(),
+            state_journal,
+            &state_root_hash,
+            &mut state_batch,
         )?;
         let proof = if let Some(proof_data) = Self::extract_proof(block) {
             let proof = ProtocolParser::deserialize::<BftProof>(&proof_data)?;
@@ -176,6 +194,7 @@ impl BlockExecutor {
         } else {
             None
         };
+        Self::store_previous_proof(block, &mut state_batch)?;
         StateStorage::commit(state_batch)?;
 
         // set state hash
@@ -253,6 +272,7 @@ impl BlockExecutor {
 
         let mut ledger_batch = MemWriteBatch::new();
         LedgerStorage::store_ledger(&mut ledger_batch, &header, &mut txs_store);
+        LedgerStorage::store_ledger_indexes(&mut ledger_batch, &header, &txs_store)?;
         LedgerStorage::commit(ledger_batch)?;
 
         LAST_COMMITTED_BLOCK_INFO_REF
@@ -341,10 +361,13 @@ impl BlockExecutor {
 
         // state commit and storage
         let mut state_batch = MemWriteBatch::new();
+        let mut state_journal = TrieJournal::default();
         let mut state_datas = HashMap::new();
         let state_changes = result.state.get_commit_changes();
         for (address, mut value) in state_changes {
-            value.data.commit_metadata_trie(&mut state_batch)?;
+            value
+                .data
+                .commit_metadata_trie(&mut state_batch, &mut state_journal)?;
             match value.action {
                 StateMapActionType::UPSERT => {
                     state_datas.insert(address.as_bytes().to_vec(), Some(value.data.serialize()));
@@ -359,6 +382,11 @@ impl BlockExecutor {
                     }
                 }
                 StateMapActionType::DELETE => {
+                    StatePruner::journal_deleted_account(
+                        &address,
+                        last_state_root_hash,
+                        &mut state_journal,
+                    )?;
                     state_datas.insert(address.as_bytes().to_vec(), None);
                 }
                 _ => {}
@@ -371,6 +399,7 @@ impl BlockExecutor {
             last_state_root_hash,
             &state_datas,
             &mut state_batch,
+            &mut state_journal,
         )?;
         // verify state hash
         match header.verify_state_hash(&state_root_hash) {
@@ -378,6 +407,12 @@ impl BlockExecutor {
             _ => bail!("verify state hash error"),
         };
 
+        StatePruner::commit(
+            header.get_height(),
+            state_journal,
+            &state_root_hash,
+            &mut state_batch,
+        )?;
         let proof = if let Some(proof_data) = Self::extract_proof(block) {
             let proof = ProtocolParser::deserialize::<BftProof>(&proof_data)?;
             StateStorage::store_last_proof(&mut state_batch, &proof);
@@ -386,7 +421,7 @@ impl BlockExecutor {
         } else {
             None
         };
-        StateStorage::commit(state_batch)?;
+        Self::store_previous_proof(block, &mut state_batch)?;
 
         // set state hash
         verify_header.set_state_hash(state_root_hash.to_vec());
@@ -480,8 +515,12 @@ impl BlockExecutor {
             _ => bail!("verify ledger hash error"),
         };
 
+        // nothing is stored until the whole header is verified
+        StateStorage::commit(state_batch)?;
+
         let mut ledger_batch = MemWriteBatch::new();
         LedgerStorage::store_ledger(&mut ledger_batch, &header, &mut txs_store);
+        LedgerStorage::store_ledger_indexes(&mut ledger_batch, &header, &txs_store)?;
         LedgerStorage::commit(ledger_batch)?;
 
         LAST_COMMITTED_BLOCK_INFO_REF
@@ -538,6 +577,7 @@ impl BlockExecutor {
     pub fn block_initialize() -> anyhow::Result<()> {
         let (header, validators, proof) =
             if let Some(height) = LedgerStorage::load_max_block_height()? {
+                StatePruner::ensure_counting()?;
                 let header = LedgerStorage::load_ledger_header_by_seq(height)?;
                 if let Some(header) = header {
                     let result = Self::load_validators(header.get_state_hash())?;
@@ -676,6 +716,15 @@ impl BlockExecutor {
         None
     }
 
+    pub fn extract_hotstuff_proof(block: &Ledger) -> Option<Vec<u8>> {
+        for kv in block.get_extended_data().get_extra_data().iter() {
+            if kv.get_key() == utils::general::HOTSTUFF_CURRENT_PROOF {
+                return Some(kv.get_value().to_vec());
+            }
+        }
+        None
+    }
+
     pub fn inject_consensus_value_hash(header: &mut LedgerHeader, consensus_hash: Vec<u8>) {
         let mut kv = KeyValuePair::default();
         kv.set_key(utils::general::BFT_CONSENSUS_VALUE_HASH.to_string());
@@ -692,6 +741,14 @@ impl BlockExecutor {
         block.mut_extended_data().mut_extra_data().push(kv);
     }
 
+    pub fn inject_hotstuff_proof(block: &mut Ledger, proof: Vec<u8>) {
+        let mut kv = KeyValuePair::default();
+        kv.set_key(utils::general::HOTSTUFF_CURRENT_PROOF.to_string());
+        kv.set_value(proof);
+
+        block.mut_extended_data().mut_extra_data().push(kv);
+    }
+
     pub fn inject_previous_proof(block: &mut Ledger, proof: Vec<u8>) {
         let mut kv = KeyValuePair::default();
         kv.set_key(utils::general::BFT_PREVIOUS_PROOF.to_string());
@@ -708,6 +765,48 @@ impl BlockExecutor {
         block.mut_extended_data().mut_extra_data().push(kv);
     }
 
+    // The proposal's proof of the previous block replaces the one collected locally, so all
+    // nodes serve the proof the consensus value of the block was built from.
+    fn store_previous_proof(block: &Ledger, batch: &mut MemWriteBatch) -> anyhow::Result<()> {
+        if let Some(proof_data) = Self::extract_previous_proof(block) {
+            let proof = ProtocolParser::deserialize::<BftProof>(&proof_data)?;
+            StateStorage::store_proof(batch, block.get_header().get_height() - 1, &proof);
+        }
+        Ok(())
+    }
+
+    /// Check the transactions of the block hash in order to the tx hash list of its consensus
+    /// value, so a proof of the value also covers them.
+    pub fn verify_tx_hash_list(block: &Ledger) -> anyhow::Result<()> {
+        let height = block.get_header().get_height();
+        let hash_list = match Self::extract_tx_hash_list(block) {
+            Some(data) => ProtocolParser::deserialize::<TxHashList>(&data)?
+                .get_hash_set()
+                .to_vec(),
+            None => Vec::new(),
+        };
+        let txs = block.get_transaction_signs();
+        if hash_list.len() != txs.len() || block.get_header().get_tx_count() != txs.len() as u64 {
+            bail!(
+                "block {} has {} transactions, its value lists {}",
+                height,
+                txs.len(),
+                hash_list.len()
+            );
+        }
+        for (index, (tx, hash)) in txs.iter().zip(hash_list.iter()).enumerate() {
+            let tx = SignedTransaction::try_from(tx.clone())?;
+            if tx.hash() != hash.as_slice() {
+                bail!(
+                    "transaction {} of block {} is not the one its value lists",
+                    index,
+                    height
+                );
+            }
+        }
+        Ok(())
+    }
+
     pub fn caculate_consensus_value_hash(block: &Ledger) -> Vec<u8> {
         let mut ledger = Ledger::default();
         let height = block.get_header().get_height();
@@ -735,40 +834,93 @@ impl BlockExecutor {
         consensus_hash
     }
 
-    pub fn call_transaction(tx: &TransactionSign) -> std::result::Result<(), ()> {
-        let header = if let Ok(Some(h)) = LedgerStorage::load_max_block_height() {
-            if let Ok(Some(header)) = LedgerStorage::load_ledger_header_by_seq(h) {
-                header
-            } else {
-                return Ok(());
-            }
-        } else {
-            return Ok(());
-        };
+    /// Execute the transaction read-only on top of the last committed state,
+    /// the changes are dropped together with the throwaway cache state.
+    pub fn call_transaction(
+        tx: &TransactionSign,
+    ) -> std::result::Result<TransactionResult, BlockExecutionError> {
+        let header = LAST_COMMITTED_BLOCK_INFO_REF.read().get_header().clone();
+        Self::call_transaction_at(tx, &header)
+    }
 
-        // initialize state by last block state root
-        let root_hash = TrieHash::default();
+    /// Execute `tx` on the state committed by `header`, nothing is stored.
+    pub fn call_transaction_at(
+        tx: &TransactionSign,
+        header: &LedgerHeader,
+    ) -> std::result::Result<TransactionResult, BlockExecutionError> {
+        let mut root_hash = TrieHash::default();
+        if header.get_state_hash().len() != root_hash.len() {
+            return Err(BlockExecutionError::NotExistError {
+                error: format!("state of block {}", header.get_height()),
+            });
+        }
+        root_hash.clone_from_slice(header.get_state_hash());
+
+        // initialize state by the block state root
         let state = CacheState::new(root_hash);
 
         // initialize contract vm
-        let mut vm = match Executor::new(&header, state.clone()) {
+        let mut vm = match Executor::new(header, state) {
             Ok(vm) => vm,
             Err(e) => {
-                return Err(());
+                return Err(BlockExecutionError::VmError {
+                    error: format!("vm init error {e:?}"),
+                });
             }
         };
 
         // execute tx
         let tx_raw = match SignedTransaction::try_from(tx.clone()) {
             Ok(tx_raw) => tx_raw,
-            Err(e) => return Err(()),
+            Err(e) => {
+                return Err(BlockExecutionError::TransactionParamError {
+                    error: format!("transaction convert error {e:?}"),
+                });
+            }
         };
-        let ret = match vm.call(&tx_raw) {
+        let ret = if tx_raw.tx_type() == TransactionType::WASM {
+            vm.wasm_call(&tx_raw)
+        } else {
+            vm.call(&tx_raw)
+                .map(|ret| Receipt::from_execution_result(0, ret.result))
+        };
+        let receipt = match ret {
             Ok(v) => v,
-            Err(e) => return Err(()),
+            Err(e) => {
+                return Err(BlockExecutionError::VmError {
+                    error: format!("{e:?}"),
+                });
+            }
         };
 
-        Ok(())
+        let mut tx_result = receipt.convert_to_geno_txresult();
+        if let Some(description) = &receipt.description {
+            tx_result.set_message(description.clone());
+        }
+        tx_result.set_block_height(header.get_height());
+        tx_result.set_block_hash(header.get_hash().to_vec());
+        Ok(tx_result)
+    }
+
+    /// Whether the gas limits of `txs` together fit in the block gas limit, transactions
+    /// past it are left in the pool for a later block and a proposal over it gets no vote.
+    pub fn within_gas_limit(txs: &[SignedTransaction]) -> bool {
+        let gas_limit = txs
+            .iter()
+            .fold(0u64, |sum, tx| sum.saturating_add(tx.gas_limit()));
+        gas_limit <= consensus_config().block_max_gas_limit
+    }
+
+    fn failed_receipt(index: usize, description: String) -> Receipt {
+        Receipt {
+            index,
+            success: false,
+            logs: vec![],
+            gas_used: 0,
+            contract_address: None,
+            output: None,
+            description: Some(description),
+        }
     }
 
     fn filter_new_validators(tx_result_set: &[TransactionResult]) -> ValidatorSet {
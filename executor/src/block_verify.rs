@@ -1,6 +1,6 @@
 use anyhow::bail;
 use protos::ledger::{Ledger, LedgerHeader};
-use types::SignedTransaction;
+use types::{eth_transaction::ETH_ENCRYPTION_TYPE, SignedTransaction};
 use utils::{signature::verify_sign, TransactionSign};
 
 pub trait Verify {
@@ -33,6 +33,10 @@ impl Verify for TransactionSign {
                     bail!("{}", e);
                 }
             };
+            // decoding an ethereum envelope already recovered its sender
+            if signature.get_encryption_type() == ETH_ENCRYPTION_TYPE {
+                return Ok(true);
+            }
             verify_sign(signature, tx.hash())
         } else {
             bail!("signature");
@@ -1,4 +1,4 @@
-use crate::{account, chain, config, node};
+use crate::{account, chain, config, db, node, snapshot};
 use anyhow::Result;
 use clap::{Parser, Subcommand};
 
@@ -19,6 +19,10 @@ pub enum Commands {
     Node(node::Command),
     #[command(name = "config")]
     Config(config::Command),
+    #[command(name = "db")]
+    Db(db::Command),
+    #[command(name = "snapshot")]
+    Snapshot(snapshot::Command),
 }
 
 impl Cmd {
@@ -28,6 +32,8 @@ impl Cmd {
             Commands::Chain(cmd) => cmd.run(),
             Commands::Account(cmd) => cmd.run(),
             Commands::Config(cmd) => cmd.run(),
+            Commands::Db(cmd) => cmd.run(),
+            Commands::Snapshot(cmd) => cmd.run(),
         }
     }
 }
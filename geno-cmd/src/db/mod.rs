@@ -0,0 +1,45 @@
+use anyhow::{bail, Result};
+use clap::{Parser, ValueEnum};
+use configure::{NodeMode, CONFIGURE_INSTANCE_REF};
+use state_store::pruner::StatePruner;
+
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// state roots to keep, defaults to N of a pruned(N) node mode
+    #[clap(name = "keep", long, value_name = "blocks")]
+    keep: Option<u64>,
+
+    #[arg(value_enum)]
+    subcmd: Option<SubCmd>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum SubCmd {
+    /// delete the state the last blocks do not reach, run it with the node stopped
+    Prune,
+}
+
+impl Command {
+    pub fn run(&self) -> Result<()> {
+        match self.subcmd {
+            Some(SubCmd::Prune) => {
+                let keep = match (self.keep, CONFIGURE_INSTANCE_REF.db.node_mode) {
+                    (Some(keep), _) => keep,
+                    (None, NodeMode::Pruned(keep)) => keep,
+                    (None, NodeMode::Archive) => {
+                        bail!("archive node, give the state roots to keep with --keep")
+                    }
+                    (None, NodeMode::Light) => bail!("light node, it keeps no state to prune"),
+                };
+                println!("prune state, keep the last {} blocks", keep);
+                let stats = StatePruner::prune_offline(keep)?;
+                println!(
+                    "kept nodes:{} deleted nodes:{} deleted code:{}",
+                    stats.kept_nodes, stats.deleted_nodes, stats.deleted_code
+                );
+            }
+            _ => (),
+        };
+        Ok(())
+    }
+}
@@ -4,6 +4,8 @@ pub mod config;
 pub mod account;
 pub mod chain;
 pub mod node;
+pub mod db;
+pub mod snapshot;
 pub mod argument;
 
 extern crate clap;
@@ -11,6 +13,7 @@ extern crate anyhow;
 extern crate ledger_store;
 extern crate configure;
 extern crate msp;
+extern crate state_store;
 extern crate ron;
 extern crate serde_json;
 #[macro_use]
@@ -0,0 +1,94 @@
+use anyhow::{bail, Result};
+use catchup::{snapshot_sync::verify_snapshot_proof, storage_executor::load_block};
+use clap::{Parser, ValueEnum};
+use configure::CONFIGURE_INSTANCE_REF;
+use executor::BlockExecutor;
+use ledger_store::LedgerStorage;
+use msp::bytes_to_hex_str;
+use state_store::snapshot::{Snapshot, SnapshotImporter};
+use std::path::PathBuf;
+use syscontract::{
+    contract_factory::initialize_system_contract_factory,
+    system_address::initialize_syscontract_address,
+};
+
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// block of the exported state, defaults to the one before the last block
+    #[clap(name = "height", long, value_name = "number")]
+    height: Option<u64>,
+
+    /// snapshot directory, export defaults to <snapshot_path>/<height>
+    #[clap(name = "path", long, value_name = "dir")]
+    path: Option<String>,
+
+    #[arg(value_enum)]
+    subcmd: Option<SubCmd>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum SubCmd {
+    /// write the state of a block into a snapshot directory
+    Export,
+    /// rebuild the state from a snapshot directory proven to the validators of the node,
+    /// the node must not be ahead of it
+    Import,
+}
+
+impl Command {
+    pub fn run(&self) -> Result<()> {
+        match self.subcmd {
+            Some(SubCmd::Export) => {
+                let height = match self.height {
+                    Some(height) => height,
+                    None => match LedgerStorage::load_max_block_height()? {
+                        Some(height) if height > 1 => height - 1,
+                        _ => bail!("no block committed after genesis"),
+                    },
+                };
+                // its proof signs the exported header
+                let mut next_block = match load_block(height + 1)? {
+                    Some(block) => block,
+                    None => bail!("block {} not found, it proves the snapshot", height + 1),
+                };
+                next_block.clear_transaction_signs();
+                let dir = match &self.path {
+                    Some(path) => PathBuf::from(path),
+                    None => PathBuf::from(&CONFIGURE_INSTANCE_REF.db.snapshot_path)
+                        .join(height.to_string()),
+                };
+                println!("export state of block {} to {}", height, dir.display());
+                let manifest = Snapshot::export(height, next_block, &dir)?;
+                println!(
+                    "accounts:{} chunks:{}",
+                    manifest.get_account_count(),
+                    manifest.get_chunk_hashes().len()
+                );
+            }
+            Some(SubCmd::Import) => {
+                let dir = match &self.path {
+                    Some(path) => PathBuf::from(path),
+                    None => bail!("give the snapshot directory with --path"),
+                };
+                // the snapshot is checked against the validators of the last block
+                initialize_syscontract_address();
+                initialize_system_contract_factory();
+                BlockExecutor::block_initialize()?;
+                let mut importer =
+                    SnapshotImporter::new(Snapshot::read_manifest(&dir)?, &verify_snapshot_proof)?;
+                println!("import state of block {}", importer.height());
+                while let Some(index) = importer.next_chunk() {
+                    importer.import_chunk(&Snapshot::read_chunk(&dir, index)?)?;
+                }
+                let header = importer.finish()?;
+                println!(
+                    "imported block {} hash {}",
+                    header.get_height(),
+                    bytes_to_hex_str(header.get_hash())
+                );
+            }
+            _ => (),
+        };
+        Ok(())
+    }
+}
@@ -0,0 +1,274 @@
+use crate::errors::JsonRpcError;
+use anyhow::{bail, format_err, Result};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Arc, time::Instant};
+use storage_db::STORAGE_INSTANCE_REF;
+
+/// HTTP header carrying the api key, websocket clients pass it in the path.
+pub const API_KEY_HEADER: &str = "x-api-key";
+/// Prefix of the api keys kept in the key value db.
+pub const API_KEY_DB_PREFIX: &str = "jsonrpc_api_key_";
+pub const API_KEY_STORE_FILE: &str = "file";
+pub const API_KEY_STORE_DB: &str = "db";
+/// Method name that allows every method.
+pub const ALL_METHODS: &str = "*";
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct ApiKey {
+    pub key: String,
+    /// methods the key may call, `*` allows all of them
+    pub methods: Vec<String>,
+    /// requests per second, 0 is unlimited
+    #[serde(default)]
+    pub rate_limit: u32,
+    /// websocket subscriptions per connection, 0 is unlimited
+    #[serde(default)]
+    pub max_subscriptions: usize,
+}
+
+impl ApiKey {
+    pub fn allows(&self, method: &str) -> bool {
+        self.methods.iter().any(|m| m == ALL_METHODS || m == method)
+    }
+}
+
+enum ApiKeySource {
+    File(HashMap<String, ApiKey>),
+    Db,
+}
+
+// token bucket holding at most one second worth of requests
+struct RateBucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl RateBucket {
+    fn new(rate_limit: u32, now: Instant) -> Self {
+        Self {
+            tokens: rate_limit as f64,
+            updated: now,
+        }
+    }
+
+    fn take(&mut self, rate_limit: u32, now: Instant) -> bool {
+        let refill = now.duration_since(self.updated).as_secs_f64() * rate_limit as f64;
+        self.tokens = (self.tokens + refill).min(rate_limit as f64);
+        self.updated = now;
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+struct InnerStore {
+    source: ApiKeySource,
+    buckets: Mutex<HashMap<String, RateBucket>>,
+}
+
+/// Api keys of the rpc servers, without a configured store every request is allowed.
+#[derive(Clone)]
+pub struct ApiKeyStore {
+    inner: Option<Arc<InnerStore>>,
+}
+
+impl ApiKeyStore {
+    pub fn new(config: &configure::JsonRpcConfig) -> Result<Self> {
+        let source = match config.api_key_store.as_deref() {
+            None => return Ok(Self { inner: None }),
+            Some(API_KEY_STORE_FILE) => {
+                let path = match &config.api_key_file {
+                    Some(path) => path,
+                    None => bail!("api_key_file is required by the file api key store"),
+                };
+                let content = std::fs::read_to_string(path)
+                    .map_err(|e| format_err!("read api key file {} error {}", path, e))?;
+                let keys: Vec<ApiKey> = serde_json::from_str(&content)
+                    .map_err(|e| format_err!("parse api key file {} error {}", path, e))?;
+                ApiKeySource::File(keys.into_iter().map(|k| (k.key.clone(), k)).collect())
+            }
+            Some(API_KEY_STORE_DB) => ApiKeySource::Db,
+            Some(store) => bail!("unknown api key store {}", store),
+        };
+        Ok(Self {
+            inner: Some(Arc::new(InnerStore {
+                source,
+                buckets: Mutex::new(HashMap::new()),
+            })),
+        })
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.inner.is_some()
+    }
+
+    pub fn get(&self, key: &str) -> Option<ApiKey> {
+        let inner = self.inner.as_ref()?;
+        match &inner.source {
+            ApiKeySource::File(keys) => keys.get(key).cloned(),
+            ApiKeySource::Db => {
+                let db_key = format!("{}{}", API_KEY_DB_PREFIX, key);
+                match STORAGE_INSTANCE_REF
+                    .key_value_db()
+                    .lock()
+                    .get(db_key.as_bytes())
+                {
+                    Ok(Some(bytes)) => serde_json::from_slice(&bytes).ok(),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    /// Resolve the key a client presents, None when authentication is disabled.
+    pub fn check_key(&self, key: Option<&str>) -> Result<Option<ApiKey>, JsonRpcError> {
+        if !self.is_enabled() {
+            return Ok(None);
+        }
+        match key.and_then(|key| self.get(key)) {
+            Some(api_key) => Ok(Some(api_key)),
+            None => Err(JsonRpcError::invalid_api_key()),
+        }
+    }
+
+    /// Check the key may call `method` and has rate budget left for it.
+    pub fn authorize(&self, key: Option<&str>, method: &str) -> Result<(), JsonRpcError> {
+        let api_key = match self.check_key(key)? {
+            Some(api_key) => api_key,
+            None => return Ok(()),
+        };
+        if !api_key.allows(method) {
+            return Err(JsonRpcError::method_not_allowed(method));
+        }
+        if api_key.rate_limit == 0 {
+            return Ok(());
+        }
+
+        let inner = self.inner.as_ref().unwrap();
+        let mut buckets = inner.buckets.lock();
+        let now = Instant::now();
+        let bucket = buckets
+            .entry(api_key.key.clone())
+            .or_insert_with(|| RateBucket::new(api_key.rate_limit, now));
+        if bucket.take(api_key.rate_limit, now) {
+            Ok(())
+        } else {
+            Err(JsonRpcError::rate_limited(api_key.rate_limit))
+        }
+    }
+
+    /// Add or replace a key of the db store.
+    pub fn put_db_key(api_key: &ApiKey) -> Result<()> {
+        let db_key = format!("{}{}", API_KEY_DB_PREFIX, api_key.key);
+        STORAGE_INSTANCE_REF
+            .key_value_db()
+            .lock()
+            .put(db_key.into_bytes(), serde_json::to_vec(api_key)?)
+    }
+
+    pub fn delete_db_key(key: &str) -> Result<()> {
+        let db_key = format!("{}{}", API_KEY_DB_PREFIX, key);
+        STORAGE_INSTANCE_REF
+            .key_value_db()
+            .lock()
+            .delete(db_key.into_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn file_store(name: &str, content: &str) -> Result<ApiKeyStore> {
+        let path = std::env::temp_dir().join(format!(
+            "geno-api-keys-{}-{}.json",
+            std::process::id(),
+            name
+        ));
+        std::fs::write(&path, content).unwrap();
+        let mut config = configure::JsonRpcConfig::default();
+        config.api_key_store = Some(API_KEY_STORE_FILE.to_string());
+        config.api_key_file = Some(path.display().to_string());
+        ApiKeyStore::new(&config)
+    }
+
+    #[test]
+    fn file_store_test() {
+        let store = file_store(
+            "file",
+            r#"[{"key":"reader","methods":["getBlock"]},{"key":"admin","methods":["*"]}]"#,
+        )
+        .unwrap();
+        assert!(store.is_enabled());
+
+        assert!(store.authorize(Some("reader"), "getBlock").is_ok());
+        assert!(store.authorize(Some("reader"), "sendTransaction").is_err());
+        assert!(store.authorize(Some("admin"), "sendTransaction").is_ok());
+        assert!(store.authorize(Some("unknown"), "getBlock").is_err());
+        assert!(store.authorize(None, "getBlock").is_err());
+        assert_eq!(
+            store.check_key(Some("admin")).unwrap().unwrap().key,
+            "admin"
+        );
+    }
+
+    #[test]
+    fn disabled_store_test() {
+        let store = ApiKeyStore::new(&configure::JsonRpcConfig::default()).unwrap();
+        assert!(!store.is_enabled());
+        assert!(store.authorize(None, "sendTransaction").is_ok());
+        assert_eq!(store.check_key(Some("any")).unwrap(), None);
+    }
+
+    #[test]
+    fn bad_config_test() {
+        assert!(file_store("bad", "not json").is_err());
+
+        let mut config = configure::JsonRpcConfig::default();
+        config.api_key_store = Some(API_KEY_STORE_FILE.to_string());
+        assert!(ApiKeyStore::new(&config).is_err());
+        config.api_key_file = Some("missing/api_keys.json".to_string());
+        assert!(ApiKeyStore::new(&config).is_err());
+        config.api_key_store = Some("ldap".to_string());
+        assert!(ApiKeyStore::new(&config).is_err());
+    }
+
+    #[test]
+    fn rate_limited_key_test() {
+        let store = file_store(
+            "limited",
+            r#"[{"key":"limited","methods":["*"],"rate_limit":2}]"#,
+        )
+        .unwrap();
+        assert!(store.authorize(Some("limited"), "getBlock").is_ok());
+        assert!(store.authorize(Some("limited"), "getBlock").is_ok());
+        assert!(store.authorize(Some("limited"), "getBlock").is_err());
+    }
+
+    #[test]
+    fn rate_bucket_test() {
+        let start = Instant::now();
+        let mut bucket = RateBucket::new(4, start);
+        for _ in 0..4 {
+            assert!(bucket.take(4, start));
+        }
+        assert!(!bucket.take(4, start));
+
+        // a quarter of a second refills one request
+        let now = start + Duration::from_millis(250);
+        assert!(bucket.take(4, now));
+        assert!(!bucket.take(4, now));
+
+        // an idle bucket holds at most one second worth of requests
+        let now = now + Duration::from_secs(60);
+        for _ in 0..4 {
+            assert!(bucket.take(4, now));
+        }
+        assert!(!bucket.take(4, now));
+    }
+}
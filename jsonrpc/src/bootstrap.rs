@@ -1,28 +1,38 @@
 use crate::{
+    auth::{ApiKeyStore, API_KEY_HEADER},
     errors::JsonRpcError,
+    eth_methods::is_eth_method,
+    light::{forward_request, is_light_local},
     methods::{build_registry, RpcRegistry},
     request::JsonRpcRequest,
     response::{JsonRpcResponse, HEADER_CHAIN_ID},
     service::JsonRpcService,
-    ws_connections::{process_publish_event, PublishEvent, WsConnections},
+    ws_connections::{
+        process_pending_transactions, process_publish_event, PublishEvent, WsConnections,
+    },
     ws_handler::ws_handler,
 };
-use anyhow::Result;
-use futures::future::{join_all, Either};
+use anyhow::{bail, Result};
+use futures::future::{join_all, BoxFuture, FutureExt};
 use protos::{common::TransactionResult, ledger::Ledger};
 use std::{net::SocketAddr, sync::Arc};
 use tokio::runtime::{Builder, Runtime};
-use tx_pool::types::ClientReceiver;
-use warp::{http::header, reject::Reject, Filter, Reply};
+use tx_pool::types::{ClientReceiver, PendingTxSender};
+use warp::{
+    http::{header, StatusCode},
+    reject::Reject,
+    Filter, Rejection, Reply,
+};
 
 pub fn start_jsonrpc_service(
     config: &configure::JsonRpcConfig,
-) -> (
+) -> Result<(
     Runtime,
     Runtime,
     ClientReceiver,
     tokio::sync::mpsc::UnboundedSender<(Ledger, Vec<TransactionResult>)>,
-) {
+    PendingTxSender,
+)> {
     let runtime = Builder::new_multi_thread()
         .thread_name("json-rpc")
         .enable_all()
@@ -41,8 +51,10 @@ pub fn start_jsonrpc_service(
         tokio::sync::mpsc::unbounded_channel::<(Ledger, Vec<TransactionResult>)>();
     let (jsonrpc_to_txpool_sender, jsonrpc_to_txpool_receiver) =
         futures::channel::mpsc::unbounded();
+    let (pending_tx_sender, pending_tx_receiver) = futures::channel::mpsc::unbounded();
     let registry = Arc::new(build_registry());
-    let service = JsonRpcService::new(config, jsonrpc_to_txpool_sender);
+    let service = JsonRpcService::new(config, jsonrpc_to_txpool_sender)?;
+    let api_keys = service.api_keys.clone();
 
     let base_route = warp::any()
         .and(warp::post())
@@ -51,6 +63,7 @@ pub fn start_jsonrpc_service(
             config.content_length_limit as u64,
         ))
         .and(warp::body::json())
+        .and(warp::header::optional::<String>(API_KEY_HEADER))
         .and(warp::any().map(move || service.clone()))
         .and(warp::any().map(move || Arc::clone(&registry)))
         .and_then(http_handler)
@@ -70,12 +83,35 @@ pub fn start_jsonrpc_service(
         .and(warp::addr::remote())
         .and(warp::path::param())
         .and(warp::any().map(move || collections.clone()))
+        .and(warp::any().map(move || api_keys.clone()))
         .map(
             |ws: warp::ws::Ws,
              remote: Option<SocketAddr>,
              api_key: String,
-             collections: WsConnections| {
-                ws.on_upgrade(move |socket| ws_handler(socket, remote, api_key, collections))
+             collections: WsConnections,
+             api_keys: ApiKeyStore| {
+                // bad keys are refused before the connection is upgraded
+                let max_subscriptions = match api_keys.check_key(Some(&api_key)) {
+                    Ok(key) => key.map(|key| key.max_subscriptions).unwrap_or(0),
+                    Err(err) => {
+                        return warp::reply::with_status(
+                            warp::reply::json(&err),
+                            StatusCode::UNAUTHORIZED,
+                        )
+                        .into_response()
+                    }
+                };
+                ws.on_upgrade(move |socket| {
+                    ws_handler(
+                        socket,
+                        remote,
+                        api_key,
+                        max_subscriptions,
+                        api_keys,
+                        collections,
+                    )
+                })
+                .into_response()
             },
         );
 
@@ -86,31 +122,82 @@ pub fn start_jsonrpc_service(
     let routes = health_route.or(route_v1.or(ws_route));
 
     let _guard = runtime.enter();
-    let server = match &config.tls_cert_path {
-        None => Either::Left(warp::serve(routes).bind(config.address.clone())),
-        Some(cert_path) => Either::Right(
-            warp::serve(routes)
-                .tls()
-                .cert_path(cert_path)
-                .key_path(config.tls_key_path.as_ref().unwrap())
-                .bind(config.address.clone()),
-        ),
-    };
+    let (_, server) = serve(routes, config)?;
 
+    ws_runtime.handle().spawn(process_pending_transactions(
+        collections_clone.clone(),
+        pending_tx_receiver,
+    ));
     ws_runtime
         .handle()
         .spawn(process_publish_event(collections_clone, ws_event_receiver));
     runtime.handle().spawn(server);
-    (
+    Ok((
         runtime,
         ws_runtime,
         jsonrpc_to_txpool_receiver,
         ws_event_sender,
-    )
+        pending_tx_sender,
+    ))
+}
+
+// the websocket route is served by the same server, so it is wss under tls
+fn serve<F>(
+    routes: F,
+    config: &configure::JsonRpcConfig,
+) -> Result<(SocketAddr, BoxFuture<'static, ()>)>
+where
+    F: Filter<Error = Rejection> + Clone + Send + Sync + 'static,
+    F::Extract: Reply,
+{
+    let (cert_path, key_path) = match tls_paths(config)? {
+        None => {
+            let (addr, server) = warp::serve(routes).bind_ephemeral(config.address);
+            return Ok((addr, server.boxed()));
+        }
+        Some(paths) => paths,
+    };
+    let mut tls = warp::serve(routes)
+        .tls()
+        .cert_path(cert_path)
+        .key_path(key_path);
+    if let Some(client_ca_path) = &config.tls_client_ca_path {
+        check_readable("tls_client_ca_path", client_ca_path)?;
+        tls = if config.tls_client_auth_required {
+            tls.client_auth_required_path(client_ca_path)
+        } else {
+            tls.client_auth_optional_path(client_ca_path)
+        };
+    }
+    let (addr, server) = tls.bind_ephemeral(config.address);
+    Ok((addr, server.boxed()))
+}
+
+// the certificate and key of the server, none without tls
+fn tls_paths(config: &configure::JsonRpcConfig) -> Result<Option<(&str, &str)>> {
+    match (&config.tls_cert_path, &config.tls_key_path) {
+        (None, None) => Ok(None),
+        (Some(cert_path), Some(key_path)) => {
+            check_readable("tls_cert_path", cert_path)?;
+            check_readable("tls_key_path", key_path)?;
+            Ok(Some((cert_path.as_str(), key_path.as_str())))
+        }
+        (Some(_), None) => bail!("[json-rpc] tls_key_path is required with tls_cert_path"),
+        (None, Some(_)) => bail!("[json-rpc] tls_cert_path is required with tls_key_path"),
+    }
+}
+
+// warp panics on a file it cannot read, so it is checked ahead
+fn check_readable(name: &str, path: &str) -> Result<()> {
+    if let Err(e) = std::fs::File::open(path) {
+        bail!("[json-rpc] {} {} is not readable: {}", name, path, e);
+    }
+    Ok(())
 }
 
 async fn http_handler(
     data: serde_json::Value,
+    api_key: Option<String>,
     service: JsonRpcService,
     registry: Arc<RpcRegistry>,
 ) -> Result<warp::reply::Response, warp::Rejection> {
@@ -118,20 +205,22 @@ async fn http_handler(
     let resp = Ok(if let serde_json::Value::Array(requests) = data {
         match service.check_batch_size_limit(requests.len()) {
             Ok(_) => {
-                let futures = requests
-                    .into_iter()
-                    .map(|req| request_handler(req, service.clone(), Arc::clone(&registry)));
+                let futures = requests.into_iter().map(|req| {
+                    request_handler(req, api_key.clone(), service.clone(), Arc::clone(&registry))
+                });
                 let responses = join_all(futures).await;
                 warp::reply::json(&responses)
             }
             Err(err) => {
+                // the batch is rejected as a whole, there is no single id to answer
                 let mut resp = JsonRpcResponse::new(chain_id.clone());
+                resp.id = None;
                 resp.error = Some(err);
                 warp::reply::json(&resp)
             }
         }
     } else {
-        let resp = request_handler(data, service, registry).await;
+        let resp = request_handler(data, api_key, service, registry).await;
         warp::reply::json(&resp)
     });
 
@@ -146,10 +235,13 @@ async fn http_handler(
 
 async fn request_handler(
     value: serde_json::Value,
+    api_key: Option<String>,
     service: JsonRpcService,
     registry: Arc<RpcRegistry>,
 ) -> JsonRpcResponse {
     let mut response = JsonRpcResponse::new(service.chain_id.clone());
+    // echo the id even when the request is malformed, so a batch entry can be matched
+    response.id = Some(value.get("id").cloned().unwrap_or(serde_json::Value::Null));
 
     let request: JsonRpcRequest = match serde_json::from_value(value) {
         Ok(req) => req,
@@ -162,13 +254,27 @@ async fn request_handler(
         response.error = Some(err);
         return response;
     }
-    response.id = Some(serde_json::Value::Number(request.id.into()));
+    if let Err(err) = service
+        .api_keys
+        .authorize(api_key.as_deref(), &request.method)
+    {
+        response.error = Some(err);
+        return response;
+    }
+    if let Some(url) = &service.light_forward_url {
+        if !is_light_local(&request) {
+            return forward_request(url, api_key.as_deref(), request, response).await;
+        }
+    }
 
+    let eth_method = is_eth_method(&request.method);
     match registry.get(&request.method) {
         Some(handler) => match handler(service, request).await {
             Ok(result) => {
                 response.result = Some(result);
-                response.error = Some(JsonRpcError::no_error());
+                if !eth_method {
+                    response.error = Some(JsonRpcError::no_error());
+                }
             }
             Err(err) => {
                 response.error = Some(
@@ -194,3 +300,73 @@ fn check_jsonrpc_format(request: &JsonRpcRequest) -> Result<(), JsonRpcError> {
 async fn health_handler() -> Result<Box<dyn warp::Reply>, warp::Rejection> {
     Ok(Box::new("ok"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio_rustls::{
+        rustls::{Certificate, ClientConfig, RootCertStore, ServerName},
+        TlsConnector,
+    };
+
+    const CERT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/testdata/localhost.crt");
+    const KEY_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/testdata/localhost.key");
+
+    fn tls_config() -> configure::JsonRpcConfig {
+        let mut config = configure::JsonRpcConfig::default();
+        config.address = "127.0.0.1:0".parse().unwrap();
+        config.tls_cert_path = Some(CERT_PATH.to_string());
+        config.tls_key_path = Some(KEY_PATH.to_string());
+        config
+    }
+
+    #[tokio::test]
+    async fn self_signed_tls_test() {
+        let routes = warp::path!("-" / "healthy").and_then(health_handler);
+        let (addr, server) = serve(routes, &tls_config()).unwrap();
+        tokio::spawn(server);
+
+        let mut roots = RootCertStore::empty();
+        let mut pem = BufReader::new(std::fs::File::open(CERT_PATH).unwrap());
+        for cert in rustls_pemfile::certs(&mut pem).unwrap() {
+            roots.add(&Certificate(cert)).unwrap();
+        }
+        let client = ClientConfig::builder()
+            .with_safe_defaults()
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+        let stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        let mut stream = TlsConnector::from(Arc::new(client))
+            .connect(ServerName::try_from("localhost").unwrap(), stream)
+            .await
+            .unwrap();
+
+        stream
+            .write_all(b"GET /-/healthy HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 200"), "{}", response);
+        assert!(response.ends_with("ok"), "{}", response);
+    }
+
+    #[tokio::test]
+    async fn tls_config_error_test() {
+        let routes = warp::path!("-" / "healthy").and_then(health_handler);
+
+        let mut config = tls_config();
+        config.tls_key_path = None;
+        assert!(serve(routes.clone(), &config).is_err());
+
+        let mut config = tls_config();
+        config.tls_cert_path = Some("testdata/missing.crt".to_string());
+        assert!(serve(routes.clone(), &config).is_err());
+
+        let mut config = tls_config();
+        config.tls_cert_path = None;
+        assert!(serve(routes, &config).is_err());
+    }
+}
@@ -13,6 +13,11 @@ pub enum RpcErrorCode {
     InvalidParams = -103,
     InvalidFormat = -104,
     DataNotFound = -105,
+    InvalidBatchSize = -106,
+    InvalidApiKey = -107,
+    MethodNotAllowed = -108,
+    RateLimited = -109,
+    TooManySubscriptions = -110,
 
     // Mempool errors
     MempoolInvalidSeqNumber = -200,
@@ -24,6 +29,7 @@ pub enum RpcErrorCode {
     MempoolUnknownError = -206,
 
     ValidationError = -300,
+    ExecutionError = -301,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -114,6 +120,49 @@ impl JsonRpcError {
         }
     }
 
+    pub fn invalid_batch_size(size: usize, limit: u16) -> Self {
+        Self {
+            code: RpcErrorCode::InvalidBatchSize as i16,
+            message: format!(
+                "Invalid batch size {}, must be between 1 and {}",
+                size, limit
+            ),
+            data: None,
+        }
+    }
+
+    pub fn invalid_api_key() -> Self {
+        Self {
+            code: RpcErrorCode::InvalidApiKey as i16,
+            message: "Invalid api key".to_string(),
+            data: None,
+        }
+    }
+
+    pub fn method_not_allowed(method: &str) -> Self {
+        Self {
+            code: RpcErrorCode::MethodNotAllowed as i16,
+            message: format!("Method {} not allowed for the api key", method),
+            data: None,
+        }
+    }
+
+    pub fn rate_limited(rate_limit: u32) -> Self {
+        Self {
+            code: RpcErrorCode::RateLimited as i16,
+            message: format!("Rate limit of {} requests per second exceeded", rate_limit),
+            data: None,
+        }
+    }
+
+    pub fn too_many_subscriptions(limit: usize) -> Self {
+        Self {
+            code: RpcErrorCode::TooManySubscriptions as i16,
+            message: format!("Subscriptions exceed limit {}", limit),
+            data: None,
+        }
+    }
+
     pub fn invalid_params_size(msg: String) -> Self {
         Self {
             code: RpcErrorCode::InvalidParams as i16,
@@ -156,6 +205,14 @@ impl JsonRpcError {
         })
     }
 
+    pub fn execution_error(msg: String) -> Self {
+        Self {
+            code: RpcErrorCode::ExecutionError as i16,
+            message: format!("Execution error: {}", msg),
+            data: None,
+        }
+    }
+
     pub fn validation_status(error: TxPoolValidationStatusCode) -> Self {
         let code = RpcErrorCode::ValidationError;
         Self {
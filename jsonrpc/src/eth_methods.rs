@@ -0,0 +1,590 @@
+use crate::{
+    errors::JsonRpcError,
+    methods::{
+        last_height, load_account_at, parse_block_number, parse_state_block, submit_transaction,
+    },
+    request::JsonRpcRequest,
+    service::JsonRpcService,
+    view::eth_view::{
+        from_eth_address, parse_data, parse_quantity, to_data, to_quantity, EthBlockView,
+        EthCallRequest, EthFeeHistoryView, EthLogFilter, EthLogView, EthReceiptView,
+        EthTransactionView,
+    },
+};
+use anyhow::{Error, Result};
+use executor::BlockExecutor;
+use ledger_store::LedgerStorage;
+use protos::{
+    common::TransactionResult,
+    ledger::{
+        LedgerHeader, Transaction, TransactionSign, TransactionSignStore,
+        TransactionSign_SourceType, TransactionType,
+    },
+};
+use serde_json::Value;
+use std::convert::TryFrom;
+use types::{eth_transaction::EthTransaction, SignedTransaction};
+use utils::general::{consensus_config, self_chain_hub, self_chain_id, GENESIS_HEIGHT};
+
+// blocks one eth_getLogs request may scan
+const ETH_LOGS_MAX_BLOCK_RANGE: u64 = 1024;
+// caller of eth_call and eth_estimateGas when the call object has no from
+const ETH_CALL_DEFAULT_FROM: &str = "0x0000000000000000000000000000000000000000";
+
+/// Ethereum clients treat any `error` member as a failure, so the eth namespace
+/// answers without the success error geno methods carry.
+pub(crate) fn is_eth_method(method: &str) -> bool {
+    method.starts_with("eth_") || method.starts_with("net_")
+}
+
+fn parse_address(request: &JsonRpcRequest, index: usize) -> Result<String> {
+    let address: String = request.parse_param(index, "address")?;
+    match from_eth_address(&address) {
+        Some(address) => Ok(address),
+        None => Err(Error::new(JsonRpcError::invalid_address(&address))),
+    }
+}
+
+// transactions are stored by the hex of their hash without prefix
+fn parse_hash(request: &JsonRpcRequest, index: usize) -> Result<String> {
+    let hash: String = request.parse_param(index, "hash")?;
+    match parse_data(&hash) {
+        Some(bytes) if bytes.len() == 32 => Ok(hex::encode(bytes)),
+        _ => Err(Error::new(JsonRpcError::invalid_param(
+            index,
+            "hash",
+            "32 bytes hex",
+        ))),
+    }
+}
+
+fn load_block(header: LedgerHeader) -> Result<(LedgerHeader, Vec<TransactionSignStore>)> {
+    let hash_list = match LedgerStorage::load_ledger_tx_list(header.get_height()) {
+        Ok(value) => value,
+        Err(e) => {
+            return Err(Error::new(JsonRpcError::internal_error(format!(
+                "db error",
+            ))))
+        }
+    };
+
+    let mut txs = Vec::new();
+    if let Some(hash_list) = hash_list {
+        for hash in hash_list.get_entry() {
+            match LedgerStorage::load_tx(&hex::encode(hash)) {
+                Ok(Some(tx_store)) => txs.push(tx_store),
+                Ok(None) => {}
+                Err(e) => {
+                    return Err(Error::new(JsonRpcError::internal_error(format!(
+                        "db error",
+                    ))))
+                }
+            }
+        }
+    }
+    Ok((header, txs))
+}
+
+fn load_block_by_height(height: u64) -> Result<Option<(LedgerHeader, Vec<TransactionSignStore>)>> {
+    match LedgerStorage::load_ledger_header_by_seq(height) {
+        Ok(Some(header)) => Ok(Some(load_block(header)?)),
+        Ok(None) => Ok(None),
+        Err(e) => Err(Error::new(JsonRpcError::internal_error(format!(
+            "db error",
+        )))),
+    }
+}
+
+fn block_receipts(header: &LedgerHeader, txs: &[TransactionSignStore]) -> Vec<EthReceiptView> {
+    let mut cumulative_gas_used = 0u64;
+    let mut log_index = 0usize;
+    let mut receipts = Vec::with_capacity(txs.len());
+    for tx_store in txs {
+        let result = tx_store.get_transaction_result();
+        cumulative_gas_used = cumulative_gas_used.saturating_add(result.get_gas_used());
+        let receipt = EthReceiptView::new(tx_store, header, cumulative_gas_used, log_index);
+        log_index += receipt.logs.len();
+        receipts.push(receipt);
+    }
+    receipts
+}
+
+fn block_view(header: LedgerHeader, txs: Vec<TransactionSignStore>, full: bool) -> EthBlockView {
+    let receipts = block_receipts(&header, &txs);
+    let transactions = if full {
+        txs.iter()
+            .map(|tx_store| {
+                serde_json::to_value(EthTransactionView::new(tx_store, &header))
+                    .unwrap_or(Value::Null)
+            })
+            .collect()
+    } else {
+        receipts
+            .iter()
+            .map(|receipt| Value::String(receipt.transaction_hash.clone()))
+            .collect()
+    };
+    EthBlockView::new(
+        &header,
+        &receipts,
+        transactions,
+        consensus_config().block_max_gas_limit,
+    )
+}
+
+fn parse_call_quantity(value: &Option<String>, name: &str) -> Result<u128> {
+    match value {
+        None => Ok(0),
+        Some(value) => match parse_quantity(value) {
+            Some(value) => Ok(value),
+            None => Err(Error::new(JsonRpcError::invalid_parameter(name, value))),
+        },
+    }
+}
+
+fn call_gas(call: &EthCallRequest) -> Result<u64> {
+    match &call.gas {
+        None => Ok(consensus_config().block_max_gas_limit),
+        Some(_) => match u64::try_from(parse_call_quantity(&call.gas, "gas")?) {
+            Ok(gas) => Ok(gas),
+            Err(_) => Err(Error::new(JsonRpcError::invalid_parameter(
+                "gas",
+                "gas overflow",
+            ))),
+        },
+    }
+}
+
+// execute the call object read-only on top of the state committed by `header`
+fn execute_call(
+    call: &EthCallRequest,
+    header: &LedgerHeader,
+    gas: u64,
+) -> Result<TransactionResult> {
+    let from = call.from.as_deref().unwrap_or(ETH_CALL_DEFAULT_FROM);
+    let source = match from_eth_address(from) {
+        Some(source) => source,
+        None => return Err(Error::new(JsonRpcError::invalid_address(from))),
+    };
+
+    let mut tx = Transaction::default();
+    tx.set_tx_type(TransactionType::ETH_LEGACY);
+    tx.set_source(source);
+    if let Some(to) = &call.to {
+        match from_eth_address(to) {
+            Some(to) => tx.set_to(to),
+            None => return Err(Error::new(JsonRpcError::invalid_address(to))),
+        }
+    }
+    tx.set_value(parse_call_quantity(&call.value, "value")?.to_string());
+    tx.set_gas_price(parse_call_quantity(&call.gas_price, "gasPrice")?.to_string());
+    tx.set_gas_limit(gas);
+    if let Some(input) = call.input.as_ref().or(call.data.as_ref()) {
+        match parse_data(input) {
+            Some(payload) => tx.set_payload(payload),
+            None => return Err(Error::new(JsonRpcError::invalid_parameter("input", input))),
+        }
+    }
+    tx.set_chain_id(self_chain_id());
+    tx.set_hub_id(self_chain_hub());
+
+    let mut transaction_sign = TransactionSign::default();
+    transaction_sign.set_transaction(tx);
+    transaction_sign.set_source_type(TransactionSign_SourceType::JSONRPC);
+
+    match BlockExecutor::call_transaction_at(&transaction_sign, header) {
+        Ok(result) => Ok(result),
+        Err(e) => Err(Error::new(JsonRpcError::internal_error(format!(
+            "call error {}",
+            e
+        )))),
+    }
+}
+
+fn check_call_result(result: &TransactionResult) -> Result<()> {
+    if result.get_err_code() != 0 {
+        return Err(Error::new(JsonRpcError::execution_error(
+            result.get_contract_result().get_message().to_string(),
+        )));
+    }
+    Ok(())
+}
+
+pub(crate) async fn chain_id(service: JsonRpcService, request: JsonRpcRequest) -> Result<String> {
+    match service.chain_id.parse::<u64>() {
+        Ok(chain_id) => Ok(to_quantity(chain_id as u128)),
+        Err(e) => Err(Error::new(JsonRpcError::internal_error(format!(
+            "chain id {} is not a number",
+            service.chain_id
+        )))),
+    }
+}
+
+pub(crate) async fn net_version(
+    service: JsonRpcService,
+    request: JsonRpcRequest,
+) -> Result<String> {
+    Ok(service.chain_id.clone())
+}
+
+pub(crate) async fn block_number(
+    service: JsonRpcService,
+    request: JsonRpcRequest,
+) -> Result<String> {
+    Ok(to_quantity(last_height() as u128))
+}
+
+// the tx-pool enforces no minimum gas price
+pub(crate) async fn gas_price(service: JsonRpcService, request: JsonRpcRequest) -> Result<String> {
+    Ok(to_quantity(0))
+}
+
+pub(crate) async fn max_priority_fee_per_gas(
+    service: JsonRpcService,
+    request: JsonRpcRequest,
+) -> Result<String> {
+    Ok(to_quantity(0))
+}
+
+pub(crate) async fn fee_history(
+    service: JsonRpcService,
+    request: JsonRpcRequest,
+) -> Result<EthFeeHistoryView> {
+    // the block count is a quantity, though some clients send a plain number
+    let block_count = match request.get_param(0) {
+        Value::Number(count) => count.as_u64().map(|count| count as u128),
+        Value::String(count) => parse_quantity(&count),
+        _ => None,
+    };
+    let block_count = match block_count {
+        Some(count) => count.min(ETH_LOGS_MAX_BLOCK_RANGE as u128) as u64,
+        None => {
+            return Err(Error::new(JsonRpcError::invalid_param(
+                0,
+                "blockCount",
+                "quantity",
+            )))
+        }
+    };
+    let newest = parse_block_number(1, "newestBlock", &request.get_param(1))?;
+    if newest > last_height() {
+        return Err(Error::new(JsonRpcError::invalid_param(
+            1,
+            "newestBlock",
+            "block not found",
+        )));
+    }
+    let percentiles: Option<Vec<f64>> = match request.get_param(2) {
+        Value::Null => None,
+        _ => Some(request.parse_param(2, "rewardPercentiles")?),
+    };
+
+    let block_count = block_count.min(newest + 1 - GENESIS_HEIGHT);
+    let zero = to_quantity(0);
+    Ok(EthFeeHistoryView {
+        oldest_block: to_quantity((newest + 1 - block_count) as u128),
+        base_fee_per_gas: vec![zero.clone(); block_count as usize + 1],
+        gas_used_ratio: vec![0.0; block_count as usize],
+        reward: percentiles
+            .map(|percentiles| vec![vec![zero; percentiles.len()]; block_count as usize]),
+    })
+}
+
+pub(crate) async fn get_balance(
+    service: JsonRpcService,
+    request: JsonRpcRequest,
+) -> Result<String> {
+    let address = parse_address(&request, 0)?;
+    let header = parse_state_block(&request, 1)?;
+    let balance = match load_account_at(&header, &address)? {
+        Some(account) => account.balance(),
+        None => 0,
+    };
+    Ok(to_quantity(balance))
+}
+
+pub(crate) async fn get_transaction_count(
+    service: JsonRpcService,
+    request: JsonRpcRequest,
+) -> Result<String> {
+    let address = parse_address(&request, 0)?;
+    let header = parse_state_block(&request, 1)?;
+    let nonce = match load_account_at(&header, &address)? {
+        Some(account) => account.nonce(),
+        None => 0,
+    };
+    Ok(to_quantity(nonce as u128))
+}
+
+pub(crate) async fn get_code(service: JsonRpcService, request: JsonRpcRequest) -> Result<String> {
+    let address = parse_address(&request, 0)?;
+    let header = parse_state_block(&request, 1)?;
+    match load_account_at(&header, &address)? {
+        Some(account) if account.has_contract() => Ok(to_data(account.contract().get_code())),
+        _ => Ok(to_data(&[])),
+    }
+}
+
+pub(crate) async fn get_storage_at(
+    service: JsonRpcService,
+    request: JsonRpcRequest,
+) -> Result<String> {
+    let address = parse_address(&request, 0)?;
+    let position: String = request.parse_param(1, "position")?;
+    let header = parse_state_block(&request, 2)?;
+
+    // the position is a quantity or a 32 bytes word
+    let digits = position.strip_prefix("0x").unwrap_or(&position);
+    let digits = if digits.len() % 2 == 1 {
+        format!("0{}", digits)
+    } else {
+        digits.to_string()
+    };
+    let mut key = match hex::decode(&digits) {
+        Ok(bytes) if bytes.len() <= 32 => {
+            let mut key = vec![0u8; 32 - bytes.len()];
+            key.extend_from_slice(&bytes);
+            key
+        }
+        _ => {
+            return Err(Error::new(JsonRpcError::invalid_param(
+                1,
+                "position",
+                "32 bytes hex",
+            )))
+        }
+    };
+    // evm storage keys and values are kept little endian
+    key.reverse();
+
+    let mut value = match load_account_at(&header, &address)? {
+        Some(mut account) => match account.get_contract_metadata(&key) {
+            Ok(value) => value.unwrap_or_default(),
+            Err(e) => {
+                return Err(Error::new(JsonRpcError::internal_error(format!(
+                    "Trie error",
+                ))))
+            }
+        },
+        None => Vec::new(),
+    };
+    value.resize(32, 0);
+    value.reverse();
+    Ok(to_data(&value))
+}
+
+pub(crate) async fn call(service: JsonRpcService, request: JsonRpcRequest) -> Result<String> {
+    let call: EthCallRequest = request.parse_param(0, "call")?;
+    let header = parse_state_block(&request, 1)?;
+
+    let result = execute_call(&call, &header, call_gas(&call)?)?;
+    check_call_result(&result)?;
+    Ok(to_data(result.get_contract_result().get_result()))
+}
+
+pub(crate) async fn estimate_gas(
+    service: JsonRpcService,
+    request: JsonRpcRequest,
+) -> Result<String> {
+    let call: EthCallRequest = request.parse_param(0, "call")?;
+    let header = parse_state_block(&request, 1)?;
+
+    let cap = call_gas(&call)?;
+    let result = execute_call(&call, &header, cap)?;
+    check_call_result(&result)?;
+
+    // refunds and the 63/64 rule of nested calls may need more gas than was used
+    let used = result.get_gas_used();
+    if used >= cap || execute_call(&call, &header, used)?.get_err_code() == 0 {
+        return Ok(to_quantity(used as u128));
+    }
+    let (mut low, mut high) = (used, cap);
+    while low + 1 < high {
+        let mid = low + (high - low) / 2;
+        if execute_call(&call, &header, mid)?.get_err_code() == 0 {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+    Ok(to_quantity(high as u128))
+}
+
+pub(crate) async fn send_raw_transaction(
+    service: JsonRpcService,
+    request: JsonRpcRequest,
+) -> Result<String> {
+    let data: String = request.parse_param(0, "data")?;
+    let raw = match parse_data(&data) {
+        Some(raw) if !raw.is_empty() => raw,
+        _ => {
+            return Err(Error::new(JsonRpcError::invalid_param(
+                0,
+                "data",
+                "hex encoded transaction",
+            )))
+        }
+    };
+    let eth_tx = match EthTransaction::decode(&raw) {
+        Ok(eth_tx) => eth_tx,
+        Err(e) => {
+            return Err(Error::new(JsonRpcError::invalid_param(
+                0,
+                "data",
+                &e.to_string(),
+            )))
+        }
+    };
+
+    if let Err(e) = eth_tx.check_chain_id(&self_chain_id()) {
+        return Err(Error::new(JsonRpcError::invalid_param(
+            0,
+            "data",
+            &e.to_string(),
+        )));
+    }
+
+    let transaction_sign = eth_tx.to_transaction_sign(&raw, TransactionSign_SourceType::JSONRPC);
+    let sign_transaction = SignedTransaction::try_from(transaction_sign)?;
+    let tx_hash = to_data(sign_transaction.hash());
+    submit_transaction(&service, sign_transaction).await?;
+    Ok(tx_hash)
+}
+
+pub(crate) async fn get_transaction_by_hash(
+    service: JsonRpcService,
+    request: JsonRpcRequest,
+) -> Result<Option<EthTransactionView>> {
+    let hash = parse_hash(&request, 0)?;
+    let tx_store = match LedgerStorage::load_tx(&hash) {
+        Ok(Some(tx_store)) => tx_store,
+        Ok(None) => return Ok(None),
+        Err(e) => {
+            return Err(Error::new(JsonRpcError::internal_error(format!(
+                "db error",
+            ))))
+        }
+    };
+    let height = tx_store.get_transaction_result().get_block_height();
+    match LedgerStorage::load_ledger_header_by_seq(height) {
+        Ok(Some(header)) => Ok(Some(EthTransactionView::new(&tx_store, &header))),
+        Ok(None) => Ok(None),
+        Err(e) => Err(Error::new(JsonRpcError::internal_error(format!(
+            "db error",
+        )))),
+    }
+}
+
+pub(crate) async fn get_transaction_receipt(
+    service: JsonRpcService,
+    request: JsonRpcRequest,
+) -> Result<Option<EthReceiptView>> {
+    let hash = parse_hash(&request, 0)?;
+    let tx_store = match LedgerStorage::load_tx(&hash) {
+        Ok(Some(tx_store)) => tx_store,
+        Ok(None) => return Ok(None),
+        Err(e) => {
+            return Err(Error::new(JsonRpcError::internal_error(format!(
+                "db error",
+            ))))
+        }
+    };
+
+    // cumulative gas and log indexes depend on the transactions before it in the block
+    let result = tx_store.get_transaction_result();
+    let (header, txs) = match load_block_by_height(result.get_block_height())? {
+        Some(block) => block,
+        None => return Ok(None),
+    };
+    let tx_hash = format!("0x{}", hash);
+    Ok(block_receipts(&header, &txs)
+        .into_iter()
+        .find(|receipt| receipt.transaction_hash == tx_hash))
+}
+
+pub(crate) async fn get_block_by_number(
+    service: JsonRpcService,
+    request: JsonRpcRequest,
+) -> Result<Option<EthBlockView>> {
+    let height = parse_block_number(0, "block", &request.get_param(0))?;
+    let full = request.get_param(1).as_bool().unwrap_or(false);
+    match load_block_by_height(height)? {
+        Some((header, txs)) => Ok(Some(block_view(header, txs, full))),
+        None => Ok(None),
+    }
+}
+
+pub(crate) async fn get_block_by_hash(
+    service: JsonRpcService,
+    request: JsonRpcRequest,
+) -> Result<Option<EthBlockView>> {
+    let hash = parse_hash(&request, 0)?;
+    let full = request.get_param(1).as_bool().unwrap_or(false);
+    match LedgerStorage::load_ledger_header_by_hash(&hash) {
+        Ok(Some(header)) => {
+            let (header, txs) = load_block(header)?;
+            Ok(Some(block_view(header, txs, full)))
+        }
+        Ok(None) => Ok(None),
+        Err(e) => Err(Error::new(JsonRpcError::internal_error(format!(
+            "db error",
+        )))),
+    }
+}
+
+pub(crate) async fn get_logs(
+    service: JsonRpcService,
+    request: JsonRpcRequest,
+) -> Result<Vec<EthLogView>> {
+    let filter: EthLogFilter = request.parse_param(0, "filter")?;
+
+    let blocks = if let Some(block_hash) = &filter.block_hash {
+        let hash = match parse_data(block_hash) {
+            Some(hash) => hex::encode(hash),
+            None => {
+                return Err(Error::new(JsonRpcError::invalid_parameter(
+                    "blockHash",
+                    block_hash,
+                )))
+            }
+        };
+        match LedgerStorage::load_ledger_header_by_hash(&hash) {
+            Ok(Some(header)) => vec![load_block(header)?],
+            Ok(None) => Vec::new(),
+            Err(e) => {
+                return Err(Error::new(JsonRpcError::internal_error(format!(
+                    "db error",
+                ))))
+            }
+        }
+    } else {
+        let from_block = filter.from_block.clone().unwrap_or(Value::Null);
+        let to_block = filter.to_block.clone().unwrap_or(Value::Null);
+        let from = parse_block_number(0, "fromBlock", &from_block)?;
+        let to = parse_block_number(0, "toBlock", &to_block)?.min(last_height());
+        if from > to {
+            return Ok(Vec::new());
+        }
+        if to - from >= ETH_LOGS_MAX_BLOCK_RANGE {
+            return Err(Error::new(JsonRpcError::invalid_parameter(
+                "filter",
+                &format!("block range exceeds {}", ETH_LOGS_MAX_BLOCK_RANGE),
+            )));
+        }
+        let mut blocks = Vec::new();
+        for height in from..=to {
+            if let Some(block) = load_block_by_height(height)? {
+                blocks.push(block);
+            }
+        }
+        blocks
+    };
+
+    let mut logs = Vec::new();
+    for (header, txs) in blocks {
+        for receipt in block_receipts(&header, &txs) {
+            logs.extend(receipt.logs.into_iter().filter(|log| filter.matches(log)));
+        }
+    }
+    Ok(logs)
+}
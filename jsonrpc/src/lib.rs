@@ -1,12 +1,16 @@
 #[macro_use]
 pub mod utils;
 
+pub mod auth;
 pub mod bootstrap;
 pub mod errors;
+pub mod eth_methods;
+pub mod light;
 pub mod methods;
 pub mod request;
 pub mod response;
 pub mod service;
+pub mod verifier;
 pub mod view;
 pub mod ws_connections;
 pub mod ws_handler;
@@ -0,0 +1,114 @@
+//! Rpc of a light node. It keeps the headers and their proofs only, so everything needing
+//! state or transactions is forwarded to a full node, and the proofs coming back are checked
+//! against the local headers before they are answered.
+use crate::{
+    auth::API_KEY_HEADER,
+    errors::JsonRpcError,
+    request::JsonRpcRequest,
+    response::JsonRpcResponse,
+    verifier::{verify_account_proof, verify_transaction_proof},
+    view::proof_view::{AccountProofView, TransactionProofView},
+};
+use anyhow::{ensure, format_err, Result};
+use hyper::{body, header, Body, Client, Request};
+use ledger_store::LedgerStorage;
+use protos::ledger::LedgerHeader;
+use serde_json::Value;
+
+// answered from the synced headers
+const LIGHT_LOCAL_METHODS: [&str; 6] = [
+    "getLastBlock",
+    "getBlockBySequence",
+    "getBlockByHash",
+    "eth_chainId",
+    "net_version",
+    "eth_blockNumber",
+];
+
+/// Whether a light node answers `request` itself, blocks asked with their transactions are not.
+pub(crate) fn is_light_local(request: &JsonRpcRequest) -> bool {
+    if !LIGHT_LOCAL_METHODS.contains(&request.method.as_str()) {
+        return false;
+    }
+    match request.method.as_str() {
+        "getBlockBySequence" | "getBlockByHash" => {
+            !matches!(request.get_param(1), Value::Bool(true))
+        }
+        _ => true,
+    }
+}
+
+/// Forward `request` to the full node at `url`, filling `response` with its answer.
+pub(crate) async fn forward_request(
+    url: &str,
+    api_key: Option<&str>,
+    request: JsonRpcRequest,
+    mut response: JsonRpcResponse,
+) -> JsonRpcResponse {
+    match forward(url, api_key, &request).await {
+        Ok(forwarded) => {
+            response.result = forwarded.result;
+            response.error = forwarded.error;
+        }
+        Err(err) => {
+            response.result = None;
+            response.error = Some(JsonRpcError::internal_error(format!(
+                "light forward: {}",
+                err
+            )));
+        }
+    }
+    response
+}
+
+async fn forward(
+    url: &str,
+    api_key: Option<&str>,
+    request: &JsonRpcRequest,
+) -> Result<JsonRpcResponse> {
+    let mut builder = Request::post(url).header(header::CONTENT_TYPE, "application/json");
+    if let Some(api_key) = api_key {
+        builder = builder.header(API_KEY_HEADER, api_key);
+    }
+    let http_request = builder.body(Body::from(serde_json::to_vec(request)?))?;
+    let http_response = Client::new().request(http_request).await?;
+    ensure!(
+        http_response.status().is_success(),
+        "full node answered {}",
+        http_response.status()
+    );
+    let bytes = body::to_bytes(http_response.into_body()).await?;
+    let response: JsonRpcResponse = serde_json::from_slice(&bytes)?;
+
+    if let Some(result) = &response.result {
+        check_proof_result(&request.method, result)?;
+    }
+    Ok(response)
+}
+
+// the proofs of the full node must lead to the headers this node synced itself
+fn check_proof_result(method: &str, result: &Value) -> Result<()> {
+    let header = match method {
+        "getTransactionProof" => {
+            let proof: TransactionProofView = serde_json::from_value(result.clone())?;
+            verify_transaction_proof(&proof)?.0
+        }
+        "getProof" => {
+            let proof: AccountProofView = serde_json::from_value(result.clone())?;
+            verify_account_proof(&proof)?.0
+        }
+        _ => return Ok(()),
+    };
+    check_local_header(&header)
+}
+
+fn check_local_header(header: &LedgerHeader) -> Result<()> {
+    let local = LedgerStorage::load_ledger_header_by_seq(header.get_height())?
+        .ok_or_else(|| format_err!("header {} is not synced yet", header.get_height()))?;
+    ensure!(
+        local.get_hash() == header.get_hash(),
+        "header {} is not the synced one",
+        header.get_height()
+    );
+    Ok(())
+}
@@ -1,21 +1,32 @@
 use crate::{
     errors::JsonRpcError,
+    eth_methods as eth,
     request::JsonRpcRequest,
     service::JsonRpcService,
     view::{
         account_view::{AccountInfoView, AccountView},
+        eth_view::parse_quantity,
         ledger_view::LedgerView,
-        transaction_view::{SubmitTx, TransactionResultView, TxHash},
+        page_view::PageView,
+        proof_view::{AccountProofView, MerkleProofView, StorageProofView, TransactionProofView},
+        transaction_view::{LogFilter, LogView, SubmitTx, TransactionResultView, TxHash},
     },
 };
 use anyhow::{ensure, format_err, Error, Result};
 use core::future::Future;
-use executor::LAST_COMMITTED_BLOCK_INFO_REF;
+use executor::{BlockExecutor, LAST_COMMITTED_BLOCK_INFO_REF};
 use futures::channel::oneshot;
 use ledger_store::LedgerStorage;
+use merkletree::Tree;
 use msp::signing::{self, check_address, check_private_key, check_tx_hash};
+use protos::{
+    common::KeyPair,
+    consensus::BftProof,
+    ledger::{LedgerHeader, TransactionResult, TransactionSignStore},
+};
 use serde_json::{Map, Value};
-use state::READING_TRIE_REF;
+use state::{AccountFrame, TrieHash, TrieReader, READING_TRIE_REF};
+use state_store::{pruner::StatePruner, StateStorage};
 use std::{
     cmp::min,
     collections::HashMap,
@@ -23,15 +34,21 @@ use std::{
     pin::Pin,
     sync::Arc,
 };
+use storage_db::STORAGE_INSTANCE_REF;
 use tx_pool::types::TxPoolStatusCode;
 use types::SignedTransaction;
 use utils::{
-    general::hash_crypto_byte,
+    general::{hash_crypto_byte, GENESIS_HEIGHT},
     parse::ProtocolParser,
     verify_sign::{sign, verify_sign},
     TransactionSign,
 };
 
+// blocks one getLogs request may scan
+const LOGS_MAX_BLOCK_RANGE: u64 = 1024;
+// storage keys one getProof request may prove
+const PROOF_MAX_STORAGE_KEYS: usize = 256;
+
 type RpcHandler =
     Box<fn(JsonRpcService, JsonRpcRequest) -> Pin<Box<dyn Future<Output = Result<Value>> + Send>>>;
 
@@ -61,6 +78,95 @@ async fn create_account(
     }));
 }
 
+// a block height as a number, a decimal or 0x hex string, or a tag, every committed block
+// is final so the tags are all the last block
+pub(crate) fn parse_block_number(index: usize, name: &str, value: &Value) -> Result<u64> {
+    let number = match value {
+        Value::Null => return Ok(last_height()),
+        Value::Number(number) => number.as_u64().map(u128::from),
+        Value::String(tag) => match tag.as_str() {
+            "latest" | "pending" | "safe" | "finalized" => return Ok(last_height()),
+            "earliest" => return Ok(GENESIS_HEIGHT),
+            number if number.starts_with("0x") => parse_quantity(number),
+            number => number.parse::<u128>().ok(),
+        },
+        _ => None,
+    };
+    match number {
+        Some(number) if number <= u64::MAX as u128 => Ok(number as u64),
+        _ => Err(Error::new(JsonRpcError::invalid_param(
+            index,
+            name,
+            "block number or tag",
+        ))),
+    }
+}
+
+pub(crate) fn last_height() -> u64 {
+    LAST_COMMITTED_BLOCK_INFO_REF
+        .read()
+        .get_header()
+        .get_height()
+}
+
+// the block a state query reads, by height or hash, the last committed block when absent
+pub(crate) fn parse_state_block(request: &JsonRpcRequest, index: usize) -> Result<LedgerHeader> {
+    let value = request.get_param(index);
+    let result = match &value {
+        Value::Null => return Ok(LAST_COMMITTED_BLOCK_INFO_REF.read().get_header().clone()),
+        Value::String(hash) if check_tx_hash(hash.trim_start_matches("0x")) => {
+            LedgerStorage::load_ledger_header_by_hash(hash.trim_start_matches("0x"))
+        }
+        _ => LedgerStorage::load_ledger_header_by_seq(parse_block_number(index, "block", &value)?),
+    };
+    match result {
+        Ok(Some(header)) => {
+            if StatePruner::retains(header.get_height(), last_height()) {
+                Ok(header)
+            } else {
+                Err(Error::new(JsonRpcError::data_not_found(
+                    "The state of this block is pruned!".to_string(),
+                )))
+            }
+        }
+        Ok(None) => Err(Error::new(JsonRpcError::data_not_found(
+            "Unable to get the block content of this height!".to_string(),
+        ))),
+        Err(e) => Err(Error::new(JsonRpcError::internal_error(format!(
+            "db error",
+        )))),
+    }
+}
+
+// the account in the state committed by `header`, the reading trie caches the last state
+pub(crate) fn load_account_at(
+    header: &LedgerHeader,
+    address: &str,
+) -> Result<Option<AccountFrame>> {
+    let last_state_hash = { LAST_COMMITTED_BLOCK_INFO_REF.read().get_state_hash() };
+    let result = if header.get_state_hash() == last_state_hash.as_slice() {
+        let change = { READING_TRIE_REF.read().is_change(&last_state_hash) };
+        if change {
+            READING_TRIE_REF.write().get_mut(&last_state_hash, address)
+        } else {
+            READING_TRIE_REF.read().get(address)
+        }
+    } else if header.get_state_hash().len() == last_state_hash.len() {
+        let mut state_hash = TrieHash::default();
+        state_hash.clone_from_slice(header.get_state_hash());
+        StateStorage::load_account(address, state_hash)
+    } else {
+        Ok(None)
+    };
+
+    match result {
+        Ok(account) => Ok(account),
+        Err(e) => Err(Error::new(JsonRpcError::internal_error(format!(
+            "Trie error",
+        )))),
+    }
+}
+
 async fn get_accountbase(
     service: JsonRpcService,
     request: JsonRpcRequest,
@@ -71,34 +177,163 @@ async fn get_accountbase(
             account_address.as_str(),
         )));
     }
+    let header = parse_state_block(&request, 1)?;
 
-    let state_hash = { LAST_COMMITTED_BLOCK_INFO_REF.read().get_state_hash() };
-    let change = { READING_TRIE_REF.read().is_change(&state_hash) };
-    let result = if change {
-        READING_TRIE_REF
-            .write()
-            .get_mut(&state_hash, &account_address)
-    } else {
-        READING_TRIE_REF.read().get(&account_address)
+    match load_account_at(&header, &account_address)? {
+        Some(account) => {
+            return Ok(Some(AccountInfoView::new(account.account())));
+        }
+        None => {
+            return Err(Error::new(JsonRpcError::data_not_found(format!(
+                "Account you are looking for does not exist"
+            ))));
+        }
+    }
+}
+
+async fn get_proof(service: JsonRpcService, request: JsonRpcRequest) -> Result<AccountProofView> {
+    let address: String = request.parse_param(0, "address")?;
+    if !check_address(&address) {
+        return Err(Error::new(JsonRpcError::invalid_address(address.as_str())));
+    }
+    let storage_keys: Vec<String> = request.parse_param(1, "storage_keys")?;
+    if storage_keys.len() > PROOF_MAX_STORAGE_KEYS {
+        return Err(Error::new(JsonRpcError::invalid_param(
+            1,
+            "storage_keys",
+            &format!("at most {} keys", PROOF_MAX_STORAGE_KEYS),
+        )));
+    }
+    let header = parse_state_block(&request, 2)?;
+    let mut root = TrieHash::default();
+    if header.get_state_hash().len() != root.len() {
+        return Err(Error::new(JsonRpcError::internal_error(format!(
+            "Trie error",
+        ))));
+    }
+    root.clone_from_slice(header.get_state_hash());
+
+    let reader = TrieReader::new(STORAGE_INSTANCE_REF.account_db(), Some(root));
+    let (account, account_proof) = match reader
+        .get(address.as_bytes())
+        .and_then(|account| Ok((account, reader.get_proof(&[address.as_bytes().to_vec()])?)))
+    {
+        Ok(value) => value,
+        Err(e) => {
+            return Err(Error::new(JsonRpcError::internal_error(format!(
+                "Trie error",
+            ))));
+        }
+    };
+    let frame = match account.as_ref().map(|data| AccountFrame::deserialize(data)) {
+        Some(Ok(frame)) => Some(frame),
+        Some(Err(e)) => {
+            return Err(Error::new(JsonRpcError::internal_error(format!(
+                "Trie error",
+            ))));
+        }
+        None => None,
     };
 
-    match result {
-        Ok(value) => match value {
-            Some(account) => {
-                return Ok(Some(AccountInfoView::new(account.account())));
+    let mut storage_proof = Vec::with_capacity(storage_keys.len());
+    for key in storage_keys {
+        let inner_key = match hex::decode(key.trim_start_matches("0x")) {
+            Ok(inner_key) => inner_key,
+            Err(_) => {
+                return Err(Error::new(JsonRpcError::invalid_param(
+                    1,
+                    "storage_keys",
+                    "hex string",
+                )));
             }
-            None => {
-                return Err(Error::new(JsonRpcError::data_not_found(format!(
-                    "Account you are looking for does not exist"
+        };
+        let (entry, proof) = match &frame {
+            Some(frame) => match frame.contract_metadata_proof(&inner_key) {
+                Ok(value) => value,
+                Err(e) => {
+                    return Err(Error::new(JsonRpcError::internal_error(format!(
+                        "Trie error",
+                    ))));
+                }
+            },
+            None => (None, Vec::new()),
+        };
+        let value = match &entry {
+            Some(entry) => match ProtocolParser::deserialize::<KeyPair>(entry) {
+                Ok(kp) => Some(hex::encode(kp.get_value())),
+                Err(e) => {
+                    return Err(Error::new(JsonRpcError::internal_error(format!(
+                        "Trie error",
+                    ))));
+                }
+            },
+            None => None,
+        };
+        storage_proof.push(StorageProofView {
+            key,
+            value,
+            entry: entry.map(hex::encode),
+            proof: proof.iter().map(hex::encode).collect(),
+        });
+    }
+
+    Ok(AccountProofView {
+        address,
+        block_height: header.get_height(),
+        account: account.map(hex::encode),
+        account_proof: account_proof.iter().map(hex::encode).collect(),
+        storage_proof,
+        header: hex::encode(ProtocolParser::serialize::<LedgerHeader>(&header)),
+    })
+}
+
+fn parse_full_transactions(request: &JsonRpcRequest, index: usize) -> Result<bool> {
+    match request.get_param(index) {
+        Value::Null => Ok(false),
+        _ => Ok(request.parse_param(index, "full_transactions")?),
+    }
+}
+
+// the header alone, or with the transactions and the proof of the block
+fn block_view(header: LedgerHeader, full_transactions: bool) -> Result<LedgerView> {
+    if !full_transactions {
+        return Ok(LedgerView::new(header));
+    }
+
+    let height = header.get_height();
+    let tx_list = match LedgerStorage::load_ledger_tx_list(height) {
+        Ok(tx_list) => tx_list.unwrap_or_default(),
+        Err(e) => {
+            return Err(Error::new(JsonRpcError::internal_error(format!(
+                "db error",
+            ))));
+        }
+    };
+    let mut transactions = Vec::with_capacity(tx_list.get_entry().len());
+    for hash in tx_list.get_entry() {
+        match LedgerStorage::load_tx(&hex::encode(hash)) {
+            Ok(Some(tx_store)) => transactions.push(tx_store),
+            Ok(None) => {
+                return Err(Error::new(JsonRpcError::data_not_found(
+                    "Unable to get the transaction!".to_string(),
+                )));
+            }
+            Err(e) => {
+                return Err(Error::new(JsonRpcError::internal_error(format!(
+                    "db error",
                 ))));
             }
-        },
+        }
+    }
+    let proof = match StateStorage::load_proof(height) {
+        Ok(proof) => proof,
         Err(e) => {
             return Err(Error::new(JsonRpcError::internal_error(format!(
-                "Trie error",
-            ))))
+                "db error",
+            ))));
         }
-    }
+    };
+    Ok(LedgerView::new(header).with_transactions(&transactions, proof.as_ref()))
 }
 
 async fn get_block_by_sequence(
@@ -113,11 +348,12 @@ async fn get_block_by_sequence(
             .get_height()
     };
     let ledger_seq = if _last_seq >= _seq { _seq } else { _last_seq };
+    let full_transactions = parse_full_transactions(&request, 1)?;
 
     match LedgerStorage::load_ledger_header_by_seq(ledger_seq) {
         Ok(value) => match value {
             Some(header) => {
-                return Ok(Some(LedgerView::new(header)));
+                return Ok(Some(block_view(header, full_transactions)?));
             }
             None => {
                 return Err(Error::new(JsonRpcError::data_not_found(
@@ -138,11 +374,12 @@ async fn get_block_by_hash(
     request: JsonRpcRequest,
 ) -> Result<Option<LedgerView>> {
     let hash: String = request.parse_param(0, "hash")?;
+    let full_transactions = parse_full_transactions(&request, 1)?;
 
     match LedgerStorage::load_ledger_header_by_hash(&hash) {
         Ok(value) => match value {
             Some(header) => {
-                return Ok(Some(LedgerView::new(header)));
+                return Ok(Some(block_view(header, full_transactions)?));
             }
             None => {
                 return Err(Error::new(JsonRpcError::data_not_found(
@@ -196,6 +433,126 @@ async fn get_transaction(
     }
 }
 
+// Merkle audit path of `leaf` among `leafs`, the same tree the executor roots the header with
+pub(crate) fn merkle_audit_path(leafs: Vec<Vec<u8>>, leaf: Vec<u8>) -> Vec<MerkleProofView> {
+    let mut tree = Tree::new();
+    tree.build(leafs);
+    let mut trail = Vec::new();
+    tree.audit_proof(leaf, &mut trail);
+    MerkleProofView::from_trail(&trail)
+}
+
+// Receipts are hashed at execution, before the block hash is known
+pub(crate) fn receipt_bytes(result: &TransactionResult) -> Vec<u8> {
+    let mut result = result.clone();
+    result.clear_block_hash();
+    ProtocolParser::serialize::<TransactionResult>(&result)
+}
+
+async fn get_transaction_proof(
+    service: JsonRpcService,
+    request: JsonRpcRequest,
+) -> Result<TransactionProofView> {
+    let hash_str: String = request.parse_param(0, "hash")?;
+
+    if !check_tx_hash(hash_str.as_str()) {
+        return Err(Error::new(JsonRpcError::invalid_parameter(
+            "tx hash",
+            "hash format error",
+        )));
+    }
+
+    let tx_store = match LedgerStorage::load_tx(&hash_str) {
+        Ok(Some(tx_store)) => tx_store,
+        Ok(None) => {
+            return Err(Error::new(JsonRpcError::data_not_found(
+                "Unable to get the transaction!".to_string(),
+            )));
+        }
+        Err(e) => {
+            return Err(Error::new(JsonRpcError::internal_error(format!(
+                "db error",
+            ))));
+        }
+    };
+    let height = tx_store.get_transaction_result().get_block_height();
+
+    let header = match LedgerStorage::load_ledger_header_by_seq(height) {
+        Ok(Some(header)) => header,
+        Ok(None) => {
+            return Err(Error::new(JsonRpcError::data_not_found(
+                "Unable to get the block content of this height!".to_string(),
+            )));
+        }
+        Err(e) => {
+            return Err(Error::new(JsonRpcError::internal_error(format!(
+                "db error",
+            ))));
+        }
+    };
+    let tx_list = match LedgerStorage::load_ledger_tx_list(height) {
+        Ok(tx_list) => tx_list.unwrap_or_default(),
+        Err(e) => {
+            return Err(Error::new(JsonRpcError::internal_error(format!(
+                "db error",
+            ))));
+        }
+    };
+    let tx_hash = hex::decode(&hash_str).unwrap_or_default();
+    let index = match tx_list.get_entry().iter().position(|h| *h == tx_hash) {
+        Some(index) => index,
+        None => {
+            return Err(Error::new(JsonRpcError::data_not_found(
+                "Unable to get the transaction in its block!".to_string(),
+            )));
+        }
+    };
+
+    let mut receipt_leafs = Vec::with_capacity(tx_list.get_entry().len());
+    let mut receipt = Vec::new();
+    for (i, hash) in tx_list.get_entry().iter().enumerate() {
+        let result = match LedgerStorage::load_tx(&hex::encode(hash)) {
+            Ok(Some(store)) => store.get_transaction_result().clone(),
+            Ok(None) => {
+                return Err(Error::new(JsonRpcError::data_not_found(
+                    "Unable to get the transaction!".to_string(),
+                )));
+            }
+            Err(e) => {
+                return Err(Error::new(JsonRpcError::internal_error(format!(
+                    "db error",
+                ))));
+            }
+        };
+        let bytes = receipt_bytes(&result);
+        receipt_leafs.push(hash_crypto_byte(&bytes));
+        if i == index {
+            receipt = bytes;
+        }
+    }
+    let receipt_leaf = receipt_leafs[index].clone();
+
+    let proof = match StateStorage::load_proof(height) {
+        Ok(proof) => proof,
+        Err(e) => {
+            return Err(Error::new(JsonRpcError::internal_error(format!(
+                "db error",
+            ))));
+        }
+    };
+
+    Ok(TransactionProofView {
+        tx_hash: hash_str,
+        block_height: height,
+        index: index as u64,
+        transaction_proof: merkle_audit_path(tx_list.get_entry().to_vec(), tx_hash),
+        receipt: hex::encode(&receipt),
+        receipt_proof: merkle_audit_path(receipt_leafs, receipt_leaf),
+        header: hex::encode(ProtocolParser::serialize::<LedgerHeader>(&header)),
+        proof: proof.map(|proof| hex::encode(ProtocolParser::serialize::<BftProof>(&proof))),
+    })
+}
+
 async fn send_transaction(service: JsonRpcService, request: JsonRpcRequest) -> Result<TxHash> {
     if request.params.len() != 1 {
         return Err(Error::new(JsonRpcError::invalid_params_size(
@@ -297,6 +654,15 @@ async fn send_transaction(service: JsonRpcService, request: JsonRpcRequest) -> R
     };
     let sign_transaction = SignedTransaction::try_from(transaction_sign)?;
     let tx_hash = sign_transaction.hash_hex();
+    submit_transaction(&service, sign_transaction).await?;
+    Ok(TxHash::new(tx_hash))
+}
+
+/// Hand the transaction to the tx-pool and wait until it is accepted or rejected.
+pub(crate) async fn submit_transaction(
+    service: &JsonRpcService,
+    sign_transaction: SignedTransaction,
+) -> Result<()> {
     let (request_sender, callback) = oneshot::channel();
     service
         .jsonrpc_to_txpool_sender
@@ -306,7 +672,7 @@ async fn send_transaction(service: JsonRpcService, request: JsonRpcRequest) -> R
     if let Some(vm_status) = vm_status_opt {
         Err(Error::new(JsonRpcError::validation_status(vm_status)))
     } else if mempool_status.code == TxPoolStatusCode::Accepted {
-        Ok(TxHash::new(tx_hash))
+        Ok(())
     } else {
         Err(Error::new(JsonRpcError::mempool_error(mempool_status)?))
     }
@@ -316,12 +682,6 @@ async fn query_contract(
     service: JsonRpcService,
     request: JsonRpcRequest,
 ) -> Result<TransactionResultView> {
-    if request.params.len() != 1 {
-        return Err(Error::new(JsonRpcError::invalid_params_size(
-            "Currently only one transaction is supported for one upload!".to_string(),
-        )));
-    }
-
     let value = request.get_param(0);
 
     let submit_tx: SubmitTx = match serde_json::from_value(value) {
@@ -338,35 +698,243 @@ async fn query_contract(
         return Err(e);
     }
 
-    let transaction_sign = match submit_tx.signature {
-        None => match submit_tx.private_key {
-            None => (),
-            Some(private_key_str) => (),
-        },
-        Some(sig_raw) => (),
+    // the query is never broadcast, so neither signature nor private key is needed
+    let mut transaction_sign = TransactionSign::default();
+    transaction_sign.set_transaction(submit_tx.transaction.to_protocol()?);
+    transaction_sign.set_source_type(protos::ledger::TransactionSign_SourceType::JSONRPC);
+    let header = parse_state_block(&request, 1)?;
+
+    match BlockExecutor::call_transaction_at(&transaction_sign, &header) {
+        Ok(result) => {
+            let mut tx_store = TransactionSignStore::default();
+            tx_store.set_transaction_sign(transaction_sign);
+            tx_store.set_transaction_result(result);
+            return Ok(TransactionResultView::from(&tx_store));
+        }
+        Err(e) => {
+            return Err(Error::new(JsonRpcError::internal_error(format!(
+                "query contract error {}",
+                e
+            ))));
+        }
+    }
+}
+
+// the optional page and page size params starting at `index`, the size defaults to the limit
+fn parse_page(
+    service: &JsonRpcService,
+    request: &JsonRpcRequest,
+    index: usize,
+) -> Result<(u64, u64)> {
+    let page: u64 = match request.get_param(index) {
+        Value::Null => 0,
+        _ => request.parse_param(index, "page")?,
+    };
+    let page_size: u64 = match request.get_param(index + 1) {
+        Value::Null => service.page_size_limit() as u64,
+        _ => request.parse_param(index + 1, "page_size")?,
     };
+    if page_size == 0 {
+        return Err(Error::new(JsonRpcError::invalid_param(
+            index + 1,
+            "page_size",
+            "must be greater than 0",
+        )));
+    }
+    service.check_page_size_limit(page_size as usize)?;
+    Ok((page, page_size))
+}
 
-    // let sign_transaction = SignedTransaction::try_from(transaction_sign)?;
-    // let tx_hash = sign_transaction.hash_hex();
-    // let (request_sender, callback) = oneshot::channel();
-    // service
-    //     .jsonrpc_to_txpool_sender
-    //     .unbounded_send((sign_transaction, request_sender))?;
-
-    // let (mempool_status, vm_status_opt) = callback.await??;
-    // if let Some(vm_status) = vm_status_opt {
-    //     Err(Error::new(JsonRpcError::validation_status(vm_status)))
-    // } else if mempool_status.code == TxPoolStatusCode::Accepted {
-    //     //Ok(TxHash::new(tx_hash))
-    //     Err(Error::new(JsonRpcError::mempool_error(mempool_status)?))
-    // } else {
-    //     Err(Error::new(JsonRpcError::mempool_error(mempool_status)?))
-    // }
-
-    return Err(Error::new(JsonRpcError::invalid_parameter(
-        "queryContract",
-        "error",
-    )));
+fn load_tx_view(hash: &[u8]) -> Result<TransactionResultView> {
+    match LedgerStorage::load_tx(&hex::encode(hash)) {
+        Ok(Some(tx_store)) => Ok(TransactionResultView::from(&tx_store)),
+        Ok(None) => Err(Error::new(JsonRpcError::data_not_found(
+            "Unable to get the transaction!".to_string(),
+        ))),
+        Err(e) => Err(Error::new(JsonRpcError::internal_error(format!(
+            "db error",
+        )))),
+    }
+}
+
+async fn get_transactions_by_block(
+    service: JsonRpcService,
+    request: JsonRpcRequest,
+) -> Result<PageView<TransactionResultView>> {
+    let seq: u64 = request.parse_param(0, "sequence")?;
+    let (page, page_size) = parse_page(&service, &request, 1)?;
+
+    let tx_list = match LedgerStorage::load_ledger_tx_list(seq) {
+        Ok(Some(tx_list)) => tx_list,
+        Ok(None) => {
+            return Err(Error::new(JsonRpcError::data_not_found(
+                "Unable to get the block content of this height!".to_string(),
+            )));
+        }
+        Err(e) => {
+            return Err(Error::new(JsonRpcError::internal_error(format!(
+                "db error",
+            ))));
+        }
+    };
+
+    let hashes = tx_list.get_entry();
+    let total = hashes.len() as u64;
+    let start = min(page.saturating_mul(page_size), total);
+    let end = min(start + page_size, total);
+    let mut items = Vec::with_capacity((end - start) as usize);
+    for hash in hashes[start as usize..end as usize].iter() {
+        items.push(load_tx_view(hash)?);
+    }
+    Ok(PageView {
+        total,
+        page,
+        page_size,
+        items,
+    })
+}
+
+/// Transactions sent or received by the account, the latest first.
+async fn get_transactions_by_account(
+    service: JsonRpcService,
+    request: JsonRpcRequest,
+) -> Result<PageView<TransactionResultView>> {
+    let address: String = request.parse_param(0, "address")?;
+    if !check_address(&address) {
+        return Err(Error::new(JsonRpcError::invalid_address(&address)));
+    }
+    let (page, page_size) = parse_page(&service, &request, 1)?;
+
+    let total = match LedgerStorage::load_account_tx_count(&address) {
+        Ok(total) => total,
+        Err(e) => {
+            return Err(Error::new(JsonRpcError::internal_error(format!(
+                "db error",
+            ))));
+        }
+    };
+    let start = min(page.saturating_mul(page_size), total);
+    let end = min(start + page_size, total);
+    let mut items = Vec::with_capacity((end - start) as usize);
+    for i in start..end {
+        match LedgerStorage::load_account_tx_hash(&address, total - 1 - i) {
+            Ok(Some(hash)) => items.push(load_tx_view(&hash)?),
+            Ok(None) => {
+                return Err(Error::new(JsonRpcError::data_not_found(
+                    "Unable to get the transaction!".to_string(),
+                )));
+            }
+            Err(e) => {
+                return Err(Error::new(JsonRpcError::internal_error(format!(
+                    "db error",
+                ))));
+            }
+        }
+    }
+    Ok(PageView {
+        total,
+        page,
+        page_size,
+        items,
+    })
+}
+
+async fn get_logs(service: JsonRpcService, request: JsonRpcRequest) -> Result<PageView<LogView>> {
+    let filter: LogFilter = request.parse_param(0, "filter")?;
+    let (page, page_size) = parse_page(&service, &request, 1)?;
+
+    let last_seq = {
+        LAST_COMMITTED_BLOCK_INFO_REF
+            .read()
+            .get_header()
+            .get_height()
+    };
+    let to_block = min(filter.to_block.unwrap_or(last_seq), last_seq);
+    let from_block = filter.from_block.unwrap_or(to_block);
+    if from_block > to_block {
+        return Err(Error::new(JsonRpcError::invalid_parameter(
+            "filter",
+            "from_block is greater than to_block",
+        )));
+    }
+    if to_block - from_block >= LOGS_MAX_BLOCK_RANGE {
+        return Err(Error::new(JsonRpcError::invalid_parameter(
+            "filter",
+            &format!("block range exceeds {}", LOGS_MAX_BLOCK_RANGE),
+        )));
+    }
+
+    let mut logs = Vec::new();
+    for seq in from_block..=to_block {
+        // blocks committed before the index existed have no bloom and are scanned
+        let bloom = match LedgerStorage::load_log_bloom(seq) {
+            Ok(bloom) => bloom,
+            Err(e) => {
+                return Err(Error::new(JsonRpcError::internal_error(format!(
+                    "db error",
+                ))));
+            }
+        };
+        if let Some(bloom) = bloom {
+            if !filter.matches_bloom(&bloom) {
+                continue;
+            }
+        }
+
+        let tx_list = match LedgerStorage::load_ledger_tx_list(seq) {
+            Ok(Some(tx_list)) => tx_list,
+            Ok(None) => continue,
+            Err(e) => {
+                return Err(Error::new(JsonRpcError::internal_error(format!(
+                    "db error",
+                ))));
+            }
+        };
+        for hash in tx_list.get_entry() {
+            let tx_store = match LedgerStorage::load_tx(&hex::encode(hash)) {
+                Ok(Some(tx_store)) => tx_store,
+                Ok(None) => continue,
+                Err(e) => {
+                    return Err(Error::new(JsonRpcError::internal_error(format!(
+                        "db error",
+                    ))));
+                }
+            };
+            let result = tx_store.get_transaction_result();
+            for (i, event) in result
+                .get_contract_result()
+                .get_contract_event()
+                .iter()
+                .enumerate()
+            {
+                if filter.matches(event) {
+                    logs.push(LogView {
+                        block_height: seq,
+                        block_hash: hex::encode(result.get_block_hash()),
+                        tx_hash: hex::encode(hash),
+                        tx_index: result.get_index() as i32,
+                        log_index: i,
+                        address: event.get_address().to_string(),
+                        topic: event.get_topic().to_vec(),
+                        data: event.get_data().to_vec(),
+                    });
+                }
+            }
+        }
+    }
+
+    let total = logs.len() as u64;
+    let items = logs
+        .into_iter()
+        .skip(page.saturating_mul(page_size) as usize)
+        .take(page_size as usize)
+        .collect();
+    Ok(PageView {
+        total,
+        page,
+        page_size,
+        items,
+    })
 }
 
 #[allow(unused_comparisons)]
@@ -374,10 +942,75 @@ pub(crate) fn build_registry() -> RpcRegistry {
     let mut registry = RpcRegistry::new();
     register_rpc_method!(registry, "createAccount", create_account, 1, 0);
     register_rpc_method!(registry, "sendTransaction", send_transaction, 1, 0);
-    register_rpc_method!(registry, "getAccountBase", get_accountbase, 1, 0);
-    register_rpc_method!(registry, "getBlockBySequence", get_block_by_sequence, 1, 0);
-    register_rpc_method!(registry, "getBlockByHash", get_block_by_hash, 1, 0);
+    register_rpc_method!(registry, "getAccountBase", get_accountbase, 1, 1);
+    register_rpc_method!(registry, "getProof", get_proof, 2, 1);
+    register_rpc_method!(registry, "getBlockBySequence", get_block_by_sequence, 1, 1);
+    register_rpc_method!(registry, "getBlockByHash", get_block_by_hash, 1, 1);
     register_rpc_method!(registry, "getLastBlock", get_lastblock, 0, 0);
     register_rpc_method!(registry, "getTransaction", get_transaction, 1, 0);
+    register_rpc_method!(registry, "getTransactionProof", get_transaction_proof, 1, 0);
+    register_rpc_method!(registry, "queryContract", query_contract, 1, 1);
+    register_rpc_method!(
+        registry,
+        "getTransactionsByBlock",
+        get_transactions_by_block,
+        1,
+        2
+    );
+    register_rpc_method!(
+        registry,
+        "getTransactionsByAccount",
+        get_transactions_by_account,
+        1,
+        2
+    );
+    register_rpc_method!(registry, "getLogs", get_logs, 1, 2);
+
+    // ethereum compatible namespace
+    register_rpc_method!(registry, "eth_chainId", eth::chain_id, 0, 0);
+    register_rpc_method!(registry, "net_version", eth::net_version, 0, 0);
+    register_rpc_method!(registry, "eth_blockNumber", eth::block_number, 0, 0);
+    register_rpc_method!(registry, "eth_gasPrice", eth::gas_price, 0, 0);
+    register_rpc_method!(registry, "eth_maxPriorityFeePerGas", eth::max_priority_fee_per_gas, 0, 0);
+    register_rpc_method!(registry, "eth_feeHistory", eth::fee_history, 2, 1);
+    register_rpc_method!(registry, "eth_getBalance", eth::get_balance, 1, 1);
+    register_rpc_method!(registry, "eth_getTransactionCount", eth::get_transaction_count, 1, 1);
+    register_rpc_method!(registry, "eth_getCode", eth::get_code, 1, 1);
+    register_rpc_method!(registry, "eth_getStorageAt", eth::get_storage_at, 2, 1);
+    register_rpc_method!(registry, "eth_call", eth::call, 1, 1);
+    register_rpc_method!(registry, "eth_estimateGas", eth::estimate_gas, 1, 1);
+    register_rpc_method!(registry, "eth_sendRawTransaction", eth::send_raw_transaction, 1, 0);
+    register_rpc_method!(registry, "eth_getTransactionByHash", eth::get_transaction_by_hash, 1, 0);
+    register_rpc_method!(registry, "eth_getTransactionReceipt", eth::get_transaction_receipt, 1, 0);
+    register_rpc_method!(registry, "eth_getBlockByNumber", eth::get_block_by_number, 1, 1);
+    register_rpc_method!(registry, "eth_getBlockByHash", eth::get_block_by_hash, 1, 1);
+    register_rpc_method!(registry, "eth_getLogs", eth::get_logs, 1, 0);
     registry
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parse_block_number_test() {
+        for value in [json!(26), json!("26"), json!("0x1a")] {
+            assert_eq!(parse_block_number(1, "block", &value).unwrap(), 26);
+        }
+        assert_eq!(
+            parse_block_number(1, "block", &json!("earliest")).unwrap(),
+            GENESIS_HEIGHT
+        );
+        for value in [
+            json!("1a"),
+            json!("0x"),
+            json!(-1),
+            json!(1.5),
+            json!("18446744073709551616"),
+            json!(true),
+        ] {
+            assert!(parse_block_number(1, "block", &value).is_err());
+        }
+    }
+}
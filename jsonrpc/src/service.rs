@@ -1,7 +1,8 @@
+use configure::{NodeMode, CONFIGURE_INSTANCE_REF};
 use tx_pool::types::ClientSender;
 use utils::general::self_chain_id;
 
-use crate::{errors::JsonRpcError, ws_connections::WsConnections};
+use crate::{auth::ApiKeyStore, errors::JsonRpcError, ws_connections::WsConnections};
 
 #[derive(Clone)]
 pub(crate) struct JsonRpcService {
@@ -9,20 +10,47 @@ pub(crate) struct JsonRpcService {
     batch_size_limit: u16,
     page_size_limit: u16,
     pub jsonrpc_to_txpool_sender: ClientSender,
+    pub api_keys: ApiKeyStore,
+    // full node rpc the requests are forwarded to, set on a light node only
+    pub light_forward_url: Option<String>,
 }
 
 impl JsonRpcService {
-    pub fn new(config: &configure::JsonRpcConfig, jsonrpc_to_txpool_sender: ClientSender) -> Self {
-        Self {
+    pub fn new(
+        config: &configure::JsonRpcConfig,
+        jsonrpc_to_txpool_sender: ClientSender,
+    ) -> anyhow::Result<Self> {
+        let api_keys = ApiKeyStore::new(config)
+            .map_err(|e| anyhow::anyhow!("[json-rpc] failed to load api keys: {}", e))?;
+        let light_forward_url = match CONFIGURE_INSTANCE_REF.db.node_mode {
+            NodeMode::Light => Some(config.light_forward_url.clone().ok_or_else(|| {
+                anyhow::anyhow!("[json-rpc] light_forward_url is required on a light node")
+            })?),
+            _ => None,
+        };
+        Ok(Self {
             chain_id: self_chain_id(),
             batch_size_limit: config.batch_size_limit,
-            page_size_limit: config.batch_size_limit,
+            page_size_limit: config.page_size_limit,
             jsonrpc_to_txpool_sender,
-        }
+            api_keys,
+            light_forward_url,
+        })
     }
 
     pub fn check_batch_size_limit(&self, size: usize) -> Result<(), JsonRpcError> {
-        self.check_size_limit("batch size", self.batch_size_limit, size)
+        if size == 0 || size > self.batch_size_limit as usize {
+            Err(JsonRpcError::invalid_batch_size(
+                size,
+                self.batch_size_limit,
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn page_size_limit(&self) -> u16 {
+        self.page_size_limit
     }
 
     pub fn check_page_size_limit(&self, size: usize) -> Result<(), JsonRpcError> {
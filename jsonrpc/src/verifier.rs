@@ -0,0 +1,534 @@
+//! Client side checks of the proofs served by the rpc. They tie transactions, receipts and
+//! account state to a header, trusting that header is up to the caller.
+use crate::view::proof_view::{
+    AccountProofView, MerkleProofView, TransactionProofView, PROOF_DIRECTION_LEFT,
+    PROOF_DIRECTION_RIGHT,
+};
+use anyhow::{bail, ensure, format_err, Result};
+use merkletree::{Branch, MerkleProofHash, Tree};
+use protos::{
+    common::KeyPair,
+    ledger::{Account, LedgerHeader, TransactionResult},
+};
+use state::{account_frame::CONTRACT_META_PREFIX, TrieHash, TrieReader};
+use utils::{
+    general::{compose_metadata_key, hash_crypto_byte},
+    parse::ProtocolParser,
+};
+
+fn decode_hex(name: &str, value: &str) -> Result<Vec<u8>> {
+    hex::decode(value).map_err(|e| format_err!("{} is not hex: {}", name, e))
+}
+
+fn decode_trie_root(name: &str, hash: &[u8]) -> Result<TrieHash> {
+    let mut root = TrieHash::default();
+    ensure!(hash.len() == root.len(), "{} is not a trie root", name);
+    root.clone_from_slice(hash);
+    Ok(root)
+}
+
+fn decode_trie_proof(proof: &[String]) -> Result<Vec<Vec<u8>>> {
+    proof
+        .iter()
+        .map(|node| decode_hex("trie node", node))
+        .collect()
+}
+
+fn decode_trail(trail: &[MerkleProofView]) -> Result<Vec<MerkleProofHash>> {
+    trail
+        .iter()
+        .map(|step| {
+            let direction = match step.direction.as_str() {
+                PROOF_DIRECTION_LEFT => Branch::Left,
+                PROOF_DIRECTION_RIGHT => Branch::Right,
+                other => bail!("unknown proof direction {}", other),
+            };
+            Ok(MerkleProofHash::new(
+                decode_hex("proof hash", &step.hash)?,
+                direction,
+            ))
+        })
+        .collect()
+}
+
+/// Check `header` hashes to its own hash field, as the executor computes it.
+pub fn verify_header_hash(header: &LedgerHeader) -> Result<()> {
+    let mut unhashed = header.clone();
+    unhashed.clear_hash();
+    let hash = hash_crypto_byte(&ProtocolParser::serialize::<LedgerHeader>(&unhashed));
+    ensure!(
+        hash.as_slice() == header.get_hash(),
+        "header hash mismatch at height {}",
+        header.get_height()
+    );
+    Ok(())
+}
+
+/// Check `leaf` is under `root` following `trail`.
+pub fn verify_merkle_path(root: &[u8], leaf: &[u8], trail: &[MerkleProofView]) -> Result<()> {
+    let trail = decode_trail(trail)?;
+    ensure!(
+        Tree::new().verify_audit(root.to_vec(), leaf.to_vec(), &trail),
+        "merkle path does not lead to the root"
+    );
+    Ok(())
+}
+
+/// Verify a `getTransactionProof` response, returning the header and the receipt it proves.
+/// The caller still has to trust the header, by its hash or by checking `proof`.
+pub fn verify_transaction_proof(
+    proof: &TransactionProofView,
+) -> Result<(LedgerHeader, TransactionResult)> {
+    let header =
+        ProtocolParser::deserialize::<LedgerHeader>(&decode_hex("header", &proof.header)?)?;
+    verify_header_hash(&header)?;
+    ensure!(
+        header.get_height() == proof.block_height,
+        "header height {} is not the block height {}",
+        header.get_height(),
+        proof.block_height
+    );
+
+    let tx_hash = decode_hex("tx hash", &proof.tx_hash)?;
+    verify_merkle_path(
+        header.get_transactions_hash(),
+        &tx_hash,
+        &proof.transaction_proof,
+    )
+    .map_err(|e| format_err!("transaction {}: {}", proof.tx_hash, e))?;
+
+    let receipt = decode_hex("receipt", &proof.receipt)?;
+    verify_merkle_path(
+        header.get_receips_hash(),
+        &hash_crypto_byte(&receipt),
+        &proof.receipt_proof,
+    )
+    .map_err(|e| format_err!("receipt of {}: {}", proof.tx_hash, e))?;
+
+    let receipt = ProtocolParser::deserialize::<TransactionResult>(&receipt)?;
+    ensure!(
+        receipt.get_block_height() == header.get_height(),
+        "receipt height {} is not the block height {}",
+        receipt.get_block_height(),
+        header.get_height()
+    );
+    Ok((header, receipt))
+}
+
+/// Verify a `getProof` response, returning the header and the proven account, None when
+/// the address has no state. Storage values are checked against the account's metadata_hash.
+pub fn verify_account_proof(proof: &AccountProofView) -> Result<(LedgerHeader, Option<Account>)> {
+    let header =
+        ProtocolParser::deserialize::<LedgerHeader>(&decode_hex("header", &proof.header)?)?;
+    verify_header_hash(&header)?;
+    ensure!(
+        header.get_height() == proof.block_height,
+        "header height {} is not the block height {}",
+        header.get_height(),
+        proof.block_height
+    );
+
+    let state_root = decode_trie_root("state hash", header.get_state_hash())?;
+    let account_bytes = match &proof.account {
+        Some(account) => Some(decode_hex("account", account)?),
+        None => None,
+    };
+    TrieReader::verify_proof(
+        &state_root,
+        &decode_trie_proof(&proof.account_proof)?,
+        &[(proof.address.as_bytes().to_vec(), account_bytes.clone())],
+    )
+    .map_err(|e| format_err!("account {}: {}", proof.address, e))?;
+
+    let account = match &account_bytes {
+        Some(bytes) => Some(ProtocolParser::deserialize::<Account>(bytes)?),
+        None => None,
+    };
+    ensure!(
+        account
+            .as_ref()
+            .map_or(true, |account| account.get_address() == proof.address),
+        "account is not {}",
+        proof.address
+    );
+    let meta_hash = account
+        .as_ref()
+        .map(|account| account.get_metadata_hash().to_vec())
+        .unwrap_or_default();
+
+    for storage in proof.storage_proof.iter() {
+        let inner_key = decode_hex("storage key", storage.key.trim_start_matches("0x"))?;
+        let entry = match &storage.entry {
+            Some(entry) => Some(decode_hex("storage entry", entry)?),
+            None => None,
+        };
+        if meta_hash.is_empty() {
+            ensure!(
+                entry.is_none(),
+                "storage {} set without metadata",
+                storage.key
+            );
+            continue;
+        }
+
+        let outer_key = compose_metadata_key(CONTRACT_META_PREFIX, &proof.address, &inner_key);
+        TrieReader::verify_proof(
+            &decode_trie_root("metadata hash", &meta_hash)?,
+            &decode_trie_proof(&storage.proof)?,
+            &[(outer_key, entry.clone())],
+        )
+        .map_err(|e| format_err!("storage {}: {}", storage.key, e))?;
+
+        let value = match &entry {
+            Some(entry) => Some(hex::encode(
+                ProtocolParser::deserialize::<KeyPair>(entry)?.get_value(),
+            )),
+            None => None,
+        };
+        ensure!(
+            value == storage.value,
+            "storage {} value does not match its entry",
+            storage.key
+        );
+    }
+
+    Ok((header, account))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        methods::{merkle_audit_path, receipt_bytes},
+        view::proof_view::StorageProofView,
+    };
+    use state::{TrieJournal, TrieWriter};
+    use std::collections::HashMap;
+    use storage_db::{
+        key_value_db::KeyValueDb, memorydb::MemoryDB, KeyValueDB, MemWriteBatch, WriteBatchTrait,
+    };
+
+    const HEIGHT: u64 = 7;
+    const CONTRACT: &str = "did:gdt:0xf6b02a2d47b84e845b7e3623355f041bcb36daf1";
+    const USER: &str = "did:gdt:0x160b54be617f4bff07bd6c994fc6dd17a69d5e4e";
+    const NOBODY: &str = "did:gdt:0x0000000000000000000000000000000000000001";
+
+    fn seal(header: &mut LedgerHeader) {
+        header.clear_hash();
+        let hash = hash_crypto_byte(&ProtocolParser::serialize::<LedgerHeader>(header));
+        header.set_hash(hash);
+    }
+
+    fn merkle_root(leafs: Vec<Vec<u8>>) -> Vec<u8> {
+        let mut tree = Tree::new();
+        tree.build(leafs);
+        tree.root()
+    }
+
+    // A block rooted the way the executor roots it, with the receipts as the ledger store
+    // keeps them, that is with the block hash filled in after hashing.
+    fn block(count: u32) -> (LedgerHeader, Vec<Vec<u8>>, Vec<TransactionResult>) {
+        let mut tx_hashes = Vec::new();
+        let mut receipts = Vec::new();
+        for i in 0..count {
+            tx_hashes.push(hash_crypto_byte(&i.to_be_bytes()));
+            let mut receipt = TransactionResult::default();
+            receipt.set_gas_used(21000 + i as u64);
+            receipt.set_block_height(HEIGHT);
+            receipt.set_index(i);
+            receipts.push(receipt);
+        }
+        let receipt_leafs = receipts
+            .iter()
+            .map(|r| hash_crypto_byte(&ProtocolParser::serialize::<TransactionResult>(r)))
+            .collect();
+
+        let mut header = LedgerHeader::default();
+        header.set_height(HEIGHT);
+        header.set_transactions_hash(merkle_root(tx_hashes.clone()));
+        header.set_receips_hash(merkle_root(receipt_leafs));
+        seal(&mut header);
+        for receipt in receipts.iter_mut() {
+            receipt.set_block_hash(header.get_hash().to_vec());
+        }
+        (header, tx_hashes, receipts)
+    }
+
+    // what getTransactionProof serves for the transaction at `index`
+    fn transaction_proof(
+        header: &LedgerHeader,
+        tx_hashes: &[Vec<u8>],
+        receipts: &[TransactionResult],
+        index: usize,
+    ) -> TransactionProofView {
+        let receipt_leafs: Vec<Vec<u8>> = receipts
+            .iter()
+            .map(|r| hash_crypto_byte(&receipt_bytes(r)))
+            .collect();
+        TransactionProofView {
+            tx_hash: hex::encode(&tx_hashes[index]),
+            block_height: header.get_height(),
+            index: index as u64,
+            transaction_proof: merkle_audit_path(tx_hashes.to_vec(), tx_hashes[index].clone()),
+            receipt: hex::encode(receipt_bytes(&receipts[index])),
+            receipt_proof: merkle_audit_path(receipt_leafs.clone(), receipt_leafs[index].clone()),
+            header: hex::encode(ProtocolParser::serialize::<LedgerHeader>(header)),
+            proof: None,
+        }
+    }
+
+    #[test]
+    fn transaction_proof_test() {
+        let (header, tx_hashes, receipts) = block(4);
+        for index in 0..tx_hashes.len() {
+            let proof = transaction_proof(&header, &tx_hashes, &receipts, index);
+            let (proven_header, receipt) = verify_transaction_proof(&proof).unwrap();
+            assert_eq!(proven_header, header);
+            assert_eq!(receipt.get_index(), index as u32);
+            assert_eq!(receipt.get_gas_used(), receipts[index].get_gas_used());
+            assert!(receipt.get_block_hash().is_empty());
+        }
+    }
+
+    #[test]
+    fn tampered_transaction_proof_test() {
+        let (header, tx_hashes, receipts) = block(4);
+        let proof = transaction_proof(&header, &tx_hashes, &receipts, 1);
+
+        // another transaction of the block under this one's path
+        let mut tampered = proof.clone();
+        tampered.tx_hash = hex::encode(&tx_hashes[2]);
+        assert!(verify_transaction_proof(&tampered).is_err());
+
+        // a receipt that was not executed
+        let mut receipt = receipts[1].clone();
+        receipt.set_gas_used(1);
+        let mut tampered = proof.clone();
+        tampered.receipt = hex::encode(receipt_bytes(&receipt));
+        assert!(verify_transaction_proof(&tampered).is_err());
+
+        // the stored receipt still carries the block hash, it is not the hashed leaf
+        let mut tampered = proof.clone();
+        tampered.receipt =
+            hex::encode(ProtocolParser::serialize::<TransactionResult>(&receipts[1]));
+        assert!(verify_transaction_proof(&tampered).is_err());
+
+        let mut tampered = proof.clone();
+        tampered.transaction_proof[0].hash = hex::encode(&tx_hashes[3]);
+        assert!(verify_transaction_proof(&tampered).is_err());
+
+        let mut tampered = proof.clone();
+        tampered.receipt_proof[0].direction = PROOF_DIRECTION_LEFT.to_string();
+        assert!(verify_transaction_proof(&tampered).is_err());
+
+        // a header that does not hash to itself
+        let mut forged = header.clone();
+        forged.set_receips_hash(forged.get_transactions_hash().to_vec());
+        let mut tampered = proof.clone();
+        tampered.header = hex::encode(ProtocolParser::serialize::<LedgerHeader>(&forged));
+        assert!(verify_transaction_proof(&tampered).is_err());
+
+        let mut tampered = proof;
+        tampered.block_height = HEIGHT + 1;
+        assert!(verify_transaction_proof(&tampered).is_err());
+    }
+
+    fn commit_trie(db: &KeyValueDB, datas: HashMap<Vec<u8>, Option<Vec<u8>>>) -> TrieHash {
+        let mut batch = MemWriteBatch::new();
+        let root = TrieWriter::commit(
+            db.clone(),
+            None,
+            &datas,
+            &mut batch,
+            &mut TrieJournal::default(),
+        )
+        .unwrap();
+        db.lock().write_batch(batch).unwrap();
+        root
+    }
+
+    fn account(address: &str, balance: &str, metadata_hash: Option<TrieHash>) -> Vec<u8> {
+        let mut account = Account::default();
+        account.set_address(address.to_string());
+        account.set_balance(balance.to_string());
+        if let Some(hash) = metadata_hash {
+            account.set_metadata_hash(hash.to_vec());
+        }
+        ProtocolParser::serialize::<Account>(&account)
+    }
+
+    // A contract storing keys 0 and 1 next to a plain account, under a sealed header.
+    fn state() -> (KeyValueDB, LedgerHeader) {
+        let db = MemoryDB::open();
+        let mut storage = HashMap::new();
+        for i in 0..2u8 {
+            let mut entry = KeyPair::default();
+            entry.set_key(vec![i]);
+            entry.set_value(vec![i; 8]);
+            storage.insert(
+                compose_metadata_key(CONTRACT_META_PREFIX, CONTRACT, &[i]),
+                Some(ProtocolParser::serialize::<KeyPair>(&entry)),
+            );
+        }
+        let meta_root = commit_trie(&db, storage);
+
+        let mut accounts = HashMap::new();
+        accounts.insert(
+            CONTRACT.as_bytes().to_vec(),
+            Some(account(CONTRACT, "100", Some(meta_root))),
+        );
+        accounts.insert(USER.as_bytes().to_vec(), Some(account(USER, "5", None)));
+        let state_root = commit_trie(&db, accounts);
+
+        let mut header = LedgerHeader::default();
+        header.set_height(HEIGHT);
+        header.set_state_hash(state_root.to_vec());
+        seal(&mut header);
+        (db, header)
+    }
+
+    // what getProof serves for `address` and its storage `keys`
+    fn account_proof(
+        db: &KeyValueDB,
+        header: &LedgerHeader,
+        address: &str,
+        keys: &[u8],
+    ) -> AccountProofView {
+        let state_root = decode_trie_root("state hash", header.get_state_hash()).unwrap();
+        let reader = TrieReader::new(db.clone(), Some(state_root));
+        let account = reader.get(address.as_bytes()).unwrap();
+        let account_proof = reader.get_proof(&[address.as_bytes().to_vec()]).unwrap();
+        let meta_hash = account
+            .as_ref()
+            .map(|data| {
+                ProtocolParser::deserialize::<Account>(data)
+                    .unwrap()
+                    .get_metadata_hash()
+                    .to_vec()
+            })
+            .unwrap_or_default();
+
+        let storage_proof = keys
+            .iter()
+            .map(|key| {
+                let (entry, proof) = if meta_hash.is_empty() {
+                    (None, Vec::new())
+                } else {
+                    let meta_root = decode_trie_root("metadata hash", &meta_hash).unwrap();
+                    let reader = TrieReader::new(db.clone(), Some(meta_root));
+                    let outer_key = compose_metadata_key(CONTRACT_META_PREFIX, address, &[*key]);
+                    (
+                        reader.get(&outer_key).unwrap(),
+                        reader.get_proof(&[outer_key]).unwrap(),
+                    )
+                };
+                StorageProofView {
+                    key: format!("0x{}", hex::encode([*key])),
+                    value: entry.as_ref().map(|entry| {
+                        hex::encode(
+                            ProtocolParser::deserialize::<KeyPair>(entry)
+                                .unwrap()
+                                .get_value(),
+                        )
+                    }),
+                    entry: entry.map(hex::encode),
+                    proof: proof.iter().map(hex::encode).collect(),
+                }
+            })
+            .collect();
+
+        AccountProofView {
+            address: address.to_string(),
+            block_height: header.get_height(),
+            account: account.map(hex::encode),
+            account_proof: account_proof.iter().map(hex::encode).collect(),
+            storage_proof,
+            header: hex::encode(ProtocolParser::serialize::<LedgerHeader>(header)),
+        }
+    }
+
+    #[test]
+    fn account_proof_test() {
+        let (db, header) = state();
+        let proof = account_proof(&db, &header, CONTRACT, &[0, 1, 9]);
+        let (proven_header, account) = verify_account_proof(&proof).unwrap();
+        assert_eq!(proven_header, header);
+        assert_eq!(account.unwrap().get_balance(), "100");
+        assert_eq!(proof.storage_proof[1].value, Some(hex::encode([1u8; 8])));
+        assert_eq!(proof.storage_proof[2].value, None);
+
+        let proof = account_proof(&db, &header, USER, &[0]);
+        let (_, account) = verify_account_proof(&proof).unwrap();
+        assert_eq!(account.unwrap().get_address(), USER);
+        assert_eq!(proof.storage_proof[0].entry, None);
+    }
+
+    #[test]
+    fn absent_account_proof_test() {
+        let (db, header) = state();
+        let proof = account_proof(&db, &header, NOBODY, &[0]);
+        let (_, account) = verify_account_proof(&proof).unwrap();
+        assert!(account.is_none());
+
+        // the absence proof does not prove an account
+        let mut tampered = proof.clone();
+        tampered.account = Some(hex::encode(account(NOBODY, "5", None)));
+        assert!(verify_account_proof(&tampered).is_err());
+
+        // nor storage of an account without metadata
+        let mut tampered = proof;
+        tampered.storage_proof = account_proof(&db, &header, CONTRACT, &[0]).storage_proof;
+        assert!(verify_account_proof(&tampered).is_err());
+
+        // a present account can not be proven absent
+        let mut tampered = account_proof(&db, &header, USER, &[]);
+        tampered.account = None;
+        assert!(verify_account_proof(&tampered).is_err());
+    }
+
+    #[test]
+    fn tampered_account_proof_test() {
+        let (db, header) = state();
+        let proof = account_proof(&db, &header, CONTRACT, &[0, 1]);
+
+        let mut tampered = proof.clone();
+        tampered.account = Some(hex::encode(account(CONTRACT, "1000000", None)));
+        assert!(verify_account_proof(&tampered).is_err());
+
+        // another account under this one's proof
+        let mut tampered = proof.clone();
+        tampered.account = account_proof(&db, &header, USER, &[]).account;
+        assert!(verify_account_proof(&tampered).is_err());
+
+        let mut tampered = account_proof(&db, &header, USER, &[]);
+        tampered.address = NOBODY.to_string();
+        assert!(verify_account_proof(&tampered).is_err());
+
+        let mut tampered = proof.clone();
+        tampered.account_proof.pop();
+        assert!(verify_account_proof(&tampered).is_err());
+
+        // storage values must be the proven entries
+        let mut tampered = proof.clone();
+        tampered.storage_proof[0].value = Some(hex::encode([7u8; 8]));
+        assert!(verify_account_proof(&tampered).is_err());
+
+        let mut tampered = proof.clone();
+        tampered.storage_proof[0].entry = tampered.storage_proof[1].entry.clone();
+        tampered.storage_proof[0].value = tampered.storage_proof[1].value.clone();
+        assert!(verify_account_proof(&tampered).is_err());
+
+        let mut tampered = proof.clone();
+        tampered.storage_proof[0].entry = None;
+        tampered.storage_proof[0].value = None;
+        assert!(verify_account_proof(&tampered).is_err());
+
+        // a sealed header of another state
+        let mut other = header.clone();
+        other.set_state_hash(state::empty_trie_root().to_vec());
+        seal(&mut other);
+        let mut tampered = proof;
+        tampered.header = hex::encode(ProtocolParser::serialize::<LedgerHeader>(&other));
+        assert!(verify_account_proof(&tampered).is_err());
+    }
+}
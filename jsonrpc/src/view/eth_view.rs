@@ -0,0 +1,413 @@
+use msp::{hash::keccak::hash_keccak256, signing::ADDRESS_PREFIX};
+use protos::ledger::{LedgerHeader, TransactionSign, TransactionSignStore};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use types::eth_transaction::{envelope_type, eth_envelope, EthTransaction};
+use utils::{
+    general::{address_filter_prefix, hash_crypto_byte},
+    parse::ProtocolParser,
+};
+
+const BLOOM_SIZE: usize = 256;
+/// keccak256 of the rlp of an empty list, the uncles hash of every block
+const EMPTY_UNCLES_HASH: &str =
+    "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347";
+
+pub fn to_quantity(value: u128) -> String {
+    format!("{:#x}", value)
+}
+
+pub fn to_data(value: &[u8]) -> String {
+    format!("0x{}", hex::encode(value))
+}
+
+pub fn parse_quantity(value: &str) -> Option<u128> {
+    let digits = value.strip_prefix("0x")?;
+    if digits.is_empty() {
+        return None;
+    }
+    u128::from_str_radix(digits, 16).ok()
+}
+
+pub fn parse_data(value: &str) -> Option<Vec<u8>> {
+    hex::decode(value.strip_prefix("0x")?).ok()
+}
+
+/// `did:gdt:0x...` to `0x...`
+pub fn to_eth_address(address: &str) -> String {
+    address_filter_prefix(address).to_lowercase()
+}
+
+/// `0x...` to `did:gdt:0x...`
+pub fn from_eth_address(address: &str) -> Option<String> {
+    let digits = address.strip_prefix("0x")?;
+    if digits.len() != 40 || hex::decode(digits).is_err() {
+        return None;
+    }
+    Some(format!("{}{}", ADDRESS_PREFIX, digits.to_lowercase()))
+}
+
+/// The hash a transaction is stored under, keccak256 of the envelope for ethereum transactions.
+pub fn transaction_hash(tx_sign: &TransactionSign) -> Vec<u8> {
+    match eth_envelope(tx_sign) {
+        Some(raw) => hash_keccak256(raw),
+        None => hash_crypto_byte(&ProtocolParser::serialize(tx_sign.get_transaction())),
+    }
+}
+
+fn accrue_bloom(bloom: &mut [u8; BLOOM_SIZE], input: &[u8]) {
+    let hash = hash_keccak256(input);
+    for i in [0, 2, 4] {
+        let bit = (((hash[i] as usize) << 8) | hash[i + 1] as usize) & (BLOOM_SIZE * 8 - 1);
+        bloom[BLOOM_SIZE - 1 - bit / 8] |= 1 << (bit % 8);
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EthLogView {
+    pub address: String,
+    pub topics: Vec<String>,
+    pub data: String,
+    pub block_number: String,
+    pub block_hash: String,
+    pub transaction_hash: String,
+    pub transaction_index: String,
+    pub log_index: String,
+    pub removed: bool,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EthReceiptView {
+    pub transaction_hash: String,
+    pub transaction_index: String,
+    pub block_hash: String,
+    pub block_number: String,
+    pub from: String,
+    pub to: Option<String>,
+    pub cumulative_gas_used: String,
+    pub gas_used: String,
+    pub effective_gas_price: String,
+    pub contract_address: Option<String>,
+    pub logs: Vec<EthLogView>,
+    pub logs_bloom: String,
+    pub status: String,
+    #[serde(rename = "type")]
+    pub tx_type: String,
+}
+
+impl EthReceiptView {
+    /// `cumulative_gas_used` includes the transaction itself, `first_log_index` is the
+    /// number of logs emitted by the transactions before it in the block.
+    pub fn new(
+        tx_store: &TransactionSignStore,
+        header: &LedgerHeader,
+        cumulative_gas_used: u64,
+        first_log_index: usize,
+    ) -> Self {
+        let tx_sign = tx_store.get_transaction_sign();
+        let tx = tx_sign.get_transaction();
+        let result = tx_store.get_transaction_result();
+        let contract_result = result.get_contract_result();
+        let tx_hash = to_data(&transaction_hash(tx_sign));
+        let block_hash = to_data(header.get_hash());
+        let block_number = to_quantity(header.get_height() as u128);
+        let transaction_index = to_quantity(result.get_index() as u128);
+
+        let mut bloom = [0u8; BLOOM_SIZE];
+        let mut logs = Vec::with_capacity(contract_result.get_contract_event().len());
+        for (i, event) in contract_result.get_contract_event().iter().enumerate() {
+            let address = to_eth_address(event.get_address());
+            if let Some(address) = parse_data(&address) {
+                accrue_bloom(&mut bloom, &address);
+            }
+            for topic in event.get_topic() {
+                if let Some(topic) = parse_data(topic) {
+                    accrue_bloom(&mut bloom, &topic);
+                }
+            }
+            logs.push(EthLogView {
+                address,
+                topics: event.get_topic().to_vec(),
+                data: format!("0x{}", event.get_data().concat()),
+                block_number: block_number.clone(),
+                block_hash: block_hash.clone(),
+                transaction_hash: tx_hash.clone(),
+                transaction_index: transaction_index.clone(),
+                log_index: to_quantity((first_log_index + i) as u128),
+                removed: false,
+            });
+        }
+
+        // the contract result message is the address of a created contract
+        let contract_address = if tx.get_to().is_empty() && result.get_err_code() == 0 {
+            Some(to_eth_address(contract_result.get_message()))
+        } else {
+            None
+        };
+
+        Self {
+            transaction_hash: tx_hash,
+            transaction_index,
+            block_hash,
+            block_number,
+            from: to_eth_address(tx.get_source()),
+            to: if tx.get_to().is_empty() {
+                None
+            } else {
+                Some(to_eth_address(tx.get_to()))
+            },
+            cumulative_gas_used: to_quantity(cumulative_gas_used as u128),
+            gas_used: to_quantity(result.get_gas_used() as u128),
+            effective_gas_price: to_quantity(tx.get_gas_price().parse::<u128>().unwrap_or(0)),
+            contract_address,
+            logs,
+            logs_bloom: to_data(&bloom),
+            status: if result.get_err_code() == 0 {
+                "0x1".to_string()
+            } else {
+                "0x0".to_string()
+            },
+            tx_type: to_quantity(eth_envelope(tx_sign).map(envelope_type).unwrap_or(0) as u128),
+        }
+    }
+
+    pub fn gas_used(&self) -> u64 {
+        parse_quantity(&self.gas_used).unwrap_or(0) as u64
+    }
+
+    pub fn merge_bloom(&self, bloom: &mut [u8]) {
+        if let Some(own) = parse_data(&self.logs_bloom) {
+            for (b, o) in bloom.iter_mut().zip(own.iter()) {
+                *b |= o;
+            }
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EthTransactionView {
+    pub hash: String,
+    pub nonce: String,
+    pub block_hash: Option<String>,
+    pub block_number: Option<String>,
+    pub transaction_index: Option<String>,
+    pub from: String,
+    pub to: Option<String>,
+    pub value: String,
+    pub gas: String,
+    pub gas_price: String,
+    pub input: String,
+    pub v: String,
+    pub r: String,
+    pub s: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_fee_per_gas: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_priority_fee_per_gas: Option<String>,
+    pub chain_id: Option<String>,
+    #[serde(rename = "type")]
+    pub tx_type: String,
+}
+
+impl EthTransactionView {
+    pub fn new(tx_store: &TransactionSignStore, header: &LedgerHeader) -> Self {
+        let tx_sign = tx_store.get_transaction_sign();
+        let tx = tx_sign.get_transaction();
+        let result = tx_store.get_transaction_result();
+
+        // native transactions carry no ethereum signature
+        let eth_tx = match eth_envelope(tx_sign).map(EthTransaction::decode) {
+            Some(Ok(eth_tx)) => Some(eth_tx),
+            _ => None,
+        };
+        let (v, r, s, chain_id) = match &eth_tx {
+            Some(eth_tx) => (
+                to_quantity(eth_tx.v as u128),
+                to_data(&eth_tx.r),
+                to_data(&eth_tx.s),
+                eth_tx.chain_id.map(|id| to_quantity(id as u128)),
+            ),
+            None => (
+                "0x0".to_string(),
+                "0x0".to_string(),
+                "0x0".to_string(),
+                None,
+            ),
+        };
+        // the gas price of an EIP-1559 transaction is its priority fee
+        let max_fee_per_gas = eth_tx
+            .as_ref()
+            .and_then(|eth_tx| eth_tx.max_fee_per_gas)
+            .map(to_quantity);
+        let max_priority_fee_per_gas = max_fee_per_gas
+            .as_ref()
+            .map(|_| to_quantity(tx.get_gas_price().parse::<u128>().unwrap_or(0)));
+
+        Self {
+            hash: to_data(&transaction_hash(tx_sign)),
+            nonce: to_quantity(tx.get_nonce() as u128),
+            block_hash: Some(to_data(header.get_hash())),
+            block_number: Some(to_quantity(header.get_height() as u128)),
+            transaction_index: Some(to_quantity(result.get_index() as u128)),
+            from: to_eth_address(tx.get_source()),
+            to: if tx.get_to().is_empty() {
+                None
+            } else {
+                Some(to_eth_address(tx.get_to()))
+            },
+            value: to_quantity(tx.get_value().parse::<u128>().unwrap_or(0)),
+            gas: to_quantity(tx.get_gas_limit() as u128),
+            gas_price: to_quantity(tx.get_gas_price().parse::<u128>().unwrap_or(0)),
+            input: to_data(tx.get_payload()),
+            v,
+            r,
+            s,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            chain_id,
+            tx_type: to_quantity(eth_tx.map(|eth_tx| eth_tx.tx_type).unwrap_or(0) as u128),
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EthBlockView {
+    pub number: String,
+    pub hash: String,
+    pub parent_hash: String,
+    pub nonce: String,
+    pub mix_hash: String,
+    pub sha3_uncles: String,
+    pub logs_bloom: String,
+    pub transactions_root: String,
+    pub state_root: String,
+    pub receipts_root: String,
+    pub miner: String,
+    pub difficulty: String,
+    pub total_difficulty: String,
+    pub extra_data: String,
+    pub size: String,
+    pub gas_limit: String,
+    pub gas_used: String,
+    pub timestamp: String,
+    /// geno has no fee market, the base fee is always zero
+    pub base_fee_per_gas: String,
+    /// transaction hashes, or `EthTransactionView`s for a full block
+    pub transactions: Vec<Value>,
+    pub uncles: Vec<String>,
+}
+
+impl EthBlockView {
+    pub fn new(
+        header: &LedgerHeader,
+        receipts: &[EthReceiptView],
+        transactions: Vec<Value>,
+        gas_limit: u64,
+    ) -> Self {
+        let mut bloom = [0u8; BLOOM_SIZE];
+        let mut gas_used = 0u64;
+        for receipt in receipts {
+            receipt.merge_bloom(&mut bloom);
+            gas_used = gas_used.saturating_add(receipt.gas_used());
+        }
+
+        Self {
+            number: to_quantity(header.get_height() as u128),
+            hash: to_data(header.get_hash()),
+            parent_hash: to_data(header.get_previous_hash()),
+            nonce: to_data(&[0u8; 8]),
+            mix_hash: to_data(&[0u8; 32]),
+            sha3_uncles: EMPTY_UNCLES_HASH.to_string(),
+            logs_bloom: to_data(&bloom),
+            transactions_root: to_data(header.get_transactions_hash()),
+            state_root: to_data(header.get_state_hash()),
+            receipts_root: to_data(header.get_receips_hash()),
+            miner: to_eth_address(header.get_proposer()),
+            difficulty: "0x0".to_string(),
+            total_difficulty: "0x0".to_string(),
+            extra_data: "0x".to_string(),
+            size: to_quantity(ProtocolParser::serialize(header).len() as u128),
+            gas_limit: to_quantity(gas_limit as u128),
+            gas_used: to_quantity(gas_used as u128),
+            // geno timestamps are milliseconds
+            timestamp: to_quantity((header.get_timestamp().max(0) / 1000) as u128),
+            base_fee_per_gas: "0x0".to_string(),
+            transactions,
+            uncles: Vec::new(),
+        }
+    }
+}
+
+/// The result of `eth_feeHistory`, fees are flat zero without a fee market.
+#[derive(Clone, Serialize, Deserialize, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EthFeeHistoryView {
+    pub oldest_block: String,
+    /// one entry more than the blocks, the base fee of the block after the newest
+    pub base_fee_per_gas: Vec<String>,
+    pub gas_used_ratio: Vec<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reward: Option<Vec<Vec<String>>>,
+}
+
+/// The call object of `eth_call` and `eth_estimateGas`.
+#[derive(Clone, Serialize, Deserialize, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EthCallRequest {
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub gas: Option<String>,
+    pub gas_price: Option<String>,
+    pub value: Option<String>,
+    pub data: Option<String>,
+    pub input: Option<String>,
+}
+
+/// The filter object of `eth_getLogs`.
+#[derive(Clone, Serialize, Deserialize, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EthLogFilter {
+    pub from_block: Option<Value>,
+    pub to_block: Option<Value>,
+    pub block_hash: Option<String>,
+    /// a single address or an array of addresses
+    pub address: Option<Value>,
+    /// per position null, a topic or an array of alternative topics
+    pub topics: Option<Vec<Value>>,
+}
+
+impl EthLogFilter {
+    pub fn matches(&self, log: &EthLogView) -> bool {
+        if let Some(address) = &self.address {
+            if !Self::match_value(address, &log.address) {
+                return false;
+            }
+        }
+        if let Some(topics) = &self.topics {
+            for (i, expected) in topics.iter().enumerate() {
+                if expected.is_null() {
+                    continue;
+                }
+                match log.topics.get(i) {
+                    Some(topic) if Self::match_value(expected, topic) => {}
+                    _ => return false,
+                }
+            }
+        }
+        true
+    }
+
+    fn match_value(expected: &Value, actual: &str) -> bool {
+        match expected {
+            Value::String(value) => value.eq_ignore_ascii_case(actual),
+            Value::Array(values) => {
+                values.is_empty() || values.iter().any(|v| Self::match_value(v, actual))
+            }
+            _ => true,
+        }
+    }
+}
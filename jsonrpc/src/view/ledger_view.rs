@@ -1,6 +1,8 @@
 use msp::bytes_to_hex_str;
-use protos::ledger::LedgerHeader;
+use protos::{consensus::BftProof, ledger::LedgerHeader, ledger::TransactionSignStore};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utils::proto2json::proto_to_json;
 
 #[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
 pub struct LedgerView {
@@ -17,6 +19,12 @@ pub struct LedgerView {
     pub proposer: String,
     pub hub_id: String,
     pub chain_id: String,
+    /// the `TransactionSignStore`s of the block, when full transactions are requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transactions: Option<Vec<Value>>,
+    /// the consensus proof committing the block
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proof: Option<Value>,
 }
 
 impl LedgerView {
@@ -26,8 +34,8 @@ impl LedgerView {
             hash: bytes_to_hex_str(header.get_hash()),
             previous_hash: bytes_to_hex_str(header.get_previous_hash()),
             state_hash: bytes_to_hex_str(header.get_state_hash()),
-            transactions_hash: bytes_to_hex_str(header.get_hash()),
-            receips_hash: bytes_to_hex_str(header.get_hash()),
+            transactions_hash: bytes_to_hex_str(header.get_transactions_hash()),
+            receips_hash: bytes_to_hex_str(header.get_receips_hash()),
             timestamp: header.get_timestamp(),
             version: header.get_version(),
             tx_count: header.get_tx_count(),
@@ -35,6 +43,23 @@ impl LedgerView {
             proposer: header.get_proposer().to_string(),
             hub_id: header.get_hub_id().to_string(),
             chain_id: header.get_chain_id().to_string(),
+            transactions: None,
+            proof: None,
         }
     }
+
+    pub fn with_transactions(
+        mut self,
+        transactions: &[TransactionSignStore],
+        proof: Option<&BftProof>,
+    ) -> LedgerView {
+        self.transactions = Some(
+            transactions
+                .iter()
+                .map(|tx_store| proto_to_json(tx_store))
+                .collect(),
+        );
+        self.proof = proof.map(|proof| proto_to_json(proof));
+        self
+    }
 }
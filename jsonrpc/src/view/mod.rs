@@ -1,3 +1,6 @@
 pub mod account_view;
+pub mod eth_view;
 pub mod ledger_view;
+pub mod page_view;
+pub mod proof_view;
 pub mod transaction_view;
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// One page of a list, `page` counts from 0.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct PageView<T> {
+    pub total: u64,
+    pub page: u64,
+    pub page_size: u64,
+    pub items: Vec<T>,
+}
@@ -0,0 +1,72 @@
+use merkletree::{Branch, MerkleProofHash};
+use msp::bytes_to_hex_str;
+use serde::{Deserialize, Serialize};
+
+pub const PROOF_DIRECTION_LEFT: &str = "left";
+pub const PROOF_DIRECTION_RIGHT: &str = "right";
+
+/// One step of a Merkle audit path, `left` when the running hash is the left child.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct MerkleProofView {
+    pub hash: String,
+    pub direction: String,
+}
+
+impl MerkleProofView {
+    pub fn from_trail(trail: &[MerkleProofHash]) -> Vec<MerkleProofView> {
+        trail
+            .iter()
+            .map(|step| MerkleProofView {
+                hash: bytes_to_hex_str(step.hash()),
+                direction: match step.direction() {
+                    Branch::Left => PROOF_DIRECTION_LEFT.to_string(),
+                    _ => PROOF_DIRECTION_RIGHT.to_string(),
+                },
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct TransactionProofView {
+    pub tx_hash: String,
+    pub block_height: u64,
+    pub index: u64,
+    /// audit path from the tx hash to the header's transactions_hash
+    pub transaction_proof: Vec<MerkleProofView>,
+    /// hex protobuf `TransactionResult` as hashed into receips_hash, without the block hash
+    pub receipt: String,
+    pub receipt_proof: Vec<MerkleProofView>,
+    /// hex protobuf `LedgerHeader` of the block
+    pub header: String,
+    /// hex protobuf `BftProof` committing the block
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proof: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct StorageProofView {
+    /// hex contract storage key
+    pub key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// hex protobuf `KeyPair` kept in the metadata trie, absent for an unset key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entry: Option<String>,
+    /// hex trie nodes proving `entry` under the account's metadata_hash
+    pub proof: Vec<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct AccountProofView {
+    pub address: String,
+    pub block_height: u64,
+    /// hex protobuf `Account`, absent when the address has no state
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<String>,
+    /// hex trie nodes proving `account` under the header's state_hash
+    pub account_proof: Vec<String>,
+    pub storage_proof: Vec<StorageProofView>,
+    /// hex protobuf `LedgerHeader` of the block
+    pub header: String,
+}
@@ -1,5 +1,6 @@
 use crate::errors::JsonRpcError;
 use anyhow::Error;
+use ledger_store::log_bloom_contains;
 use msp::signing::check_address;
 use protos::{
     common::{ContractEvent, Signature},
@@ -29,6 +30,62 @@ impl From<&ContractEvent> for ContractEventView {
     }
 }
 
+/// An event with the position it was emitted at.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct LogView {
+    pub block_height: u64,
+    pub block_hash: String,
+    pub tx_hash: String,
+    pub tx_index: i32,
+    /// position of the event within the transaction
+    pub log_index: usize,
+    pub address: String,
+    pub topic: Vec<String>,
+    pub data: Vec<String>,
+}
+
+/// Events from any of the addresses carrying all of the topics.
+#[derive(Clone, Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct LogFilter {
+    pub from_block: Option<u64>,
+    pub to_block: Option<u64>,
+    pub address: Option<Vec<String>>,
+    pub topics: Option<Vec<String>>,
+}
+
+impl LogFilter {
+    pub fn matches(&self, event: &ContractEvent) -> bool {
+        if let Some(address) = &self.address {
+            if !address.is_empty() && !address.iter().any(|a| a == event.get_address()) {
+                return false;
+            }
+        }
+        match &self.topics {
+            Some(topics) => topics.iter().all(|t| event.get_topic().contains(t)),
+            None => true,
+        }
+    }
+
+    /// Whether the bloom of a block may hold a matching event.
+    pub fn matches_bloom(&self, bloom: &[u8]) -> bool {
+        if let Some(address) = &self.address {
+            if !address.is_empty()
+                && !address
+                    .iter()
+                    .any(|a| log_bloom_contains(bloom, a.as_bytes()))
+            {
+                return false;
+            }
+        }
+        match &self.topics {
+            Some(topics) => topics
+                .iter()
+                .all(|t| log_bloom_contains(bloom, t.as_bytes())),
+            None => true,
+        }
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
 pub struct TransactionResultView {
     pub hash: String,
@@ -194,12 +251,13 @@ impl TransactionRaw {
             )));
         }
 
+        // system contracts and wasm contract invocations take a json payload
         let mut is_sys_address = false;
         protocol_tx.set_source(self.source.clone());
         protocol_tx.set_nonce(self.nonce);
         if let Some(to) = &self.to {
             protocol_tx.set_to(to.clone());
-            if is_system_contract(to) {
+            if is_system_contract(to) || self.tx_type == 2 {
                 is_sys_address = true;
             }
         }
@@ -1,3 +1,4 @@
+use futures::StreamExt;
 use parking_lot::RwLock;
 use protos::{
     common::TransactionResult,
@@ -11,6 +12,8 @@ use std::{
     sync::Arc,
 };
 use tokio::sync::mpsc;
+use tx_pool::types::PendingTxReceiver;
+use types::SignedTransaction;
 use utils::{general::hash_crypto_byte, proto2json::proto_to_json};
 use warp::{ws::Message, Filter, Rejection};
 
@@ -19,6 +22,7 @@ use crate::{errors::JsonRpcError, response::JsonRpcResponse};
 pub const TOPIC_TRANSACTIONS: &str = "transactions";
 pub const TOPIC_HEADERS: &str = "headers";
 pub const TOPIC_LOGS: &str = "logs";
+pub const TOPIC_PENDING_TRANSACTIONS: &str = "newPendingTransactions";
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SubscribeLogs {
@@ -42,11 +46,46 @@ impl SubscribeLogs {
     }
 }
 
+/// Filter of the pending transactions topic, an empty filter matches every transaction.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct SubscribePendingTransactions {
+    /// push the full transaction instead of its hash
+    #[serde(default)]
+    pub full: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<Vec<String>>,
+}
+
+impl SubscribePendingTransactions {
+    pub fn into_bytes(&self) -> Vec<u8> {
+        let mut value = TOPIC_PENDING_TRANSACTIONS.to_string();
+        value += &serde_json::to_string(self).unwrap_or_default();
+        value.as_bytes().to_vec()
+    }
+
+    pub fn matches(&self, tx: &SignedTransaction) -> bool {
+        if let Some(from) = &self.from {
+            if !from.iter().any(|a| a == tx.sender()) {
+                return false;
+            }
+        }
+        if let Some(to) = &self.to {
+            if !to.iter().any(|a| a == tx.to()) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum SubscribeTopic {
     Transactions(String),
     Headers(String),
     Logs((String, SubscribeLogs)),
+    PendingTransactions(SubscribePendingTransactions),
 }
 
 impl SubscribeTopic {
@@ -55,6 +94,9 @@ impl SubscribeTopic {
             SubscribeTopic::Transactions(v) => hex::encode(hash_crypto_byte(v.as_bytes())),
             SubscribeTopic::Headers(v) => hex::encode(hash_crypto_byte(v.as_bytes())),
             SubscribeTopic::Logs(v) => hex::encode(hash_crypto_byte(&v.1.into_bytes())),
+            SubscribeTopic::PendingTransactions(v) => {
+                hex::encode(hash_crypto_byte(&v.into_bytes()))
+            }
         }
     }
 }
@@ -63,19 +105,21 @@ pub enum PublishEvent {
     Transactions(protos::ledger::TransactionSignStore),
     Headers(protos::ledger::LedgerHeader),
     Logs(protos::common::ContractEvent),
+    PendingTransaction(SignedTransaction),
 }
 
 impl PublishEvent {
-    pub fn contain(&self, topic: &SubscribeTopic) -> Option<&dyn protobuf::Message> {
+    /// The json pushed to subscribers of `topic`, None when the event doesn't match it.
+    pub fn contain(&self, topic: &SubscribeTopic) -> Option<serde_json::Value> {
         match self {
             PublishEvent::Transactions(value) => match topic {
-                SubscribeTopic::Transactions(_) => return Some(value),
+                SubscribeTopic::Transactions(_) => return Some(proto_to_json(value)),
                 _ => {
                     return None;
                 }
             },
             PublishEvent::Headers(value) => match topic {
-                SubscribeTopic::Headers(_) => return Some(value),
+                SubscribeTopic::Headers(_) => return Some(proto_to_json(value)),
                 _ => {
                     return None;
                 }
@@ -84,12 +128,12 @@ impl PublishEvent {
                 SubscribeTopic::Logs((_, log)) => {
                     if let Some(addres) = &log.address {
                         if addres.contains(&value.get_address().to_string()) {
-                            return Some(value);
+                            return Some(proto_to_json(value));
                         }
                     }
                     for t in &log.topics {
                         if value.get_topic().contains(t) {
-                            return Some(value);
+                            return Some(proto_to_json(value));
                         }
                     }
                     return None;
@@ -98,6 +142,22 @@ impl PublishEvent {
                     return None;
                 }
             },
+            PublishEvent::PendingTransaction(value) => match topic {
+                SubscribeTopic::PendingTransactions(filter) => {
+                    if !filter.matches(value) {
+                        return None;
+                    }
+                    if filter.full {
+                        let mut tx_sign = value.convert_into();
+                        tx_sign.mut_transaction().clear_reserves();
+                        return Some(proto_to_json(&tx_sign));
+                    }
+                    return Some(serde_json::Value::String(value.hash_hex()));
+                }
+                _ => {
+                    return None;
+                }
+            },
         }
     }
 }
@@ -108,6 +168,9 @@ pub struct WsConnection {
     // subscribe id : subscribe content
     pub topics: HashMap<String, SubscribeTopic>,
     pub sender: Option<mpsc::UnboundedSender<std::result::Result<Message, warp::Error>>>,
+    pub api_key: String,
+    /// 0 is unlimited
+    pub max_subscriptions: usize,
 }
 
 pub struct InnerConnections {
@@ -157,6 +220,14 @@ impl WsConnections {
         }
     }
 
+    pub fn api_key(&self, id: &str) -> Option<String> {
+        self.conns
+            .read()
+            .connections
+            .get(id)
+            .map(|conn| conn.api_key.clone())
+    }
+
     pub fn del(&self, id: &str) {
         self.conns.write().connections.remove(id);
     }
@@ -169,6 +240,12 @@ impl WsConnections {
         if let Some(conn) = self.conns.write().connections.get_mut(id) {
             let topic = Self::parse_params(params)?;
             let subscribe_id = topic.subscribe_id();
+            if conn.max_subscriptions > 0
+                && !conn.topics.contains_key(&subscribe_id)
+                && conn.topics.len() >= conn.max_subscriptions
+            {
+                return Err(JsonRpcError::too_many_subscriptions(conn.max_subscriptions));
+            }
             conn.topics.insert(topic.subscribe_id(), topic);
             return Ok(subscribe_id);
         }
@@ -197,9 +274,8 @@ impl WsConnections {
             for (_, topic) in conn.topics.iter() {
                 if let Some(value) = event.contain(topic) {
                     if let Some(sender) = &conn.sender {
-                        let john = proto_to_json(value);
                         let mut response = JsonRpcResponse::new(self.chain_id.clone());
-                        response.result = Some(john);
+                        response.result = Some(value);
                         match serde_json::to_string(&response) {
                             Ok(js) => {
                                 if let Err(e) = sender.send(Ok(Message::text(js))) {
@@ -247,6 +323,23 @@ impl WsConnections {
                     };
 
                     return Ok(SubscribeTopic::Logs((TOPIC_LOGS.to_string(), logs)));
+                } else if sub_type == TOPIC_PENDING_TRANSACTIONS {
+                    let filter = match params.len() {
+                        1 => SubscribePendingTransactions::default(),
+                        2 => match serde_json::from_value(params[1].clone()) {
+                            Ok(filter) => filter,
+                            Err(e) => {
+                                return Err(JsonRpcError::invalid_parameter(
+                                    "ws subscribe",
+                                    "params pending transactions filter format",
+                                ));
+                            }
+                        },
+                        _ => {
+                            return Err(JsonRpcError::invalid_params_size(format!("ws subscribe")))
+                        }
+                    };
+                    return Ok(SubscribeTopic::PendingTransactions(filter));
                 } else {
                     return Err(JsonRpcError::invalid_parameter(
                         "ws subscribe",
@@ -295,3 +388,143 @@ pub async fn process_publish_event(
         }
     }
 }
+
+pub async fn process_pending_transactions(
+    connections: WsConnections,
+    mut pending_tx_receiver: PendingTxReceiver,
+) {
+    while let Some(tx) = pending_tx_receiver.next().await {
+        connections.publish_event(PublishEvent::PendingTransaction(tx));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protos::ledger::{Transaction, TransactionSign};
+    use serde_json::json;
+
+    const ALICE: &str = "did:gdt:0xf6b02a2d47b84e845b7e3623355f041bcb36daf1";
+    const BOB: &str = "did:gdt:0x160b54be617f4bff07bd6c994fc6dd17a69d5e4e";
+
+    type Receiver = mpsc::UnboundedReceiver<std::result::Result<Message, warp::Error>>;
+
+    fn connections(max_subscriptions: usize) -> (WsConnections, Receiver) {
+        let connections = WsConnections::new("2024".to_string());
+        let (sender, receiver) = mpsc::unbounded_channel();
+        connections.set(
+            "conn".to_string(),
+            WsConnection {
+                id: "conn".to_string(),
+                topics: HashMap::new(),
+                sender: Some(sender),
+                api_key: String::new(),
+                max_subscriptions,
+            },
+        );
+        (connections, receiver)
+    }
+
+    fn transaction(source: &str, to: &str) -> SignedTransaction {
+        let mut tx = Transaction::default();
+        tx.set_source(source.to_string());
+        tx.set_to(to.to_string());
+        tx.set_value("0".to_string());
+        tx.set_gas_price("1".to_string());
+        let mut tx_sign = TransactionSign::default();
+        tx_sign.set_transaction(tx);
+        SignedTransaction::try_from(tx_sign).unwrap()
+    }
+
+    fn pushed(receiver: &mut Receiver) -> Vec<serde_json::Value> {
+        let mut results = Vec::new();
+        while let Ok(message) = receiver.try_recv() {
+            let message = message.unwrap();
+            let response: serde_json::Value =
+                serde_json::from_str(message.to_str().unwrap()).unwrap();
+            results.push(response["result"].clone());
+        }
+        results
+    }
+
+    #[test]
+    fn pending_transactions_filter_test() {
+        let (connections, mut receiver) = connections(0);
+        let params = vec![
+            json!(TOPIC_PENDING_TRANSACTIONS),
+            json!({ "from": [ALICE] }),
+        ];
+        let subscribe_id = connections.add_topic("conn", &params).unwrap();
+
+        let from_alice = transaction(ALICE, BOB);
+        connections.publish_event(PublishEvent::PendingTransaction(from_alice.clone()));
+        connections.publish_event(PublishEvent::PendingTransaction(transaction(BOB, ALICE)));
+        assert_eq!(pushed(&mut receiver), vec![json!(from_alice.hash_hex())]);
+
+        // nothing is pushed once unsubscribed
+        connections.del_topic("conn", &subscribe_id);
+        connections.publish_event(PublishEvent::PendingTransaction(from_alice));
+        assert!(pushed(&mut receiver).is_empty());
+    }
+
+    #[test]
+    fn pending_transactions_full_test() {
+        let (connections, mut receiver) = connections(0);
+        let params = vec![
+            json!(TOPIC_PENDING_TRANSACTIONS),
+            json!({ "full": true, "to": [BOB] }),
+        ];
+        connections.add_topic("conn", &params).unwrap();
+
+        connections.publish_event(PublishEvent::PendingTransaction(transaction(ALICE, BOB)));
+        connections.publish_event(PublishEvent::PendingTransaction(transaction(BOB, ALICE)));
+        let results = pushed(&mut receiver);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["transaction"]["source"], json!(ALICE));
+        assert_eq!(results[0]["transaction"]["to"], json!(BOB));
+    }
+
+    #[test]
+    fn subscribe_params_test() {
+        let (connections, _receiver) = connections(0);
+        let all = connections
+            .add_topic("conn", &vec![json!(TOPIC_PENDING_TRANSACTIONS)])
+            .unwrap();
+        // the same filter is the same subscription
+        assert_eq!(
+            connections
+                .add_topic("conn", &vec![json!(TOPIC_PENDING_TRANSACTIONS), json!({})])
+                .unwrap(),
+            all
+        );
+        assert!(connections
+            .add_topic("conn", &vec![json!(TOPIC_PENDING_TRANSACTIONS), json!(1)])
+            .is_err());
+        assert!(connections
+            .add_topic("conn", &vec![json!("unknown")])
+            .is_err());
+        assert!(connections
+            .add_topic("missing", &vec![json!(TOPIC_HEADERS)])
+            .is_err());
+    }
+
+    #[test]
+    fn max_subscriptions_test() {
+        let (connections, _receiver) = connections(1);
+        let headers = connections
+            .add_topic("conn", &vec![json!(TOPIC_HEADERS)])
+            .unwrap();
+        // subscribing again to the same topic takes no new slot
+        assert!(connections
+            .add_topic("conn", &vec![json!(TOPIC_HEADERS)])
+            .is_ok());
+        assert!(connections
+            .add_topic("conn", &vec![json!(TOPIC_TRANSACTIONS)])
+            .is_err());
+
+        connections.del_topic("conn", &headers);
+        assert!(connections
+            .add_topic("conn", &vec![json!(TOPIC_TRANSACTIONS)])
+            .is_ok());
+    }
+}
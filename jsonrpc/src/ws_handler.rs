@@ -1,4 +1,5 @@
 use crate::{
+    auth::ApiKeyStore,
     errors::JsonRpcError,
     request::JsonRpcRequest,
     response::JsonRpcResponse,
@@ -26,6 +27,8 @@ pub async fn ws_handler(
     websocket: WebSocket,
     remote: Option<SocketAddr>,
     api_key: String,
+    max_subscriptions: usize,
+    api_keys: ApiKeyStore,
     connections: WsConnections,
 ) {
     println!(
@@ -54,6 +57,8 @@ pub async fn ws_handler(
         id: addr_id.clone(),
         topics: HashMap::new(),
         sender: Some(client_sender),
+        api_key,
+        max_subscriptions,
     };
     if !connections.set(addr_id.clone(), c) {
         eprintln!("connection socket address duplicate");
@@ -73,26 +78,36 @@ pub async fn ws_handler(
                 break;
             }
         };
-        handle_websocket_message(&addr_id, msg, connections.clone());
+        handle_websocket_message(&addr_id, msg, &api_keys, connections.clone());
     }
 
     connections.del(&addr_id);
     println!("connection {} disconnected", addr_id);
 }
 
-fn handle_websocket_message(conn_id: &str, msg: Message, connections: WsConnections) {
+fn handle_websocket_message(
+    conn_id: &str,
+    msg: Message,
+    api_keys: &ApiKeyStore,
+    connections: WsConnections,
+) {
     println!(
         "handle_websocket_message received message from {}: {:?}",
         conn_id, msg
     );
     if msg.is_text() {
-        handle_text_message(conn_id, msg, connections);
+        handle_text_message(conn_id, msg, api_keys, connections);
     } else {
         handle_bytes_message(conn_id, msg, connections);
     }
 }
 
-fn handle_text_message(conn_id: &str, msg: Message, connections: WsConnections) {
+fn handle_text_message(
+    conn_id: &str,
+    msg: Message,
+    api_keys: &ApiKeyStore,
+    connections: WsConnections,
+) {
     let mut response = JsonRpcResponse::new(connections.chain_id());
 
     let message = match msg.to_str() {
@@ -123,6 +138,13 @@ fn handle_text_message(conn_id: &str, msg: Message, connections: WsConnections)
     };
     response.id = Some(serde_json::Value::Number(request.id.into()));
 
+    let api_key = connections.api_key(conn_id);
+    if let Err(err) = api_keys.authorize(api_key.as_deref(), &request.method) {
+        response.error = Some(err);
+        send_text_response(conn_id, response, connections);
+        return;
+    }
+
     if request.method == METHOD_SUBSCRIBE {
         match connections.add_topic(conn_id, &request.params) {
             Ok(subscribe_id) => {
@@ -135,12 +157,13 @@ fn handle_text_message(conn_id: &str, msg: Message, connections: WsConnections)
                 return;
             }
         }
-    } else if request.method == METHOD_SUBSCRIBE {
+    } else if request.method == METHOD_UNSUBSCRIBE {
         for value in request.params.iter() {
             if let Some(subscribe_id) = value.as_str() {
                 connections.del_topic(conn_id, subscribe_id);
             }
         }
+        response.error = Some(JsonRpcError::no_error());
     } else {
         response.error = Some(JsonRpcError::method_not_found());
         send_text_response(conn_id, response, connections);
@@ -0,0 +1,6 @@
+use tiny_keccak::keccak256;
+
+/// Hashes the given bytes with Keccak-256, as ethereum does for transactions and addresses
+pub fn hash_keccak256(bytes: &[u8]) -> Vec<u8> {
+    keccak256(bytes).to_vec()
+}
@@ -1,6 +1,7 @@
 use crate::hash::sha256::{hash_sha256, verify_sha256};
 use crate::hash::sm3::{hash_sm3, verify_sm3};
 
+pub mod keccak;
 pub mod sha256;
 pub mod sm3;
 #[derive(Clone, Copy)]
@@ -93,6 +93,25 @@ impl Secp256k1Context {
     }
 }
 
+impl Secp256k1Context {
+    /// Recovers the uncompressed public key that produced the compact `signature`
+    /// over the 32 bytes `hash`, the ecrecover of ethereum.
+    pub fn recover(
+        &self,
+        hash: &[u8],
+        signature: &[u8],
+        recovery_id: u8,
+    ) -> Result<Secp256k1PublicKey, Error> {
+        let recovery_id = secp256k1::RecoveryId::from_i32(recovery_id as i32)?;
+        let signature =
+            secp256k1::RecoverableSignature::from_compact(&self.context, signature, recovery_id)?;
+        let public_key = self
+            .context
+            .recover(&secp256k1::Message::from_slice(hash)?, &signature)?;
+        Secp256k1PublicKey::from_bytes(&public_key.serialize_vec(&self.context, false))
+    }
+}
+
 impl Default for Secp256k1Context {
     fn default() -> Self {
         Self::new()
@@ -1923,6 +1923,492 @@ impl ::protobuf::reflect::ProtobufValue for Signature {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct SignerRequest {
+    // message fields
+    pub key_id: ::std::string::String,
+    pub encryption_type: ::std::string::String,
+    pub data: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SignerRequest {
+    fn default() -> &'a SignerRequest {
+        <SignerRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SignerRequest {
+    pub fn new() -> SignerRequest {
+        ::std::default::Default::default()
+    }
+
+    // string key_id = 1;
+
+
+    pub fn get_key_id(&self) -> &str {
+        &self.key_id
+    }
+    pub fn clear_key_id(&mut self) {
+        self.key_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_id(&mut self, v: ::std::string::String) {
+        self.key_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key_id(&mut self) -> &mut ::std::string::String {
+        &mut self.key_id
+    }
+
+    // Take field
+    pub fn take_key_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key_id, ::std::string::String::new())
+    }
+
+    // string encryption_type = 2;
+
+
+    pub fn get_encryption_type(&self) -> &str {
+        &self.encryption_type
+    }
+    pub fn clear_encryption_type(&mut self) {
+        self.encryption_type.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_encryption_type(&mut self, v: ::std::string::String) {
+        self.encryption_type = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_encryption_type(&mut self) -> &mut ::std::string::String {
+        &mut self.encryption_type
+    }
+
+    // Take field
+    pub fn take_encryption_type(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.encryption_type, ::std::string::String::new())
+    }
+
+    // bytes data = 3;
+
+
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
+    }
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.data
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for SignerRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.encryption_type)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key_id);
+        }
+        if !self.encryption_type.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.encryption_type);
+        }
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.data);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.key_id.is_empty() {
+            os.write_string(1, &self.key_id)?;
+        }
+        if !self.encryption_type.is_empty() {
+            os.write_string(2, &self.encryption_type)?;
+        }
+        if !self.data.is_empty() {
+            os.write_bytes(3, &self.data)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SignerRequest {
+        SignerRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "key_id",
+                |m: &SignerRequest| { &m.key_id },
+                |m: &mut SignerRequest| { &mut m.key_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "encryption_type",
+                |m: &SignerRequest| { &m.encryption_type },
+                |m: &mut SignerRequest| { &mut m.encryption_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "data",
+                |m: &SignerRequest| { &m.data },
+                |m: &mut SignerRequest| { &mut m.data },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SignerRequest>(
+                "SignerRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SignerRequest {
+        static instance: ::protobuf::rt::LazyV2<SignerRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SignerRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SignerRequest {
+    fn clear(&mut self) {
+        self.key_id.clear();
+        self.encryption_type.clear();
+        self.data.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SignerRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SignerRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SignerResponse {
+    // message fields
+    pub public_key: ::std::vec::Vec<u8>,
+    pub sign_data: ::std::vec::Vec<u8>,
+    pub error: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SignerResponse {
+    fn default() -> &'a SignerResponse {
+        <SignerResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SignerResponse {
+    pub fn new() -> SignerResponse {
+        ::std::default::Default::default()
+    }
+
+    // bytes public_key = 1;
+
+
+    pub fn get_public_key(&self) -> &[u8] {
+        &self.public_key
+    }
+    pub fn clear_public_key(&mut self) {
+        self.public_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_public_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.public_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_public_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.public_key
+    }
+
+    // Take field
+    pub fn take_public_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.public_key, ::std::vec::Vec::new())
+    }
+
+    // bytes sign_data = 2;
+
+
+    pub fn get_sign_data(&self) -> &[u8] {
+        &self.sign_data
+    }
+    pub fn clear_sign_data(&mut self) {
+        self.sign_data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sign_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.sign_data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sign_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.sign_data
+    }
+
+    // Take field
+    pub fn take_sign_data(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.sign_data, ::std::vec::Vec::new())
+    }
+
+    // string error = 3;
+
+
+    pub fn get_error(&self) -> &str {
+        &self.error
+    }
+    pub fn clear_error(&mut self) {
+        self.error.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.error = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        &mut self.error
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for SignerResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.public_key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.sign_data)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.public_key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.public_key);
+        }
+        if !self.sign_data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.sign_data);
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.error);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.public_key.is_empty() {
+            os.write_bytes(1, &self.public_key)?;
+        }
+        if !self.sign_data.is_empty() {
+            os.write_bytes(2, &self.sign_data)?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(3, &self.error)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SignerResponse {
+        SignerResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "public_key",
+                |m: &SignerResponse| { &m.public_key },
+                |m: &mut SignerResponse| { &mut m.public_key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "sign_data",
+                |m: &SignerResponse| { &m.sign_data },
+                |m: &mut SignerResponse| { &mut m.sign_data },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "error",
+                |m: &SignerResponse| { &m.error },
+                |m: &mut SignerResponse| { &mut m.error },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SignerResponse>(
+                "SignerResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SignerResponse {
+        static instance: ::protobuf::rt::LazyV2<SignerResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SignerResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for SignerResponse {
+    fn clear(&mut self) {
+        self.public_key.clear();
+        self.sign_data.clear();
+        self.error.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SignerResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SignerResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct Validator {
     // message fields
@@ -4169,6 +4655,7 @@ pub enum ProtocolsMessageType {
     LIGHT = 8,
     TRANSACTION_SYNC = 9,
     SYNCBLOCK = 10,
+    SYNCSNAPSHOT = 11,
 }
 
 impl ::protobuf::ProtobufEnum for ProtocolsMessageType {
@@ -4189,6 +4676,7 @@ impl ::protobuf::ProtobufEnum for ProtocolsMessageType {
             8 => ::std::option::Option::Some(ProtocolsMessageType::LIGHT),
             9 => ::std::option::Option::Some(ProtocolsMessageType::TRANSACTION_SYNC),
             10 => ::std::option::Option::Some(ProtocolsMessageType::SYNCBLOCK),
+            11 => ::std::option::Option::Some(ProtocolsMessageType::SYNCSNAPSHOT),
             _ => ::std::option::Option::None
         }
     }
@@ -4206,6 +4694,7 @@ impl ::protobuf::ProtobufEnum for ProtocolsMessageType {
             ProtocolsMessageType::LIGHT,
             ProtocolsMessageType::TRANSACTION_SYNC,
             ProtocolsMessageType::SYNCBLOCK,
+            ProtocolsMessageType::SYNCSNAPSHOT,
         ];
         values
     }
@@ -4257,43 +4746,49 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     lue\x18\x02\x20\x01(\x0cR\x05valueB\0:\0\"x\n\tSignature\x12\x1f\n\npubl\
     ic_key\x18\x01\x20\x01(\x0cR\tpublicKeyB\0\x12\x1d\n\tsign_data\x18\x02\
     \x20\x01(\x0cR\x08signDataB\0\x12)\n\x0fencryption_type\x18\x03\x20\x01(\
-    \tR\x0eencryptionTypeB\0:\0\"P\n\tValidator\x12\x1a\n\x07address\x18\x01\
-    \x20\x01(\tR\x07addressB\0\x12%\n\rpledge_amount\x18\x02\x20\x01(\x03R\
-    \x0cpledgeAmountB\0:\0\"V\n\x0cValidatorSet\x12\x16\n\x05epoch\x18\x01\
-    \x20\x01(\x04R\x05epochB\0\x12,\n\nvalidators\x18\x02\x20\x03(\x0b2\n.Va\
-    lidatorR\nvalidatorsB\0:\0\"%\n\tEntryList\x12\x16\n\x05entry\x18\x01\
-    \x20\x03(\x0cR\x05entryB\0:\0\"\xf1\x02\n\x0cHelloMessage\x12\x1f\n\nnet\
-    work_id\x18\x01\x20\x01(\x04R\tnetworkIdB\0\x12'\n\x0eledger_version\x18\
-    \x02\x20\x01(\x04R\rledgerVersionB\0\x12)\n\x0fnetwork_version\x18\x03\
-    \x20\x01(\x04R\x0enetworkVersionB\0\x12%\n\rchain_version\x18\x04\x20\
-    \x01(\tR\x0cchainVersionB\0\x12'\n\x0elistening_port\x18\x05\x20\x01(\
-    \x03R\rlisteningPortB\0\x12#\n\x0cnode_address\x18\x06\x20\x01(\tR\x0bno\
-    deAddressB\0\x12\x1d\n\tnode_rand\x18\x07\x20\x01(\tR\x08nodeRandB\0\x12\
-    \x1b\n\x08chain_id\x18\x08\x20\x01(\tR\x07chainIdB\0\x12\x1d\n\tchain_hu\
-    b\x18\t\x20\x01(\tR\x08chainHubB\0\x12\x1a\n\x07license\x18\n\x20\x01(\t\
-    R\x07licenseB\0:\0\"R\n\x14HelloResponseMessage\x12\x1b\n\x08err_code\
-    \x18\x01\x20\x01(\x05R\x07errCodeB\0\x12\x1b\n\x08err_desc\x18\x02\x20\
-    \x01(\tR\x07errDescB\0:\0\"\x85\x02\n\x11TransactionResult\x12\x1b\n\x08\
-    err_code\x18\x01\x20\x01(\x05R\x07errCodeB\0\x12\x1a\n\x07message\x18\
-    \x02\x20\x01(\tR\x07messageB\0\x12:\n\x0fcontract_result\x18\x03\x20\x01\
-    (\x0b2\x0f.ContractResultR\x0econtractResultB\0\x12\x1b\n\x08gas_used\
-    \x18\x04\x20\x01(\x04R\x07gasUsedB\0\x12#\n\x0cblock_height\x18\x05\x20\
-    \x01(\x04R\x0bblockHeightB\0\x12\x1f\n\nblock_hash\x18\x06\x20\x01(\x0cR\
-    \tblockHashB\0\x12\x16\n\x05index\x18\x07\x20\x01(\rR\x05indexB\0:\0\"\
-    \x9e\x01\n\x0eContractResult\x12\x1b\n\x08err_code\x18\x01\x20\x01(\x05R\
-    \x07errCodeB\0\x12\x18\n\x06result\x18\x02\x20\x01(\x0cR\x06resultB\0\
-    \x12\x1a\n\x07message\x18\x03\x20\x01(\tR\x07messageB\0\x127\n\x0econtra\
-    ct_event\x18\x04\x20\x03(\x0b2\x0e.ContractEventR\rcontractEventB\0:\0\"\
-    [\n\rContractEvent\x12\x1a\n\x07address\x18\x01\x20\x01(\tR\x07addressB\
-    \0\x12\x16\n\x05topic\x18\x02\x20\x03(\tR\x05topicB\0\x12\x14\n\x04data\
-    \x18\x03\x20\x03(\tR\x04dataB\0:\0*H\n\x1aProtocolsActionMessageType\x12\
-    \r\n\tBROADCAST\x10\0\x12\x0b\n\x07REQUEST\x10\x01\x12\x0c\n\x08RESPONSE\
-    \x10\x02\x1a\0*\xbc\x01\n\x14ProtocolsMessageType\x12\x08\n\x04NONE\x10\
-    \0\x12\t\n\x05HELLO\x10\x01\x12\x08\n\x04PING\x10\x02\x12\t\n\x05PEERS\
-    \x10\x03\x12\x0f\n\x0bTRANSACTION\x10\x04\x12\r\n\tSYNCCHAIN\x10\x05\x12\
-    \r\n\tCONSENSUS\x10\x06\x12\x19\n\x15LEDGER_UPGRADE_NOTIFY\x10\x07\x12\t\
-    \n\x05LIGHT\x10\x08\x12\x14\n\x10TRANSACTION_SYNC\x10\t\x12\r\n\tSYNCBLO\
-    CK\x10\n\x1a\0B\0b\x06proto3\
+    \tR\x0eencryptionTypeB\0:\0\"k\n\rSignerRequest\x12\x17\n\x06key_id\x18\
+    \x01\x20\x01(\tR\x05keyIdB\0\x12)\n\x0fencryption_type\x18\x02\x20\x01(\
+    \tR\x0eencryptionTypeB\0\x12\x14\n\x04data\x18\x03\x20\x01(\x0cR\x04data\
+    B\0:\0\"j\n\x0eSignerResponse\x12\x1f\n\npublic_key\x18\x01\x20\x01(\x0c\
+    R\tpublicKeyB\0\x12\x1d\n\tsign_data\x18\x02\x20\x01(\x0cR\x08signDataB\
+    \0\x12\x16\n\x05error\x18\x03\x20\x01(\tR\x05errorB\0:\0\"P\n\tValidator\
+    \x12\x1a\n\x07address\x18\x01\x20\x01(\tR\x07addressB\0\x12%\n\rpledge_a\
+    mount\x18\x02\x20\x01(\x03R\x0cpledgeAmountB\0:\0\"V\n\x0cValidatorSet\
+    \x12\x16\n\x05epoch\x18\x01\x20\x01(\x04R\x05epochB\0\x12,\n\nvalidators\
+    \x18\x02\x20\x03(\x0b2\n.ValidatorR\nvalidatorsB\0:\0\"%\n\tEntryList\
+    \x12\x16\n\x05entry\x18\x01\x20\x03(\x0cR\x05entryB\0:\0\"\xf1\x02\n\x0c\
+    HelloMessage\x12\x1f\n\nnetwork_id\x18\x01\x20\x01(\x04R\tnetworkIdB\0\
+    \x12'\n\x0eledger_version\x18\x02\x20\x01(\x04R\rledgerVersionB\0\x12)\n\
+    \x0fnetwork_version\x18\x03\x20\x01(\x04R\x0enetworkVersionB\0\x12%\n\rc\
+    hain_version\x18\x04\x20\x01(\tR\x0cchainVersionB\0\x12'\n\x0elistening_\
+    port\x18\x05\x20\x01(\x03R\rlisteningPortB\0\x12#\n\x0cnode_address\x18\
+    \x06\x20\x01(\tR\x0bnodeAddressB\0\x12\x1d\n\tnode_rand\x18\x07\x20\x01(\
+    \tR\x08nodeRandB\0\x12\x1b\n\x08chain_id\x18\x08\x20\x01(\tR\x07chainIdB\
+    \0\x12\x1d\n\tchain_hub\x18\t\x20\x01(\tR\x08chainHubB\0\x12\x1a\n\x07li\
+    cense\x18\n\x20\x01(\tR\x07licenseB\0:\0\"R\n\x14HelloResponseMessage\
+    \x12\x1b\n\x08err_code\x18\x01\x20\x01(\x05R\x07errCodeB\0\x12\x1b\n\x08\
+    err_desc\x18\x02\x20\x01(\tR\x07errDescB\0:\0\"\x85\x02\n\x11Transaction\
+    Result\x12\x1b\n\x08err_code\x18\x01\x20\x01(\x05R\x07errCodeB\0\x12\x1a\
+    \n\x07message\x18\x02\x20\x01(\tR\x07messageB\0\x12:\n\x0fcontract_resul\
+    t\x18\x03\x20\x01(\x0b2\x0f.ContractResultR\x0econtractResultB\0\x12\x1b\
+    \n\x08gas_used\x18\x04\x20\x01(\x04R\x07gasUsedB\0\x12#\n\x0cblock_heigh\
+    t\x18\x05\x20\x01(\x04R\x0bblockHeightB\0\x12\x1f\n\nblock_hash\x18\x06\
+    \x20\x01(\x0cR\tblockHashB\0\x12\x16\n\x05index\x18\x07\x20\x01(\rR\x05i\
+    ndexB\0:\0\"\x9e\x01\n\x0eContractResult\x12\x1b\n\x08err_code\x18\x01\
+    \x20\x01(\x05R\x07errCodeB\0\x12\x18\n\x06result\x18\x02\x20\x01(\x0cR\
+    \x06resultB\0\x12\x1a\n\x07message\x18\x03\x20\x01(\tR\x07messageB\0\x12\
+    7\n\x0econtract_event\x18\x04\x20\x03(\x0b2\x0e.ContractEventR\rcontract\
+    EventB\0:\0\"[\n\rContractEvent\x12\x1a\n\x07address\x18\x01\x20\x01(\tR\
+    \x07addressB\0\x12\x16\n\x05topic\x18\x02\x20\x03(\tR\x05topicB\0\x12\
+    \x14\n\x04data\x18\x03\x20\x03(\tR\x04dataB\0:\0*H\n\x1aProtocolsActionM\
+    essageType\x12\r\n\tBROADCAST\x10\0\x12\x0b\n\x07REQUEST\x10\x01\x12\x0c\
+    \n\x08RESPONSE\x10\x02\x1a\0*\xce\x01\n\x14ProtocolsMessageType\x12\x08\
+    \n\x04NONE\x10\0\x12\t\n\x05HELLO\x10\x01\x12\x08\n\x04PING\x10\x02\x12\
+    \t\n\x05PEERS\x10\x03\x12\x0f\n\x0bTRANSACTION\x10\x04\x12\r\n\tSYNCCHAI\
+    N\x10\x05\x12\r\n\tCONSENSUS\x10\x06\x12\x19\n\x15LEDGER_UPGRADE_NOTIFY\
+    \x10\x07\x12\t\n\x05LIGHT\x10\x08\x12\x14\n\x10TRANSACTION_SYNC\x10\t\
+    \x12\r\n\tSYNCBLOCK\x10\n\x12\x10\n\x0cSYNCSNAPSHOT\x10\x0b\x1a\0B\0b\
+    \x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
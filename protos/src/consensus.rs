@@ -4413,6 +4413,2452 @@ impl ::protobuf::reflect::ProtobufValue for NewViewRepondParam {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct HotStuffNode {
+    // message fields
+    pub view: i64,
+    pub height: u64,
+    pub parent_hash: ::std::vec::Vec<u8>,
+    pub justify: ::protobuf::SingularPtrField<HotStuffQc>,
+    pub timestamp: i64,
+    pub proposer: ::std::string::String,
+    pub version: u64,
+    pub tx_hashes: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a HotStuffNode {
+    fn default() -> &'a HotStuffNode {
+        <HotStuffNode as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HotStuffNode {
+    pub fn new() -> HotStuffNode {
+        ::std::default::Default::default()
+    }
+
+    // int64 view = 1;
+
+
+    pub fn get_view(&self) -> i64 {
+        self.view
+    }
+    pub fn clear_view(&mut self) {
+        self.view = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view(&mut self, v: i64) {
+        self.view = v;
+    }
+
+    // uint64 height = 2;
+
+
+    pub fn get_height(&self) -> u64 {
+        self.height
+    }
+    pub fn clear_height(&mut self) {
+        self.height = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_height(&mut self, v: u64) {
+        self.height = v;
+    }
+
+    // bytes parent_hash = 3;
+
+
+    pub fn get_parent_hash(&self) -> &[u8] {
+        &self.parent_hash
+    }
+    pub fn clear_parent_hash(&mut self) {
+        self.parent_hash.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_parent_hash(&mut self, v: ::std::vec::Vec<u8>) {
+        self.parent_hash = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_parent_hash(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.parent_hash
+    }
+
+    // Take field
+    pub fn take_parent_hash(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.parent_hash, ::std::vec::Vec::new())
+    }
+
+    // .HotStuffQc justify = 4;
+
+
+    pub fn get_justify(&self) -> &HotStuffQc {
+        self.justify.as_ref().unwrap_or_else(|| <HotStuffQc as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_justify(&mut self) {
+        self.justify.clear();
+    }
+
+    pub fn has_justify(&self) -> bool {
+        self.justify.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_justify(&mut self, v: HotStuffQc) {
+        self.justify = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_justify(&mut self) -> &mut HotStuffQc {
+        if self.justify.is_none() {
+            self.justify.set_default();
+        }
+        self.justify.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_justify(&mut self) -> HotStuffQc {
+        self.justify.take().unwrap_or_else(|| HotStuffQc::new())
+    }
+
+    // int64 timestamp = 5;
+
+
+    pub fn get_timestamp(&self) -> i64 {
+        self.timestamp
+    }
+    pub fn clear_timestamp(&mut self) {
+        self.timestamp = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_timestamp(&mut self, v: i64) {
+        self.timestamp = v;
+    }
+
+    // string proposer = 6;
+
+
+    pub fn get_proposer(&self) -> &str {
+        &self.proposer
+    }
+    pub fn clear_proposer(&mut self) {
+        self.proposer.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_proposer(&mut self, v: ::std::string::String) {
+        self.proposer = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_proposer(&mut self) -> &mut ::std::string::String {
+        &mut self.proposer
+    }
+
+    // Take field
+    pub fn take_proposer(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.proposer, ::std::string::String::new())
+    }
+
+    // uint64 version = 7;
+
+
+    pub fn get_version(&self) -> u64 {
+        self.version
+    }
+    pub fn clear_version(&mut self) {
+        self.version = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: u64) {
+        self.version = v;
+    }
+
+    // repeated bytes tx_hashes = 8;
+
+
+    pub fn get_tx_hashes(&self) -> &[::std::vec::Vec<u8>] {
+        &self.tx_hashes
+    }
+    pub fn clear_tx_hashes(&mut self) {
+        self.tx_hashes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tx_hashes(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.tx_hashes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tx_hashes(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.tx_hashes
+    }
+
+    // Take field
+    pub fn take_tx_hashes(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.tx_hashes, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for HotStuffNode {
+    fn is_initialized(&self) -> bool {
+        for v in &self.justify {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.view = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.height = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.parent_hash)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.justify)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.timestamp = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.proposer)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.version = tmp;
+                },
+                8 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.tx_hashes)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.view != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.view, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.height != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.height, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.parent_hash.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.parent_hash);
+        }
+        if let Some(ref v) = self.justify.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.timestamp != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.timestamp, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.proposer.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.proposer);
+        }
+        if self.version != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.version, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.tx_hashes {
+            my_size += ::protobuf::rt::bytes_size(8, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.view != 0 {
+            os.write_int64(1, self.view)?;
+        }
+        if self.height != 0 {
+            os.write_uint64(2, self.height)?;
+        }
+        if !self.parent_hash.is_empty() {
+            os.write_bytes(3, &self.parent_hash)?;
+        }
+        if let Some(ref v) = self.justify.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.timestamp != 0 {
+            os.write_int64(5, self.timestamp)?;
+        }
+        if !self.proposer.is_empty() {
+            os.write_string(6, &self.proposer)?;
+        }
+        if self.version != 0 {
+            os.write_uint64(7, self.version)?;
+        }
+        for v in &self.tx_hashes {
+            os.write_bytes(8, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> HotStuffNode {
+        HotStuffNode::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "view",
+                |m: &HotStuffNode| { &m.view },
+                |m: &mut HotStuffNode| { &mut m.view },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "height",
+                |m: &HotStuffNode| { &m.height },
+                |m: &mut HotStuffNode| { &mut m.height },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "parent_hash",
+                |m: &HotStuffNode| { &m.parent_hash },
+                |m: &mut HotStuffNode| { &mut m.parent_hash },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<HotStuffQc>>(
+                "justify",
+                |m: &HotStuffNode| { &m.justify },
+                |m: &mut HotStuffNode| { &mut m.justify },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "timestamp",
+                |m: &HotStuffNode| { &m.timestamp },
+                |m: &mut HotStuffNode| { &mut m.timestamp },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "proposer",
+                |m: &HotStuffNode| { &m.proposer },
+                |m: &mut HotStuffNode| { &mut m.proposer },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "version",
+                |m: &HotStuffNode| { &m.version },
+                |m: &mut HotStuffNode| { &mut m.version },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "tx_hashes",
+                |m: &HotStuffNode| { &m.tx_hashes },
+                |m: &mut HotStuffNode| { &mut m.tx_hashes },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<HotStuffNode>(
+                "HotStuffNode",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static HotStuffNode {
+        static instance: ::protobuf::rt::LazyV2<HotStuffNode> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(HotStuffNode::new)
+    }
+}
+
+impl ::protobuf::Clear for HotStuffNode {
+    fn clear(&mut self) {
+        self.view = 0;
+        self.height = 0;
+        self.parent_hash.clear();
+        self.justify.clear();
+        self.timestamp = 0;
+        self.proposer.clear();
+        self.version = 0;
+        self.tx_hashes.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for HotStuffNode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HotStuffNode {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct HotStuffVote {
+    // message fields
+    pub view: i64,
+    pub height: u64,
+    pub node_hash: ::std::vec::Vec<u8>,
+    pub chain_id: ::std::string::String,
+    pub signature: ::protobuf::SingularPtrField<super::common::Signature>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a HotStuffVote {
+    fn default() -> &'a HotStuffVote {
+        <HotStuffVote as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HotStuffVote {
+    pub fn new() -> HotStuffVote {
+        ::std::default::Default::default()
+    }
+
+    // int64 view = 1;
+
+
+    pub fn get_view(&self) -> i64 {
+        self.view
+    }
+    pub fn clear_view(&mut self) {
+        self.view = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view(&mut self, v: i64) {
+        self.view = v;
+    }
+
+    // uint64 height = 2;
+
+
+    pub fn get_height(&self) -> u64 {
+        self.height
+    }
+    pub fn clear_height(&mut self) {
+        self.height = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_height(&mut self, v: u64) {
+        self.height = v;
+    }
+
+    // bytes node_hash = 3;
+
+
+    pub fn get_node_hash(&self) -> &[u8] {
+        &self.node_hash
+    }
+    pub fn clear_node_hash(&mut self) {
+        self.node_hash.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node_hash(&mut self, v: ::std::vec::Vec<u8>) {
+        self.node_hash = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_node_hash(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.node_hash
+    }
+
+    // Take field
+    pub fn take_node_hash(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.node_hash, ::std::vec::Vec::new())
+    }
+
+    // string chain_id = 4;
+
+
+    pub fn get_chain_id(&self) -> &str {
+        &self.chain_id
+    }
+    pub fn clear_chain_id(&mut self) {
+        self.chain_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chain_id(&mut self, v: ::std::string::String) {
+        self.chain_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_chain_id(&mut self) -> &mut ::std::string::String {
+        &mut self.chain_id
+    }
+
+    // Take field
+    pub fn take_chain_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.chain_id, ::std::string::String::new())
+    }
+
+    // .Signature signature = 5;
+
+
+    pub fn get_signature(&self) -> &super::common::Signature {
+        self.signature.as_ref().unwrap_or_else(|| <super::common::Signature as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_signature(&mut self) {
+        self.signature.clear();
+    }
+
+    pub fn has_signature(&self) -> bool {
+        self.signature.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signature(&mut self, v: super::common::Signature) {
+        self.signature = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_signature(&mut self) -> &mut super::common::Signature {
+        if self.signature.is_none() {
+            self.signature.set_default();
+        }
+        self.signature.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_signature(&mut self) -> super::common::Signature {
+        self.signature.take().unwrap_or_else(|| super::common::Signature::new())
+    }
+}
+
+impl ::protobuf::Message for HotStuffVote {
+    fn is_initialized(&self) -> bool {
+        for v in &self.signature {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.view = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.height = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.node_hash)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.chain_id)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.signature)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.view != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.view, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.height != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.height, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.node_hash.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.node_hash);
+        }
+        if !self.chain_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.chain_id);
+        }
+        if let Some(ref v) = self.signature.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.view != 0 {
+            os.write_int64(1, self.view)?;
+        }
+        if self.height != 0 {
+            os.write_uint64(2, self.height)?;
+        }
+        if !self.node_hash.is_empty() {
+            os.write_bytes(3, &self.node_hash)?;
+        }
+        if !self.chain_id.is_empty() {
+            os.write_string(4, &self.chain_id)?;
+        }
+        if let Some(ref v) = self.signature.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> HotStuffVote {
+        HotStuffVote::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "view",
+                |m: &HotStuffVote| { &m.view },
+                |m: &mut HotStuffVote| { &mut m.view },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "height",
+                |m: &HotStuffVote| { &m.height },
+                |m: &mut HotStuffVote| { &mut m.height },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "node_hash",
+                |m: &HotStuffVote| { &m.node_hash },
+                |m: &mut HotStuffVote| { &mut m.node_hash },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "chain_id",
+                |m: &HotStuffVote| { &m.chain_id },
+                |m: &mut HotStuffVote| { &mut m.chain_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::Signature>>(
+                "signature",
+                |m: &HotStuffVote| { &m.signature },
+                |m: &mut HotStuffVote| { &mut m.signature },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<HotStuffVote>(
+                "HotStuffVote",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static HotStuffVote {
+        static instance: ::protobuf::rt::LazyV2<HotStuffVote> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(HotStuffVote::new)
+    }
+}
+
+impl ::protobuf::Clear for HotStuffVote {
+    fn clear(&mut self) {
+        self.view = 0;
+        self.height = 0;
+        self.node_hash.clear();
+        self.chain_id.clear();
+        self.signature.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for HotStuffVote {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HotStuffVote {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct HotStuffQc {
+    // message fields
+    pub view: i64,
+    pub height: u64,
+    pub node_hash: ::std::vec::Vec<u8>,
+    pub signatures: ::protobuf::RepeatedField<super::common::Signature>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a HotStuffQc {
+    fn default() -> &'a HotStuffQc {
+        <HotStuffQc as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HotStuffQc {
+    pub fn new() -> HotStuffQc {
+        ::std::default::Default::default()
+    }
+
+    // int64 view = 1;
+
+
+    pub fn get_view(&self) -> i64 {
+        self.view
+    }
+    pub fn clear_view(&mut self) {
+        self.view = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view(&mut self, v: i64) {
+        self.view = v;
+    }
+
+    // uint64 height = 2;
+
+
+    pub fn get_height(&self) -> u64 {
+        self.height
+    }
+    pub fn clear_height(&mut self) {
+        self.height = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_height(&mut self, v: u64) {
+        self.height = v;
+    }
+
+    // bytes node_hash = 3;
+
+
+    pub fn get_node_hash(&self) -> &[u8] {
+        &self.node_hash
+    }
+    pub fn clear_node_hash(&mut self) {
+        self.node_hash.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node_hash(&mut self, v: ::std::vec::Vec<u8>) {
+        self.node_hash = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_node_hash(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.node_hash
+    }
+
+    // Take field
+    pub fn take_node_hash(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.node_hash, ::std::vec::Vec::new())
+    }
+
+    // repeated .Signature signatures = 4;
+
+
+    pub fn get_signatures(&self) -> &[super::common::Signature] {
+        &self.signatures
+    }
+    pub fn clear_signatures(&mut self) {
+        self.signatures.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signatures(&mut self, v: ::protobuf::RepeatedField<super::common::Signature>) {
+        self.signatures = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_signatures(&mut self) -> &mut ::protobuf::RepeatedField<super::common::Signature> {
+        &mut self.signatures
+    }
+
+    // Take field
+    pub fn take_signatures(&mut self) -> ::protobuf::RepeatedField<super::common::Signature> {
+        ::std::mem::replace(&mut self.signatures, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for HotStuffQc {
+    fn is_initialized(&self) -> bool {
+        for v in &self.signatures {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.view = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.height = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.node_hash)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.signatures)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.view != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.view, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.height != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.height, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.node_hash.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.node_hash);
+        }
+        for value in &self.signatures {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.view != 0 {
+            os.write_int64(1, self.view)?;
+        }
+        if self.height != 0 {
+            os.write_uint64(2, self.height)?;
+        }
+        if !self.node_hash.is_empty() {
+            os.write_bytes(3, &self.node_hash)?;
+        }
+        for v in &self.signatures {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> HotStuffQc {
+        HotStuffQc::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "view",
+                |m: &HotStuffQc| { &m.view },
+                |m: &mut HotStuffQc| { &mut m.view },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "height",
+                |m: &HotStuffQc| { &m.height },
+                |m: &mut HotStuffQc| { &mut m.height },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "node_hash",
+                |m: &HotStuffQc| { &m.node_hash },
+                |m: &mut HotStuffQc| { &mut m.node_hash },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::Signature>>(
+                "signatures",
+                |m: &HotStuffQc| { &m.signatures },
+                |m: &mut HotStuffQc| { &mut m.signatures },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<HotStuffQc>(
+                "HotStuffQc",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static HotStuffQc {
+        static instance: ::protobuf::rt::LazyV2<HotStuffQc> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(HotStuffQc::new)
+    }
+}
+
+impl ::protobuf::Clear for HotStuffQc {
+    fn clear(&mut self) {
+        self.view = 0;
+        self.height = 0;
+        self.node_hash.clear();
+        self.signatures.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for HotStuffQc {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HotStuffQc {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct HotStuffNewView {
+    // message fields
+    pub view: i64,
+    pub high_qc: ::protobuf::SingularPtrField<HotStuffQc>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a HotStuffNewView {
+    fn default() -> &'a HotStuffNewView {
+        <HotStuffNewView as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HotStuffNewView {
+    pub fn new() -> HotStuffNewView {
+        ::std::default::Default::default()
+    }
+
+    // int64 view = 1;
+
+
+    pub fn get_view(&self) -> i64 {
+        self.view
+    }
+    pub fn clear_view(&mut self) {
+        self.view = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view(&mut self, v: i64) {
+        self.view = v;
+    }
+
+    // .HotStuffQc high_qc = 2;
+
+
+    pub fn get_high_qc(&self) -> &HotStuffQc {
+        self.high_qc.as_ref().unwrap_or_else(|| <HotStuffQc as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_high_qc(&mut self) {
+        self.high_qc.clear();
+    }
+
+    pub fn has_high_qc(&self) -> bool {
+        self.high_qc.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_high_qc(&mut self, v: HotStuffQc) {
+        self.high_qc = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_high_qc(&mut self) -> &mut HotStuffQc {
+        if self.high_qc.is_none() {
+            self.high_qc.set_default();
+        }
+        self.high_qc.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_high_qc(&mut self) -> HotStuffQc {
+        self.high_qc.take().unwrap_or_else(|| HotStuffQc::new())
+    }
+}
+
+impl ::protobuf::Message for HotStuffNewView {
+    fn is_initialized(&self) -> bool {
+        for v in &self.high_qc {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.view = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.high_qc)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.view != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.view, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.high_qc.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.view != 0 {
+            os.write_int64(1, self.view)?;
+        }
+        if let Some(ref v) = self.high_qc.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> HotStuffNewView {
+        HotStuffNewView::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "view",
+                |m: &HotStuffNewView| { &m.view },
+                |m: &mut HotStuffNewView| { &mut m.view },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<HotStuffQc>>(
+                "high_qc",
+                |m: &HotStuffNewView| { &m.high_qc },
+                |m: &mut HotStuffNewView| { &mut m.high_qc },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<HotStuffNewView>(
+                "HotStuffNewView",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static HotStuffNewView {
+        static instance: ::protobuf::rt::LazyV2<HotStuffNewView> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(HotStuffNewView::new)
+    }
+}
+
+impl ::protobuf::Clear for HotStuffNewView {
+    fn clear(&mut self) {
+        self.view = 0;
+        self.high_qc.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for HotStuffNewView {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HotStuffNewView {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct HotStuff {
+    // message fields
+    pub msg_type: HotStuffMessageType,
+    pub proposal: ::protobuf::SingularPtrField<HotStuffNode>,
+    pub vote: ::protobuf::SingularPtrField<HotStuffVote>,
+    pub new_view: ::protobuf::SingularPtrField<HotStuffNewView>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a HotStuff {
+    fn default() -> &'a HotStuff {
+        <HotStuff as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HotStuff {
+    pub fn new() -> HotStuff {
+        ::std::default::Default::default()
+    }
+
+    // .HotStuffMessageType msg_type = 1;
+
+
+    pub fn get_msg_type(&self) -> HotStuffMessageType {
+        self.msg_type
+    }
+    pub fn clear_msg_type(&mut self) {
+        self.msg_type = HotStuffMessageType::PROPOSAL;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_msg_type(&mut self, v: HotStuffMessageType) {
+        self.msg_type = v;
+    }
+
+    // .HotStuffNode proposal = 2;
+
+
+    pub fn get_proposal(&self) -> &HotStuffNode {
+        self.proposal.as_ref().unwrap_or_else(|| <HotStuffNode as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_proposal(&mut self) {
+        self.proposal.clear();
+    }
+
+    pub fn has_proposal(&self) -> bool {
+        self.proposal.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_proposal(&mut self, v: HotStuffNode) {
+        self.proposal = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_proposal(&mut self) -> &mut HotStuffNode {
+        if self.proposal.is_none() {
+            self.proposal.set_default();
+        }
+        self.proposal.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_proposal(&mut self) -> HotStuffNode {
+        self.proposal.take().unwrap_or_else(|| HotStuffNode::new())
+    }
+
+    // .HotStuffVote vote = 3;
+
+
+    pub fn get_vote(&self) -> &HotStuffVote {
+        self.vote.as_ref().unwrap_or_else(|| <HotStuffVote as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_vote(&mut self) {
+        self.vote.clear();
+    }
+
+    pub fn has_vote(&self) -> bool {
+        self.vote.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vote(&mut self, v: HotStuffVote) {
+        self.vote = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_vote(&mut self) -> &mut HotStuffVote {
+        if self.vote.is_none() {
+            self.vote.set_default();
+        }
+        self.vote.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_vote(&mut self) -> HotStuffVote {
+        self.vote.take().unwrap_or_else(|| HotStuffVote::new())
+    }
+
+    // .HotStuffNewView new_view = 4;
+
+
+    pub fn get_new_view(&self) -> &HotStuffNewView {
+        self.new_view.as_ref().unwrap_or_else(|| <HotStuffNewView as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_new_view(&mut self) {
+        self.new_view.clear();
+    }
+
+    pub fn has_new_view(&self) -> bool {
+        self.new_view.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_new_view(&mut self, v: HotStuffNewView) {
+        self.new_view = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_new_view(&mut self) -> &mut HotStuffNewView {
+        if self.new_view.is_none() {
+            self.new_view.set_default();
+        }
+        self.new_view.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_new_view(&mut self) -> HotStuffNewView {
+        self.new_view.take().unwrap_or_else(|| HotStuffNewView::new())
+    }
+}
+
+impl ::protobuf::Message for HotStuff {
+    fn is_initialized(&self) -> bool {
+        for v in &self.proposal {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.vote {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.new_view {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.msg_type, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.proposal)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.vote)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.new_view)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.msg_type != HotStuffMessageType::PROPOSAL {
+            my_size += ::protobuf::rt::enum_size(1, self.msg_type);
+        }
+        if let Some(ref v) = self.proposal.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.vote.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.new_view.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.msg_type != HotStuffMessageType::PROPOSAL {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.msg_type))?;
+        }
+        if let Some(ref v) = self.proposal.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.vote.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.new_view.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> HotStuff {
+        HotStuff::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<HotStuffMessageType>>(
+                "msg_type",
+                |m: &HotStuff| { &m.msg_type },
+                |m: &mut HotStuff| { &mut m.msg_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<HotStuffNode>>(
+                "proposal",
+                |m: &HotStuff| { &m.proposal },
+                |m: &mut HotStuff| { &mut m.proposal },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<HotStuffVote>>(
+                "vote",
+                |m: &HotStuff| { &m.vote },
+                |m: &mut HotStuff| { &mut m.vote },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<HotStuffNewView>>(
+                "new_view",
+                |m: &HotStuff| { &m.new_view },
+                |m: &mut HotStuff| { &mut m.new_view },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<HotStuff>(
+                "HotStuff",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static HotStuff {
+        static instance: ::protobuf::rt::LazyV2<HotStuff> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(HotStuff::new)
+    }
+}
+
+impl ::protobuf::Clear for HotStuff {
+    fn clear(&mut self) {
+        self.msg_type = HotStuffMessageType::PROPOSAL;
+        self.proposal.clear();
+        self.vote.clear();
+        self.new_view.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for HotStuff {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HotStuff {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct HotStuffSign {
+    // message fields
+    pub hotstuff: ::protobuf::SingularPtrField<HotStuff>,
+    pub signature: ::protobuf::SingularPtrField<super::common::Signature>,
+    pub chain_id: ::std::string::String,
+    pub chain_hub: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a HotStuffSign {
+    fn default() -> &'a HotStuffSign {
+        <HotStuffSign as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HotStuffSign {
+    pub fn new() -> HotStuffSign {
+        ::std::default::Default::default()
+    }
+
+    // .HotStuff hotstuff = 1;
+
+
+    pub fn get_hotstuff(&self) -> &HotStuff {
+        self.hotstuff.as_ref().unwrap_or_else(|| <HotStuff as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_hotstuff(&mut self) {
+        self.hotstuff.clear();
+    }
+
+    pub fn has_hotstuff(&self) -> bool {
+        self.hotstuff.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hotstuff(&mut self, v: HotStuff) {
+        self.hotstuff = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_hotstuff(&mut self) -> &mut HotStuff {
+        if self.hotstuff.is_none() {
+            self.hotstuff.set_default();
+        }
+        self.hotstuff.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_hotstuff(&mut self) -> HotStuff {
+        self.hotstuff.take().unwrap_or_else(|| HotStuff::new())
+    }
+
+    // .Signature signature = 2;
+
+
+    pub fn get_signature(&self) -> &super::common::Signature {
+        self.signature.as_ref().unwrap_or_else(|| <super::common::Signature as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_signature(&mut self) {
+        self.signature.clear();
+    }
+
+    pub fn has_signature(&self) -> bool {
+        self.signature.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signature(&mut self, v: super::common::Signature) {
+        self.signature = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_signature(&mut self) -> &mut super::common::Signature {
+        if self.signature.is_none() {
+            self.signature.set_default();
+        }
+        self.signature.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_signature(&mut self) -> super::common::Signature {
+        self.signature.take().unwrap_or_else(|| super::common::Signature::new())
+    }
+
+    // string chain_id = 3;
+
+
+    pub fn get_chain_id(&self) -> &str {
+        &self.chain_id
+    }
+    pub fn clear_chain_id(&mut self) {
+        self.chain_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chain_id(&mut self, v: ::std::string::String) {
+        self.chain_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_chain_id(&mut self) -> &mut ::std::string::String {
+        &mut self.chain_id
+    }
+
+    // Take field
+    pub fn take_chain_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.chain_id, ::std::string::String::new())
+    }
+
+    // string chain_hub = 4;
+
+
+    pub fn get_chain_hub(&self) -> &str {
+        &self.chain_hub
+    }
+    pub fn clear_chain_hub(&mut self) {
+        self.chain_hub.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chain_hub(&mut self, v: ::std::string::String) {
+        self.chain_hub = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_chain_hub(&mut self) -> &mut ::std::string::String {
+        &mut self.chain_hub
+    }
+
+    // Take field
+    pub fn take_chain_hub(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.chain_hub, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for HotStuffSign {
+    fn is_initialized(&self) -> bool {
+        for v in &self.hotstuff {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.signature {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.hotstuff)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.signature)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.chain_id)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.chain_hub)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.hotstuff.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.signature.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.chain_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.chain_id);
+        }
+        if !self.chain_hub.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.chain_hub);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.hotstuff.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.signature.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.chain_id.is_empty() {
+            os.write_string(3, &self.chain_id)?;
+        }
+        if !self.chain_hub.is_empty() {
+            os.write_string(4, &self.chain_hub)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> HotStuffSign {
+        HotStuffSign::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<HotStuff>>(
+                "hotstuff",
+                |m: &HotStuffSign| { &m.hotstuff },
+                |m: &mut HotStuffSign| { &mut m.hotstuff },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::Signature>>(
+                "signature",
+                |m: &HotStuffSign| { &m.signature },
+                |m: &mut HotStuffSign| { &mut m.signature },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "chain_id",
+                |m: &HotStuffSign| { &m.chain_id },
+                |m: &mut HotStuffSign| { &mut m.chain_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "chain_hub",
+                |m: &HotStuffSign| { &m.chain_hub },
+                |m: &mut HotStuffSign| { &mut m.chain_hub },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<HotStuffSign>(
+                "HotStuffSign",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static HotStuffSign {
+        static instance: ::protobuf::rt::LazyV2<HotStuffSign> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(HotStuffSign::new)
+    }
+}
+
+impl ::protobuf::Clear for HotStuffSign {
+    fn clear(&mut self) {
+        self.hotstuff.clear();
+        self.signature.clear();
+        self.chain_id.clear();
+        self.chain_hub.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for HotStuffSign {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HotStuffSign {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct HotStuffProof {
+    // message fields
+    pub chain: ::protobuf::RepeatedField<HotStuffNode>,
+    pub qc: ::protobuf::SingularPtrField<HotStuffQc>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a HotStuffProof {
+    fn default() -> &'a HotStuffProof {
+        <HotStuffProof as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HotStuffProof {
+    pub fn new() -> HotStuffProof {
+        ::std::default::Default::default()
+    }
+
+    // repeated .HotStuffNode chain = 1;
+
+
+    pub fn get_chain(&self) -> &[HotStuffNode] {
+        &self.chain
+    }
+    pub fn clear_chain(&mut self) {
+        self.chain.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chain(&mut self, v: ::protobuf::RepeatedField<HotStuffNode>) {
+        self.chain = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_chain(&mut self) -> &mut ::protobuf::RepeatedField<HotStuffNode> {
+        &mut self.chain
+    }
+
+    // Take field
+    pub fn take_chain(&mut self) -> ::protobuf::RepeatedField<HotStuffNode> {
+        ::std::mem::replace(&mut self.chain, ::protobuf::RepeatedField::new())
+    }
+
+    // .HotStuffQc qc = 2;
+
+
+    pub fn get_qc(&self) -> &HotStuffQc {
+        self.qc.as_ref().unwrap_or_else(|| <HotStuffQc as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_qc(&mut self) {
+        self.qc.clear();
+    }
+
+    pub fn has_qc(&self) -> bool {
+        self.qc.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_qc(&mut self, v: HotStuffQc) {
+        self.qc = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_qc(&mut self) -> &mut HotStuffQc {
+        if self.qc.is_none() {
+            self.qc.set_default();
+        }
+        self.qc.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_qc(&mut self) -> HotStuffQc {
+        self.qc.take().unwrap_or_else(|| HotStuffQc::new())
+    }
+}
+
+impl ::protobuf::Message for HotStuffProof {
+    fn is_initialized(&self) -> bool {
+        for v in &self.chain {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.qc {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.chain)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.qc)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.chain {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.qc.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.chain {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.qc.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> HotStuffProof {
+        HotStuffProof::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<HotStuffNode>>(
+                "chain",
+                |m: &HotStuffProof| { &m.chain },
+                |m: &mut HotStuffProof| { &mut m.chain },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<HotStuffQc>>(
+                "qc",
+                |m: &HotStuffProof| { &m.qc },
+                |m: &mut HotStuffProof| { &mut m.qc },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<HotStuffProof>(
+                "HotStuffProof",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static HotStuffProof {
+        static instance: ::protobuf::rt::LazyV2<HotStuffProof> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(HotStuffProof::new)
+    }
+}
+
+impl ::protobuf::Clear for HotStuffProof {
+    fn clear(&mut self) {
+        self.chain.clear();
+        self.qc.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for HotStuffProof {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HotStuffProof {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct HotStuffState {
+    // message fields
+    pub view: i64,
+    pub last_voted_view: i64,
+    pub high_qc: ::protobuf::SingularPtrField<HotStuffQc>,
+    pub locked_hash: ::std::vec::Vec<u8>,
+    pub nodes: ::protobuf::RepeatedField<HotStuffNode>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a HotStuffState {
+    fn default() -> &'a HotStuffState {
+        <HotStuffState as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HotStuffState {
+    pub fn new() -> HotStuffState {
+        ::std::default::Default::default()
+    }
+
+    // int64 view = 1;
+
+
+    pub fn get_view(&self) -> i64 {
+        self.view
+    }
+    pub fn clear_view(&mut self) {
+        self.view = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view(&mut self, v: i64) {
+        self.view = v;
+    }
+
+    // int64 last_voted_view = 2;
+
+
+    pub fn get_last_voted_view(&self) -> i64 {
+        self.last_voted_view
+    }
+    pub fn clear_last_voted_view(&mut self) {
+        self.last_voted_view = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_voted_view(&mut self, v: i64) {
+        self.last_voted_view = v;
+    }
+
+    // .HotStuffQc high_qc = 3;
+
+
+    pub fn get_high_qc(&self) -> &HotStuffQc {
+        self.high_qc.as_ref().unwrap_or_else(|| <HotStuffQc as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_high_qc(&mut self) {
+        self.high_qc.clear();
+    }
+
+    pub fn has_high_qc(&self) -> bool {
+        self.high_qc.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_high_qc(&mut self, v: HotStuffQc) {
+        self.high_qc = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_high_qc(&mut self) -> &mut HotStuffQc {
+        if self.high_qc.is_none() {
+            self.high_qc.set_default();
+        }
+        self.high_qc.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_high_qc(&mut self) -> HotStuffQc {
+        self.high_qc.take().unwrap_or_else(|| HotStuffQc::new())
+    }
+
+    // bytes locked_hash = 4;
+
+
+    pub fn get_locked_hash(&self) -> &[u8] {
+        &self.locked_hash
+    }
+    pub fn clear_locked_hash(&mut self) {
+        self.locked_hash.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_locked_hash(&mut self, v: ::std::vec::Vec<u8>) {
+        self.locked_hash = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_locked_hash(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.locked_hash
+    }
+
+    // Take field
+    pub fn take_locked_hash(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.locked_hash, ::std::vec::Vec::new())
+    }
+
+    // repeated .HotStuffNode nodes = 5;
+
+
+    pub fn get_nodes(&self) -> &[HotStuffNode] {
+        &self.nodes
+    }
+    pub fn clear_nodes(&mut self) {
+        self.nodes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nodes(&mut self, v: ::protobuf::RepeatedField<HotStuffNode>) {
+        self.nodes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_nodes(&mut self) -> &mut ::protobuf::RepeatedField<HotStuffNode> {
+        &mut self.nodes
+    }
+
+    // Take field
+    pub fn take_nodes(&mut self) -> ::protobuf::RepeatedField<HotStuffNode> {
+        ::std::mem::replace(&mut self.nodes, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for HotStuffState {
+    fn is_initialized(&self) -> bool {
+        for v in &self.high_qc {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.nodes {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.view = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.last_voted_view = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.high_qc)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.locked_hash)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.nodes)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.view != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.view, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.last_voted_view != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.last_voted_view, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.high_qc.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.locked_hash.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.locked_hash);
+        }
+        for value in &self.nodes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.view != 0 {
+            os.write_int64(1, self.view)?;
+        }
+        if self.last_voted_view != 0 {
+            os.write_int64(2, self.last_voted_view)?;
+        }
+        if let Some(ref v) = self.high_qc.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.locked_hash.is_empty() {
+            os.write_bytes(4, &self.locked_hash)?;
+        }
+        for v in &self.nodes {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> HotStuffState {
+        HotStuffState::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "view",
+                |m: &HotStuffState| { &m.view },
+                |m: &mut HotStuffState| { &mut m.view },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "last_voted_view",
+                |m: &HotStuffState| { &m.last_voted_view },
+                |m: &mut HotStuffState| { &mut m.last_voted_view },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<HotStuffQc>>(
+                "high_qc",
+                |m: &HotStuffState| { &m.high_qc },
+                |m: &mut HotStuffState| { &mut m.high_qc },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "locked_hash",
+                |m: &HotStuffState| { &m.locked_hash },
+                |m: &mut HotStuffState| { &mut m.locked_hash },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<HotStuffNode>>(
+                "nodes",
+                |m: &HotStuffState| { &m.nodes },
+                |m: &mut HotStuffState| { &mut m.nodes },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<HotStuffState>(
+                "HotStuffState",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static HotStuffState {
+        static instance: ::protobuf::rt::LazyV2<HotStuffState> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(HotStuffState::new)
+    }
+}
+
+impl ::protobuf::Clear for HotStuffState {
+    fn clear(&mut self) {
+        self.view = 0;
+        self.last_voted_view = 0;
+        self.high_qc.clear();
+        self.locked_hash.clear();
+        self.nodes.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for HotStuffState {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HotStuffState {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct LedgerUpgrade {
     // message fields
@@ -5169,6 +7615,7 @@ impl ::protobuf::reflect::ProtobufValue for LedgerUpgradeInfo {
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum ConsensusType {
     PBFT = 0,
+    HOTSTUFF = 1,
 }
 
 impl ::protobuf::ProtobufEnum for ConsensusType {
@@ -5179,6 +7626,7 @@ impl ::protobuf::ProtobufEnum for ConsensusType {
     fn from_i32(value: i32) -> ::std::option::Option<ConsensusType> {
         match value {
             0 => ::std::option::Option::Some(ConsensusType::PBFT),
+            1 => ::std::option::Option::Some(ConsensusType::HOTSTUFF),
             _ => ::std::option::Option::None
         }
     }
@@ -5186,6 +7634,7 @@ impl ::protobuf::ProtobufEnum for ConsensusType {
     fn values() -> &'static [Self] {
         static values: &'static [ConsensusType] = &[
             ConsensusType::PBFT,
+            ConsensusType::HOTSTUFF,
         ];
         values
     }
@@ -5275,6 +7724,59 @@ impl ::protobuf::reflect::ProtobufValue for BftMessageType {
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum HotStuffMessageType {
+    PROPOSAL = 0,
+    VOTE = 1,
+    NEW_VIEW = 2,
+}
+
+impl ::protobuf::ProtobufEnum for HotStuffMessageType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<HotStuffMessageType> {
+        match value {
+            0 => ::std::option::Option::Some(HotStuffMessageType::PROPOSAL),
+            1 => ::std::option::Option::Some(HotStuffMessageType::VOTE),
+            2 => ::std::option::Option::Some(HotStuffMessageType::NEW_VIEW),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [HotStuffMessageType] = &[
+            HotStuffMessageType::PROPOSAL,
+            HotStuffMessageType::VOTE,
+            HotStuffMessageType::NEW_VIEW,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<HotStuffMessageType>("HotStuffMessageType", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for HotStuffMessageType {
+}
+
+impl ::std::default::Default for HotStuffMessageType {
+    fn default() -> Self {
+        HotStuffMessageType::PROPOSAL
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HotStuffMessageType {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x0fconsensus.proto\x1a\x0ccommon.proto\"Z\n\tConsensus\x127\n\x0econs\
     ensus_type\x18\x01\x20\x01(\x0e2\x0e.ConsensusTypeR\rconsensusTypeB\0\
@@ -5331,20 +7833,55 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     ChangesB\0:\0\"=\n\x11ViewChangeMessage\x12&\n\x04item\x18\x01\x20\x03(\
     \x0b2\x10.ViewChangeStoreR\x04itemB\0:\0\"o\n\x12NewViewRepondParam\x12!\
     \n\x0bview_number\x18\x01\x20\x01(\x03R\nviewNumberB\0\x124\n\x0cprepare\
-    d_set\x18\x02\x20\x01(\x0b2\x0f.BftPreparedSetR\x0bpreparedSetB\0:\0\"p\
-    \n\rLedgerUpgrade\x12!\n\x0bnew_version\x18\x01\x20\x01(\x04R\nnewVersio\
-    nB\0\x12\x1b\n\x08chain_id\x18\x02\x20\x01(\tR\x07chainIdB\0\x12\x1d\n\t\
-    chain_hub\x18\x03\x20\x01(\tR\x08chainHubB\0:\0\"\x87\x01\n\x13LedgerUpg\
-    radeNotify\x12\x16\n\x05nonce\x18\x01\x20\x01(\x03R\x05nonceB\0\x12*\n\
-    \x07upgrade\x18\x02\x20\x01(\x0b2\x0e.LedgerUpgradeR\x07upgradeB\0\x12*\
-    \n\tsignature\x18\x03\x20\x01(\x0b2\n.SignatureR\tsignatureB\0:\0\"z\n\
-    \x11LedgerUpgradeInfo\x12\x1d\n\trecv_time\x18\x01\x20\x01(\x03R\x08recv\
-    TimeB\0\x12\x1a\n\x07address\x18\x02\x20\x01(\tR\x07addressB\0\x12(\n\
-    \x03msg\x18\x03\x20\x01(\x0b2\x14.LedgerUpgradeNotifyR\x03msgB\0:\0*\x1b\
-    \n\rConsensusType\x12\x08\n\x04PBFT\x10\0\x1a\0*r\n\x0eBftMessageType\
-    \x12\x0f\n\x0bPRE_PREPARE\x10\0\x12\x0b\n\x07PREPARE\x10\x01\x12\n\n\x06\
-    COMMIT\x10\x02\x12\x0f\n\x0bVIEW_CHANGE\x10\x03\x12\x0c\n\x08NEW_VIEW\
-    \x10\x04\x12\x15\n\x11VIEW_CHANGE_VALUE\x10\x05\x1a\0B\0b\x06proto3\
+    d_set\x18\x02\x20\x01(\x0b2\x0f.BftPreparedSetR\x0bpreparedSetB\0:\0\"\
+    \x85\x02\n\x0cHotStuffNode\x12\x14\n\x04view\x18\x01\x20\x01(\x03R\x04vi\
+    ewB\0\x12\x18\n\x06height\x18\x02\x20\x01(\x04R\x06heightB\0\x12!\n\x0bp\
+    arent_hash\x18\x03\x20\x01(\x0cR\nparentHashB\0\x12'\n\x07justify\x18\
+    \x04\x20\x01(\x0b2\x0b.HotStuffQcR\x07justifyB\0\x12\x1e\n\ttimestamp\
+    \x18\x05\x20\x01(\x03R\ttimestampB\0\x12\x1c\n\x08proposer\x18\x06\x20\
+    \x01(\tR\x08proposerB\0\x12\x1a\n\x07version\x18\x07\x20\x01(\x04R\x07ve\
+    rsionB\0\x12\x1d\n\ttx_hashes\x18\x08\x20\x03(\x0cR\x08txHashesB\0:\0\"\
+    \xa8\x01\n\x0cHotStuffVote\x12\x14\n\x04view\x18\x01\x20\x01(\x03R\x04vi\
+    ewB\0\x12\x18\n\x06height\x18\x02\x20\x01(\x04R\x06heightB\0\x12\x1d\n\t\
+    node_hash\x18\x03\x20\x01(\x0cR\x08nodeHashB\0\x12\x1b\n\x08chain_id\x18\
+    \x04\x20\x01(\tR\x07chainIdB\0\x12*\n\tsignature\x18\x05\x20\x01(\x0b2\n\
+    .SignatureR\tsignatureB\0:\0\"\x8b\x01\n\nHotStuffQc\x12\x14\n\x04view\
+    \x18\x01\x20\x01(\x03R\x04viewB\0\x12\x18\n\x06height\x18\x02\x20\x01(\
+    \x04R\x06heightB\0\x12\x1d\n\tnode_hash\x18\x03\x20\x01(\x0cR\x08nodeHas\
+    hB\0\x12,\n\nsignatures\x18\x04\x20\x03(\x0b2\n.SignatureR\nsignaturesB\
+    \0:\0\"Q\n\x0fHotStuffNewView\x12\x14\n\x04view\x18\x01\x20\x01(\x03R\
+    \x04viewB\0\x12&\n\x07high_qc\x18\x02\x20\x01(\x0b2\x0b.HotStuffQcR\x06h\
+    ighQcB\0:\0\"\xc0\x01\n\x08HotStuff\x121\n\x08msg_type\x18\x01\x20\x01(\
+    \x0e2\x14.HotStuffMessageTypeR\x07msgTypeB\0\x12+\n\x08proposal\x18\x02\
+    \x20\x01(\x0b2\r.HotStuffNodeR\x08proposalB\0\x12#\n\x04vote\x18\x03\x20\
+    \x01(\x0b2\r.HotStuffVoteR\x04voteB\0\x12-\n\x08new_view\x18\x04\x20\x01\
+    (\x0b2\x10.HotStuffNewViewR\x07newViewB\0:\0\"\xa1\x01\n\x0cHotStuffSign\
+    \x12'\n\x08hotstuff\x18\x01\x20\x01(\x0b2\t.HotStuffR\x08hotstuffB\0\x12\
+    *\n\tsignature\x18\x02\x20\x01(\x0b2\n.SignatureR\tsignatureB\0\x12\x1b\
+    \n\x08chain_id\x18\x03\x20\x01(\tR\x07chainIdB\0\x12\x1d\n\tchain_hub\
+    \x18\x04\x20\x01(\tR\x08chainHubB\0:\0\"W\n\rHotStuffProof\x12%\n\x05cha\
+    in\x18\x01\x20\x03(\x0b2\r.HotStuffNodeR\x05chainB\0\x12\x1d\n\x02qc\x18\
+    \x02\x20\x01(\x0b2\x0b.HotStuffQcR\x02qcB\0:\0\"\xc3\x01\n\rHotStuffStat\
+    e\x12\x14\n\x04view\x18\x01\x20\x01(\x03R\x04viewB\0\x12(\n\x0flast_vote\
+    d_view\x18\x02\x20\x01(\x03R\rlastVotedViewB\0\x12&\n\x07high_qc\x18\x03\
+    \x20\x01(\x0b2\x0b.HotStuffQcR\x06highQcB\0\x12!\n\x0blocked_hash\x18\
+    \x04\x20\x01(\x0cR\nlockedHashB\0\x12%\n\x05nodes\x18\x05\x20\x03(\x0b2\
+    \r.HotStuffNodeR\x05nodesB\0:\0\"p\n\rLedgerUpgrade\x12!\n\x0bnew_versio\
+    n\x18\x01\x20\x01(\x04R\nnewVersionB\0\x12\x1b\n\x08chain_id\x18\x02\x20\
+    \x01(\tR\x07chainIdB\0\x12\x1d\n\tchain_hub\x18\x03\x20\x01(\tR\x08chain\
+    HubB\0:\0\"\x87\x01\n\x13LedgerUpgradeNotify\x12\x16\n\x05nonce\x18\x01\
+    \x20\x01(\x03R\x05nonceB\0\x12*\n\x07upgrade\x18\x02\x20\x01(\x0b2\x0e.L\
+    edgerUpgradeR\x07upgradeB\0\x12*\n\tsignature\x18\x03\x20\x01(\x0b2\n.Si\
+    gnatureR\tsignatureB\0:\0\"z\n\x11LedgerUpgradeInfo\x12\x1d\n\trecv_time\
+    \x18\x01\x20\x01(\x03R\x08recvTimeB\0\x12\x1a\n\x07address\x18\x02\x20\
+    \x01(\tR\x07addressB\0\x12(\n\x03msg\x18\x03\x20\x01(\x0b2\x14.LedgerUpg\
+    radeNotifyR\x03msgB\0:\0*)\n\rConsensusType\x12\x08\n\x04PBFT\x10\0\x12\
+    \x0c\n\x08HOTSTUFF\x10\x01\x1a\0*r\n\x0eBftMessageType\x12\x0f\n\x0bPRE_\
+    PREPARE\x10\0\x12\x0b\n\x07PREPARE\x10\x01\x12\n\n\x06COMMIT\x10\x02\x12\
+    \x0f\n\x0bVIEW_CHANGE\x10\x03\x12\x0c\n\x08NEW_VIEW\x10\x04\x12\x15\n\
+    \x11VIEW_CHANGE_VALUE\x10\x05\x1a\0*=\n\x13HotStuffMessageType\x12\x0c\n\
+    \x08PROPOSAL\x10\0\x12\x08\n\x04VOTE\x10\x01\x12\x0c\n\x08NEW_VIEW\x10\
+    \x02\x1a\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
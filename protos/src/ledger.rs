@@ -4254,6 +4254,3045 @@ impl ::protobuf::reflect::ProtobufValue for SyncBlockResponse {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct SnapshotEntry {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    pub value: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SnapshotEntry {
+    fn default() -> &'a SnapshotEntry {
+        <SnapshotEntry as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SnapshotEntry {
+    pub fn new() -> SnapshotEntry {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    // bytes value = 2;
+
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for SnapshotEntry {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(2, &self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SnapshotEntry {
+        SnapshotEntry::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "key",
+                |m: &SnapshotEntry| { &m.key },
+                |m: &mut SnapshotEntry| { &mut m.key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "value",
+                |m: &SnapshotEntry| { &m.value },
+                |m: &mut SnapshotEntry| { &mut m.value },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SnapshotEntry>(
+                "SnapshotEntry",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SnapshotEntry {
+        static instance: ::protobuf::rt::LazyV2<SnapshotEntry> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SnapshotEntry::new)
+    }
+}
+
+impl ::protobuf::Clear for SnapshotEntry {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SnapshotEntry {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SnapshotEntry {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SnapshotAccount {
+    // message fields
+    pub account: ::protobuf::SingularPtrField<Account>,
+    pub metadatas: ::protobuf::RepeatedField<SnapshotEntry>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SnapshotAccount {
+    fn default() -> &'a SnapshotAccount {
+        <SnapshotAccount as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SnapshotAccount {
+    pub fn new() -> SnapshotAccount {
+        ::std::default::Default::default()
+    }
+
+    // .Account account = 1;
+
+
+    pub fn get_account(&self) -> &Account {
+        self.account.as_ref().unwrap_or_else(|| <Account as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_account(&mut self) {
+        self.account.clear();
+    }
+
+    pub fn has_account(&self) -> bool {
+        self.account.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account(&mut self, v: Account) {
+        self.account = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account(&mut self) -> &mut Account {
+        if self.account.is_none() {
+            self.account.set_default();
+        }
+        self.account.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_account(&mut self) -> Account {
+        self.account.take().unwrap_or_else(|| Account::new())
+    }
+
+    // repeated .SnapshotEntry metadatas = 2;
+
+
+    pub fn get_metadatas(&self) -> &[SnapshotEntry] {
+        &self.metadatas
+    }
+    pub fn clear_metadatas(&mut self) {
+        self.metadatas.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_metadatas(&mut self, v: ::protobuf::RepeatedField<SnapshotEntry>) {
+        self.metadatas = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_metadatas(&mut self) -> &mut ::protobuf::RepeatedField<SnapshotEntry> {
+        &mut self.metadatas
+    }
+
+    // Take field
+    pub fn take_metadatas(&mut self) -> ::protobuf::RepeatedField<SnapshotEntry> {
+        ::std::mem::replace(&mut self.metadatas, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for SnapshotAccount {
+    fn is_initialized(&self) -> bool {
+        for v in &self.account {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.metadatas {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.account)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.metadatas)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.account.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.metadatas {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.account.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.metadatas {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SnapshotAccount {
+        SnapshotAccount::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Account>>(
+                "account",
+                |m: &SnapshotAccount| { &m.account },
+                |m: &mut SnapshotAccount| { &mut m.account },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SnapshotEntry>>(
+                "metadatas",
+                |m: &SnapshotAccount| { &m.metadatas },
+                |m: &mut SnapshotAccount| { &mut m.metadatas },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SnapshotAccount>(
+                "SnapshotAccount",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SnapshotAccount {
+        static instance: ::protobuf::rt::LazyV2<SnapshotAccount> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SnapshotAccount::new)
+    }
+}
+
+impl ::protobuf::Clear for SnapshotAccount {
+    fn clear(&mut self) {
+        self.account.clear();
+        self.metadatas.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SnapshotAccount {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SnapshotAccount {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SnapshotChunk {
+    // message fields
+    pub index: u64,
+    pub accounts: ::protobuf::RepeatedField<SnapshotAccount>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SnapshotChunk {
+    fn default() -> &'a SnapshotChunk {
+        <SnapshotChunk as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SnapshotChunk {
+    pub fn new() -> SnapshotChunk {
+        ::std::default::Default::default()
+    }
+
+    // uint64 index = 1;
+
+
+    pub fn get_index(&self) -> u64 {
+        self.index
+    }
+    pub fn clear_index(&mut self) {
+        self.index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: u64) {
+        self.index = v;
+    }
+
+    // repeated .SnapshotAccount accounts = 2;
+
+
+    pub fn get_accounts(&self) -> &[SnapshotAccount] {
+        &self.accounts
+    }
+    pub fn clear_accounts(&mut self) {
+        self.accounts.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_accounts(&mut self, v: ::protobuf::RepeatedField<SnapshotAccount>) {
+        self.accounts = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_accounts(&mut self) -> &mut ::protobuf::RepeatedField<SnapshotAccount> {
+        &mut self.accounts
+    }
+
+    // Take field
+    pub fn take_accounts(&mut self) -> ::protobuf::RepeatedField<SnapshotAccount> {
+        ::std::mem::replace(&mut self.accounts, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for SnapshotChunk {
+    fn is_initialized(&self) -> bool {
+        for v in &self.accounts {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.index = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.accounts)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.index != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.accounts {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.index != 0 {
+            os.write_uint64(1, self.index)?;
+        }
+        for v in &self.accounts {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SnapshotChunk {
+        SnapshotChunk::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "index",
+                |m: &SnapshotChunk| { &m.index },
+                |m: &mut SnapshotChunk| { &mut m.index },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SnapshotAccount>>(
+                "accounts",
+                |m: &SnapshotChunk| { &m.accounts },
+                |m: &mut SnapshotChunk| { &mut m.accounts },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SnapshotChunk>(
+                "SnapshotChunk",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SnapshotChunk {
+        static instance: ::protobuf::rt::LazyV2<SnapshotChunk> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SnapshotChunk::new)
+    }
+}
+
+impl ::protobuf::Clear for SnapshotChunk {
+    fn clear(&mut self) {
+        self.index = 0;
+        self.accounts.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SnapshotChunk {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SnapshotChunk {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SnapshotManifest {
+    // message fields
+    pub chain_id: ::std::string::String,
+    pub header: ::protobuf::SingularPtrField<LedgerHeader>,
+    pub proof: ::std::vec::Vec<u8>,
+    pub account_count: u64,
+    pub chunk_hashes: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub next_block: ::protobuf::SingularPtrField<Ledger>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SnapshotManifest {
+    fn default() -> &'a SnapshotManifest {
+        <SnapshotManifest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SnapshotManifest {
+    pub fn new() -> SnapshotManifest {
+        ::std::default::Default::default()
+    }
+
+    // string chain_id = 1;
+
+
+    pub fn get_chain_id(&self) -> &str {
+        &self.chain_id
+    }
+    pub fn clear_chain_id(&mut self) {
+        self.chain_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chain_id(&mut self, v: ::std::string::String) {
+        self.chain_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_chain_id(&mut self) -> &mut ::std::string::String {
+        &mut self.chain_id
+    }
+
+    // Take field
+    pub fn take_chain_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.chain_id, ::std::string::String::new())
+    }
+
+    // .LedgerHeader header = 2;
+
+
+    pub fn get_header(&self) -> &LedgerHeader {
+        self.header.as_ref().unwrap_or_else(|| <LedgerHeader as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: LedgerHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut LedgerHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> LedgerHeader {
+        self.header.take().unwrap_or_else(|| LedgerHeader::new())
+    }
+
+    // bytes proof = 3;
+
+
+    pub fn get_proof(&self) -> &[u8] {
+        &self.proof
+    }
+    pub fn clear_proof(&mut self) {
+        self.proof.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_proof(&mut self, v: ::std::vec::Vec<u8>) {
+        self.proof = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_proof(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.proof
+    }
+
+    // Take field
+    pub fn take_proof(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.proof, ::std::vec::Vec::new())
+    }
+
+    // uint64 account_count = 4;
+
+
+    pub fn get_account_count(&self) -> u64 {
+        self.account_count
+    }
+    pub fn clear_account_count(&mut self) {
+        self.account_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_count(&mut self, v: u64) {
+        self.account_count = v;
+    }
+
+    // repeated bytes chunk_hashes = 5;
+
+
+    pub fn get_chunk_hashes(&self) -> &[::std::vec::Vec<u8>] {
+        &self.chunk_hashes
+    }
+    pub fn clear_chunk_hashes(&mut self) {
+        self.chunk_hashes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chunk_hashes(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.chunk_hashes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_chunk_hashes(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.chunk_hashes
+    }
+
+    // Take field
+    pub fn take_chunk_hashes(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.chunk_hashes, ::protobuf::RepeatedField::new())
+    }
+
+    // .Ledger next_block = 6;
+
+
+    pub fn get_next_block(&self) -> &Ledger {
+        self.next_block.as_ref().unwrap_or_else(|| <Ledger as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_next_block(&mut self) {
+        self.next_block.clear();
+    }
+
+    pub fn has_next_block(&self) -> bool {
+        self.next_block.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_next_block(&mut self, v: Ledger) {
+        self.next_block = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_next_block(&mut self) -> &mut Ledger {
+        if self.next_block.is_none() {
+            self.next_block.set_default();
+        }
+        self.next_block.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_next_block(&mut self) -> Ledger {
+        self.next_block.take().unwrap_or_else(|| Ledger::new())
+    }
+}
+
+impl ::protobuf::Message for SnapshotManifest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.next_block {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.chain_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.proof)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.account_count = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.chunk_hashes)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.next_block)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.chain_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.chain_id);
+        }
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.proof.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.proof);
+        }
+        if self.account_count != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.account_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.chunk_hashes {
+            my_size += ::protobuf::rt::bytes_size(5, &value);
+        };
+        if let Some(ref v) = self.next_block.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.chain_id.is_empty() {
+            os.write_string(1, &self.chain_id)?;
+        }
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.proof.is_empty() {
+            os.write_bytes(3, &self.proof)?;
+        }
+        if self.account_count != 0 {
+            os.write_uint64(4, self.account_count)?;
+        }
+        for v in &self.chunk_hashes {
+            os.write_bytes(5, &v)?;
+        };
+        if let Some(ref v) = self.next_block.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SnapshotManifest {
+        SnapshotManifest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "chain_id",
+                |m: &SnapshotManifest| { &m.chain_id },
+                |m: &mut SnapshotManifest| { &mut m.chain_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LedgerHeader>>(
+                "header",
+                |m: &SnapshotManifest| { &m.header },
+                |m: &mut SnapshotManifest| { &mut m.header },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "proof",
+                |m: &SnapshotManifest| { &m.proof },
+                |m: &mut SnapshotManifest| { &mut m.proof },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "account_count",
+                |m: &SnapshotManifest| { &m.account_count },
+                |m: &mut SnapshotManifest| { &mut m.account_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "chunk_hashes",
+                |m: &SnapshotManifest| { &m.chunk_hashes },
+                |m: &mut SnapshotManifest| { &mut m.chunk_hashes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ledger>>(
+                "next_block",
+                |m: &SnapshotManifest| { &m.next_block },
+                |m: &mut SnapshotManifest| { &mut m.next_block },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SnapshotManifest>(
+                "SnapshotManifest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SnapshotManifest {
+        static instance: ::protobuf::rt::LazyV2<SnapshotManifest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SnapshotManifest::new)
+    }
+}
+
+impl ::protobuf::Clear for SnapshotManifest {
+    fn clear(&mut self) {
+        self.chain_id.clear();
+        self.header.clear();
+        self.proof.clear();
+        self.account_count = 0;
+        self.chunk_hashes.clear();
+        self.next_block.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SnapshotManifest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SnapshotManifest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SyncSnapshotRequest {
+    // message fields
+    pub chain_id: ::std::string::String,
+    pub height: u64,
+    pub manifest: bool,
+    pub index: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SyncSnapshotRequest {
+    fn default() -> &'a SyncSnapshotRequest {
+        <SyncSnapshotRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SyncSnapshotRequest {
+    pub fn new() -> SyncSnapshotRequest {
+        ::std::default::Default::default()
+    }
+
+    // string chain_id = 1;
+
+
+    pub fn get_chain_id(&self) -> &str {
+        &self.chain_id
+    }
+    pub fn clear_chain_id(&mut self) {
+        self.chain_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chain_id(&mut self, v: ::std::string::String) {
+        self.chain_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_chain_id(&mut self) -> &mut ::std::string::String {
+        &mut self.chain_id
+    }
+
+    // Take field
+    pub fn take_chain_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.chain_id, ::std::string::String::new())
+    }
+
+    // uint64 height = 2;
+
+
+    pub fn get_height(&self) -> u64 {
+        self.height
+    }
+    pub fn clear_height(&mut self) {
+        self.height = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_height(&mut self, v: u64) {
+        self.height = v;
+    }
+
+    // bool manifest = 3;
+
+
+    pub fn get_manifest(&self) -> bool {
+        self.manifest
+    }
+    pub fn clear_manifest(&mut self) {
+        self.manifest = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_manifest(&mut self, v: bool) {
+        self.manifest = v;
+    }
+
+    // uint64 index = 4;
+
+
+    pub fn get_index(&self) -> u64 {
+        self.index
+    }
+    pub fn clear_index(&mut self) {
+        self.index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: u64) {
+        self.index = v;
+    }
+}
+
+impl ::protobuf::Message for SyncSnapshotRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.chain_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.height = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.manifest = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.index = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.chain_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.chain_id);
+        }
+        if self.height != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.height, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.manifest != false {
+            my_size += 2;
+        }
+        if self.index != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.chain_id.is_empty() {
+            os.write_string(1, &self.chain_id)?;
+        }
+        if self.height != 0 {
+            os.write_uint64(2, self.height)?;
+        }
+        if self.manifest != false {
+            os.write_bool(3, self.manifest)?;
+        }
+        if self.index != 0 {
+            os.write_uint64(4, self.index)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SyncSnapshotRequest {
+        SyncSnapshotRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "chain_id",
+                |m: &SyncSnapshotRequest| { &m.chain_id },
+                |m: &mut SyncSnapshotRequest| { &mut m.chain_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "height",
+                |m: &SyncSnapshotRequest| { &m.height },
+                |m: &mut SyncSnapshotRequest| { &mut m.height },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "manifest",
+                |m: &SyncSnapshotRequest| { &m.manifest },
+                |m: &mut SyncSnapshotRequest| { &mut m.manifest },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "index",
+                |m: &SyncSnapshotRequest| { &m.index },
+                |m: &mut SyncSnapshotRequest| { &mut m.index },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SyncSnapshotRequest>(
+                "SyncSnapshotRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SyncSnapshotRequest {
+        static instance: ::protobuf::rt::LazyV2<SyncSnapshotRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SyncSnapshotRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SyncSnapshotRequest {
+    fn clear(&mut self) {
+        self.chain_id.clear();
+        self.height = 0;
+        self.manifest = false;
+        self.index = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SyncSnapshotRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyncSnapshotRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SyncSnapshotResponse {
+    // message fields
+    pub chain_id: ::std::string::String,
+    pub height: u64,
+    pub manifest: ::protobuf::SingularPtrField<SnapshotManifest>,
+    pub chunk: ::protobuf::SingularPtrField<SnapshotChunk>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SyncSnapshotResponse {
+    fn default() -> &'a SyncSnapshotResponse {
+        <SyncSnapshotResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SyncSnapshotResponse {
+    pub fn new() -> SyncSnapshotResponse {
+        ::std::default::Default::default()
+    }
+
+    // string chain_id = 1;
+
+
+    pub fn get_chain_id(&self) -> &str {
+        &self.chain_id
+    }
+    pub fn clear_chain_id(&mut self) {
+        self.chain_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chain_id(&mut self, v: ::std::string::String) {
+        self.chain_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_chain_id(&mut self) -> &mut ::std::string::String {
+        &mut self.chain_id
+    }
+
+    // Take field
+    pub fn take_chain_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.chain_id, ::std::string::String::new())
+    }
+
+    // uint64 height = 2;
+
+
+    pub fn get_height(&self) -> u64 {
+        self.height
+    }
+    pub fn clear_height(&mut self) {
+        self.height = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_height(&mut self, v: u64) {
+        self.height = v;
+    }
+
+    // .SnapshotManifest manifest = 3;
+
+
+    pub fn get_manifest(&self) -> &SnapshotManifest {
+        self.manifest.as_ref().unwrap_or_else(|| <SnapshotManifest as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_manifest(&mut self) {
+        self.manifest.clear();
+    }
+
+    pub fn has_manifest(&self) -> bool {
+        self.manifest.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_manifest(&mut self, v: SnapshotManifest) {
+        self.manifest = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_manifest(&mut self) -> &mut SnapshotManifest {
+        if self.manifest.is_none() {
+            self.manifest.set_default();
+        }
+        self.manifest.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_manifest(&mut self) -> SnapshotManifest {
+        self.manifest.take().unwrap_or_else(|| SnapshotManifest::new())
+    }
+
+    // .SnapshotChunk chunk = 4;
+
+
+    pub fn get_chunk(&self) -> &SnapshotChunk {
+        self.chunk.as_ref().unwrap_or_else(|| <SnapshotChunk as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_chunk(&mut self) {
+        self.chunk.clear();
+    }
+
+    pub fn has_chunk(&self) -> bool {
+        self.chunk.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chunk(&mut self, v: SnapshotChunk) {
+        self.chunk = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_chunk(&mut self) -> &mut SnapshotChunk {
+        if self.chunk.is_none() {
+            self.chunk.set_default();
+        }
+        self.chunk.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_chunk(&mut self) -> SnapshotChunk {
+        self.chunk.take().unwrap_or_else(|| SnapshotChunk::new())
+    }
+}
+
+impl ::protobuf::Message for SyncSnapshotResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.manifest {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.chunk {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.chain_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.height = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.manifest)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.chunk)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.chain_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.chain_id);
+        }
+        if self.height != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.height, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.manifest.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.chunk.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.chain_id.is_empty() {
+            os.write_string(1, &self.chain_id)?;
+        }
+        if self.height != 0 {
+            os.write_uint64(2, self.height)?;
+        }
+        if let Some(ref v) = self.manifest.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.chunk.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SyncSnapshotResponse {
+        SyncSnapshotResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "chain_id",
+                |m: &SyncSnapshotResponse| { &m.chain_id },
+                |m: &mut SyncSnapshotResponse| { &mut m.chain_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "height",
+                |m: &SyncSnapshotResponse| { &m.height },
+                |m: &mut SyncSnapshotResponse| { &mut m.height },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SnapshotManifest>>(
+                "manifest",
+                |m: &SyncSnapshotResponse| { &m.manifest },
+                |m: &mut SyncSnapshotResponse| { &mut m.manifest },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SnapshotChunk>>(
+                "chunk",
+                |m: &SyncSnapshotResponse| { &m.chunk },
+                |m: &mut SyncSnapshotResponse| { &mut m.chunk },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SyncSnapshotResponse>(
+                "SyncSnapshotResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SyncSnapshotResponse {
+        static instance: ::protobuf::rt::LazyV2<SyncSnapshotResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SyncSnapshotResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for SyncSnapshotResponse {
+    fn clear(&mut self) {
+        self.chain_id.clear();
+        self.height = 0;
+        self.manifest.clear();
+        self.chunk.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SyncSnapshotResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyncSnapshotResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LightHeader {
+    // message fields
+    pub header: ::protobuf::SingularPtrField<LedgerHeader>,
+    pub proof: ::std::vec::Vec<u8>,
+    pub validators_account: ::std::vec::Vec<u8>,
+    pub account_proof: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub validators_entry: ::std::vec::Vec<u8>,
+    pub validators_proof: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub previous_proof: ::std::vec::Vec<u8>,
+    pub tx_hash_list: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a LightHeader {
+    fn default() -> &'a LightHeader {
+        <LightHeader as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LightHeader {
+    pub fn new() -> LightHeader {
+        ::std::default::Default::default()
+    }
+
+    // .LedgerHeader header = 1;
+
+
+    pub fn get_header(&self) -> &LedgerHeader {
+        self.header.as_ref().unwrap_or_else(|| <LedgerHeader as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: LedgerHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut LedgerHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> LedgerHeader {
+        self.header.take().unwrap_or_else(|| LedgerHeader::new())
+    }
+
+    // bytes proof = 2;
+
+
+    pub fn get_proof(&self) -> &[u8] {
+        &self.proof
+    }
+    pub fn clear_proof(&mut self) {
+        self.proof.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_proof(&mut self, v: ::std::vec::Vec<u8>) {
+        self.proof = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_proof(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.proof
+    }
+
+    // Take field
+    pub fn take_proof(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.proof, ::std::vec::Vec::new())
+    }
+
+    // bytes validators_account = 3;
+
+
+    pub fn get_validators_account(&self) -> &[u8] {
+        &self.validators_account
+    }
+    pub fn clear_validators_account(&mut self) {
+        self.validators_account.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_validators_account(&mut self, v: ::std::vec::Vec<u8>) {
+        self.validators_account = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_validators_account(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.validators_account
+    }
+
+    // Take field
+    pub fn take_validators_account(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.validators_account, ::std::vec::Vec::new())
+    }
+
+    // repeated bytes account_proof = 4;
+
+
+    pub fn get_account_proof(&self) -> &[::std::vec::Vec<u8>] {
+        &self.account_proof
+    }
+    pub fn clear_account_proof(&mut self) {
+        self.account_proof.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_proof(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.account_proof = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_account_proof(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.account_proof
+    }
+
+    // Take field
+    pub fn take_account_proof(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.account_proof, ::protobuf::RepeatedField::new())
+    }
+
+    // bytes validators_entry = 5;
+
+
+    pub fn get_validators_entry(&self) -> &[u8] {
+        &self.validators_entry
+    }
+    pub fn clear_validators_entry(&mut self) {
+        self.validators_entry.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_validators_entry(&mut self, v: ::std::vec::Vec<u8>) {
+        self.validators_entry = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_validators_entry(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.validators_entry
+    }
+
+    // Take field
+    pub fn take_validators_entry(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.validators_entry, ::std::vec::Vec::new())
+    }
+
+    // repeated bytes validators_proof = 6;
+
+
+    pub fn get_validators_proof(&self) -> &[::std::vec::Vec<u8>] {
+        &self.validators_proof
+    }
+    pub fn clear_validators_proof(&mut self) {
+        self.validators_proof.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_validators_proof(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.validators_proof = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_validators_proof(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.validators_proof
+    }
+
+    // Take field
+    pub fn take_validators_proof(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.validators_proof, ::protobuf::RepeatedField::new())
+    }
+
+    // bytes previous_proof = 7;
+
+
+    pub fn get_previous_proof(&self) -> &[u8] {
+        &self.previous_proof
+    }
+    pub fn clear_previous_proof(&mut self) {
+        self.previous_proof.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_previous_proof(&mut self, v: ::std::vec::Vec<u8>) {
+        self.previous_proof = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_previous_proof(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.previous_proof
+    }
+
+    // Take field
+    pub fn take_previous_proof(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.previous_proof, ::std::vec::Vec::new())
+    }
+
+    // bytes tx_hash_list = 8;
+
+
+    pub fn get_tx_hash_list(&self) -> &[u8] {
+        &self.tx_hash_list
+    }
+    pub fn clear_tx_hash_list(&mut self) {
+        self.tx_hash_list.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tx_hash_list(&mut self, v: ::std::vec::Vec<u8>) {
+        self.tx_hash_list = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tx_hash_list(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.tx_hash_list
+    }
+
+    // Take field
+    pub fn take_tx_hash_list(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.tx_hash_list, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for LightHeader {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.proof)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.validators_account)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.account_proof)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.validators_entry)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.validators_proof)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.previous_proof)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.tx_hash_list)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.proof.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.proof);
+        }
+        if !self.validators_account.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.validators_account);
+        }
+        for value in &self.account_proof {
+            my_size += ::protobuf::rt::bytes_size(4, &value);
+        };
+        if !self.validators_entry.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(5, &self.validators_entry);
+        }
+        for value in &self.validators_proof {
+            my_size += ::protobuf::rt::bytes_size(6, &value);
+        };
+        if !self.previous_proof.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(7, &self.previous_proof);
+        }
+        if !self.tx_hash_list.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(8, &self.tx_hash_list);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.proof.is_empty() {
+            os.write_bytes(2, &self.proof)?;
+        }
+        if !self.validators_account.is_empty() {
+            os.write_bytes(3, &self.validators_account)?;
+        }
+        for v in &self.account_proof {
+            os.write_bytes(4, &v)?;
+        };
+        if !self.validators_entry.is_empty() {
+            os.write_bytes(5, &self.validators_entry)?;
+        }
+        for v in &self.validators_proof {
+            os.write_bytes(6, &v)?;
+        };
+        if !self.previous_proof.is_empty() {
+            os.write_bytes(7, &self.previous_proof)?;
+        }
+        if !self.tx_hash_list.is_empty() {
+            os.write_bytes(8, &self.tx_hash_list)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LightHeader {
+        LightHeader::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LedgerHeader>>(
+                "header",
+                |m: &LightHeader| { &m.header },
+                |m: &mut LightHeader| { &mut m.header },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "proof",
+                |m: &LightHeader| { &m.proof },
+                |m: &mut LightHeader| { &mut m.proof },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "validators_account",
+                |m: &LightHeader| { &m.validators_account },
+                |m: &mut LightHeader| { &mut m.validators_account },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "account_proof",
+                |m: &LightHeader| { &m.account_proof },
+                |m: &mut LightHeader| { &mut m.account_proof },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "validators_entry",
+                |m: &LightHeader| { &m.validators_entry },
+                |m: &mut LightHeader| { &mut m.validators_entry },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "validators_proof",
+                |m: &LightHeader| { &m.validators_proof },
+                |m: &mut LightHeader| { &mut m.validators_proof },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "previous_proof",
+                |m: &LightHeader| { &m.previous_proof },
+                |m: &mut LightHeader| { &mut m.previous_proof },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "tx_hash_list",
+                |m: &LightHeader| { &m.tx_hash_list },
+                |m: &mut LightHeader| { &mut m.tx_hash_list },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<LightHeader>(
+                "LightHeader",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static LightHeader {
+        static instance: ::protobuf::rt::LazyV2<LightHeader> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(LightHeader::new)
+    }
+}
+
+impl ::protobuf::Clear for LightHeader {
+    fn clear(&mut self) {
+        self.header.clear();
+        self.proof.clear();
+        self.validators_account.clear();
+        self.account_proof.clear();
+        self.validators_entry.clear();
+        self.validators_proof.clear();
+        self.previous_proof.clear();
+        self.tx_hash_list.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LightHeader {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LightHeader {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LightHeaderRequest {
+    // message fields
+    pub chain_id: ::std::string::String,
+    pub begin: u64,
+    pub end: u64,
+    pub requestid: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a LightHeaderRequest {
+    fn default() -> &'a LightHeaderRequest {
+        <LightHeaderRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LightHeaderRequest {
+    pub fn new() -> LightHeaderRequest {
+        ::std::default::Default::default()
+    }
+
+    // string chain_id = 1;
+
+
+    pub fn get_chain_id(&self) -> &str {
+        &self.chain_id
+    }
+    pub fn clear_chain_id(&mut self) {
+        self.chain_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chain_id(&mut self, v: ::std::string::String) {
+        self.chain_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_chain_id(&mut self) -> &mut ::std::string::String {
+        &mut self.chain_id
+    }
+
+    // Take field
+    pub fn take_chain_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.chain_id, ::std::string::String::new())
+    }
+
+    // uint64 begin = 2;
+
+
+    pub fn get_begin(&self) -> u64 {
+        self.begin
+    }
+    pub fn clear_begin(&mut self) {
+        self.begin = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_begin(&mut self, v: u64) {
+        self.begin = v;
+    }
+
+    // uint64 end = 3;
+
+
+    pub fn get_end(&self) -> u64 {
+        self.end
+    }
+    pub fn clear_end(&mut self) {
+        self.end = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end(&mut self, v: u64) {
+        self.end = v;
+    }
+
+    // int64 requestid = 4;
+
+
+    pub fn get_requestid(&self) -> i64 {
+        self.requestid
+    }
+    pub fn clear_requestid(&mut self) {
+        self.requestid = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requestid(&mut self, v: i64) {
+        self.requestid = v;
+    }
+}
+
+impl ::protobuf::Message for LightHeaderRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.chain_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.begin = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.end = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.requestid = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.chain_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.chain_id);
+        }
+        if self.begin != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.begin, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.end != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.end, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.requestid != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.requestid, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.chain_id.is_empty() {
+            os.write_string(1, &self.chain_id)?;
+        }
+        if self.begin != 0 {
+            os.write_uint64(2, self.begin)?;
+        }
+        if self.end != 0 {
+            os.write_uint64(3, self.end)?;
+        }
+        if self.requestid != 0 {
+            os.write_int64(4, self.requestid)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LightHeaderRequest {
+        LightHeaderRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "chain_id",
+                |m: &LightHeaderRequest| { &m.chain_id },
+                |m: &mut LightHeaderRequest| { &mut m.chain_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "begin",
+                |m: &LightHeaderRequest| { &m.begin },
+                |m: &mut LightHeaderRequest| { &mut m.begin },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "end",
+                |m: &LightHeaderRequest| { &m.end },
+                |m: &mut LightHeaderRequest| { &mut m.end },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "requestid",
+                |m: &LightHeaderRequest| { &m.requestid },
+                |m: &mut LightHeaderRequest| { &mut m.requestid },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<LightHeaderRequest>(
+                "LightHeaderRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static LightHeaderRequest {
+        static instance: ::protobuf::rt::LazyV2<LightHeaderRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(LightHeaderRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for LightHeaderRequest {
+    fn clear(&mut self) {
+        self.chain_id.clear();
+        self.begin = 0;
+        self.end = 0;
+        self.requestid = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LightHeaderRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LightHeaderRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LightHeaderResponse {
+    // message fields
+    pub chain_id: ::std::string::String,
+    pub requestid: i64,
+    pub headers: ::protobuf::RepeatedField<LightHeader>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a LightHeaderResponse {
+    fn default() -> &'a LightHeaderResponse {
+        <LightHeaderResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LightHeaderResponse {
+    pub fn new() -> LightHeaderResponse {
+        ::std::default::Default::default()
+    }
+
+    // string chain_id = 1;
+
+
+    pub fn get_chain_id(&self) -> &str {
+        &self.chain_id
+    }
+    pub fn clear_chain_id(&mut self) {
+        self.chain_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chain_id(&mut self, v: ::std::string::String) {
+        self.chain_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_chain_id(&mut self) -> &mut ::std::string::String {
+        &mut self.chain_id
+    }
+
+    // Take field
+    pub fn take_chain_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.chain_id, ::std::string::String::new())
+    }
+
+    // int64 requestid = 2;
+
+
+    pub fn get_requestid(&self) -> i64 {
+        self.requestid
+    }
+    pub fn clear_requestid(&mut self) {
+        self.requestid = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requestid(&mut self, v: i64) {
+        self.requestid = v;
+    }
+
+    // repeated .LightHeader headers = 3;
+
+
+    pub fn get_headers(&self) -> &[LightHeader] {
+        &self.headers
+    }
+    pub fn clear_headers(&mut self) {
+        self.headers.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_headers(&mut self, v: ::protobuf::RepeatedField<LightHeader>) {
+        self.headers = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_headers(&mut self) -> &mut ::protobuf::RepeatedField<LightHeader> {
+        &mut self.headers
+    }
+
+    // Take field
+    pub fn take_headers(&mut self) -> ::protobuf::RepeatedField<LightHeader> {
+        ::std::mem::replace(&mut self.headers, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for LightHeaderResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.headers {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.chain_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.requestid = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.headers)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.chain_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.chain_id);
+        }
+        if self.requestid != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.requestid, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.headers {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.chain_id.is_empty() {
+            os.write_string(1, &self.chain_id)?;
+        }
+        if self.requestid != 0 {
+            os.write_int64(2, self.requestid)?;
+        }
+        for v in &self.headers {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LightHeaderResponse {
+        LightHeaderResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "chain_id",
+                |m: &LightHeaderResponse| { &m.chain_id },
+                |m: &mut LightHeaderResponse| { &mut m.chain_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "requestid",
+                |m: &LightHeaderResponse| { &m.requestid },
+                |m: &mut LightHeaderResponse| { &mut m.requestid },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LightHeader>>(
+                "headers",
+                |m: &LightHeaderResponse| { &m.headers },
+                |m: &mut LightHeaderResponse| { &mut m.headers },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<LightHeaderResponse>(
+                "LightHeaderResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static LightHeaderResponse {
+        static instance: ::protobuf::rt::LazyV2<LightHeaderResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(LightHeaderResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for LightHeaderResponse {
+    fn clear(&mut self) {
+        self.chain_id.clear();
+        self.requestid = 0;
+        self.headers.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LightHeaderResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LightHeaderResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SyncTransactionRequest {
+    // message fields
+    pub chain_id: ::std::string::String,
+    pub requestid: i64,
+    pub hashes: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SyncTransactionRequest {
+    fn default() -> &'a SyncTransactionRequest {
+        <SyncTransactionRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SyncTransactionRequest {
+    pub fn new() -> SyncTransactionRequest {
+        ::std::default::Default::default()
+    }
+
+    // string chain_id = 1;
+
+
+    pub fn get_chain_id(&self) -> &str {
+        &self.chain_id
+    }
+    pub fn clear_chain_id(&mut self) {
+        self.chain_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chain_id(&mut self, v: ::std::string::String) {
+        self.chain_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_chain_id(&mut self) -> &mut ::std::string::String {
+        &mut self.chain_id
+    }
+
+    // Take field
+    pub fn take_chain_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.chain_id, ::std::string::String::new())
+    }
+
+    // int64 requestid = 2;
+
+
+    pub fn get_requestid(&self) -> i64 {
+        self.requestid
+    }
+    pub fn clear_requestid(&mut self) {
+        self.requestid = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requestid(&mut self, v: i64) {
+        self.requestid = v;
+    }
+
+    // repeated bytes hashes = 3;
+
+
+    pub fn get_hashes(&self) -> &[::std::vec::Vec<u8>] {
+        &self.hashes
+    }
+    pub fn clear_hashes(&mut self) {
+        self.hashes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hashes(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.hashes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_hashes(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.hashes
+    }
+
+    // Take field
+    pub fn take_hashes(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.hashes, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for SyncTransactionRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.chain_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.requestid = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.hashes)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.chain_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.chain_id);
+        }
+        if self.requestid != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.requestid, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.hashes {
+            my_size += ::protobuf::rt::bytes_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.chain_id.is_empty() {
+            os.write_string(1, &self.chain_id)?;
+        }
+        if self.requestid != 0 {
+            os.write_int64(2, self.requestid)?;
+        }
+        for v in &self.hashes {
+            os.write_bytes(3, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SyncTransactionRequest {
+        SyncTransactionRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "chain_id",
+                |m: &SyncTransactionRequest| { &m.chain_id },
+                |m: &mut SyncTransactionRequest| { &mut m.chain_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "requestid",
+                |m: &SyncTransactionRequest| { &m.requestid },
+                |m: &mut SyncTransactionRequest| { &mut m.requestid },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "hashes",
+                |m: &SyncTransactionRequest| { &m.hashes },
+                |m: &mut SyncTransactionRequest| { &mut m.hashes },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SyncTransactionRequest>(
+                "SyncTransactionRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SyncTransactionRequest {
+        static instance: ::protobuf::rt::LazyV2<SyncTransactionRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SyncTransactionRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SyncTransactionRequest {
+    fn clear(&mut self) {
+        self.chain_id.clear();
+        self.requestid = 0;
+        self.hashes.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SyncTransactionRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyncTransactionRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SyncTransactionResponse {
+    // message fields
+    pub chain_id: ::std::string::String,
+    pub requestid: i64,
+    pub transactions: ::protobuf::RepeatedField<TransactionSign>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SyncTransactionResponse {
+    fn default() -> &'a SyncTransactionResponse {
+        <SyncTransactionResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SyncTransactionResponse {
+    pub fn new() -> SyncTransactionResponse {
+        ::std::default::Default::default()
+    }
+
+    // string chain_id = 1;
+
+
+    pub fn get_chain_id(&self) -> &str {
+        &self.chain_id
+    }
+    pub fn clear_chain_id(&mut self) {
+        self.chain_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chain_id(&mut self, v: ::std::string::String) {
+        self.chain_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_chain_id(&mut self) -> &mut ::std::string::String {
+        &mut self.chain_id
+    }
+
+    // Take field
+    pub fn take_chain_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.chain_id, ::std::string::String::new())
+    }
+
+    // int64 requestid = 2;
+
+
+    pub fn get_requestid(&self) -> i64 {
+        self.requestid
+    }
+    pub fn clear_requestid(&mut self) {
+        self.requestid = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requestid(&mut self, v: i64) {
+        self.requestid = v;
+    }
+
+    // repeated .TransactionSign transactions = 3;
+
+
+    pub fn get_transactions(&self) -> &[TransactionSign] {
+        &self.transactions
+    }
+    pub fn clear_transactions(&mut self) {
+        self.transactions.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_transactions(&mut self, v: ::protobuf::RepeatedField<TransactionSign>) {
+        self.transactions = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_transactions(&mut self) -> &mut ::protobuf::RepeatedField<TransactionSign> {
+        &mut self.transactions
+    }
+
+    // Take field
+    pub fn take_transactions(&mut self) -> ::protobuf::RepeatedField<TransactionSign> {
+        ::std::mem::replace(&mut self.transactions, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for SyncTransactionResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.transactions {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.chain_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.requestid = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.transactions)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.chain_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.chain_id);
+        }
+        if self.requestid != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.requestid, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.transactions {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.chain_id.is_empty() {
+            os.write_string(1, &self.chain_id)?;
+        }
+        if self.requestid != 0 {
+            os.write_int64(2, self.requestid)?;
+        }
+        for v in &self.transactions {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SyncTransactionResponse {
+        SyncTransactionResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "chain_id",
+                |m: &SyncTransactionResponse| { &m.chain_id },
+                |m: &mut SyncTransactionResponse| { &mut m.chain_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "requestid",
+                |m: &SyncTransactionResponse| { &m.requestid },
+                |m: &mut SyncTransactionResponse| { &mut m.requestid },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TransactionSign>>(
+                "transactions",
+                |m: &SyncTransactionResponse| { &m.transactions },
+                |m: &mut SyncTransactionResponse| { &mut m.transactions },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SyncTransactionResponse>(
+                "SyncTransactionResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SyncTransactionResponse {
+        static instance: ::protobuf::rt::LazyV2<SyncTransactionResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SyncTransactionResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for SyncTransactionResponse {
+    fn clear(&mut self) {
+        self.chain_id.clear();
+        self.requestid = 0;
+        self.transactions.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SyncTransactionResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyncTransactionResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum TransactionType {
     EVM_GENO = 0,
@@ -4369,9 +7408,49 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x06number\x18\x01\x20\x01(\x03R\x06numberB\0\x12\x1e\n\trequestid\x18\
     \x02\x20\x01(\x03R\trequestidB\0\x12\x18\n\x06finish\x18\x03\x20\x01(\
     \x08R\x06finishB\0\x12\x1b\n\x08chain_id\x18\x04\x20\x01(\tR\x07chainIdB\
-    \0\x12!\n\x06blocks\x18\x05\x20\x03(\x0b2\x07.LedgerR\x06blocksB\0:\0*;\
-    \n\x0fTransactionType\x12\x0c\n\x08EVM_GENO\x10\0\x12\x0e\n\nETH_LEGACY\
-    \x10\x01\x12\x08\n\x04WASM\x10\x02\x1a\0B\0b\x06proto3\
+    \0\x12!\n\x06blocks\x18\x05\x20\x03(\x0b2\x07.LedgerR\x06blocksB\0:\0\"=\
+    \n\rSnapshotEntry\x12\x12\n\x03key\x18\x01\x20\x01(\x0cR\x03keyB\0\x12\
+    \x16\n\x05value\x18\x02\x20\x01(\x0cR\x05valueB\0:\0\"i\n\x0fSnapshotAcc\
+    ount\x12$\n\x07account\x18\x01\x20\x01(\x0b2\x08.AccountR\x07accountB\0\
+    \x12.\n\tmetadatas\x18\x02\x20\x03(\x0b2\x0e.SnapshotEntryR\tmetadatasB\
+    \0:\0\"Y\n\rSnapshotChunk\x12\x16\n\x05index\x18\x01\x20\x01(\x04R\x05in\
+    dexB\0\x12.\n\x08accounts\x18\x02\x20\x03(\x0b2\x10.SnapshotAccountR\x08\
+    accountsB\0:\0\"\xe8\x01\n\x10SnapshotManifest\x12\x1b\n\x08chain_id\x18\
+    \x01\x20\x01(\tR\x07chainIdB\0\x12'\n\x06header\x18\x02\x20\x01(\x0b2\r.\
+    LedgerHeaderR\x06headerB\0\x12\x16\n\x05proof\x18\x03\x20\x01(\x0cR\x05p\
+    roofB\0\x12%\n\raccount_count\x18\x04\x20\x01(\x04R\x0caccountCountB\0\
+    \x12#\n\x0cchunk_hashes\x18\x05\x20\x03(\x0cR\x0bchunkHashesB\0\x12(\n\n\
+    next_block\x18\x06\x20\x01(\x0b2\x07.LedgerR\tnextBlockB\0:\0\"\x84\x01\
+    \n\x13SyncSnapshotRequest\x12\x1b\n\x08chain_id\x18\x01\x20\x01(\tR\x07c\
+    hainIdB\0\x12\x18\n\x06height\x18\x02\x20\x01(\x04R\x06heightB\0\x12\x1c\
+    \n\x08manifest\x18\x03\x20\x01(\x08R\x08manifestB\0\x12\x16\n\x05index\
+    \x18\x04\x20\x01(\x04R\x05indexB\0:\0\"\xa8\x01\n\x14SyncSnapshotRespons\
+    e\x12\x1b\n\x08chain_id\x18\x01\x20\x01(\tR\x07chainIdB\0\x12\x18\n\x06h\
+    eight\x18\x02\x20\x01(\x04R\x06heightB\0\x12/\n\x08manifest\x18\x03\x20\
+    \x01(\x0b2\x11.SnapshotManifestR\x08manifestB\0\x12&\n\x05chunk\x18\x04\
+    \x20\x01(\x0b2\x0e.SnapshotChunkR\x05chunkB\0:\0\"\xcf\x02\n\x0bLightHea\
+    der\x12'\n\x06header\x18\x01\x20\x01(\x0b2\r.LedgerHeaderR\x06headerB\0\
+    \x12\x16\n\x05proof\x18\x02\x20\x01(\x0cR\x05proofB\0\x12/\n\x12validato\
+    rs_account\x18\x03\x20\x01(\x0cR\x11validatorsAccountB\0\x12%\n\raccount\
+    _proof\x18\x04\x20\x03(\x0cR\x0caccountProofB\0\x12+\n\x10validators_ent\
+    ry\x18\x05\x20\x01(\x0cR\x0fvalidatorsEntryB\0\x12+\n\x10validators_proo\
+    f\x18\x06\x20\x03(\x0cR\x0fvalidatorsProofB\0\x12'\n\x0eprevious_proof\
+    \x18\x07\x20\x01(\x0cR\rpreviousProofB\0\x12\"\n\x0ctx_hash_list\x18\x08\
+    \x20\x01(\x0cR\ntxHashListB\0:\0\"\x7f\n\x12LightHeaderRequest\x12\x1b\n\
+    \x08chain_id\x18\x01\x20\x01(\tR\x07chainIdB\0\x12\x16\n\x05begin\x18\
+    \x02\x20\x01(\x04R\x05beginB\0\x12\x12\n\x03end\x18\x03\x20\x01(\x04R\
+    \x03endB\0\x12\x1e\n\trequestid\x18\x04\x20\x01(\x03R\trequestidB\0:\0\"\
+    ~\n\x13LightHeaderResponse\x12\x1b\n\x08chain_id\x18\x01\x20\x01(\tR\x07\
+    chainIdB\0\x12\x1e\n\trequestid\x18\x02\x20\x01(\x03R\trequestidB\0\x12(\
+    \n\x07headers\x18\x03\x20\x03(\x0b2\x0c.LightHeaderR\x07headersB\0:\0\"q\
+    \n\x16SyncTransactionRequest\x12\x1b\n\x08chain_id\x18\x01\x20\x01(\tR\
+    \x07chainIdB\0\x12\x1e\n\trequestid\x18\x02\x20\x01(\x03R\trequestidB\0\
+    \x12\x18\n\x06hashes\x18\x03\x20\x03(\x0cR\x06hashesB\0:\0\"\x90\x01\n\
+    \x17SyncTransactionResponse\x12\x1b\n\x08chain_id\x18\x01\x20\x01(\tR\
+    \x07chainIdB\0\x12\x1e\n\trequestid\x18\x02\x20\x01(\x03R\trequestidB\0\
+    \x126\n\x0ctransactions\x18\x03\x20\x03(\x0b2\x10.TransactionSignR\x0ctr\
+    ansactionsB\0:\0*;\n\x0fTransactionType\x12\x0c\n\x08EVM_GENO\x10\0\x12\
+    \x0e\n\nETH_LEGACY\x10\x01\x12\x08\n\x04WASM\x10\x02\x1a\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -3,9 +3,17 @@ use std::sync::{
     Arc,
 };
 
-use catchup::{catchuper::Catchuper, network::CatchupNetwork, storage_executor::StoreageExecutor};
-use configure::CONFIGURE_INSTANCE_REF;
+use catchup::{
+    catchuper::Catchuper, light::LightClient, network::CatchupNetwork,
+    storage_executor::StoreageExecutor,
+};
+use configure::{
+    NodeMode, CONFIGURE_INSTANCE_REF, CONSENSUS_TYPE_HOTSTUFF, CONSENSUS_TYPE_INSTANT,
+    CONSENSUS_TYPE_PBFT, CONSENSUS_TYPE_SOLO,
+};
+use consensus_hotstuff::bootstrap::start_hotstuff_consensus;
 use consensus_pbft::bootstrap::start_consensus;
+use consensus_solo::bootstrap::start_solo_consensus;
 use executor::BlockExecutor;
 use jsonrpc::bootstrap::start_jsonrpc_service;
 use network::{NetworkConfigType, PeerNetwork};
@@ -14,7 +22,9 @@ use syscontract::{
     system_address::initialize_syscontract_address,
 };
 use tx_pool::start_txpool_service;
-use utils::{logger::LogUtil, timer_manager::initialize_timer_manager};
+use utils::{
+    logger::LogUtil, signer::initialize_node_signer, timer_manager::initialize_timer_manager,
+};
 
 fn main() {
     let _guard = LogUtil::init("./log", "app.log", "setting/log_filter.txt").unwrap();
@@ -27,22 +37,81 @@ fn main() {
     initialize_system_contract_factory();
     initialize_timer_manager();
 
-    if let Err(e) = BlockExecutor::block_initialize() {
+    let light = CONFIGURE_INSTANCE_REF.db.node_mode == NodeMode::Light;
+    let initialized = if light {
+        LightClient::<CatchupNetwork>::initialize()
+    } else {
+        BlockExecutor::block_initialize()
+    };
+    if let Err(e) = initialized {
         eprintln!("start block error:{}", e);
         std::process::exit(1);
     }
+    // light nodes never sign, full nodes sign consensus messages with the configured signer
+    if !light {
+        if let Err(e) = initialize_node_signer(&CONFIGURE_INSTANCE_REF) {
+            eprintln!("node signer error:{}", e);
+            std::process::exit(1);
+        }
+    }
+
+    let consensus_type = CONFIGURE_INSTANCE_REF.consensus.consensus_type.as_str();
+    let solo = consensus_type == CONSENSUS_TYPE_SOLO || consensus_type == CONSENSUS_TYPE_INSTANT;
+    if !solo && consensus_type != CONSENSUS_TYPE_PBFT && consensus_type != CONSENSUS_TYPE_HOTSTUFF {
+        eprintln!("unknown consensus type:{}", consensus_type);
+        std::process::exit(1);
+    }
+    if !light && solo {
+        // a single node sealing alone, no peers at all
+        let (
+            _jsonrpc_runtime,
+            _ws_runtime,
+            jsonrpc_to_txpool_receiver,
+            ws_event_sender,
+            pending_tx_sender,
+        ) = or_exit(start_jsonrpc_service(&CONFIGURE_INSTANCE_REF.json_rpc));
+        let (_txpool_runtime, _broadcast_tx_sender, consensus_committed_sender) =
+            start_txpool_service(
+                &CONFIGURE_INSTANCE_REF.tx_pool,
+                jsonrpc_to_txpool_receiver,
+                pending_tx_sender,
+                None,
+            );
+        start_solo_consensus(
+            consensus_committed_sender,
+            ws_event_sender,
+            consensus_type == CONSENSUS_TYPE_INSTANT,
+        );
+        loop {
+            std::thread::park();
+        }
+    }
 
     let network = PeerNetwork::start_service("peers", NetworkConfigType::Normal);
+    if light {
+        // headers only, the json-rpc forwards what needs state to a full node
+        let _jsonrpc = or_exit(start_jsonrpc_service(&CONFIGURE_INSTANCE_REF.json_rpc));
+        LightClient::create_and_start(CatchupNetwork::new(network));
+        loop {
+            std::thread::park();
+        }
+    }
     let network_consensus =
         PeerNetwork::start_service("consensus_peers", NetworkConfigType::Consensus);
 
-    let (jsonrpc_runtime, ws_runtime, jsonrpc_to_txpool_receiver, ws_event_sender) =
-        start_jsonrpc_service(&CONFIGURE_INSTANCE_REF.json_rpc);
+    let (
+        jsonrpc_runtime,
+        ws_runtime,
+        jsonrpc_to_txpool_receiver,
+        ws_event_sender,
+        pending_tx_sender,
+    ) = or_exit(start_jsonrpc_service(&CONFIGURE_INSTANCE_REF.json_rpc));
 
     let (txpool_runtime, broadcast_tx_sender, consensus_committed_sender) = start_txpool_service(
         &CONFIGURE_INSTANCE_REF.tx_pool,
         jsonrpc_to_txpool_receiver,
-        network.clone(),
+        pending_tx_sender,
+        Some(network.clone()),
     );
 
     let commit_notify = Catchuper::create_and_start(
@@ -51,13 +120,23 @@ fn main() {
         broadcast_tx_sender,
     );
 
-    start_consensus(
-        network,
-        network_consensus,
-        consensus_committed_sender,
-        ws_event_sender,
-        commit_notify,
-    );
+    if consensus_type == CONSENSUS_TYPE_HOTSTUFF {
+        start_hotstuff_consensus(
+            network,
+            network_consensus,
+            consensus_committed_sender,
+            ws_event_sender,
+            commit_notify,
+        );
+    } else {
+        start_consensus(
+            network,
+            network_consensus,
+            consensus_committed_sender,
+            ws_event_sender,
+            commit_notify,
+        );
+    }
 
     let term = Arc::new(AtomicBool::new(false));
     while !term.load(Ordering::Acquire) {
@@ -65,3 +144,13 @@ fn main() {
     }
     //test
 }
+
+fn or_exit<T, E: std::fmt::Display>(result: Result<T, E>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(e) => {
+            eprintln!("start error:{}", e);
+            std::process::exit(1);
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{cache_state::StateMapActionType, TrieHash, TrieReader, TrieWriter, TRIE_KEY_MAX_LEN};
+use crate::{
+    cache_state::StateMapActionType, TrieHash, TrieJournal, TrieReader, TrieWriter,
+    TRIE_KEY_MAX_LEN,
+};
 use log::*;
 use protobuf::Message;
 use protos::{
@@ -291,7 +294,11 @@ impl AccountFrame {
         self.account.set_metadata_hash(root);
     }
 
-    pub fn commit_metadata_trie(&mut self, batch: &mut MemWriteBatch) -> anyhow::Result<()> {
+    pub fn commit_metadata_trie(
+        &mut self,
+        batch: &mut MemWriteBatch,
+        journal: &mut TrieJournal,
+    ) -> anyhow::Result<()> {
         // no chang nothing to do
         if self.metadata.is_empty() {
             return Ok(());
@@ -319,11 +326,11 @@ impl AccountFrame {
         let state_db = STORAGE_INSTANCE_REF.account_db();
         let meta_hash = self.account.get_metadata_hash();
         let result = if meta_hash.len() == 0 {
-            TrieWriter::commit(state_db, None, &mut datas, batch)
+            TrieWriter::commit(state_db, None, &mut datas, batch, journal)
         } else {
             let mut root_hash: TrieHash = Default::default();
             root_hash.clone_from_slice(&meta_hash[0..32]);
-            TrieWriter::commit(state_db, Some(root_hash), &mut datas, batch)
+            TrieWriter::commit(state_db, Some(root_hash), &mut datas, batch, journal)
         };
 
         match result {
@@ -369,6 +376,28 @@ impl AccountFrame {
         }
     }
 
+    /// The committed metadata trie entry of a contract storage key and its proof to
+    /// the metadata hash, both empty when the account has no metadata.
+    pub fn contract_metadata_proof(
+        &self,
+        inner_key: &[u8],
+    ) -> anyhow::Result<(Option<Vec<u8>>, Vec<Vec<u8>>)> {
+        let meta_hash = self.account.get_metadata_hash();
+        if meta_hash.len() == 0 {
+            return Ok((None, Vec::new()));
+        }
+
+        let mut root_hash: TrieHash = Default::default();
+        root_hash.clone_from_slice(&meta_hash[0..32]);
+        let outer_key =
+            compose_metadata_key(CONTRACT_META_PREFIX, self.account.get_address(), inner_key);
+        let state_db = STORAGE_INSTANCE_REF.account_db();
+        let reader = TrieReader::new(state_db, Some(root_hash));
+        let entry = reader.get(&outer_key)?;
+        let proof = reader.get_proof(&[outer_key])?;
+        Ok((entry, proof))
+    }
+
     pub fn serialize(&self) -> Vec<u8> {
         self.account.write_to_bytes().unwrap().clone()
     }
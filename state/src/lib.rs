@@ -6,7 +6,7 @@ pub mod trie_hashdb;
 
 pub use account_frame::AccountFrame;
 pub use cache_state::CacheState;
-pub use trie::{TrieHash, TrieReader, TrieWriter};
+pub use trie::{empty_trie_root, is_trie_node, TrieHash, TrieJournal, TrieReader, TrieWriter};
 
 use parking_lot::RwLock;
 use std::sync::Arc;
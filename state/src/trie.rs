@@ -1,13 +1,40 @@
 use crate::TrieHashDB;
-use hash_db::Hasher;
+use hash_db::{HashDB, Hasher};
 use keccak_hasher::KeccakHasher;
+use msp::bytes_to_hex_str;
 use reference_trie::ExtensionLayout;
 use std::collections::HashMap;
 use storage_db::{KeyValueDB, MemWriteBatch, WriteBatchTrait};
-use trie_db::{Trie, TrieDBBuilder, TrieDBMutBuilder, TrieMut};
+use trie_db::{
+    node::{Node, NodeHandle, Value},
+    proof::{generate_proof, verify_proof},
+    NibbleVec, NodeCodec, Trie, TrieDBBuilder, TrieDBMutBuilder, TrieLayout, TrieMut,
+};
 
 pub type TrieHash = <KeccakHasher as Hasher>::Out;
 
+/// Trie nodes the commit of one block wrote and replaced. Replaced nodes may still back
+/// older state roots, so they stay in the db until pruning releases them.
+#[derive(Default)]
+pub struct TrieJournal {
+    pub inserted: Vec<Vec<u8>>,
+    pub removed: Vec<Vec<u8>>,
+    /// metadata roots of the accounts the block deleted
+    pub dropped_roots: Vec<TrieHash>,
+    /// code hashes of the contracts the block deleted
+    pub dropped_code: Vec<Vec<u8>>,
+}
+
+/// Whether a db pair is a trie node, nodes are stored under their hash.
+pub fn is_trie_node(key: &[u8], value: &[u8]) -> bool {
+    key.len() >= 32 && KeccakHasher::hash(value)[..] == key[key.len() - 32..]
+}
+
+/// Root of a trie without entries.
+pub fn empty_trie_root() -> TrieHash {
+    KeccakHasher::hash(&[0u8][..])
+}
+
 pub struct TrieReader {
     pub root: TrieHash,
     trie_db: TrieHashDB,
@@ -55,6 +82,136 @@ impl TrieReader {
             Err(err) => return Err(anyhow::anyhow!("trie iter error:{}", err.to_string())),
         }
     }
+
+    /// Compact proof of the values of `keys`, or of their absence, under the root.
+    pub fn get_proof(&self, keys: &[Vec<u8>]) -> anyhow::Result<Vec<Vec<u8>>> {
+        match generate_proof::<_, ExtensionLayout, _, _>(&self.trie_db, &self.root, keys) {
+            Ok(proof) => return Ok(proof),
+            Err(err) => return Err(anyhow::anyhow!("trie proof error:{}", err.to_string())),
+        }
+    }
+
+    /// Check a `get_proof` result, a None value proves the key is absent.
+    pub fn verify_proof(
+        root: &TrieHash,
+        proof: &[Vec<u8>],
+        items: &[(Vec<u8>, Option<Vec<u8>>)],
+    ) -> anyhow::Result<()> {
+        match verify_proof::<ExtensionLayout, _, _, _>(root, proof, items) {
+            Ok(()) => return Ok(()),
+            Err(err) => return Err(anyhow::anyhow!("trie proof verify error:{}", err)),
+        }
+    }
+
+    /// Walk the nodes stored under the root depth first. `visit` gets the db key of each
+    /// node and returns false to skip its children, `leaf` gets the values of the leaves.
+    pub fn walk(
+        &self,
+        visit: &mut dyn FnMut(&[u8]) -> bool,
+        leaf: &mut dyn FnMut(&[u8]),
+    ) -> anyhow::Result<()> {
+        if self.root == TrieHash::default() || self.root == empty_trie_root() {
+            return Ok(());
+        }
+        self.walk_node(&self.root, &NibbleVec::new(), visit, leaf)
+    }
+
+    fn walk_node(
+        &self,
+        hash: &TrieHash,
+        prefix: &NibbleVec,
+        visit: &mut dyn FnMut(&[u8]) -> bool,
+        leaf: &mut dyn FnMut(&[u8]),
+    ) -> anyhow::Result<()> {
+        let key = memory_db::prefixed_key::<KeccakHasher>(hash, prefix.as_prefix());
+        if !visit(&key) {
+            return Ok(());
+        }
+        match HashDB::get(&self.trie_db, hash, prefix.as_prefix()) {
+            Some(data) => self.walk_data(&data, prefix, visit, leaf),
+            None => Err(anyhow::anyhow!(
+                "trie node {} missing",
+                bytes_to_hex_str(&key)
+            )),
+        }
+    }
+
+    fn walk_data(
+        &self,
+        data: &[u8],
+        prefix: &NibbleVec,
+        visit: &mut dyn FnMut(&[u8]) -> bool,
+        leaf: &mut dyn FnMut(&[u8]),
+    ) -> anyhow::Result<()> {
+        let node = match <ExtensionLayout as TrieLayout>::Codec::decode(data) {
+            Ok(node) => node,
+            Err(err) => return Err(anyhow::anyhow!("trie node decode error:{:?}", err)),
+        };
+        match node {
+            Node::Empty => {}
+            Node::Leaf(_, value) => Self::walk_value(&value, leaf),
+            Node::Extension(partial, child) => {
+                let mut child_prefix = prefix.clone();
+                child_prefix.append_partial(partial.right());
+                self.walk_handle(&child, &child_prefix, visit, leaf)?;
+            }
+            Node::Branch(children, value) => {
+                if let Some(value) = value {
+                    Self::walk_value(&value, leaf);
+                }
+                for (i, child) in children.iter().enumerate() {
+                    if let Some(child) = child {
+                        let mut child_prefix = prefix.clone();
+                        child_prefix.push(i as u8);
+                        self.walk_handle(child, &child_prefix, visit, leaf)?;
+                    }
+                }
+            }
+            Node::NibbledBranch(partial, children, value) => {
+                if let Some(value) = value {
+                    Self::walk_value(&value, leaf);
+                }
+                let mut branch_prefix = prefix.clone();
+                branch_prefix.append_partial(partial.right());
+                for (i, child) in children.iter().enumerate() {
+                    if let Some(child) = child {
+                        let mut child_prefix = branch_prefix.clone();
+                        child_prefix.push(i as u8);
+                        self.walk_handle(child, &child_prefix, visit, leaf)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn walk_handle(
+        &self,
+        handle: &NodeHandle,
+        prefix: &NibbleVec,
+        visit: &mut dyn FnMut(&[u8]) -> bool,
+        leaf: &mut dyn FnMut(&[u8]),
+    ) -> anyhow::Result<()> {
+        match handle {
+            NodeHandle::Hash(bytes) => {
+                let mut hash = TrieHash::default();
+                if bytes.len() != hash.len() {
+                    return Err(anyhow::anyhow!("trie node hash length {}", bytes.len()));
+                }
+                hash.copy_from_slice(bytes);
+                self.walk_node(&hash, prefix, visit, leaf)
+            }
+            // inline nodes live inside their parent, only their children are stored
+            NodeHandle::Inline(data) => self.walk_data(data, prefix, visit, leaf),
+        }
+    }
+
+    // values are always inlined by this layout
+    fn walk_value(value: &Value, leaf: &mut dyn FnMut(&[u8])) {
+        if let Value::Inline(data) = value {
+            leaf(data);
+        }
+    }
 }
 
 pub struct TrieWriter;
@@ -65,6 +222,7 @@ impl TrieWriter {
         root_hash: Option<TrieHash>,
         datas: &HashMap<Vec<u8>, Option<Vec<u8>>>,
         batch: &mut MemWriteBatch,
+        journal: &mut TrieJournal,
     ) -> anyhow::Result<TrieHash> {
         let mut trie_db = TrieHashDB::new(db, None);
         let (mut root, exist) = match root_hash {
@@ -97,11 +255,118 @@ impl TrieWriter {
 
         for (key, value) in trie_db.cache {
             match value {
-                Some(value) => batch.set(key, value),
-                None => batch.delete(key),
+                Some(value) => {
+                    journal.inserted.push(key.clone());
+                    batch.set(key, value);
+                }
+                None => journal.removed.push(key),
             }
         }
 
         Ok(new_root)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use storage_db::{key_value_db::KeyValueDb, memorydb::MemoryDB};
+
+    fn entries() -> Vec<(Vec<u8>, Vec<u8>)> {
+        (0..16u8)
+            .map(|i| (format!("account-{}", i).into_bytes(), vec![i; 40]))
+            .collect()
+    }
+
+    fn build_trie(entries: &[(Vec<u8>, Vec<u8>)]) -> TrieReader {
+        let db = MemoryDB::open();
+        let datas = entries
+            .iter()
+            .map(|(k, v)| (k.clone(), Some(v.clone())))
+            .collect();
+        let mut batch = MemWriteBatch::new();
+        let root = TrieWriter::commit(
+            db.clone(),
+            None,
+            &datas,
+            &mut batch,
+            &mut TrieJournal::default(),
+        )
+        .unwrap();
+        db.lock().write_batch(batch).unwrap();
+        TrieReader::new(db, Some(root))
+    }
+
+    #[test]
+    fn proof_round_trip_test() {
+        let entries = entries();
+        let reader = build_trie(&entries);
+
+        let (key, value) = &entries[3];
+        let proof = reader.get_proof(&[key.clone()]).unwrap();
+        assert!(TrieReader::verify_proof(
+            &reader.root,
+            &proof,
+            &[(key.clone(), Some(value.clone()))]
+        )
+        .is_ok());
+
+        let keys: Vec<Vec<u8>> = entries.iter().map(|(k, _)| k.clone()).collect();
+        let items: Vec<(Vec<u8>, Option<Vec<u8>>)> = entries
+            .iter()
+            .map(|(k, v)| (k.clone(), Some(v.clone())))
+            .collect();
+        let proof = reader.get_proof(&keys).unwrap();
+        assert!(TrieReader::verify_proof(&reader.root, &proof, &items).is_ok());
+    }
+
+    #[test]
+    fn absent_key_proof_test() {
+        let entries = entries();
+        let reader = build_trie(&entries);
+
+        let absent = b"account-99".to_vec();
+        assert_eq!(reader.get(&absent).unwrap(), None);
+        let proof = reader.get_proof(&[absent.clone()]).unwrap();
+        assert!(TrieReader::verify_proof(&reader.root, &proof, &[(absent.clone(), None)]).is_ok());
+        // the absence proof does not prove a value
+        assert!(
+            TrieReader::verify_proof(&reader.root, &proof, &[(absent, Some(vec![1; 40]))]).is_err()
+        );
+
+        // nor does a presence proof prove absence
+        let (key, _) = &entries[0];
+        let proof = reader.get_proof(&[key.clone()]).unwrap();
+        assert!(TrieReader::verify_proof(&reader.root, &proof, &[(key.clone(), None)]).is_err());
+    }
+
+    #[test]
+    fn tampered_proof_test() {
+        let entries = entries();
+        let reader = build_trie(&entries);
+        let (key, value) = &entries[5];
+        let items = [(key.clone(), Some(value.clone()))];
+        let proof = reader.get_proof(&[key.clone()]).unwrap();
+
+        let wrong_value = [(key.clone(), Some(vec![0xff; 40]))];
+        assert!(TrieReader::verify_proof(&reader.root, &proof, &wrong_value).is_err());
+
+        let other_root = build_trie(&entries[1..]).root;
+        assert!(TrieReader::verify_proof(&other_root, &proof, &items).is_err());
+
+        for i in 0..proof.len() {
+            let mut tampered = proof.clone();
+            let last = tampered[i].len() - 1;
+            tampered[i][last] ^= 1;
+            assert!(TrieReader::verify_proof(&reader.root, &tampered, &items).is_err());
+
+            let mut missing = proof.clone();
+            missing.remove(i);
+            assert!(TrieReader::verify_proof(&reader.root, &missing, &items).is_err());
+        }
+
+        let mut extra = proof.clone();
+        extra.push(proof[0].clone());
+        assert!(TrieReader::verify_proof(&reader.root, &extra, &items).is_err());
+    }
+}
@@ -1,6 +1,9 @@
 use protobuf::{Message, RepeatedField};
-use protos::common::ValidatorSet;
-use storage_db::{MemWriteBatch, WriteBatchTrait, STORAGE_INSTANCE_REF};
+use protos::{
+    common::ValidatorSet,
+    consensus::{BftMessageType, BftSign},
+};
+use storage_db::{KeyValueDB, MemWriteBatch, WriteBatchTrait, STORAGE_INSTANCE_REF};
 use utils::{general::compose_prefix_u64, parse::ProtocolParser};
 
 pub const CONSENSUS_PREFIX: &str = "consensus";
@@ -8,6 +11,15 @@ pub const VIEW_ACTIVE: &str = "bft_view_active";
 pub const SEQUENCE_NAME: &str = "bft_sequence";
 pub const VIEW_NUMBER_NAME: &str = "bft_view_number";
 pub const VALIDATORS_PREFIX: &str = "validators";
+/// Write-ahead log of the in-flight bft messages, keyed by view, class, sequence, message type
+/// and replica id so a scan returns them in the order they are replayed. View change and new
+/// view messages are keyed without their sequence, a replica has one of each per view.
+pub const BFT_MESSAGE_PREFIX: &str = "consensus_bft_message_";
+
+// within a view the view changes go first, then the new view, then the instances of the view
+const VIEW_CHANGE_CLASS: u8 = 0;
+const NEW_VIEW_CLASS: u8 = 1;
+const INSTANCE_CLASS: u8 = 2;
 pub struct BftStorage {}
 
 impl BftStorage {
@@ -127,4 +139,316 @@ impl BftStorage {
         let key = compose_prefix_u64(VALIDATORS_PREFIX, seq);
         STORAGE_INSTANCE_REF.key_value_db().lock().delete(key)
     }
+
+    /// Log a pre-prepare, prepare, commit, view change or new view message. Other messages are
+    /// not logged.
+    pub fn store_message(bft_sign: &BftSign) -> anyhow::Result<()> {
+        Self::store_message_in(&STORAGE_INSTANCE_REF.key_value_db(), bft_sign)
+    }
+
+    /// The logged messages in replay order.
+    pub fn load_messages() -> anyhow::Result<Vec<BftSign>> {
+        Self::load_messages_in(&STORAGE_INSTANCE_REF.key_value_db())
+    }
+
+    /// Drop the instance messages up to the checkpoint `sequence` and the view change and new
+    /// view messages up to the entered `view_number`.
+    pub fn delete_messages(sequence: u64, view_number: i64) -> anyhow::Result<()> {
+        Self::delete_messages_in(&STORAGE_INSTANCE_REF.key_value_db(), sequence, view_number)
+    }
+
+    fn store_message_in(db: &KeyValueDB, bft_sign: &BftSign) -> anyhow::Result<()> {
+        match Self::message_key(bft_sign) {
+            Some(key) => db
+                .lock()
+                .put(key, ProtocolParser::serialize::<BftSign>(bft_sign)),
+            None => Ok(()),
+        }
+    }
+
+    fn load_messages_in(db: &KeyValueDB) -> anyhow::Result<Vec<BftSign>> {
+        let mut values = Vec::new();
+        db.lock()
+            .scan(BFT_MESSAGE_PREFIX.as_bytes(), &mut |_, value| {
+                values.push(value.to_vec());
+                true
+            })?;
+        values
+            .iter()
+            .map(|value| ProtocolParser::deserialize::<BftSign>(value))
+            .collect()
+    }
+
+    fn delete_messages_in(db: &KeyValueDB, sequence: u64, view_number: i64) -> anyhow::Result<()> {
+        let mut batch = MemWriteBatch::new();
+        let mut db = db.lock();
+        db.scan(BFT_MESSAGE_PREFIX.as_bytes(), &mut |key, _| {
+            let rest = &key[BFT_MESSAGE_PREFIX.len()..];
+            if rest.len() < 17 {
+                return true;
+            }
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&rest[0..8]);
+            let view = u64::from_be_bytes(bytes) as i64;
+            bytes.copy_from_slice(&rest[9..17]);
+            let seq = u64::from_be_bytes(bytes);
+            let expired = if rest[8] == INSTANCE_CLASS {
+                seq <= sequence
+            } else {
+                view <= view_number
+            };
+            if expired {
+                batch.delete(key.to_vec());
+            }
+            true
+        })?;
+        db.write_batch(batch)
+    }
+
+    fn message_key(bft_sign: &BftSign) -> Option<Vec<u8>> {
+        let bft = bft_sign.get_bft();
+        let (class, base) = match bft.get_msg_type() {
+            BftMessageType::PRE_PREPARE => (INSTANCE_CLASS, bft.get_pre_prepare().get_base()),
+            BftMessageType::PREPARE => (INSTANCE_CLASS, bft.get_prepare().get_base()),
+            BftMessageType::COMMIT => (INSTANCE_CLASS, bft.get_commit().get_base()),
+            BftMessageType::VIEW_CHANGE_VALUE => (
+                VIEW_CHANGE_CLASS,
+                bft.get_view_change_value()
+                    .get_view_change_env()
+                    .get_bft()
+                    .get_view_change()
+                    .get_base(),
+            ),
+            BftMessageType::NEW_VIEW => (NEW_VIEW_CLASS, bft.get_new_view().get_base()),
+            _ => return None,
+        };
+        if base.get_view_number() < 0 {
+            return None;
+        }
+        let sequence = if class == INSTANCE_CLASS {
+            base.get_sequence()
+        } else {
+            0
+        };
+        let mut key = BFT_MESSAGE_PREFIX.as_bytes().to_vec();
+        key.extend_from_slice(&(base.get_view_number() as u64).to_be_bytes());
+        key.push(class);
+        key.extend_from_slice(&sequence.to_be_bytes());
+        key.push(bft.get_msg_type() as u8);
+        key.extend_from_slice(&base.get_replica_id().to_be_bytes());
+        Some(key)
+    }
+}
+
+#[cfg(all(test, not(target_os = "windows")))]
+mod tests {
+    use super::*;
+    use protos::consensus::{Bft, BftBaseInfo};
+    use storage_db::rocksdb::RocksDbDriver;
+
+    fn base(view_number: i64, sequence: u64, replica_id: i64) -> BftBaseInfo {
+        let mut base = BftBaseInfo::new();
+        base.set_view_number(view_number);
+        base.set_sequence(sequence);
+        base.set_replica_id(replica_id);
+        base
+    }
+
+    fn sign(bft: Bft) -> BftSign {
+        let mut bft_sign = BftSign::new();
+        bft_sign.set_bft(bft);
+        bft_sign
+    }
+
+    fn instance_message(
+        msg_type: BftMessageType,
+        view_number: i64,
+        sequence: u64,
+        replica_id: i64,
+    ) -> BftSign {
+        let mut bft = Bft::new();
+        bft.set_msg_type(msg_type);
+        let base = base(view_number, sequence, replica_id);
+        match msg_type {
+            BftMessageType::PRE_PREPARE => bft.mut_pre_prepare().set_base(base),
+            BftMessageType::PREPARE => bft.mut_prepare().set_base(base),
+            _ => bft.mut_commit().set_base(base),
+        }
+        sign(bft)
+    }
+
+    fn view_change_message(view_number: i64, sequence: u64, replica_id: i64) -> BftSign {
+        let mut view_change = Bft::new();
+        view_change.set_msg_type(BftMessageType::VIEW_CHANGE);
+        view_change
+            .mut_view_change()
+            .set_base(base(view_number, sequence, replica_id));
+        let mut bft = Bft::new();
+        bft.set_msg_type(BftMessageType::VIEW_CHANGE_VALUE);
+        bft.mut_view_change_value()
+            .set_view_change_env(sign(view_change));
+        sign(bft)
+    }
+
+    fn new_view_message(view_number: i64, sequence: u64, replica_id: i64) -> BftSign {
+        let mut bft = Bft::new();
+        bft.set_msg_type(BftMessageType::NEW_VIEW);
+        bft.mut_new_view()
+            .set_base(base(view_number, sequence, replica_id));
+        sign(bft)
+    }
+
+    fn desc(bft_sign: &BftSign) -> (BftMessageType, i64, u64, i64) {
+        let bft = bft_sign.get_bft();
+        let base = match bft.get_msg_type() {
+            BftMessageType::PRE_PREPARE => bft.get_pre_prepare().get_base(),
+            BftMessageType::PREPARE => bft.get_prepare().get_base(),
+            BftMessageType::COMMIT => bft.get_commit().get_base(),
+            BftMessageType::NEW_VIEW => bft.get_new_view().get_base(),
+            _ => bft
+                .get_view_change_value()
+                .get_view_change_env()
+                .get_bft()
+                .get_view_change()
+                .get_base(),
+        };
+        (
+            bft.get_msg_type(),
+            base.get_view_number(),
+            base.get_sequence(),
+            base.get_replica_id(),
+        )
+    }
+
+    // a replica that logs `messages`, is killed and restarts on the same database
+    fn restart_with(name: &str, messages: &[BftSign]) -> (String, Vec<BftSign>) {
+        let path = std::env::temp_dir()
+            .join(format!("bft-log-{}-{}", name, std::process::id()))
+            .to_string_lossy()
+            .to_string();
+        let _ = std::fs::remove_dir_all(&path);
+        {
+            let db = RocksDbDriver::open(&path, 16);
+            for bft_sign in messages.iter() {
+                BftStorage::store_message_in(&db, bft_sign).unwrap();
+            }
+        }
+        let db = RocksDbDriver::open(&path, 16);
+        let replayed = BftStorage::load_messages_in(&db).unwrap();
+        (path, replayed)
+    }
+
+    #[test]
+    fn replays_the_messages_of_each_phase_after_restart() {
+        let phases = [
+            BftMessageType::PRE_PREPARE,
+            BftMessageType::PREPARE,
+            BftMessageType::COMMIT,
+        ];
+        for (killed, phase) in phases.iter().enumerate() {
+            // received out of order, the replica's own votes among them
+            let mut messages = Vec::new();
+            for msg_type in phases[..=killed].iter().rev() {
+                let replicas = if *msg_type == BftMessageType::PRE_PREPARE {
+                    vec![1]
+                } else {
+                    vec![3, 0, 2, 1]
+                };
+                for replica_id in replicas {
+                    messages.push(instance_message(*msg_type, 1, 7, replica_id));
+                }
+            }
+            let (path, replayed) = restart_with(&format!("{:?}", phase), &messages);
+
+            assert_eq!(replayed.len(), messages.len());
+            let replayed: Vec<_> = replayed.iter().map(desc).collect();
+            assert_eq!(replayed[0], (BftMessageType::PRE_PREPARE, 1, 7, 1));
+            for pair in replayed.windows(2) {
+                assert!((pair[0].0 as i32, pair[0].3) < (pair[1].0 as i32, pair[1].3));
+            }
+            assert_eq!(replayed.last().unwrap().0, *phase);
+            let _ = std::fs::remove_dir_all(&path);
+        }
+    }
+
+    #[test]
+    fn replays_a_view_change_between_the_views_it_separates() {
+        let messages = vec![
+            instance_message(BftMessageType::PREPARE, 2, 8, 0),
+            new_view_message(2, 7, 2),
+            instance_message(BftMessageType::PRE_PREPARE, 2, 8, 2),
+            view_change_message(2, 7, 0),
+            instance_message(BftMessageType::PREPARE, 1, 8, 0),
+            view_change_message(2, 7, 3),
+            instance_message(BftMessageType::PRE_PREPARE, 1, 8, 1),
+        ];
+        let (path, replayed) = restart_with("view-change", &messages);
+
+        let replayed: Vec<_> = replayed.iter().map(desc).collect();
+        assert_eq!(
+            replayed,
+            vec![
+                (BftMessageType::PRE_PREPARE, 1, 8, 1),
+                (BftMessageType::PREPARE, 1, 8, 0),
+                (BftMessageType::VIEW_CHANGE_VALUE, 2, 7, 0),
+                (BftMessageType::VIEW_CHANGE_VALUE, 2, 7, 3),
+                (BftMessageType::NEW_VIEW, 2, 7, 2),
+                (BftMessageType::PRE_PREPARE, 2, 8, 2),
+                (BftMessageType::PREPARE, 2, 8, 0),
+            ]
+        );
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn checkpoint_drops_the_executed_messages() {
+        let messages = vec![
+            instance_message(BftMessageType::COMMIT, 1, 9, 0),
+            instance_message(BftMessageType::COMMIT, 1, 10, 0),
+            instance_message(BftMessageType::PRE_PREPARE, 1, 11, 1),
+            view_change_message(1, 5, 0),
+            view_change_message(2, 11, 0),
+        ];
+        let (path, _) = restart_with("checkpoint", &messages);
+        let db = RocksDbDriver::open(&path, 16);
+        BftStorage::delete_messages_in(&db, 10, 1).unwrap();
+
+        let left: Vec<_> = BftStorage::load_messages_in(&db)
+            .unwrap()
+            .iter()
+            .map(desc)
+            .collect();
+        assert_eq!(
+            left,
+            vec![
+                (BftMessageType::PRE_PREPARE, 1, 11, 1),
+                (BftMessageType::VIEW_CHANGE_VALUE, 2, 11, 0),
+            ]
+        );
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn keeps_one_view_change_per_replica_and_view() {
+        // a faulty replica resending its view change with other sequences
+        let messages = vec![
+            view_change_message(2, 7, 3),
+            view_change_message(2, 8, 3),
+            new_view_message(2, 7, 2),
+            new_view_message(2, 9, 2),
+            view_change_message(2, 7, 0),
+        ];
+        let (path, replayed) = restart_with("resent-view-change", &messages);
+
+        let replayed: Vec<_> = replayed.iter().map(desc).collect();
+        assert_eq!(
+            replayed,
+            vec![
+                (BftMessageType::VIEW_CHANGE_VALUE, 2, 7, 0),
+                (BftMessageType::VIEW_CHANGE_VALUE, 2, 8, 3),
+                (BftMessageType::NEW_VIEW, 2, 9, 2),
+            ]
+        );
+        let _ = std::fs::remove_dir_all(&path);
+    }
 }
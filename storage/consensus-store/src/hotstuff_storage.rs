@@ -0,0 +1,46 @@
+use protos::consensus::{HotStuffProof, HotStuffState};
+use storage_db::STORAGE_INSTANCE_REF;
+use utils::{general::compose_prefix_u64, parse::ProtocolParser};
+
+pub const HOTSTUFF_STATE: &str = "consensus_hotstuff_state";
+pub const HOTSTUFF_PROOF_PREFIX: &str = "hotstuff_proof";
+
+pub struct HotStuffStorage {}
+
+impl HotStuffStorage {
+    pub fn load_state() -> anyhow::Result<Option<HotStuffState>> {
+        match STORAGE_INSTANCE_REF
+            .key_value_db()
+            .lock()
+            .get(HOTSTUFF_STATE.as_bytes())?
+        {
+            Some(value) => Ok(Some(ProtocolParser::deserialize::<HotStuffState>(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn store_state(state: &HotStuffState) -> anyhow::Result<()> {
+        STORAGE_INSTANCE_REF.key_value_db().lock().put(
+            HOTSTUFF_STATE.as_bytes().to_vec(),
+            ProtocolParser::serialize::<HotStuffState>(state),
+        )
+    }
+
+    pub fn load_proof(height: u64) -> anyhow::Result<Option<HotStuffProof>> {
+        match STORAGE_INSTANCE_REF
+            .key_value_db()
+            .lock()
+            .get(&compose_prefix_u64(HOTSTUFF_PROOF_PREFIX, height))?
+        {
+            Some(value) => Ok(Some(ProtocolParser::deserialize::<HotStuffProof>(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn store_proof(height: u64, proof: &HotStuffProof) -> anyhow::Result<()> {
+        STORAGE_INSTANCE_REF.key_value_db().lock().put(
+            compose_prefix_u64(HOTSTUFF_PROOF_PREFIX, height),
+            ProtocolParser::serialize::<HotStuffProof>(proof),
+        )
+    }
+}
@@ -1 +1,2 @@
 pub mod bft_storage;
+pub mod hotstuff_storage;
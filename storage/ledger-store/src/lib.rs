@@ -17,6 +17,39 @@ pub const LEDGER_PREFIX: &str = "lg";
 pub const LEDGER_HASH_PREFIX: &str = "lg_hash";
 pub const TRANSACTION_PREFIX: &str = "tx";
 pub const TRANSACTION_HASH_LIST_PREFIX: &str = "tx_lst";
+pub const ACCOUNT_TX_COUNT_PREFIX: &str = "acc_tx_cnt";
+pub const ACCOUNT_TX_PREFIX: &str = "acc_tx";
+pub const LOG_BLOOM_PREFIX: &str = "lg_bloom";
+
+pub const LOG_BLOOM_SIZE: usize = 256;
+
+/// Set the three bits of `value` in a 2048 bits bloom.
+pub fn log_bloom_accrue(bloom: &mut [u8], value: &[u8]) {
+    for (index, bit) in log_bloom_bits(value) {
+        bloom[index] |= bit;
+    }
+}
+
+pub fn log_bloom_contains(bloom: &[u8], value: &[u8]) -> bool {
+    bloom.len() == LOG_BLOOM_SIZE
+        && log_bloom_bits(value)
+            .iter()
+            .all(|(index, bit)| bloom[*index] & bit != 0)
+}
+
+fn log_bloom_bits(value: &[u8]) -> [(usize, u8); 3] {
+    let hash = hash_crypto_byte(value);
+    let mut bits = [(0usize, 0u8); 3];
+    for (i, bit) in bits.iter_mut().enumerate() {
+        let n = ((hash[2 * i] as usize) << 8 | hash[2 * i + 1] as usize) & 2047;
+        *bit = (LOG_BLOOM_SIZE - 1 - n / 8, 1u8 << (n % 8));
+    }
+    bits
+}
+
+fn account_tx_key(address: &str, index: u64) -> Vec<u8> {
+    compose_prefix_str(ACCOUNT_TX_PREFIX, &format!("{}_{}", address, index))
+}
 
 pub struct LedgerStorage;
 
@@ -75,6 +108,32 @@ impl LedgerStorage {
         }
     }
 
+    /// Number of transactions sent or received by the account.
+    pub fn load_account_tx_count(address: &str) -> anyhow::Result<u64> {
+        let result = STORAGE_INSTANCE_REF
+            .ledger_db()
+            .lock()
+            .get(&compose_prefix_str(ACCOUNT_TX_COUNT_PREFIX, address))?;
+
+        Ok(result.map(utils::general::vector_2_u64).unwrap_or(0))
+    }
+
+    /// Hash of the `index`th transaction of the account, in commit order.
+    pub fn load_account_tx_hash(address: &str, index: u64) -> anyhow::Result<Option<Vec<u8>>> {
+        STORAGE_INSTANCE_REF
+            .ledger_db()
+            .lock()
+            .get(&account_tx_key(address, index))
+    }
+
+    /// Bloom of the addresses and topics of the events emitted in the block.
+    pub fn load_log_bloom(seq: u64) -> anyhow::Result<Option<Vec<u8>>> {
+        STORAGE_INSTANCE_REF
+            .ledger_db()
+            .lock()
+            .get(&compose_prefix_u64(LOG_BLOOM_PREFIX, seq))
+    }
+
     pub fn load_max_block_height() -> anyhow::Result<Option<u64>> {
         let result = STORAGE_INSTANCE_REF
             .ledger_db()
@@ -130,17 +189,80 @@ impl LedgerStorage {
         }
     }
 
+    pub fn store_account_tx_index(
+        batch: &mut MemWriteBatch,
+        txs: &Vec<(Vec<u8>, TransactionSignStore)>,
+    ) -> anyhow::Result<()> {
+        let mut counts: HashMap<String, u64> = HashMap::new();
+        for (tx_hash, tx) in txs.iter() {
+            let transaction = tx.get_transaction_sign().get_transaction();
+            let mut accounts = vec![transaction.get_source()];
+            if !transaction.get_to().is_empty() && transaction.get_to() != transaction.get_source()
+            {
+                accounts.push(transaction.get_to());
+            }
+            for address in accounts {
+                if !counts.contains_key(address) {
+                    let count = Self::load_account_tx_count(address)?;
+                    counts.insert(address.to_string(), count);
+                }
+                let count = counts.get_mut(address).unwrap();
+                batch.set(account_tx_key(address, *count), tx_hash.clone());
+                *count += 1;
+            }
+        }
+        for (address, count) in counts.iter() {
+            batch.set(
+                compose_prefix_str(ACCOUNT_TX_COUNT_PREFIX, address),
+                utils::general::u64_2_vector(*count),
+            );
+        }
+        Ok(())
+    }
+
+    pub fn store_log_bloom(
+        batch: &mut MemWriteBatch,
+        header: &LedgerHeader,
+        txs: &Vec<(Vec<u8>, TransactionSignStore)>,
+    ) {
+        let mut bloom = vec![0u8; LOG_BLOOM_SIZE];
+        for (_, tx) in txs.iter() {
+            let result = tx.get_transaction_result().get_contract_result();
+            for event in result.get_contract_event() {
+                log_bloom_accrue(&mut bloom, event.get_address().as_bytes());
+                for topic in event.get_topic() {
+                    log_bloom_accrue(&mut bloom, topic.as_bytes());
+                }
+            }
+        }
+        batch.set(
+            compose_prefix_u64(LOG_BLOOM_PREFIX, header.get_height()),
+            bloom,
+        );
+    }
+
+    /// Secondary indexes serving the account and log queries.
+    pub fn store_ledger_indexes(
+        batch: &mut MemWriteBatch,
+        header: &LedgerHeader,
+        txs: &Vec<(Vec<u8>, TransactionSignStore)>,
+    ) -> anyhow::Result<()> {
+        Self::store_account_tx_index(batch, txs)?;
+        Self::store_log_bloom(batch, header, txs);
+        Ok(())
+    }
+
     pub fn store_ledger(
         batch: &mut MemWriteBatch,
         header: &LedgerHeader,
         txs: &mut Vec<(Vec<u8>, TransactionSignStore)>,
     ) {
-        let _ = txs.iter_mut().map(|(_, t)| {
+        for (_, t) in txs.iter_mut() {
             t.mut_transaction_result()
                 .set_block_hash(header.get_hash().to_vec());
             t.mut_transaction_result()
                 .set_block_height(header.get_height());
-        });
+        }
         Self::store_max_block_height(batch, header.get_height());
         Self::store_ledger_header(batch, header);
         Self::store_ledger_tx_list(batch, header, txs);
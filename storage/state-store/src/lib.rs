@@ -1,3 +1,6 @@
+pub mod pruner;
+pub mod snapshot;
+
 use ledger_store::LedgerStorage;
 use protos::{common::ValidatorSet, consensus::BftProof, ledger::Account};
 use state::{AccountFrame, TrieHash, TrieReader};
@@ -103,3 +106,13 @@ impl StateStorage {
         STORAGE_INSTANCE_REF.account_db().lock().write_batch(batch)
     }
 }
+
+// the trie root a hash field holds, None when it holds no root
+pub(crate) fn trie_root(hash: &[u8]) -> Option<TrieHash> {
+    let mut root = TrieHash::default();
+    if hash.len() != root.len() {
+        return None;
+    }
+    root.copy_from_slice(hash);
+    Some(root)
+}
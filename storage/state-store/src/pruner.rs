@@ -0,0 +1,648 @@
+//! State pruning. In `pruned(N)` mode each block commit counts the trie nodes it writes and
+//! journals the ones it replaces. A block's journal is released once the block leaves the
+//! window of the last N state roots, deleting the nodes whose count drops to zero.
+//! Counting starts at genesis or after `prune_offline`, which also removes the nodes written
+//! while the node ran in archive mode.
+use crate::{trie_root, StateStorage, CODE_HASH_PREFIX};
+use anyhow::{bail, ensure, Result};
+use configure::{NodeMode, CONFIGURE_INSTANCE_REF};
+use ledger_store::LedgerStorage;
+use log::warn;
+use protos::{common::EntryList, ledger::Account};
+use state::{is_trie_node, TrieHash, TrieJournal, TrieReader};
+use std::collections::{HashMap, HashSet};
+use storage_db::{KeyValueDB, MemWriteBatch, WriteBatchTrait, STORAGE_INSTANCE_REF};
+use utils::{
+    general::{
+        compose_prefix_bytes, compose_prefix_u64, u64_2_vector, vector_2_u64, GENESIS_HEIGHT,
+    },
+    parse::ProtocolParser,
+};
+
+pub const TRIE_REFS_PREFIX: &str = "trie_refs";
+pub const TRIE_JOURNAL_PREFIX: &str = "trie_journal";
+/// Height the node counts trie references since, absent while they are not counted.
+pub const TRIE_PRUNING_KEY: &str = "trie_pruning";
+const JOURNAL_REMOVED_PREFIX: &str = "trie_journal_removed";
+const JOURNAL_ROOTS_PREFIX: &str = "trie_journal_roots";
+const JOURNAL_CODE_PREFIX: &str = "trie_journal_code";
+// writes per batch of the offline pruning
+const PRUNE_BATCH_SIZE: usize = 10_000;
+
+#[derive(Debug, Default)]
+pub struct PruneStats {
+    pub kept_nodes: u64,
+    pub deleted_nodes: u64,
+    pub deleted_code: u64,
+}
+
+pub struct StatePruner;
+
+impl StatePruner {
+    pub fn node_mode() -> NodeMode {
+        CONFIGURE_INSTANCE_REF.db.node_mode
+    }
+
+    /// Whether the state committed at `height` is still kept when `last` is the latest block.
+    pub fn retains(height: u64, last: u64) -> bool {
+        match Self::node_mode() {
+            NodeMode::Archive => true,
+            NodeMode::Pruned(keep) => height.saturating_add(keep) > last,
+            NodeMode::Light => height == GENESIS_HEIGHT,
+        }
+    }
+
+    /// Journal the metadata trie and the code of an account the block deletes, the
+    /// account is read from the parent state.
+    pub fn journal_deleted_account(
+        address: &str,
+        parent_root: Option<TrieHash>,
+        journal: &mut TrieJournal,
+    ) -> Result<()> {
+        let parent_root = match parent_root {
+            Some(root) => root,
+            None => return Ok(()),
+        };
+        if let Some(account) = StateStorage::load_account(address, parent_root)? {
+            if let Some(root) = trie_root(&account.metadatas_hash()) {
+                journal.dropped_roots.push(root);
+            }
+            if account.has_contract() {
+                journal.dropped_code.push(account.contract_code_hash());
+            }
+        }
+        Ok(())
+    }
+
+    /// A pruned node counts trie references since genesis or since `prune_offline`, one that
+    /// committed blocks in another mode has to be pruned offline before it prunes again.
+    pub fn ensure_counting() -> Result<()> {
+        if let NodeMode::Pruned(_) = Self::node_mode() {
+            let db = STORAGE_INSTANCE_REF.account_db();
+            let counting = db.lock().get(TRIE_PRUNING_KEY.as_bytes())?.is_some();
+            ensure!(
+                counting,
+                "trie references are not counted, stop the node and run `db prune` first"
+            );
+        }
+        Ok(())
+    }
+
+    /// Count the trie nodes the block at `height` wrote into `batch` and release the journal
+    /// of the block leaving the window. `state_root` is the new state root of the block.
+    pub fn commit(
+        height: u64,
+        journal: TrieJournal,
+        state_root: &TrieHash,
+        batch: &mut MemWriteBatch,
+    ) -> Result<()> {
+        let db = STORAGE_INSTANCE_REF.account_db();
+        match Self::node_mode() {
+            NodeMode::Archive | NodeMode::Light => {
+                // the counts go stale as soon as a block is not counted
+                if db.lock().get(TRIE_PRUNING_KEY.as_bytes())?.is_some() {
+                    batch.delete(TRIE_PRUNING_KEY.as_bytes().to_vec());
+                }
+                Ok(())
+            }
+            NodeMode::Pruned(keep) => Self::count(&db, keep, height, journal, state_root, batch),
+        }
+    }
+
+    // `commit` of a pruned(keep) node
+    fn count(
+        db: &KeyValueDB,
+        keep: u64,
+        height: u64,
+        journal: TrieJournal,
+        state_root: &TrieHash,
+        batch: &mut MemWriteBatch,
+    ) -> Result<()> {
+        if db.lock().get(TRIE_PRUNING_KEY.as_bytes())?.is_none() {
+            if height != GENESIS_HEIGHT {
+                warn!(
+                    "block {} is not counted, run `db prune` to start pruning",
+                    height
+                );
+                return Ok(());
+            }
+            batch.set(TRIE_PRUNING_KEY.as_bytes().to_vec(), u64_2_vector(height));
+        }
+
+        let mut deltas: HashMap<Vec<u8>, i64> = HashMap::new();
+        for key in journal.inserted.iter() {
+            *deltas.entry(key.clone()).or_insert(0) += 1;
+        }
+
+        // the nodes replaced by block `released` backed the roots before it, the last of
+        // which leaves the window with this block
+        if height + 1 < keep {
+            Self::store_journal(height, &journal, batch);
+            return Self::apply_deltas(db, deltas, batch);
+        }
+        let released = height + 1 - keep;
+        let released_journal = if released == height {
+            journal
+        } else {
+            Self::store_journal(height, &journal, batch);
+            Self::take_journal(db, released, batch)?
+        };
+
+        for key in released_journal.removed {
+            *deltas.entry(key).or_insert(0) -= 1;
+        }
+        for root in released_journal.dropped_roots {
+            let result = TrieReader::new(db.clone(), Some(root)).walk(
+                &mut |key| {
+                    *deltas.entry(key.to_vec()).or_insert(0) -= 1;
+                    true
+                },
+                &mut |_| {},
+            );
+            if let Err(e) = result {
+                warn!("release metadata trie of block {} error {}", released, e);
+            }
+        }
+        let state = TrieReader::new(db.clone(), Some(*state_root));
+        for code_hash in released_journal.dropped_code {
+            let key = compose_prefix_bytes(CODE_HASH_PREFIX, &code_hash);
+            let address = db.lock().get(&key)?;
+            if let Some(address) = address {
+                if state.get(&address)?.is_none() {
+                    batch.delete(key);
+                }
+            }
+        }
+
+        Self::apply_deltas(db, deltas, batch)
+    }
+
+    fn apply_deltas(
+        db: &KeyValueDB,
+        deltas: HashMap<Vec<u8>, i64>,
+        batch: &mut MemWriteBatch,
+    ) -> Result<()> {
+        for (key, delta) in deltas {
+            if delta == 0 {
+                continue;
+            }
+            let refs_key = compose_prefix_bytes(TRIE_REFS_PREFIX, &key);
+            let refs = db.lock().get(&refs_key)?.map(vector_2_u64);
+            match refs {
+                Some(refs) if refs as i64 + delta > 0 => {
+                    batch.set(refs_key, u64_2_vector((refs as i64 + delta) as u64));
+                }
+                Some(_) => {
+                    batch.delete(refs_key);
+                    batch.delete(key);
+                }
+                None if delta > 0 => batch.set(refs_key, u64_2_vector(delta as u64)),
+                // written before counting started, only offline pruning removes it
+                None => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn store_journal(height: u64, journal: &TrieJournal, batch: &mut MemWriteBatch) {
+        let roots: Vec<Vec<u8>> = journal.dropped_roots.iter().map(|r| r.to_vec()).collect();
+        for (prefix, entries) in [
+            (JOURNAL_REMOVED_PREFIX, &journal.removed),
+            (JOURNAL_ROOTS_PREFIX, &roots),
+            (JOURNAL_CODE_PREFIX, &journal.dropped_code),
+        ] {
+            if entries.is_empty() {
+                continue;
+            }
+            let mut list = EntryList::default();
+            list.set_entry(entries.clone().into());
+            batch.set(
+                compose_prefix_u64(prefix, height),
+                ProtocolParser::serialize::<EntryList>(&list),
+            );
+        }
+    }
+
+    // load the journal of a block and delete it in `batch`
+    fn take_journal(
+        db: &KeyValueDB,
+        height: u64,
+        batch: &mut MemWriteBatch,
+    ) -> Result<TrieJournal> {
+        let mut parts = Vec::with_capacity(3);
+        for prefix in [
+            JOURNAL_REMOVED_PREFIX,
+            JOURNAL_ROOTS_PREFIX,
+            JOURNAL_CODE_PREFIX,
+        ] {
+            let key = compose_prefix_u64(prefix, height);
+            let entries = match db.lock().get(&key)? {
+                Some(value) => ProtocolParser::deserialize::<EntryList>(&value)?
+                    .take_entry()
+                    .into_vec(),
+                None => Vec::new(),
+            };
+            batch.delete(key);
+            parts.push(entries);
+        }
+        let dropped_code = parts.pop().unwrap_or_default();
+        let dropped_roots = parts
+            .pop()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|root| trie_root(root))
+            .collect();
+        Ok(TrieJournal {
+            inserted: Vec::new(),
+            removed: parts.pop().unwrap_or_default(),
+            dropped_roots,
+            dropped_code,
+        })
+    }
+
+    /// Delete every trie node the state roots of the last `keep` blocks do not reach, with
+    /// the code hash entries of removed contracts, then set up the counts and journals
+    /// `pruned(keep)` continues from. Only run it while the node is stopped.
+    pub fn prune_offline(keep: u64) -> Result<PruneStats> {
+        ensure!(keep > 0, "at least one state root has to be kept");
+        let last = match LedgerStorage::load_max_block_height()? {
+            Some(last) => last,
+            None => bail!("no block committed, nothing to prune"),
+        };
+        let first = (last + 1).saturating_sub(keep).max(GENESIS_HEIGHT);
+
+        let mut roots = Vec::new();
+        for height in (first..=last).rev() {
+            let header = match LedgerStorage::load_ledger_header_by_seq(height)? {
+                Some(header) => header,
+                // a node synced from a snapshot has no blocks before it
+                None if height < last => break,
+                None => bail!("block {} not found", height),
+            };
+            match trie_root(header.get_state_hash()) {
+                Some(root) => roots.push((height, root)),
+                None => bail!("block {} has no state root", height),
+            }
+        }
+        Self::prune_roots(&STORAGE_INSTANCE_REF.account_db(), &roots)
+    }
+
+    // `prune_offline` of the state `roots`, from the last block down
+    fn prune_roots(db: &KeyValueDB, roots: &[(u64, TrieHash)]) -> Result<PruneStats> {
+        let (last, last_root) = match roots.first() {
+            Some(root) => *root,
+            None => bail!("no state root to keep"),
+        };
+
+        // node key -> the last height whose state reaches it and the tries reaching it there
+        let mut reachable: HashMap<Vec<u8>, (u64, u64)> = HashMap::new();
+        let mut walked = HashSet::new();
+        for (height, root) in roots.iter() {
+            if let Err(e) = Self::mark(db, *root, *height, &mut reachable, &mut walked) {
+                // an incomplete older root loses nothing the roots after it still need
+                if *height == last {
+                    return Err(e);
+                }
+                warn!("state of block {} is incomplete {}", height, e);
+            }
+        }
+        drop(walked);
+
+        let mut stats = PruneStats::default();
+        let mut deletions = Vec::new();
+        let mut code_entries = Vec::new();
+        {
+            let mut db = db.lock();
+            db.scan(&[], &mut |key, value| {
+                if !reachable.contains_key(key) && is_trie_node(key, value) {
+                    deletions.push(key.to_vec());
+                }
+                true
+            })?;
+            for prefix in [TRIE_REFS_PREFIX, TRIE_JOURNAL_PREFIX] {
+                db.scan(prefix.as_bytes(), &mut |key, _| {
+                    deletions.push(key.to_vec());
+                    true
+                })?;
+            }
+            db.scan(CODE_HASH_PREFIX.as_bytes(), &mut |key, value| {
+                code_entries.push((key.to_vec(), value.to_vec()));
+                true
+            })?;
+        }
+        stats.deleted_nodes = deletions.len() as u64;
+        let state = TrieReader::new(db.clone(), Some(last_root));
+        for (key, address) in code_entries {
+            if state.get(&address)?.is_none() {
+                deletions.push(key);
+                stats.deleted_code += 1;
+            }
+        }
+        // the counts and journals go first so none of them is taken for a node
+        stats.deleted_nodes -= deletions
+            .iter()
+            .filter(|key| {
+                key.starts_with(TRIE_REFS_PREFIX.as_bytes())
+                    || key.starts_with(TRIE_JOURNAL_PREFIX.as_bytes())
+            })
+            .count() as u64;
+
+        let mut batch = MemWriteBatch::new();
+        let mut pending = 0;
+        for key in deletions {
+            batch.delete(key);
+            pending += 1;
+            if pending == PRUNE_BATCH_SIZE {
+                db.lock()
+                    .write_batch(std::mem::replace(&mut batch, MemWriteBatch::new()))?;
+                pending = 0;
+            }
+        }
+        db.lock()
+            .write_batch(std::mem::replace(&mut batch, MemWriteBatch::new()))?;
+
+        // each trie reaching a node last at `height` releases it with block `height + 1`
+        let mut journals: HashMap<u64, TrieJournal> = HashMap::new();
+        stats.kept_nodes = reachable.len() as u64;
+        pending = 0;
+        for (key, (height, refs)) in reachable {
+            batch.set(
+                compose_prefix_bytes(TRIE_REFS_PREFIX, &key),
+                u64_2_vector(refs),
+            );
+            if height < last {
+                let removed = &mut journals.entry(height + 1).or_default().removed;
+                removed.extend(std::iter::repeat(key).take(refs as usize));
+            }
+            pending += 1;
+            if pending == PRUNE_BATCH_SIZE {
+                db.lock()
+                    .write_batch(std::mem::replace(&mut batch, MemWriteBatch::new()))?;
+                pending = 0;
+            }
+        }
+        for (height, journal) in journals.iter() {
+            Self::store_journal(*height, journal, &mut batch);
+        }
+        batch.set(TRIE_PRUNING_KEY.as_bytes().to_vec(), u64_2_vector(last));
+        db.lock().write_batch(batch)?;
+        Ok(stats)
+    }
+
+    // mark the nodes of the state trie at `root` and of the metadata tries of its accounts
+    fn mark(
+        db: &KeyValueDB,
+        root: TrieHash,
+        height: u64,
+        reachable: &mut HashMap<Vec<u8>, (u64, u64)>,
+        walked: &mut HashSet<(Vec<u8>, Vec<u8>)>,
+    ) -> Result<()> {
+        let mut metadata_roots = Vec::new();
+        Self::mark_trie(db, root, &[], height, reachable, walked, &mut |value| {
+            if let Ok(account) = ProtocolParser::deserialize::<Account>(value) {
+                if let Some(root) = trie_root(account.get_metadata_hash()) {
+                    metadata_roots.push((account.get_address().as_bytes().to_vec(), root));
+                }
+            }
+        })?;
+        for (address, root) in metadata_roots {
+            Self::mark_trie(db, root, &address, height, reachable, walked, &mut |_| {})?;
+        }
+        Ok(())
+    }
+
+    // Every trie holding a node counts one reference to it, as the blocks writing it did.
+    // `trie` is empty for the state trie and the account address for a metadata trie, a
+    // node a trie already reached at a later height has its children reached there too.
+    fn mark_trie(
+        db: &KeyValueDB,
+        root: TrieHash,
+        trie: &[u8],
+        height: u64,
+        reachable: &mut HashMap<Vec<u8>, (u64, u64)>,
+        walked: &mut HashSet<(Vec<u8>, Vec<u8>)>,
+        leaf: &mut dyn FnMut(&[u8]),
+    ) -> Result<()> {
+        TrieReader::new(db.clone(), Some(root)).walk(
+            &mut |key| {
+                if !walked.insert((trie.to_vec(), key.to_vec())) {
+                    return false;
+                }
+                let (last, refs) = reachable.entry(key.to_vec()).or_insert((height, 0));
+                if *last == height {
+                    *refs += 1;
+                }
+                true
+            },
+            leaf,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use state::TrieWriter;
+    use storage_db::{key_value_db::KeyValueDb, memorydb::MemoryDB};
+
+    const KEEP: u64 = 2;
+    const ALICE: &str = "did:gdt:0xf6b02a2d47b84e845b7e3623355f041bcb36daf1";
+    const BOB: &str = "did:gdt:0x160b54be617f4bff07bd6c994fc6dd17a69d5e4e";
+    const CAROL: &str = "did:gdt:0x2b6a4f4a3a4c94ae9b8dd0fcb9a1d15e4ba9c0e1";
+
+    fn account(address: &str, balance: u64, metadata_root: Option<TrieHash>) -> Vec<u8> {
+        let mut account = Account::default();
+        account.set_address(address.to_string());
+        account.set_balance(balance.to_string());
+        if let Some(root) = metadata_root {
+            account.set_metadata_hash(root.to_vec());
+        }
+        ProtocolParser::serialize::<Account>(&account)
+    }
+
+    // the metadata trie of a contract, every contract here stores the same entries so their
+    // tries share all their nodes
+    fn metadata_trie(
+        db: &KeyValueDB,
+        batch: &mut MemWriteBatch,
+        journal: &mut TrieJournal,
+    ) -> TrieHash {
+        let entries = (0..8u8).map(|i| (vec![i; 32], Some(vec![i; 40]))).collect();
+        TrieWriter::commit(db.clone(), None, &entries, batch, journal).unwrap()
+    }
+
+    // Commit a block the way the executor does. `accounts` are set with their balance and
+    // whether they are contracts, `deleted` accounts drop their metadata trie. The block is
+    // counted as pruned(KEEP) when `counted`.
+    fn commit_block(
+        db: &KeyValueDB,
+        height: u64,
+        parent: Option<TrieHash>,
+        accounts: &[(&str, u64, bool)],
+        deleted: &[(&str, Option<TrieHash>)],
+        counted: bool,
+    ) -> TrieHash {
+        let mut batch = MemWriteBatch::new();
+        let mut journal = TrieJournal::default();
+        let mut datas = HashMap::new();
+        for (address, balance, contract) in accounts {
+            let metadata_root = if *contract {
+                Some(metadata_trie(db, &mut batch, &mut journal))
+            } else {
+                None
+            };
+            datas.insert(
+                address.as_bytes().to_vec(),
+                Some(account(address, *balance, metadata_root)),
+            );
+        }
+        for (address, metadata_root) in deleted {
+            journal.dropped_roots.extend(metadata_root);
+            datas.insert(address.as_bytes().to_vec(), None);
+        }
+        let root =
+            TrieWriter::commit(db.clone(), parent, &datas, &mut batch, &mut journal).unwrap();
+        if counted {
+            StatePruner::count(db, KEEP, height, journal, &root, &mut batch).unwrap();
+        }
+        db.lock().write_batch(batch).unwrap();
+        root
+    }
+
+    fn pruning_height(db: &KeyValueDB) -> Option<u64> {
+        let value = db.lock().get(TRIE_PRUNING_KEY.as_bytes()).unwrap();
+        value.map(vector_2_u64)
+    }
+
+    fn readable(db: &KeyValueDB, root: TrieHash) -> bool {
+        TrieReader::new(db.clone(), Some(root)).all().is_ok()
+    }
+
+    fn metadata_root(db: &KeyValueDB, root: TrieHash, address: &str) -> TrieHash {
+        let value = TrieReader::new(db.clone(), Some(root))
+            .get(address.as_bytes())
+            .unwrap()
+            .unwrap();
+        let account = ProtocolParser::deserialize::<Account>(&value).unwrap();
+        trie_root(account.get_metadata_hash()).unwrap()
+    }
+
+    fn node_refs(db: &KeyValueDB, root: TrieHash) -> Vec<Option<u64>> {
+        let mut keys = Vec::new();
+        TrieReader::new(db.clone(), Some(root))
+            .walk(
+                &mut |key| {
+                    keys.push(key.to_vec());
+                    true
+                },
+                &mut |_| {},
+            )
+            .unwrap();
+        keys.iter()
+            .map(|key| {
+                let refs = db.lock().get(&compose_prefix_bytes(TRIE_REFS_PREFIX, key));
+                refs.unwrap().map(vector_2_u64)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn refcount_test() {
+        let db = MemoryDB::open();
+        let root1 = commit_block(&db, GENESIS_HEIGHT, None, &[(ALICE, 1, false)], &[], true);
+        assert_eq!(pruning_height(&db), Some(GENESIS_HEIGHT));
+        assert!(node_refs(&db, root1).iter().all(|refs| *refs == Some(1)));
+
+        let root2 = commit_block(&db, 2, Some(root1), &[(ALICE, 2, false)], &[], true);
+        assert!(readable(&db, root1));
+        // root1 leaves the window of the last KEEP roots
+        let root3 = commit_block(&db, 3, Some(root2), &[(ALICE, 3, false)], &[], true);
+        assert!(!readable(&db, root1));
+        assert!(readable(&db, root2));
+        assert!(readable(&db, root3));
+
+        let root4 = commit_block(&db, 4, Some(root3), &[(BOB, 1, false)], &[], true);
+        assert!(!readable(&db, root2));
+        assert!(readable(&db, root3));
+        assert!(readable(&db, root4));
+    }
+
+    #[test]
+    fn uncounted_commit_test() {
+        let db = MemoryDB::open();
+        let root1 = commit_block(&db, GENESIS_HEIGHT, None, &[(ALICE, 1, false)], &[], false);
+        // counting can only start at genesis, later blocks wait for the offline pruning
+        let root2 = commit_block(&db, 2, Some(root1), &[(ALICE, 2, false)], &[], true);
+        assert_eq!(pruning_height(&db), None);
+        assert!(node_refs(&db, root2).iter().all(|refs| refs.is_none()));
+    }
+
+    #[test]
+    fn offline_prune_test() {
+        let db = MemoryDB::open();
+        let root1 = commit_block(
+            &db,
+            1,
+            None,
+            &[(ALICE, 1, false), (BOB, 1, false)],
+            &[],
+            false,
+        );
+        let root2 = commit_block(&db, 2, Some(root1), &[(ALICE, 2, false)], &[], false);
+        let root3 = commit_block(
+            &db,
+            3,
+            Some(root2),
+            &[(ALICE, 3, false), (CAROL, 1, true)],
+            &[],
+            false,
+        );
+        let root4 = commit_block(&db, 4, Some(root3), &[(ALICE, 4, false)], &[], false);
+
+        let stats = StatePruner::prune_roots(&db, &[(4, root4), (3, root3)]).unwrap();
+        assert!(stats.deleted_nodes > 0);
+        assert_eq!(stats.deleted_code, 0);
+        assert!(!readable(&db, root1));
+        assert!(!readable(&db, root2));
+        assert!(readable(&db, root3));
+        assert!(readable(&db, root4));
+        assert!(readable(&db, metadata_root(&db, root4, CAROL)));
+        assert_eq!(pruning_height(&db), Some(4));
+
+        // counting goes on from the pruned state, the nodes only root3 reaches go next
+        let root5 = commit_block(&db, 5, Some(root4), &[(ALICE, 5, false)], &[], true);
+        assert!(!readable(&db, root3));
+        assert!(readable(&db, root4));
+        assert!(readable(&db, root5));
+        assert!(readable(&db, metadata_root(&db, root5, CAROL)));
+    }
+
+    #[test]
+    fn offline_prune_shared_nodes_test() {
+        let db = MemoryDB::open();
+        let root1 = commit_block(
+            &db,
+            1,
+            None,
+            &[(ALICE, 1, true), (BOB, 1, true), (CAROL, 1, false)],
+            &[],
+            false,
+        );
+        let metadata = metadata_root(&db, root1, ALICE);
+        assert_eq!(metadata, metadata_root(&db, root1, BOB));
+
+        StatePruner::prune_roots(&db, &[(1, root1)]).unwrap();
+        // both contracts reference the nodes of their metadata trie
+        assert!(node_refs(&db, metadata).iter().all(|refs| *refs == Some(2)));
+
+        let root2 = commit_block(&db, 2, Some(root1), &[], &[(ALICE, Some(metadata))], true);
+        let root3 = commit_block(&db, 3, Some(root2), &[(CAROL, 2, false)], &[], true);
+        assert!(node_refs(&db, metadata).iter().all(|refs| *refs == Some(1)));
+        assert!(readable(&db, metadata));
+
+        let root4 = commit_block(&db, 4, Some(root3), &[], &[(BOB, Some(metadata))], true);
+        assert!(readable(&db, metadata));
+        commit_block(&db, 5, Some(root4), &[(CAROL, 3, false)], &[], true);
+        assert!(!readable(&db, metadata));
+    }
+}
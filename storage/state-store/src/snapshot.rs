@@ -0,0 +1,724 @@
+//! State snapshots. A snapshot holds every account of the state committed at one block with
+//! the entries of its metadata trie, cut into chunks in trie order. The manifest anchors it to
+//! the block header and the `BftProof` committing that block, and lists the hash of each chunk.
+//! On disk a snapshot is a directory with a `manifest` file and one `chunk_{index}` file per
+//! chunk, each a protobuf message.
+//!
+//! A proof only signs the consensus value of its block, which leaves the state hash out. The
+//! manifest therefore also carries the block after the snapshot: its signed value holds the
+//! hash of the snapshot header, and so its state root.
+use crate::{pruner::StatePruner, trie_root, StateStorage, CODE_HASH_PREFIX};
+use anyhow::{bail, ensure, format_err, Result};
+use configure::{NodeMode, CONFIGURE_INSTANCE_REF};
+use ledger_store::LedgerStorage;
+use log::warn;
+use msp::bytes_to_hex_str;
+use protobuf::Message;
+use protos::{
+    common::KeyPair,
+    consensus::BftProof,
+    ledger::{
+        Account, Ledger, LedgerHeader, SnapshotAccount, SnapshotChunk, SnapshotEntry,
+        SnapshotManifest,
+    },
+};
+use state::{
+    empty_trie_root, AccountFrame, TrieHash, TrieJournal, TrieReader, TrieWriter, TRIE_KEY_MAX_LEN,
+};
+use std::{
+    cell::Cell,
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+};
+use storage_db::{
+    key_value_db::KeyValueDb, memorydb::MemoryDB, KeyValueDB, MemWriteBatch, StorageFactory,
+    WriteBatchTrait, STORAGE_INSTANCE_REF,
+};
+use utils::{
+    general::{hash_crypto_byte, self_chain_id},
+    parse::ProtocolParser,
+};
+
+pub const SNAPSHOT_MANIFEST_FILE: &str = "manifest";
+pub const SNAPSHOT_CHUNK_FILE_PREFIX: &str = "chunk_";
+// bytes of accounts and metadata after which a chunk is sealed
+pub const SNAPSHOT_CHUNK_SIZE: usize = 1 << 20;
+// directory under the snapshot path an import rebuilds the state in
+pub const SNAPSHOT_STAGING_DIR: &str = "staging";
+const SNAPSHOT_STAGING_MAX_OPEN_FILES: u64 = 64;
+// entries per write batch when the rebuilt state moves into the account database
+const SNAPSHOT_MOVE_BATCH: usize = 10_000;
+
+pub struct Snapshot;
+
+impl Snapshot {
+    /// Cut the state committed at `height` into chunks handed to `sink` in order and return
+    /// the manifest of the snapshot. `next_block` is the committed block at `height + 1`.
+    pub fn create(
+        height: u64,
+        next_block: Ledger,
+        sink: &mut dyn FnMut(&SnapshotChunk) -> Result<()>,
+    ) -> Result<SnapshotManifest> {
+        ensure!(
+            next_block.get_header().get_height() == height + 1,
+            "block {} does not follow block {}",
+            next_block.get_header().get_height(),
+            height
+        );
+        let header = match LedgerStorage::load_ledger_header_by_seq(height)? {
+            Some(header) => header,
+            None => bail!("block {} not found", height),
+        };
+        let proof = match StateStorage::load_proof(height)? {
+            Some(proof) => proof,
+            None => bail!("block {} has no proof", height),
+        };
+        let root = match trie_root(header.get_state_hash()) {
+            Some(root) => root,
+            None => bail!("block {} has no state root", height),
+        };
+        let (account_count, chunk_hashes) =
+            Self::chunks(STORAGE_INSTANCE_REF.account_db(), root, sink)?;
+
+        let mut manifest = SnapshotManifest::default();
+        manifest.set_chain_id(self_chain_id());
+        manifest.set_header(header);
+        manifest.set_proof(ProtocolParser::serialize::<BftProof>(&proof));
+        manifest.set_account_count(account_count);
+        manifest.set_chunk_hashes(chunk_hashes.into());
+        manifest.set_next_block(next_block);
+        Ok(manifest)
+    }
+
+    // the account count and the chunk hashes of the state under `root`
+    fn chunks(
+        db: KeyValueDB,
+        root: TrieHash,
+        sink: &mut dyn FnMut(&SnapshotChunk) -> Result<()>,
+    ) -> Result<(u64, Vec<Vec<u8>>)> {
+        let mut chunk = SnapshotChunk::default();
+        let mut chunk_size = 0;
+        let mut chunk_hashes = Vec::new();
+        let mut account_count = 0;
+        let mut failure = None;
+        let failed = Cell::new(false);
+        TrieReader::new(db.clone(), Some(root)).walk(&mut |_| !failed.get(), &mut |value| {
+            if failed.get() {
+                return;
+            }
+            let result = Self::snapshot_account(&db, value).and_then(|account| {
+                chunk_size += account.compute_size() as usize;
+                chunk.mut_accounts().push(account);
+                account_count += 1;
+                if chunk_size < SNAPSHOT_CHUNK_SIZE {
+                    return Ok(());
+                }
+                chunk_size = 0;
+                Self::seal_chunk(&mut chunk, &mut chunk_hashes, sink)
+            });
+            if let Err(e) = result {
+                failure = Some(e);
+                failed.set(true);
+            }
+        })?;
+        if let Some(e) = failure {
+            return Err(e);
+        }
+        if !chunk.get_accounts().is_empty() {
+            Self::seal_chunk(&mut chunk, &mut chunk_hashes, sink)?;
+        }
+        Ok((account_count, chunk_hashes))
+    }
+
+    fn seal_chunk(
+        chunk: &mut SnapshotChunk,
+        chunk_hashes: &mut Vec<Vec<u8>>,
+        sink: &mut dyn FnMut(&SnapshotChunk) -> Result<()>,
+    ) -> Result<()> {
+        chunk.set_index(chunk_hashes.len() as u64);
+        chunk_hashes.push(Self::chunk_hash(chunk));
+        sink(chunk)?;
+        *chunk = SnapshotChunk::default();
+        Ok(())
+    }
+
+    fn snapshot_account(db: &KeyValueDB, value: &[u8]) -> Result<SnapshotAccount> {
+        let account = ProtocolParser::deserialize::<Account>(value)?;
+        let mut entry = SnapshotAccount::default();
+        if let Some(root) = trie_root(account.get_metadata_hash()) {
+            let mut metadatas: Vec<(Vec<u8>, Vec<u8>)> = TrieReader::new(db.clone(), Some(root))
+                .all()?
+                .into_iter()
+                .collect();
+            metadatas.sort();
+            for (key, value) in metadatas {
+                let mut metadata = SnapshotEntry::default();
+                metadata.set_key(key);
+                metadata.set_value(value);
+                entry.mut_metadatas().push(metadata);
+            }
+        }
+        entry.set_account(account);
+        Ok(entry)
+    }
+
+    pub fn chunk_hash(chunk: &SnapshotChunk) -> Vec<u8> {
+        hash_crypto_byte(&ProtocolParser::serialize::<SnapshotChunk>(chunk))
+    }
+
+    /// Write the snapshot of the state at `height` into `dir`, the manifest goes last so a
+    /// directory with a manifest holds a complete snapshot.
+    pub fn export(height: u64, next_block: Ledger, dir: &Path) -> Result<SnapshotManifest> {
+        if dir.exists() {
+            fs::remove_dir_all(dir)?;
+        }
+        fs::create_dir_all(dir)?;
+        let manifest = Self::create(height, next_block, &mut |chunk| {
+            fs::write(
+                Self::chunk_path(dir, chunk.get_index()),
+                ProtocolParser::serialize::<SnapshotChunk>(chunk),
+            )?;
+            Ok(())
+        })?;
+        fs::write(
+            dir.join(SNAPSHOT_MANIFEST_FILE),
+            ProtocolParser::serialize::<SnapshotManifest>(&manifest),
+        )?;
+        Ok(manifest)
+    }
+
+    fn chunk_path(dir: &Path, index: u64) -> PathBuf {
+        dir.join(format!("{}{}", SNAPSHOT_CHUNK_FILE_PREFIX, index))
+    }
+
+    pub fn read_manifest(dir: &Path) -> Result<SnapshotManifest> {
+        ProtocolParser::deserialize::<SnapshotManifest>(&fs::read(
+            dir.join(SNAPSHOT_MANIFEST_FILE),
+        )?)
+    }
+
+    pub fn read_chunk(dir: &Path, index: u64) -> Result<SnapshotChunk> {
+        ProtocolParser::deserialize::<SnapshotChunk>(&fs::read(Self::chunk_path(dir, index))?)
+    }
+
+    /// The newest complete snapshot under `root`, whose subdirectories are named by height.
+    pub fn latest(root: &Path) -> Result<Option<(u64, PathBuf)>> {
+        if !root.exists() {
+            return Ok(None);
+        }
+        let mut latest = None;
+        for entry in fs::read_dir(root)? {
+            let path = entry?.path();
+            let height = match path.file_name().and_then(|name| name.to_str()) {
+                Some(name) => match name.parse::<u64>() {
+                    Ok(height) => height,
+                    Err(_) => continue,
+                },
+                None => continue,
+            };
+            if !path.join(SNAPSHOT_MANIFEST_FILE).exists() {
+                continue;
+            }
+            if latest.as_ref().map_or(true, |(latest, _)| height > *latest) {
+                latest = Some((height, path));
+            }
+        }
+        Ok(latest)
+    }
+
+    /// Remove the snapshots under `root` older than `height`.
+    pub fn remove_older(root: &Path, height: u64) -> Result<()> {
+        for entry in fs::read_dir(root)? {
+            let path = entry?.path();
+            if let Some(Ok(older)) = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .map(|name| name.parse::<u64>())
+            {
+                if older < height {
+                    fs::remove_dir_all(&path)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the manifest belongs to this chain, its header hashes to its hash field and the
+    /// next block links to it. The proofs are only decoded, whether their signers may commit
+    /// the blocks is up to the check given to `SnapshotImporter::new`.
+    pub fn verify_manifest(manifest: &SnapshotManifest) -> Result<()> {
+        ensure!(
+            manifest.get_chain_id() == self_chain_id(),
+            "snapshot of chain {}",
+            manifest.get_chain_id()
+        );
+        let header = manifest.get_header();
+        let mut unhashed = header.clone();
+        unhashed.clear_hash();
+        let hash = hash_crypto_byte(&ProtocolParser::serialize::<LedgerHeader>(&unhashed));
+        ensure!(
+            hash.as_slice() == header.get_hash(),
+            "snapshot header hash mismatch at height {}",
+            header.get_height()
+        );
+        ensure!(
+            trie_root(header.get_state_hash()).is_some(),
+            "snapshot header has no state root"
+        );
+        let next = manifest.get_next_block().get_header();
+        ensure!(
+            next.get_height() == header.get_height() + 1
+                && next.get_previous_hash() == header.get_hash(),
+            "snapshot block {} is not followed by its next block",
+            header.get_height()
+        );
+        let proof = ProtocolParser::deserialize::<BftProof>(manifest.get_proof())?;
+        ensure!(
+            !proof.get_commits().is_empty(),
+            "snapshot proof has no commits"
+        );
+        ensure!(
+            !manifest.get_chunk_hashes().is_empty(),
+            "snapshot has no chunks"
+        );
+        Ok(())
+    }
+}
+
+/// Rebuilds the state of a snapshot chunk by chunk in a staging database and anchors the node
+/// at its block. Nothing reaches the account database before the rebuilt root is the state
+/// root of the proven header.
+pub struct SnapshotImporter {
+    manifest: SnapshotManifest,
+    staging: KeyValueDB,
+    // removed with the importer
+    staging_dir: Option<PathBuf>,
+    root: Option<TrieHash>,
+    next: u64,
+    accounts: u64,
+}
+
+impl SnapshotImporter {
+    /// Start importing the snapshot of `manifest`, `check_proof` tells whether the proof of
+    /// its next block is signed by validators the node trusts.
+    pub fn new(
+        manifest: SnapshotManifest,
+        check_proof: &dyn Fn(&SnapshotManifest) -> Result<()>,
+    ) -> Result<Self> {
+        Snapshot::verify_manifest(&manifest)?;
+        check_proof(&manifest)?;
+        let height = manifest.get_header().get_height();
+        if let Some(last) = LedgerStorage::load_max_block_height()? {
+            ensure!(last < height, "the node is at block {} already", last);
+        }
+
+        // a staging directory left over by an import that did not finish
+        let dir = Path::new(&CONFIGURE_INSTANCE_REF.db.snapshot_path).join(SNAPSHOT_STAGING_DIR);
+        if dir.exists() {
+            fs::remove_dir_all(&dir)?;
+        }
+        fs::create_dir_all(&dir)?;
+        let staging =
+            StorageFactory::open_db(&dir.to_string_lossy(), SNAPSHOT_STAGING_MAX_OPEN_FILES);
+        Ok(Self::with_staging(manifest, staging, Some(dir)))
+    }
+
+    fn with_staging(
+        manifest: SnapshotManifest,
+        staging: KeyValueDB,
+        staging_dir: Option<PathBuf>,
+    ) -> Self {
+        Self {
+            manifest,
+            staging,
+            staging_dir,
+            root: None,
+            next: 0,
+            accounts: 0,
+        }
+    }
+
+    pub fn manifest(&self) -> &SnapshotManifest {
+        &self.manifest
+    }
+
+    pub fn height(&self) -> u64 {
+        self.manifest.get_header().get_height()
+    }
+
+    /// Index of the chunk to import next, None once all are imported.
+    pub fn next_chunk(&self) -> Option<u64> {
+        if (self.next as usize) < self.manifest.get_chunk_hashes().len() {
+            Some(self.next)
+        } else {
+            None
+        }
+    }
+
+    pub fn import_chunk(&mut self, chunk: &SnapshotChunk) -> Result<()> {
+        let index = match self.next_chunk() {
+            Some(index) => index,
+            None => bail!("snapshot has {} chunks", self.next),
+        };
+        ensure!(
+            chunk.get_index() == index,
+            "chunk {} while expecting {}",
+            chunk.get_index(),
+            index
+        );
+        ensure!(
+            Snapshot::chunk_hash(chunk).as_slice()
+                == self.manifest.get_chunk_hashes()[index as usize].as_slice(),
+            "chunk {} hash mismatch",
+            index
+        );
+
+        let mut batch = MemWriteBatch::new();
+        let mut journal = TrieJournal::default();
+        let mut datas = HashMap::new();
+        for entry in chunk.get_accounts() {
+            let account = AccountFrame::try_from(entry.get_account().clone())?;
+            let address = account.address();
+            let mut metadatas = HashMap::new();
+            for metadata in entry.get_metadatas() {
+                ensure!(
+                    metadata.get_key().len() < TRIE_KEY_MAX_LEN,
+                    "metadata key of {} too long",
+                    address
+                );
+                let kp = ProtocolParser::deserialize::<KeyPair>(metadata.get_value())?;
+                metadatas.insert(
+                    metadata.get_key().to_vec(),
+                    Some(ProtocolParser::serialize::<KeyPair>(&kp)),
+                );
+            }
+            let rebuilt = if metadatas.is_empty() {
+                Vec::new()
+            } else {
+                let root = TrieWriter::commit(
+                    self.staging.clone(),
+                    None,
+                    &metadatas,
+                    &mut batch,
+                    &mut journal,
+                )?;
+                root.to_vec()
+            };
+            let metadata_hash = account.metadatas_hash();
+            ensure!(
+                rebuilt == metadata_hash
+                    || (rebuilt.is_empty() && metadata_hash == empty_trie_root().to_vec()),
+                "metadata of {} does not match its hash",
+                address
+            );
+
+            if account.has_contract() {
+                StateStorage::store_codehash_address_map(
+                    &account.contract_code_hash(),
+                    &address,
+                    &mut batch,
+                );
+            }
+            datas.insert(address.as_bytes().to_vec(), Some(account.serialize()));
+        }
+        let root = TrieWriter::commit(
+            self.staging.clone(),
+            self.root,
+            &datas,
+            &mut batch,
+            &mut journal,
+        )?;
+        self.staging.lock().write_batch(batch)?;
+
+        self.root = Some(root);
+        self.next += 1;
+        self.accounts += chunk.get_accounts().len() as u64;
+        Ok(())
+    }
+
+    // the rebuilt state root once every chunk is imported and it is the header's state root
+    fn verified_root(&self) -> Result<TrieHash> {
+        ensure!(
+            self.next_chunk().is_none(),
+            "snapshot chunk {} not imported",
+            self.next
+        );
+        ensure!(
+            self.accounts == self.manifest.get_account_count(),
+            "snapshot has {} accounts, the manifest {}",
+            self.accounts,
+            self.manifest.get_account_count()
+        );
+        let header = self.manifest.get_header();
+        let root = self
+            .root
+            .ok_or_else(|| format_err!("snapshot state is empty"))?;
+        ensure!(
+            root.as_slice() == header.get_state_hash(),
+            "rebuilt state root {} is not the state hash {}",
+            bytes_to_hex_str(root.as_slice()),
+            bytes_to_hex_str(header.get_state_hash())
+        );
+        Ok(root)
+    }
+
+    // copy the tries under the rebuilt root and the code hashes into the account database,
+    // the nodes only reached by the roots between chunks stay behind
+    fn move_staged(&self, root: TrieHash) -> Result<()> {
+        let mut batch = MemWriteBatch::new();
+        let mut metadata_roots = HashSet::new();
+        self.move_trie(root, &mut batch, &mut |value| {
+            if let Ok(account) = ProtocolParser::deserialize::<Account>(value) {
+                metadata_roots.extend(trie_root(account.get_metadata_hash()));
+            }
+        })?;
+        for root in metadata_roots {
+            self.move_trie(root, &mut batch, &mut |_| {})?;
+        }
+
+        let mut codes = Vec::new();
+        self.staging
+            .lock()
+            .scan(CODE_HASH_PREFIX.as_bytes(), &mut |key, value| {
+                codes.push((key.to_vec(), value.to_vec()));
+                true
+            })?;
+        for (key, value) in codes {
+            batch.set(key, value);
+        }
+        StateStorage::commit(batch)
+    }
+
+    fn move_trie(
+        &self,
+        root: TrieHash,
+        batch: &mut MemWriteBatch,
+        leaf: &mut dyn FnMut(&[u8]),
+    ) -> Result<()> {
+        let mut failure = None;
+        TrieReader::new(self.staging.clone(), Some(root)).walk(
+            &mut |key| match self.move_node(key, batch) {
+                Ok(()) => true,
+                Err(e) => {
+                    failure = Some(e);
+                    false
+                }
+            },
+            leaf,
+        )?;
+        match failure {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    fn move_node(&self, key: &[u8], batch: &mut MemWriteBatch) -> Result<()> {
+        let value = self.staging.lock().get(key)?;
+        match value {
+            Some(value) => batch.set(key.to_vec(), value),
+            None => bail!("staged trie node {} missing", bytes_to_hex_str(key)),
+        }
+        if batch.insertions.len() >= SNAPSHOT_MOVE_BATCH {
+            StateStorage::commit(std::mem::replace(batch, MemWriteBatch::new()))?;
+        }
+        Ok(())
+    }
+
+    /// Check the rebuilt state root against the header, move the state into the account
+    /// database and make the snapshot block the last block of the node, returning its header.
+    pub fn finish(self) -> Result<LedgerHeader> {
+        let root = self.verified_root()?;
+        self.move_staged(root)?;
+
+        let header = self.manifest.get_header().clone();
+        let proof = ProtocolParser::deserialize::<BftProof>(self.manifest.get_proof())?;
+        let mut state_batch = MemWriteBatch::new();
+        StateStorage::store_last_proof(&mut state_batch, &proof);
+        StateStorage::store_proof(&mut state_batch, header.get_height(), &proof);
+        StateStorage::commit(state_batch)?;
+
+        let mut ledger_batch = MemWriteBatch::new();
+        LedgerStorage::store_ledger_header(&mut ledger_batch, &header);
+        LedgerStorage::store_max_block_height(&mut ledger_batch, header.get_height());
+        LedgerStorage::commit(ledger_batch)?;
+
+        // starts counting references from the imported state
+        if let NodeMode::Pruned(keep) = StatePruner::node_mode() {
+            StatePruner::prune_offline(keep)?;
+        }
+        Ok(header)
+    }
+}
+
+impl Drop for SnapshotImporter {
+    fn drop(&mut self) {
+        if let Some(dir) = self.staging_dir.take() {
+            // closes the staging database before its files go
+            self.staging = MemoryDB::open();
+            if let Err(e) = fs::remove_dir_all(&dir) {
+                warn!("remove snapshot staging {} error {}", dir.display(), e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protos::consensus::BftSign;
+
+    const ALICE: &str = "did:gdt:0xf6b02a2d47b84e845b7e3623355f041bcb36daf1";
+    const BOB: &str = "did:gdt:0x160b54be617f4bff07bd6c994fc6dd17a69d5e4e";
+    const HEIGHT: u64 = 7;
+
+    // a state of two accounts, ALICE with a metadata trie
+    fn state(db: &KeyValueDB) -> TrieHash {
+        let mut batch = MemWriteBatch::new();
+        let mut journal = TrieJournal::default();
+        let metadatas = (0..4u8)
+            .map(|i| {
+                let mut kp = KeyPair::default();
+                kp.set_key(vec![i; 8]);
+                kp.set_value(vec![i; 40]);
+                (vec![i; 8], Some(ProtocolParser::serialize::<KeyPair>(&kp)))
+            })
+            .collect();
+        let metadata_root =
+            TrieWriter::commit(db.clone(), None, &metadatas, &mut batch, &mut journal).unwrap();
+
+        let mut datas = HashMap::new();
+        for (address, metadata_root) in [(ALICE, Some(metadata_root)), (BOB, None)] {
+            let mut account = Account::default();
+            account.set_address(address.to_string());
+            account.set_balance("100".to_string());
+            if let Some(root) = metadata_root {
+                account.set_metadata_hash(root.to_vec());
+            }
+            datas.insert(
+                address.as_bytes().to_vec(),
+                Some(ProtocolParser::serialize::<Account>(&account)),
+            );
+        }
+        let root = TrieWriter::commit(db.clone(), None, &datas, &mut batch, &mut journal).unwrap();
+        db.lock().write_batch(batch).unwrap();
+        root
+    }
+
+    fn seal(header: &mut LedgerHeader) {
+        header.clear_hash();
+        let hash = hash_crypto_byte(&ProtocolParser::serialize::<LedgerHeader>(header));
+        header.set_hash(hash);
+    }
+
+    fn snapshot(db: &KeyValueDB, root: TrieHash) -> (SnapshotManifest, Vec<SnapshotChunk>) {
+        let mut chunks = Vec::new();
+        let (account_count, chunk_hashes) = Snapshot::chunks(db.clone(), root, &mut |chunk| {
+            chunks.push(chunk.clone());
+            Ok(())
+        })
+        .unwrap();
+
+        let mut header = LedgerHeader::default();
+        header.set_height(HEIGHT);
+        header.set_state_hash(root.to_vec());
+        seal(&mut header);
+        let mut next_block = Ledger::default();
+        next_block.mut_header().set_height(HEIGHT + 1);
+        next_block
+            .mut_header()
+            .set_previous_hash(header.get_hash().to_vec());
+        let mut proof = BftProof::default();
+        proof.mut_commits().push(BftSign::default());
+
+        let mut manifest = SnapshotManifest::default();
+        manifest.set_chain_id(self_chain_id());
+        manifest.set_header(header);
+        manifest.set_proof(ProtocolParser::serialize::<BftProof>(&proof));
+        manifest.set_account_count(account_count);
+        manifest.set_chunk_hashes(chunk_hashes.into());
+        manifest.set_next_block(next_block);
+        (manifest, chunks)
+    }
+
+    fn import(manifest: SnapshotManifest, chunks: &[SnapshotChunk]) -> Result<SnapshotImporter> {
+        Snapshot::verify_manifest(&manifest)?;
+        let mut importer = SnapshotImporter::with_staging(manifest, MemoryDB::open(), None);
+        for chunk in chunks {
+            importer.import_chunk(chunk)?;
+        }
+        importer.verified_root()?;
+        Ok(importer)
+    }
+
+    #[test]
+    fn import_round_trip_test() {
+        configure::init_test_config();
+        let db = MemoryDB::open();
+        let root = state(&db);
+        let (manifest, chunks) = snapshot(&db, root);
+        assert_eq!(manifest.get_account_count(), 2);
+
+        let importer = import(manifest, &chunks).unwrap();
+        assert_eq!(importer.verified_root().unwrap(), root);
+        let staged = TrieReader::new(importer.staging.clone(), Some(root));
+        assert_eq!(
+            staged.all().unwrap(),
+            TrieReader::new(db.clone(), Some(root)).all().unwrap()
+        );
+        let alice = staged.get(ALICE.as_bytes()).unwrap().unwrap();
+        let metadata_root = ProtocolParser::deserialize::<Account>(&alice)
+            .unwrap()
+            .get_metadata_hash()
+            .to_vec();
+        assert_eq!(
+            TrieReader::new(importer.staging.clone(), trie_root(&metadata_root))
+                .all()
+                .unwrap()
+                .len(),
+            4
+        );
+    }
+
+    #[test]
+    fn forged_state_test() {
+        configure::init_test_config();
+        let db = MemoryDB::open();
+        let root = state(&db);
+        let (mut manifest, mut chunks) = snapshot(&db, root);
+
+        // a chunk the manifest does not list
+        let mut forged = chunks[0].clone();
+        forged.mut_accounts()[0]
+            .mut_account()
+            .set_balance("1000000".to_string());
+        assert!(import(manifest.clone(), &[forged.clone()]).is_err());
+
+        // listed, but the accounts do not rebuild the proven state root
+        manifest.mut_chunk_hashes()[0] = Snapshot::chunk_hash(&forged);
+        chunks[0] = forged;
+        assert!(import(manifest, &chunks).is_err());
+    }
+
+    #[test]
+    fn forged_manifest_test() {
+        configure::init_test_config();
+        let db = MemoryDB::open();
+        let root = state(&db);
+        let (manifest, chunks) = snapshot(&db, root);
+        assert!(import(manifest.clone(), &chunks).is_ok());
+
+        // the header does not hash to its hash field
+        let mut forged = manifest.clone();
+        forged.mut_header().set_state_hash(vec![1; 32]);
+        assert!(Snapshot::verify_manifest(&forged).is_err());
+
+        // a resealed header the next block does not link to
+        seal(forged.mut_header());
+        assert!(Snapshot::verify_manifest(&forged).is_err());
+
+        // the proof of the next block is not accepted
+        let result = SnapshotImporter::new(manifest, &|_| bail!("proof of unknown signers"));
+        assert!(result.is_err());
+    }
+}
@@ -12,6 +12,8 @@ pub trait KeyValueDb {
     fn put(&mut self, key: Vec<u8>, value: Vec<u8>) -> Result<()>;
     fn delete(&mut self, key: Vec<u8>) -> Result<()>;
     fn write_batch(&mut self, mem_benchs: MemWriteBatch) -> Result<()>;
+    /// Visit the pairs whose key starts with `prefix` in key order, until `f` returns false.
+    fn scan(&mut self, prefix: &[u8], f: &mut dyn FnMut(&[u8], &[u8]) -> bool) -> Result<()>;
 }
 
 pub trait WriteBatchTrait {
@@ -2,7 +2,7 @@ use crate::key_value_db::{KeyValueDB, KeyValueDb, MemWriteBatch};
 use anyhow::{anyhow, Ok, Result};
 use parking_lot::Mutex;
 #[cfg(target_os = "windows")]
-use rusty_leveldb::{Options, WriteBatch, DB,compressor,CompressorId};
+use rusty_leveldb::{compressor, CompressorId, LdbIterator, Options, WriteBatch, DB};
 use std::sync::Arc;
 
 /// The central object responsible for handling all the connections.
@@ -62,4 +62,20 @@ impl KeyValueDb for LevelDbDriver {
         }
         Ok(())
     }
+
+    fn scan(&mut self, prefix: &[u8], f: &mut dyn FnMut(&[u8], &[u8]) -> bool) -> Result<()> {
+        let mut iter = match self.key_value_db.new_iter() {
+            std::result::Result::Ok(iter) => iter,
+            Err(err) => return Err(anyhow!("db scan error:{}", err.to_string())),
+        };
+        iter.seek(prefix);
+        let (mut key, mut value) = (Vec::new(), Vec::new());
+        while iter.valid() && iter.current(&mut key, &mut value) {
+            if !key.starts_with(prefix) || !f(&key, &value) {
+                break;
+            }
+            iter.advance();
+        }
+        Ok(())
+    }
 }
@@ -51,4 +51,19 @@ impl KeyValueDb for MemoryDB {
         }
         Ok(())
     }
+
+    fn scan(&mut self, prefix: &[u8], f: &mut dyn FnMut(&[u8], &[u8]) -> bool) -> Result<()> {
+        let mut keys: Vec<&Vec<u8>> = self
+            .key_value_db
+            .keys()
+            .filter(|key| key.starts_with(prefix))
+            .collect();
+        keys.sort();
+        for key in keys {
+            if !f(key, &self.key_value_db[key]) {
+                break;
+            }
+        }
+        Ok(())
+    }
 }
@@ -2,7 +2,9 @@ use crate::key_value_db::{KeyValueDB, KeyValueDb, MemWriteBatch};
 use anyhow::{anyhow, Result};
 use parking_lot::Mutex;
 #[cfg(not(target_os = "windows"))]
-use rocksdb::{BlockBasedOptions, DBCompactionStyle, Options, WriteBatch, DB};
+use rocksdb::{
+    BlockBasedOptions, DBCompactionStyle, Direction, IteratorMode, Options, WriteBatch, DB,
+};
 use std::sync::Arc;
 
 pub const BACKGROUND_FLUSHES: i32 = 2;
@@ -78,4 +80,20 @@ impl KeyValueDb for RocksDbDriver {
         }
         Ok(())
     }
+
+    fn scan(&mut self, prefix: &[u8], f: &mut dyn FnMut(&[u8], &[u8]) -> bool) -> Result<()> {
+        let iter = self
+            .key_value_db
+            .iterator(IteratorMode::From(prefix, Direction::Forward));
+        for item in iter {
+            let (key, value) = match item {
+                Ok(kv) => kv,
+                Err(err) => return Err(anyhow!("db scan error:{}", err.to_string())),
+            };
+            if !key.starts_with(prefix) || !f(&key, &value) {
+                break;
+            }
+        }
+        Ok(())
+    }
 }
@@ -50,6 +50,14 @@ impl StorageFactory {
         }
     }
 
+    //Open a database apart from the node's, with the driver of the platform.
+    pub fn open_db(db_path: &str, max_open_files: u64) -> KeyValueDB {
+        #[cfg(target_os = "windows")]
+        return LevelDbDriver::open(db_path, max_open_files);
+        #[cfg(not(target_os = "windows"))]
+        RocksDbDriver::open(db_path, max_open_files)
+    }
+
     //Store other data except account, ledger and transaction.
     pub fn key_value_db(&self) -> KeyValueDB {
         self.key_value_db.clone()
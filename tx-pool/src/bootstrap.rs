@@ -2,7 +2,8 @@ use crate::pool::Pool;
 use crate::transaction::TxState;
 use crate::types::{
     get_account_nonce_banace, BroadCastTxSender, BroadcastTxReceiver, ClientReceiver,
-    CommitNotificationReceiver, CommitNotificationSender, Shared, SubmissionStatusBundle,
+    CommitNotificationReceiver, CommitNotificationSender, PendingTxSender, Shared,
+    SubmissionStatusBundle,
     TxPoolCommitNotification, TxPoolStatus, TxPoolStatusCode, TxPoolValidationStatusCode,
     Validation, Validator,
 };
@@ -30,6 +31,7 @@ use tokio::{
 use tokio_stream::wrappers::IntervalStream;
 use tracing::*;
 use types::SignedTransaction;
+use utils::general::consensus_config;
 pub type SubmissionStatus = (TxPoolStatus, Option<TxPoolValidationStatusCode>);
 
 #[derive(Clone, Debug)]
@@ -180,12 +182,21 @@ where
         })
         .collect::<Vec<_>>();
 
+    let block_gas_limit = consensus_config().block_max_gas_limit;
     let transactions: Vec<_> = transactions
         .into_iter()
         .enumerate()
         .filter_map(|(idx, tx)| {
             if let Ok((seq, banace)) = nonce_and_banace_vec[idx] {
-                if tx.nonce() >= seq {
+                if tx.gas_limit() > block_gas_limit {
+                    statuses.push((
+                        tx,
+                        (
+                            TxPoolStatus::new(TxPoolStatusCode::ValidationError),
+                            Some(TxPoolValidationStatusCode::GasLimitExceeded),
+                        ),
+                    ));
+                } else if tx.nonce() >= seq {
                     //check balance for limit fee
                     if tx.gas_limit() as u128 * tx.gas_price() > banace {
                         statuses.push((
@@ -274,10 +285,12 @@ where
         });
 }
 
+/// Without `network` the accepted transactions are not broadcast, for a single node.
 pub fn start_txpool_service(
     config: &configure::TxPoolConfig,
     client_events: ClientReceiver,
-    network: PeerNetwork,
+    pending_tx_sender: PendingTxSender,
+    network: Option<PeerNetwork>,
 ) -> (Runtime, BroadCastTxSender, CommitNotificationSender) {
     let runtime = Builder::new_multi_thread()
         .thread_name("shared-mem")
@@ -289,7 +302,7 @@ pub fn start_txpool_service(
     let (consensus_committed_sender, committed_events) = futures::channel::mpsc::channel(1024);
     let pool = TX_POOL_INSTANCE_REF.clone();
     {
-        pool.write().reinit(config, network);
+        pool.write().reinit(config, network, pending_tx_sender);
     }
     let validator = Arc::new(RwLock::new(Validator::new()));
     let executor = runtime.handle();
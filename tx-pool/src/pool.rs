@@ -1,6 +1,8 @@
 //! pool is used to track transactions which have been submitted but not yet
 //! agreed upon.
-use crate::types::{TxPoolCommitted, TxPoolStatus, TxPoolStatusCode};
+use crate::types::{
+    PendingTxSender, TxPoolCommitted, TxPoolStatus, TxPoolStatusCode, TxReadySender,
+};
 use crate::{
     index::PriorityIndex,
     store::Store,
@@ -9,7 +11,11 @@ use crate::{
 use network::PeerNetwork;
 use protobuf::{Message, RepeatedField};
 use protos::common::{ProtocolsActionMessageType, ProtocolsMessage, ProtocolsMessageType};
-use std::{cmp::max, collections::HashMap, time::Duration};
+use std::{
+    cmp::max,
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
 use utils::TransactionSign;
 
 use types::SignedTransaction;
@@ -24,6 +30,11 @@ pub struct Pool {
     pub broadcast_max_batch_size: usize,
     pub broadcast_cache: Vec<SignedTransaction>,
     pub network: Option<PeerNetwork>,
+
+    // notified of every accepted transaction
+    pub pending_tx_sender: Option<PendingTxSender>,
+    // woken when transactions become ready for a block
+    pub tx_ready_sender: Option<TxReadySender>,
 }
 
 impl Pool {
@@ -35,15 +46,23 @@ impl Pool {
             broadcast_cache: Vec::new(),
             broadcast_max_batch_size: config.broadcast_max_batch_size,
             network,
+            pending_tx_sender: None,
+            tx_ready_sender: None,
         }
     }
 
-    pub fn reinit(&mut self, config: &configure::TxPoolConfig, network: PeerNetwork) {
+    pub fn reinit(
+        &mut self,
+        config: &configure::TxPoolConfig,
+        network: Option<PeerNetwork>,
+        pending_tx_sender: PendingTxSender,
+    ) {
         self.transactions = Store::new(&config);
         self.seq_cache = HashMap::with_capacity(config.capacity);
         self.broadcast_max_batch_size = config.broadcast_max_batch_size;
         self.transaction_timeout = Duration::from_secs(config.system_transaction_timeout_secs);
-        self.network = Some(network);
+        self.network = network;
+        self.pending_tx_sender = Some(pending_tx_sender);
     }
 
     pub fn get_by_hash(&self, hash: &[u8]) -> Option<SignedTransaction> {
@@ -97,6 +116,11 @@ impl Pool {
         let expiration_time = duration_since_epoch() + self.transaction_timeout;
         let txn_info = PoolTransaction::new(txn.clone(), expiration_time, state, seq);
         let status = self.transactions.insert(txn_info);
+        if status.code == TxPoolStatusCode::Accepted {
+            if let Some(sender) = &self.pending_tx_sender {
+                let _ = sender.unbounded_send(txn.clone());
+            }
+        }
         if status.code == TxPoolStatusCode::Accepted
             && (txn.source_type == protos::ledger::TransactionSign_SourceType::JSONRPC
                 || txn.source_type == protos::ledger::TransactionSign_SourceType::WEBSOCKET)
@@ -148,6 +172,10 @@ impl Pool {
 
         let sended_txs = Self::classify(broadcast.get_transactions());
         self.transactions.flag_send(&sended_txs);
+        // a wake-up already pending covers these as well
+        if let Some(sender) = &self.tx_ready_sender {
+            let _ = sender.try_send(());
+        }
     }
 
     fn classify(arr: &[TransactionSign]) -> HashMap<String, Vec<u64>> {
@@ -212,25 +240,38 @@ impl Pool {
         &self,
         max_tx_size: u64,
         max_contract_size: u64,
+        max_gas_limit: u64,
         exclude_txs: &HashMap<String, TxPoolCommitted>,
     ) -> Vec<Vec<u8>> {
         let mut txn_walked = 0u64;
+        let mut block_gas_limit = 0u64;
+        // accounts with a transaction left for a later block, their next ones wait as well
+        let mut deferred: HashSet<String> = HashSet::new();
         let mut priority_index = PriorityIndex::new();
         let iter_queue =
             self.transactions
                 .iter_queue(&mut priority_index, &self.seq_cache, max_contract_size);
         let mut block: Vec<Vec<u8>> = Vec::with_capacity(max_tx_size as usize);
         for k in iter_queue {
-            if let Some(hash) = self.transactions.get_hash(&k.address, k.seq) {
+            if let Some(tx) = self.transactions.get(&k.address, k.seq) {
                 // exclude commited tx
                 if let Some(v) = exclude_txs.get(&k.address) {
                     if k.seq <= v.max_seq {
                         continue;
                     }
                 }
+                if deferred.contains(&k.address) {
+                    continue;
+                }
+                let gas_limit = block_gas_limit.saturating_add(tx.gas_limit());
+                if gas_limit > max_gas_limit {
+                    deferred.insert(k.address.clone());
+                    continue;
+                }
+                block_gas_limit = gas_limit;
 
                 txn_walked += 1;
-                block.push(hash);
+                block.push(tx.hash().to_vec());
                 if txn_walked >= max_tx_size {
                     break;
                 }
@@ -268,3 +309,86 @@ impl Pool {
         self.transactions.gc();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protos::ledger::{Transaction, TransactionSign};
+
+    const ALICE: &str = "did:gdt:0xf6b02a2d47b84e845b7e3623355f041bcb36daf1";
+    const BOB: &str = "did:gdt:0x160b54be617f4bff07bd6c994fc6dd17a69d5e4e";
+
+    fn transaction(source: &str, nonce: u64, gas_limit: u64) -> SignedTransaction {
+        let mut tx = Transaction::default();
+        tx.set_source(source.to_string());
+        tx.set_nonce(nonce);
+        tx.set_value("0".to_string());
+        tx.set_gas_limit(gas_limit);
+        tx.set_gas_price("1".to_string());
+        let mut tx_sign = TransactionSign::default();
+        tx_sign.set_transaction(tx);
+        SignedTransaction::try_from(tx_sign).unwrap()
+    }
+
+    fn pool_of(txs: &[SignedTransaction]) -> Pool {
+        let mut pool = Pool::new(&configure::TxPoolConfig::default(), None);
+        for tx in txs {
+            pool.add(tx.clone(), 0, TxState::Sended);
+        }
+        pool
+    }
+
+    fn hashes(txs: &[&SignedTransaction]) -> HashSet<Vec<u8>> {
+        txs.iter().map(|tx| tx.hash().to_vec()).collect()
+    }
+
+    #[test]
+    fn block_gas_limit_test() {
+        let txs = vec![
+            transaction(ALICE, 0, 40),
+            transaction(ALICE, 1, 40),
+            transaction(ALICE, 2, 40),
+            transaction(BOB, 0, 10),
+        ];
+        let pool = pool_of(&txs);
+
+        let block = pool.get_block_hash_list(100, 100, 100, &HashMap::new());
+        let block: HashSet<Vec<u8>> = block.into_iter().collect();
+        assert_eq!(block, hashes(&[&txs[0], &txs[1], &txs[3]]));
+
+        // the left transaction goes into the next block
+        let mut committed = HashMap::new();
+        committed.insert(
+            ALICE.to_string(),
+            TxPoolCommitted {
+                sender: ALICE.to_string(),
+                max_seq: 1,
+                seqs: HashSet::new(),
+            },
+        );
+        committed.insert(
+            BOB.to_string(),
+            TxPoolCommitted {
+                sender: BOB.to_string(),
+                max_seq: 0,
+                seqs: HashSet::new(),
+            },
+        );
+        let block = pool.get_block_hash_list(100, 100, 100, &committed);
+        assert_eq!(block, vec![txs[2].hash().to_vec()]);
+    }
+
+    #[test]
+    fn deferred_account_keeps_nonce_order_test() {
+        let txs = vec![
+            transaction(ALICE, 0, 40),
+            transaction(ALICE, 1, 70),
+            transaction(ALICE, 2, 10),
+        ];
+        let pool = pool_of(&txs);
+
+        // the third one fits but would skip the nonce of the second
+        let block = pool.get_block_hash_list(100, 100, 100, &HashMap::new());
+        assert_eq!(block, vec![txs[0].hash().to_vec()]);
+    }
+}
@@ -10,7 +10,7 @@ use std::{
     fmt,
     sync::Arc,
 };
-use types::SignedTransaction;
+use types::{eth_transaction::ETH_ENCRYPTION_TYPE, SignedTransaction};
 use utils::{
     verify_pool::{verify_pool_exist, verify_pool_set},
     verify_sign::verify_sign,
@@ -108,6 +108,8 @@ pub enum TxPoolValidationStatusCode {
     ResourceDoesNotExist = 8,
     //
     UnknownStatus = 9,
+    // The gas limit is over the block gas limit, no block can hold the transaction
+    GasLimitExceeded = 10,
 }
 
 pub trait Validation: Send + Sync + Clone {
@@ -132,6 +134,10 @@ impl Validation for Validator {
             if verify_pool_exist(txn.hash()) {
                 continue;
             }
+            // the sender of an ethereum envelope was recovered from this signature on decoding
+            if signature.get_encryption_type() == ETH_ENCRYPTION_TYPE {
+                continue;
+            }
 
             match verify_sign(&signature, &txn.hash()) {
                 Ok(value) => {
@@ -288,6 +294,11 @@ pub type CommitNotificationSender = mpsc::Sender<TxPoolCommitNotification>;
 pub type CommitNotificationReceiver = mpsc::Receiver<TxPoolCommitNotification>;
 pub type BroadCastTxSender = mpsc::UnboundedSender<Vec<SignedTransaction>>;
 pub type BroadcastTxReceiver = mpsc::UnboundedReceiver<Vec<SignedTransaction>>;
+/// Transactions accepted into the pool, for subscribers of pending transactions.
+pub type PendingTxSender = mpsc::UnboundedSender<SignedTransaction>;
+pub type PendingTxReceiver = mpsc::UnboundedReceiver<SignedTransaction>;
+/// Woken once transactions are ready for a block, for a node sealing as soon as there are any.
+pub type TxReadySender = std::sync::mpsc::SyncSender<()>;
 
 pub fn get_account_nonce_banace(_account_address: &str) -> Result<(u64, u128)> {
     let state_hash = { LAST_COMMITTED_BLOCK_INFO_REF.read().get_state_hash() };
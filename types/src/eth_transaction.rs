@@ -0,0 +1,412 @@
+use anyhow::{bail, Result};
+use msp::hash::keccak::hash_keccak256;
+use msp::signing::secp256k1::{Secp256k1Context, Secp256k1PublicKey};
+use msp::signing::{PublicKey, ADDRESS_PREFIX};
+use protobuf::RepeatedField;
+use protos::common::Signature;
+use protos::ledger::{Transaction, TransactionSign, TransactionSign_SourceType, TransactionType};
+use rlp::{Rlp, RlpStream};
+use utils::general::self_chain_hub;
+
+/// `encryption_type` of a signature carrying a signed ethereum transaction, the
+/// `sign_data` is the raw envelope and the `public_key` the recovered key.
+pub const ETH_ENCRYPTION_TYPE: &str = "eth_secp256k1";
+
+pub const LEGACY_TX_TYPE: u8 = 0;
+/// EIP-2930 `0x01 || rlp([chainId, nonce, gasPrice, gas, to, value, data, accessList, yParity, r, s])`
+pub const ACCESS_LIST_TX_TYPE: u8 = 1;
+/// EIP-1559 `0x02 || rlp([chainId, nonce, maxPriorityFeePerGas, maxFeePerGas, gas, to, value, data, accessList, yParity, r, s])`
+pub const DYNAMIC_FEE_TX_TYPE: u8 = 2;
+
+const ETH_ADDRESS_LENGTH: usize = 20;
+// half of the secp256k1 curve order, EIP-2 rejects signatures with a larger s
+const SECP256K1_HALF_ORDER: [u8; 32] = [
+    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+];
+
+/// An ethereum transaction decoded from its signed envelope.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EthTransaction {
+    pub tx_type: u8,
+    /// keccak256 of the raw envelope, the hash wallets know the transaction by
+    pub hash: Vec<u8>,
+    pub sender: String,
+    pub public_key: Vec<u8>,
+    pub nonce: u64,
+    /// the price paid per gas, geno has no base fee so an EIP-1559 transaction
+    /// pays its priority fee
+    pub gas_price: u128,
+    pub max_fee_per_gas: Option<u128>,
+    pub gas_limit: u64,
+    pub to: Option<String>,
+    pub value: u128,
+    pub data: Vec<u8>,
+    /// None for a legacy transaction signed without EIP-155 replay protection
+    pub chain_id: Option<u64>,
+    /// v of a legacy signature, the y parity of a typed one
+    pub v: u64,
+    pub r: Vec<u8>,
+    pub s: Vec<u8>,
+}
+
+impl EthTransaction {
+    /// Decode a signed legacy, EIP-2930 or EIP-1559 envelope and recover its sender.
+    pub fn decode(raw: &[u8]) -> Result<Self> {
+        match raw.first() {
+            None => bail!("empty transaction envelope"),
+            // a legacy envelope is an rlp list, typed envelopes start with their type
+            Some(first) if *first >= 0xc0 => Self::decode_legacy(raw),
+            Some(&ACCESS_LIST_TX_TYPE) => Self::decode_typed(raw, ACCESS_LIST_TX_TYPE),
+            Some(&DYNAMIC_FEE_TX_TYPE) => Self::decode_typed(raw, DYNAMIC_FEE_TX_TYPE),
+            Some(tx_type) => bail!("unsupported transaction type {}", tx_type),
+        }
+    }
+
+    // rlp([nonce, gasPrice, gas, to, value, data, v, r, s])
+    fn decode_legacy(raw: &[u8]) -> Result<Self> {
+        let rlp = Rlp::new(raw);
+        // the hash covers the whole envelope, nothing may follow the list
+        if !rlp.is_list() || rlp.item_count()? != 9 || rlp.payload_info()?.total() != raw.len() {
+            bail!("invalid legacy transaction envelope");
+        }
+
+        let v = decode_u64(&rlp.at(6)?)?;
+        let (chain_id, recovery_id) = match v {
+            27 | 28 => (None, (v - 27) as u8),
+            v if v >= 35 => (Some((v - 35) / 2), ((v - 35) % 2) as u8),
+            v => bail!("invalid signature v {}", v),
+        };
+
+        // the signing payload is the unsigned fields, followed by [chain_id, 0, 0] for EIP-155
+        let mut stream = RlpStream::new_list(if chain_id.is_some() { 9 } else { 6 });
+        for i in 0..6 {
+            stream.append_raw(rlp.at(i)?.as_raw(), 1);
+        }
+        if let Some(chain_id) = chain_id {
+            stream.append(&chain_id);
+            stream.append_empty_data();
+            stream.append_empty_data();
+        }
+        let signing_hash = hash_keccak256(&stream.out());
+        let (r, s, public_key) = recover(&signing_hash, &rlp.at(7)?, &rlp.at(8)?, recovery_id)?;
+
+        Ok(Self {
+            tx_type: LEGACY_TX_TYPE,
+            hash: hash_keccak256(raw),
+            sender: public_key.get_address(),
+            public_key: public_key.as_slice().to_vec(),
+            nonce: decode_u64(&rlp.at(0)?)?,
+            gas_price: decode_u128(&rlp.at(1)?)?,
+            max_fee_per_gas: None,
+            gas_limit: decode_u64(&rlp.at(2)?)?,
+            to: decode_address(&rlp.at(3)?)?,
+            value: decode_u128(&rlp.at(4)?)?,
+            data: rlp.at(5)?.data()?.to_vec(),
+            chain_id,
+            v,
+            r,
+            s,
+        })
+    }
+
+    fn decode_typed(raw: &[u8], tx_type: u8) -> Result<Self> {
+        let rlp = Rlp::new(&raw[1..]);
+        // fields before the signature, the access list is the last of them
+        let unsigned = if tx_type == ACCESS_LIST_TX_TYPE { 8 } else { 9 };
+        if !rlp.is_list()
+            || rlp.item_count()? != unsigned + 3
+            || rlp.payload_info()?.total() != raw.len() - 1
+        {
+            bail!("invalid type {} transaction envelope", tx_type);
+        }
+        // the access list only warms slots up, it is checked but not applied
+        if !rlp.at(unsigned - 1)?.is_list() {
+            bail!("invalid access list");
+        }
+
+        let mut stream = RlpStream::new_list(unsigned);
+        for i in 0..unsigned {
+            stream.append_raw(rlp.at(i)?.as_raw(), 1);
+        }
+        let signing_hash = hash_keccak256(&[&[tx_type][..], &stream.out()[..]].concat());
+        let y_parity = decode_u64(&rlp.at(unsigned)?)?;
+        if y_parity > 1 {
+            bail!("invalid signature y parity {}", y_parity);
+        }
+        let (r, s, public_key) = recover(
+            &signing_hash,
+            &rlp.at(unsigned + 1)?,
+            &rlp.at(unsigned + 2)?,
+            y_parity as u8,
+        )?;
+
+        let (gas_price, max_fee_per_gas, next) = if tx_type == ACCESS_LIST_TX_TYPE {
+            (decode_u128(&rlp.at(2)?)?, None, 3)
+        } else {
+            let max_priority_fee_per_gas = decode_u128(&rlp.at(2)?)?;
+            let max_fee_per_gas = decode_u128(&rlp.at(3)?)?;
+            if max_priority_fee_per_gas > max_fee_per_gas {
+                bail!(
+                    "max priority fee {} exceeds max fee {}",
+                    max_priority_fee_per_gas,
+                    max_fee_per_gas
+                );
+            }
+            (max_priority_fee_per_gas, Some(max_fee_per_gas), 4)
+        };
+
+        Ok(Self {
+            tx_type,
+            hash: hash_keccak256(raw),
+            sender: public_key.get_address(),
+            public_key: public_key.as_slice().to_vec(),
+            nonce: decode_u64(&rlp.at(1)?)?,
+            gas_price,
+            max_fee_per_gas,
+            gas_limit: decode_u64(&rlp.at(next)?)?,
+            to: decode_address(&rlp.at(next + 1)?)?,
+            value: decode_u128(&rlp.at(next + 2)?)?,
+            data: rlp.at(next + 3)?.data()?.to_vec(),
+            chain_id: Some(decode_u64(&rlp.at(0)?)?),
+            v: y_parity,
+            r,
+            s,
+        })
+    }
+
+    /// Transactions must be replay protected and signed for this chain.
+    pub fn check_chain_id(&self, chain_id: &str) -> Result<()> {
+        match self.chain_id {
+            None => bail!("transaction is not replay protected by EIP-155"),
+            Some(id) if id.to_string() != chain_id => {
+                bail!("transaction chain id {} mismatch {}", id, chain_id)
+            }
+            Some(_) => Ok(()),
+        }
+    }
+
+    /// Wrap the raw envelope into a `TransactionSign`, the protocol fields mirror the
+    /// decoded ones and the envelope itself travels in the signature.
+    pub fn to_transaction_sign(
+        &self,
+        raw: &[u8],
+        source_type: TransactionSign_SourceType,
+    ) -> TransactionSign {
+        let mut tx = Transaction::new();
+        tx.set_tx_type(TransactionType::ETH_LEGACY);
+        tx.set_source(self.sender.clone());
+        tx.set_nonce(self.nonce);
+        if let Some(to) = &self.to {
+            tx.set_to(to.clone());
+        }
+        tx.set_value(self.value.to_string());
+        tx.set_payload(self.data.clone());
+        tx.set_gas_limit(self.gas_limit);
+        tx.set_gas_price(self.gas_price.to_string());
+        tx.set_hub_id(self_chain_hub());
+        if let Some(chain_id) = self.chain_id {
+            tx.set_chain_id(chain_id.to_string());
+        }
+
+        let mut signature = Signature::new();
+        signature.set_encryption_type(ETH_ENCRYPTION_TYPE.to_string());
+        signature.set_sign_data(raw.to_vec());
+        signature.set_public_key(self.public_key.clone());
+
+        let mut tx_sign = TransactionSign::new();
+        tx_sign.set_transaction(tx);
+        tx_sign.set_signatures(RepeatedField::from(vec![signature]));
+        tx_sign.set_source_type(source_type);
+        tx_sign
+    }
+
+    /// Check `tx_sign` carries the protocol fields and signature `to_transaction_sign` makes
+    /// from `raw`. They are stored and indexed next to the envelope, which alone is signed.
+    pub fn check_transaction_sign(&self, raw: &[u8], tx_sign: &TransactionSign) -> Result<()> {
+        let expected = self.to_transaction_sign(raw, tx_sign.get_source_type());
+        if expected.get_transaction() != tx_sign.get_transaction()
+            || expected.get_signatures() != tx_sign.get_signatures()
+        {
+            bail!("transaction fields do not match its ethereum envelope");
+        }
+        Ok(())
+    }
+}
+
+/// The raw ethereum envelope carried by the transaction, if it was submitted as one.
+pub fn eth_envelope(tx_sign: &TransactionSign) -> Option<&[u8]> {
+    tx_sign
+        .get_signatures()
+        .iter()
+        .find(|s| s.get_encryption_type() == ETH_ENCRYPTION_TYPE)
+        .map(|s| s.get_sign_data())
+}
+
+/// The EIP-2718 type of a raw envelope.
+pub fn envelope_type(raw: &[u8]) -> u8 {
+    match raw.first() {
+        Some(first) if *first < 0x7f => *first,
+        _ => LEGACY_TX_TYPE,
+    }
+}
+
+// returns the 32 bytes r and s and the recovered public key
+fn recover(
+    signing_hash: &[u8],
+    r: &Rlp,
+    s: &Rlp,
+    recovery_id: u8,
+) -> Result<(Vec<u8>, Vec<u8>, Secp256k1PublicKey)> {
+    let r = decode_word(r)?;
+    let s = decode_word(s)?;
+    if s.as_slice() > SECP256K1_HALF_ORDER.as_slice() {
+        bail!("signature s is not in the lower half of the curve order");
+    }
+    let signature = [r.as_slice(), s.as_slice()].concat();
+    match Secp256k1Context::new().recover(signing_hash, &signature, recovery_id) {
+        Ok(public_key) => Ok((r, s, public_key)),
+        Err(e) => bail!("recover sender error {}", e),
+    }
+}
+
+fn decode_uint(rlp: &Rlp, max_len: usize) -> Result<u128> {
+    let data = rlp.data()?;
+    if data.len() > max_len {
+        bail!("integer of {} bytes overflow", data.len());
+    }
+    if data.first() == Some(&0) {
+        bail!("integer with leading zero");
+    }
+    Ok(data.iter().fold(0u128, |acc, b| (acc << 8) | *b as u128))
+}
+
+fn decode_u64(rlp: &Rlp) -> Result<u64> {
+    Ok(decode_uint(rlp, 8)? as u64)
+}
+
+fn decode_u128(rlp: &Rlp) -> Result<u128> {
+    decode_uint(rlp, 16)
+}
+
+// r and s are 32 bytes big endian words with the leading zeros stripped
+fn decode_word(rlp: &Rlp) -> Result<Vec<u8>> {
+    let data = rlp.data()?;
+    if data.len() > 32 {
+        bail!("signature word of {} bytes", data.len());
+    }
+    let mut word = vec![0u8; 32 - data.len()];
+    word.extend_from_slice(data);
+    Ok(word)
+}
+
+fn decode_address(rlp: &Rlp) -> Result<Option<String>> {
+    let data = rlp.data()?;
+    match data.len() {
+        0 => Ok(None),
+        ETH_ADDRESS_LENGTH => Ok(Some(format!("{}{}", ADDRESS_PREFIX, hex::encode(data)))),
+        len => bail!("invalid address length {}", len),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the example transaction of EIP-155, signed with the key 0x4646...46 for chain 1
+    const EIP155_RAW: &str = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+
+    #[test]
+    fn decode_eip155_test() {
+        let raw = hex::decode(EIP155_RAW).unwrap();
+        let tx = EthTransaction::decode(&raw).unwrap();
+
+        assert_eq!(tx.tx_type, LEGACY_TX_TYPE);
+        assert_eq!(
+            tx.sender,
+            "did:gdt:0x9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f"
+        );
+        assert_eq!(tx.nonce, 9);
+        assert_eq!(tx.gas_price, 20_000_000_000);
+        assert_eq!(tx.gas_limit, 21000);
+        assert_eq!(
+            tx.to,
+            Some("did:gdt:0x3535353535353535353535353535353535353535".to_string())
+        );
+        assert_eq!(tx.value, 1_000_000_000_000_000_000);
+        assert!(tx.data.is_empty());
+        assert_eq!(tx.chain_id, Some(1));
+        assert_eq!(tx.v, 37);
+        assert_eq!(tx.hash, hash_keccak256(&raw));
+
+        assert!(tx.check_chain_id("1").is_ok());
+        assert!(tx.check_chain_id("2024").is_err());
+    }
+
+    #[test]
+    fn decode_invalid_test() {
+        let raw = hex::decode(EIP155_RAW).unwrap();
+
+        // truncated envelope
+        assert!(EthTransaction::decode(&raw[..raw.len() - 1]).is_err());
+        // bytes after the envelope would give the same transaction another hash
+        assert!(EthTransaction::decode(&[&raw[..], &[0x00][..]].concat()).is_err());
+        // unknown envelope type
+        assert!(EthTransaction::decode(&[0x03, 0xc0]).is_err());
+        // typed envelope with the wrong number of fields
+        assert!(EthTransaction::decode(&[DYNAMIC_FEE_TX_TYPE, 0xc0]).is_err());
+        assert!(EthTransaction::decode(&[]).is_err());
+
+        // a tampered signature recovers another sender or nothing at all
+        let mut tampered = raw.clone();
+        let last = tampered.len() - 1;
+        tampered[last] ^= 0x01;
+        if let Ok(tx) = EthTransaction::decode(&tampered) {
+            assert_ne!(
+                tx.sender,
+                "did:gdt:0x9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f"
+            );
+        }
+    }
+
+    #[test]
+    fn transaction_sign_test() {
+        configure::init_test_config();
+        let raw = hex::decode(EIP155_RAW).unwrap();
+        let tx = EthTransaction::decode(&raw).unwrap();
+        let tx_sign = tx.to_transaction_sign(&raw, TransactionSign_SourceType::JSONRPC);
+        assert!(tx.check_transaction_sign(&raw, &tx_sign).is_ok());
+
+        // fields rewritten next to the unchanged envelope
+        let mut rewritten = tx_sign.clone();
+        rewritten
+            .mut_transaction()
+            .set_source("did:gdt:0x3535353535353535353535353535353535353535".to_string());
+        assert!(tx.check_transaction_sign(&raw, &rewritten).is_err());
+
+        let mut rewritten = tx_sign.clone();
+        rewritten.mut_transaction().clear_to();
+        assert!(tx.check_transaction_sign(&raw, &rewritten).is_err());
+
+        // a signature added to the envelope's
+        let mut rewritten = tx_sign;
+        rewritten.mut_signatures().push(Signature::new());
+        assert!(tx.check_transaction_sign(&raw, &rewritten).is_err());
+    }
+
+    #[test]
+    fn envelope_type_test() {
+        assert_eq!(
+            envelope_type(&hex::decode(EIP155_RAW).unwrap()),
+            LEGACY_TX_TYPE
+        );
+        assert_eq!(
+            envelope_type(&[ACCESS_LIST_TX_TYPE, 0xc0]),
+            ACCESS_LIST_TX_TYPE
+        );
+        assert_eq!(
+            envelope_type(&[DYNAMIC_FEE_TX_TYPE, 0xc0]),
+            DYNAMIC_FEE_TX_TYPE
+        );
+    }
+}
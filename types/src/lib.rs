@@ -1,3 +1,4 @@
 pub mod error;
+pub mod eth_transaction;
 pub mod transaction;
 pub use transaction::SignedTransaction;
@@ -1,7 +1,8 @@
+use crate::eth_transaction::{eth_envelope, EthTransaction};
 use protobuf::{Message, RepeatedField};
 use protos::common::Signature;
 use protos::ledger::*;
-use utils::general::hash_crypto_byte;
+use utils::general::{hash_crypto_byte, self_chain_id};
 #[derive(Clone, Default)]
 pub struct SignedTransaction {
     tx_hash: Vec<u8>,
@@ -117,6 +118,29 @@ impl TryFrom<TransactionSign> for SignedTransaction {
     type Error = anyhow::Error;
 
     fn try_from(tx_sign: TransactionSign) -> anyhow::Result<Self> {
+        // an ethereum envelope is the source of truth for its fields and hash
+        if let Some(raw) = eth_envelope(&tx_sign) {
+            let eth_tx = EthTransaction::decode(raw)?;
+            eth_tx.check_chain_id(&self_chain_id())?;
+            eth_tx.check_transaction_sign(raw, &tx_sign)?;
+            return Ok(Self {
+                tx_hash: eth_tx.hash,
+                source: eth_tx.sender,
+                nonce: eth_tx.nonce,
+                value: eth_tx.value,
+                to: eth_tx.to.unwrap_or_default(),
+                payload: eth_tx.data,
+                gas_price: eth_tx.gas_price,
+                gas_limit: eth_tx.gas_limit,
+                hub_id: tx_sign.get_transaction().get_hub_id().to_string(),
+                chain_id: eth_tx.chain_id.unwrap_or_default().to_string(),
+                reserves: None,
+                signatures: tx_sign.get_signatures().to_vec(),
+                source_type: tx_sign.get_source_type(),
+                tx_type: TransactionType::ETH_LEGACY,
+            });
+        }
+
         let tx = tx_sign.get_transaction().clone();
         let reserves = if tx.has_reserves() {
             Some(tx.get_reserves().clone())
@@ -79,7 +79,7 @@ pub struct MerkleProofHash {
     direction: Branch,
 }
 
-#[derive(PartialEq)]
+#[derive(PartialEq, Clone, Debug)]
 pub enum Branch {
     Left,
     Right,
@@ -93,6 +93,14 @@ impl MerkleProofHash {
             direction: direction,
         }
     }
+
+    pub fn hash(&self) -> &[u8] {
+        &self.hash
+    }
+
+    pub fn direction(&self) -> &Branch {
+        &self.direction
+    }
 }
 
 pub struct Tree {
@@ -405,3 +413,21 @@ fn test_audit_tree() {
     );
     assert_eq!(true, result);
 }
+
+#[test]
+fn test_audit_trail_rebuilt() {
+    for count in [1u8, 3, 5] {
+        let base_leafs: Vec<Vec<u8>> = (0..count).map(|i| hash_crypto_byte(&[i])).collect();
+        let mut tree = Tree::new();
+        tree.build(base_leafs.clone());
+        for leaf in base_leafs.iter() {
+            let mut audit_trail: Vec<MerkleProofHash> = Vec::new();
+            tree.audit_proof(leaf.clone(), &mut audit_trail);
+            let rebuilt: Vec<MerkleProofHash> = audit_trail
+                .iter()
+                .map(|p| MerkleProofHash::new(p.hash().to_vec(), p.direction().clone()))
+                .collect();
+            assert!(Tree::new().verify_audit(tree.root(), leaf.clone(), &rebuilt));
+        }
+    }
+}
@@ -12,6 +12,7 @@ pub const BFT_PREVIOUS_PROOF: &str = "bft_previous_proof";
 pub const BFT_CURRENT_PROOF: &str = "bft_current_proof";
 pub const BFT_CONSENSUS_VALUE_HASH: &str = "bft_consensus_value_hash";
 pub const BFT_TX_HASH_LIST: &str = "bft_tx_hash_list";
+pub const HOTSTUFF_CURRENT_PROOF: &str = "hotstuff_current_proof";
 
 pub const NODE_VOLIDATORE: u64 = 0;
 pub const NODE_CANDIDATE_ADD: u64 = 1;
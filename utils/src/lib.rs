@@ -8,6 +8,7 @@ pub mod logger;
 pub mod parse;
 pub mod proto2json;
 pub mod signature;
+pub mod signer;
 pub mod timer;
 pub mod timer_manager;
 pub mod timing;
@@ -0,0 +1,270 @@
+use std::{
+    io::{Read, Write},
+    os::unix::net::UnixStream,
+    time::Duration,
+};
+
+use anyhow::{anyhow, bail};
+use configure::{Configure, SignerConfig, SIGNER_TYPE_EXTERNAL, SIGNER_TYPE_LOCAL};
+use msp::signing::{
+    check_private_key, create_context, create_private_key, create_public_key_by_bytes,
+};
+use once_cell::sync::OnceCell;
+use protos::common::{Signature, SignerRequest, SignerResponse};
+
+use crate::{
+    parse::ProtocolParser,
+    verify_sign::{sign, verify_sign},
+};
+
+// no signer answer is anywhere near this large
+const MAX_RESPONSE_SIZE: usize = 64 * 1024;
+
+/// Signs consensus messages for the node. The signature carries the key's encryption type, so
+/// receivers verify it whatever algorithm the node uses.
+pub trait Signer: Send + Sync {
+    fn encryption_type(&self) -> &str;
+
+    /// The address of the signing key.
+    fn address(&self) -> &str;
+
+    fn sign(&self, data: &[u8]) -> anyhow::Result<Signature>;
+}
+
+/// Signs with a private key held in the process.
+pub struct LocalSigner {
+    private_key: String,
+    encryption_type: String,
+    address: String,
+}
+
+impl LocalSigner {
+    pub fn new(private_key: &str, encryption_type: &str) -> anyhow::Result<Self> {
+        let context = create_context(encryption_type).map_err(|e| anyhow!("{:?}", e))?;
+        if !check_private_key(private_key) {
+            bail!("node private key is not a hex private key");
+        }
+        let key =
+            create_private_key(encryption_type, private_key).map_err(|e| anyhow!("{:?}", e))?;
+        let public_key = context
+            .get_public_key(key.as_ref())
+            .map_err(|e| anyhow!("{:?}", e))?;
+        Ok(Self {
+            private_key: private_key.to_string(),
+            encryption_type: encryption_type.to_string(),
+            address: public_key.get_address(),
+        })
+    }
+}
+
+impl Signer for LocalSigner {
+    fn encryption_type(&self) -> &str {
+        &self.encryption_type
+    }
+
+    fn address(&self) -> &str {
+        &self.address
+    }
+
+    fn sign(&self, data: &[u8]) -> anyhow::Result<Signature> {
+        sign(&self.private_key, data, &self.encryption_type)
+    }
+}
+
+/// Asks a signer process listening on a unix socket, which may keep its keys in an HSM behind
+/// a PKCS#11 module. Every request opens its own connection, a restarted signer is picked up
+/// on the next signature.
+pub struct ExternalSigner {
+    socket_path: String,
+    key_id: String,
+    encryption_type: String,
+    timeout: Duration,
+    public_key: Vec<u8>,
+    address: String,
+}
+
+impl ExternalSigner {
+    /// Connect to the signer of `config` and learn its public key.
+    pub fn connect(config: &SignerConfig) -> anyhow::Result<Self> {
+        if config.socket_path.is_empty() {
+            bail!("external signer has no socket_path");
+        }
+        let mut signer = Self {
+            socket_path: config.socket_path.clone(),
+            key_id: config.key_id.clone(),
+            encryption_type: config.encryption_type.clone(),
+            timeout: Duration::from_millis(config.timeout),
+            public_key: Vec::new(),
+            address: String::new(),
+        };
+        let response = signer.request(&[])?;
+        let public_key =
+            create_public_key_by_bytes(&signer.encryption_type, response.get_public_key())
+                .map_err(|e| anyhow!("{:?}", e))?;
+        signer.address = public_key.get_address();
+        signer.public_key = response.get_public_key().to_vec();
+        Ok(signer)
+    }
+
+    fn request(&self, data: &[u8]) -> anyhow::Result<SignerResponse> {
+        let mut stream = UnixStream::connect(&self.socket_path)?;
+        stream.set_read_timeout(Some(self.timeout))?;
+        stream.set_write_timeout(Some(self.timeout))?;
+
+        let mut request = SignerRequest::new();
+        request.set_key_id(self.key_id.clone());
+        request.set_encryption_type(self.encryption_type.clone());
+        request.set_data(data.to_vec());
+        let body = ProtocolParser::serialize::<SignerRequest>(&request);
+        stream.write_all(&(body.len() as u32).to_be_bytes())?;
+        stream.write_all(&body)?;
+
+        let mut len = [0u8; 4];
+        stream.read_exact(&mut len)?;
+        let len = u32::from_be_bytes(len) as usize;
+        if len > MAX_RESPONSE_SIZE {
+            bail!("external signer response of {} bytes is too large", len);
+        }
+        let mut body = vec![0u8; len];
+        stream.read_exact(&mut body)?;
+        let response = ProtocolParser::deserialize::<SignerResponse>(&body)?;
+        if !response.get_error().is_empty() {
+            bail!("external signer error: {}", response.get_error());
+        }
+        Ok(response)
+    }
+}
+
+impl Signer for ExternalSigner {
+    fn encryption_type(&self) -> &str {
+        &self.encryption_type
+    }
+
+    fn address(&self) -> &str {
+        &self.address
+    }
+
+    fn sign(&self, data: &[u8]) -> anyhow::Result<Signature> {
+        let response = self.request(data)?;
+        if response.get_public_key() != self.public_key.as_slice() {
+            bail!("external signer answered with another key");
+        }
+        let mut signature = Signature::new();
+        signature.set_public_key(self.public_key.clone());
+        signature.set_sign_data(response.get_sign_data().to_vec());
+        signature.set_encryption_type(self.encryption_type.clone());
+        // a bad signature would only get the node's messages dropped by its peers
+        if !verify_sign(&signature, data)? {
+            bail!("external signer returned an invalid signature");
+        }
+        Ok(signature)
+    }
+}
+
+static NODE_SIGNER: OnceCell<Box<dyn Signer>> = OnceCell::new();
+
+/// Create the signer of `config`, which must sign as its node_address.
+pub fn create_signer(config: &Configure) -> anyhow::Result<Box<dyn Signer>> {
+    let signer: Box<dyn Signer> = match config.signer.signer_type.as_str() {
+        SIGNER_TYPE_LOCAL => Box::new(LocalSigner::new(
+            &config.node_private_key,
+            &config.signer.encryption_type,
+        )?),
+        SIGNER_TYPE_EXTERNAL => Box::new(ExternalSigner::connect(&config.signer)?),
+        signer_type => bail!("unknown signer type:{}", signer_type),
+    };
+    if signer.address() != config.node_address {
+        bail!(
+            "the signer key address {} is not node_address {}",
+            signer.address(),
+            config.node_address
+        );
+    }
+    Ok(signer)
+}
+
+pub fn initialize_node_signer(config: &Configure) -> anyhow::Result<()> {
+    let signer = create_signer(config)?;
+    let _ = NODE_SIGNER.set(signer);
+    Ok(())
+}
+
+pub fn node_signer() -> &'static dyn Signer {
+    NODE_SIGNER
+        .get()
+        .expect("node signer is not initialized")
+        .as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use msp::signing::create_secret_key;
+    use std::os::unix::net::UnixListener;
+
+    const ALGORITHMS: [&str; 3] = ["eddsa_ed25519", "secp256k1", "sm2"];
+
+    #[test]
+    fn local_signer_signs_with_each_algorithm() {
+        for algorithm in ALGORITHMS {
+            let key = create_secret_key(algorithm).unwrap();
+            let signer = LocalSigner::new(&key.as_hex(), algorithm).unwrap();
+            assert_eq!(signer.address(), key.get_address());
+
+            let signature = signer.sign(b"consensus").unwrap();
+            assert_eq!(signature.get_encryption_type(), algorithm);
+            assert!(verify_sign(&signature, b"consensus").unwrap());
+        }
+    }
+
+    #[test]
+    fn external_signer_signs_over_socket() {
+        let socket_path = std::env::temp_dir().join(format!("signer-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&socket_path);
+        let listener = UnixListener::bind(&socket_path).unwrap();
+        let key = create_secret_key("sm2").unwrap();
+        let key_hex = key.as_hex();
+        let address = key.get_address();
+
+        // answers the public key request of connect, then one signature
+        let server = std::thread::spawn(move || {
+            for _ in 0..2 {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut len = [0u8; 4];
+                stream.read_exact(&mut len).unwrap();
+                let mut body = vec![0u8; u32::from_be_bytes(len) as usize];
+                stream.read_exact(&mut body).unwrap();
+                let request = ProtocolParser::deserialize::<SignerRequest>(&body).unwrap();
+                assert_eq!(request.get_key_id(), "validator");
+
+                let signature = sign(&key_hex, request.get_data(), "sm2").unwrap();
+                let mut response = SignerResponse::new();
+                response.set_public_key(signature.get_public_key().to_vec());
+                if !request.get_data().is_empty() {
+                    response.set_sign_data(signature.get_sign_data().to_vec());
+                }
+                let body = ProtocolParser::serialize::<SignerResponse>(&response);
+                stream
+                    .write_all(&(body.len() as u32).to_be_bytes())
+                    .unwrap();
+                stream.write_all(&body).unwrap();
+            }
+        });
+
+        let config = SignerConfig {
+            signer_type: SIGNER_TYPE_EXTERNAL.to_string(),
+            encryption_type: "sm2".to_string(),
+            socket_path: socket_path.to_string_lossy().to_string(),
+            key_id: "validator".to_string(),
+            timeout: 3000,
+        };
+        let signer = ExternalSigner::connect(&config).unwrap();
+        assert_eq!(signer.address(), address);
+        let signature = signer.sign(b"consensus").unwrap();
+        assert_eq!(signature.get_encryption_type(), "sm2");
+        assert!(verify_sign(&signature, b"consensus").unwrap());
+
+        server.join().unwrap();
+        let _ = std::fs::remove_file(&socket_path);
+    }
+}
@@ -23,6 +23,10 @@ pub enum TimerEventType {
     PbftDelayDeleteTx,
     // Tbft TimeOut
     TbftTimeOutCheck,
+    // delay timer, until the next hotstuff block is due
+    HotStuffPropose,
+    // delay timer, the timeout of the current hotstuff view
+    HotStuffViewTimeout,
 }
 
 pub static GLOBAL_TIMER: Lazy<Mutex<timer::Timer>> = Lazy::new(|| {
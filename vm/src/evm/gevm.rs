@@ -2,6 +2,7 @@ use std::collections::BTreeMap;
 
 use crate::{
     database::{State, VmState},
+    fee::{block_gas_limit, fee_beneficiary},
     post_state::{PostAccount, Receipt},
     utils::AddressConverter,
     PostState,
@@ -54,23 +55,14 @@ impl EvmVM {
         };
 
         let ResultAndState { result, state } = ret_and_state;
-        let (output, contract_address) = match result.clone() {
-            ExecutionResult::Success { output, .. } => match output {
-                Output::Call(value) => (Some(value.into()), None),
-                Output::Create(value, address) => (Some(value.into()), address),
-            },
-            ExecutionResult::Revert { gas_used, output } => {
-                println!(
-                    "Execution reverted: gas used: {}, output: {:?}",
-                    gas_used, output
-                );
-                (Some(output.into()), None)
-            }
-            ExecutionResult::Halt { reason, gas_used } => {
-                println!("Execution halted: {:?}, gas used: {}", reason, gas_used);
-                (None, None)
-            }
-        };
+        if let ExecutionResult::Revert { gas_used, output } = &result {
+            println!(
+                "Execution reverted: gas used: {}, output: {:?}",
+                gas_used, output
+            );
+        } else if let ExecutionResult::Halt { reason, gas_used } = &result {
+            println!("Execution halted: {:?}, gas used: {}", reason, gas_used);
+        }
 
         let blocknum = match u64::try_from(self.evm.env.block.number) {
             Ok(n) => n,
@@ -80,23 +72,15 @@ impl EvmVM {
                 });
             }
         };
-        self.commit_changes(blocknum, state, true, post_state);
-
-        post_state.add_receipt(
-            blocknum,
-            Receipt {
-                index: index,
-                // Success flag was added in `EIP-658: Embedding transaction status code in
-                // receipts`.
-                success: result.is_success(),
-                gas_used: result.gas_used(),
-                contract_address: contract_address,
-                output: output,
-                // convert to reth log
-                logs: result.into_logs().into_iter().collect(),
-                description: None,
-            },
-        );
+        // flush the changes of every transaction into the cache state, so that the sysvm and
+        // wasm transactions after it see them, and reload the accounts for the next one
+        let mut tx_post_state = PostState::new();
+        self.commit_changes(blocknum, state, true, &mut tx_post_state);
+        let cache_state = self.db().db.state();
+        tx_post_state.convert_to_geno_state(blocknum, cache_state)?;
+        self.db().accounts.clear();
+
+        post_state.add_receipt(blocknum, Receipt::from_execution_result(index, result));
 
         Ok(())
     }
@@ -306,13 +290,15 @@ impl EvmVM {
         header: &LedgerHeader,
     ) -> std::result::Result<(), VmError> {
         self.evm.env.block.number = U256::from(header.get_height());
-        self.evm.env.block.coinbase = AddressConverter::to_evm_address(header.get_proposer())?;
+        // revm pays the fee of the transaction to the coinbase
+        self.evm.env.block.coinbase = AddressConverter::to_evm_address(&fee_beneficiary(header))?;
         self.evm.env.block.timestamp = U256::from(header.get_timestamp());
 
         self.evm.env.block.prevrandao = Some(B256::from(U256::from(1)));
         self.evm.env.block.difficulty = U256::ZERO;
+        // nothing is burnt, the whole gas_used * gas_price goes to the coinbase
         self.evm.env.block.basefee = U256::ZERO;
-        self.evm.env.block.gas_limit = U256::MAX;
+        self.evm.env.block.gas_limit = U256::from(block_gas_limit());
         Ok(())
     }
 
@@ -344,6 +330,8 @@ impl EvmVM {
         transaction: &SignedTransaction,
     ) -> std::result::Result<ResultAndState, VmError> {
         self.fill_tx_env(&transaction)?;
+        // read-only call, the nonce of the caller is not checked
+        self.evm.env.tx.nonce = None;
 
         // main execution.
         let out = self.evm.transact();
@@ -2,8 +2,8 @@ use protos::common::{ContractEvent, ContractResult, TransactionResult};
 use revm::primitives::{
     hash_map::{self, Entry},
     hex::ToHex,
-    AccountInfo, Address, Bytecode, ExecutionResult, Log, ResultAndState, TransactTo, TxEnv, B160,
-    B256, U256,
+    AccountInfo, Address, Bytecode, ExecutionResult, Log, Output, ResultAndState, TransactTo, TxEnv,
+    B160, B256, U256,
 };
 use state::{AccountFrame, CacheState};
 use std::{
@@ -18,7 +18,8 @@ pub use account::{AccountChanges, PostAccount};
 mod storage;
 pub use storage::{Storage, StorageChanges, StorageChangeset, StorageWipe};
 
-use crate::utils::{u256_2_u128, AddressConverter, StorageConverter};
+use crate::utils::{decode_revert_reason, u256_2_u128, AddressConverter, StorageConverter};
+use crate::wasm::types::WasmResult;
 
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
 pub struct Receipt {
@@ -32,6 +33,86 @@ pub struct Receipt {
 }
 
 impl Receipt {
+    pub fn from_execution_result(index: usize, result: ExecutionResult) -> Receipt {
+        let (output, contract_address, description) = match result.clone() {
+            ExecutionResult::Success { output, .. } => match output {
+                Output::Call(value) => (Some(value.into()), None, None),
+                Output::Create(value, address) => (Some(value.into()), address, None),
+            },
+            ExecutionResult::Revert { output, .. } => {
+                let reason = decode_revert_reason(&output)
+                    .unwrap_or_else(|| "execution reverted".to_string());
+                (Some(output.into()), None, Some(reason))
+            }
+            ExecutionResult::Halt { reason, .. } => (None, None, Some(format!("{:?}", reason))),
+        };
+
+        Receipt {
+            index,
+            // Success flag was added in `EIP-658: Embedding transaction status code in
+            // receipts`.
+            success: result.is_success(),
+            gas_used: result.gas_used(),
+            contract_address,
+            output,
+            logs: result.into_logs().into_iter().collect(),
+            description,
+        }
+    }
+
+    pub(crate) fn from_wasm_result(
+        address: B160,
+        created: bool,
+        gas_limit: u64,
+        result: WasmResult,
+    ) -> Receipt {
+        let contract_address = if created { Some(address) } else { None };
+        match result {
+            WasmResult::Success {
+                used_gas,
+                event,
+                data,
+            } => Receipt {
+                index: 0,
+                success: true,
+                logs: event
+                    .into_iter()
+                    .map(|e| Log {
+                        address,
+                        topics: Vec::new(),
+                        data: bytes::Bytes::from(e.into_bytes()),
+                    })
+                    .collect(),
+                gas_used: used_gas,
+                contract_address,
+                output: Some(bytes::Bytes::from(data.into_bytes())),
+                description: None,
+            },
+            WasmResult::Reject {
+                code,
+                reason,
+                used_gas,
+            } => Receipt {
+                index: 0,
+                success: false,
+                logs: Vec::new(),
+                gas_used: used_gas,
+                contract_address: None,
+                output: None,
+                description: Some(format!("{:?}: {}", code, reason)),
+            },
+            WasmResult::OutOfGas => Receipt {
+                index: 0,
+                success: false,
+                logs: Vec::new(),
+                gas_used: gas_limit,
+                contract_address: None,
+                output: None,
+                description: Some("out of gas".to_string()),
+            },
+        }
+    }
+
     // lack error code,message,block_hash,block_height to set
     pub fn convert_to_geno_txresult(&self) -> TransactionResult {
         let mut tx_result = TransactionResult::default();
@@ -307,6 +388,9 @@ impl PostState {
                         tracing::error!("post_state Process storages failed,update storage error");
                         panic!("post_state Process storages failed,update storage error");
                     }
+                } else {
+                    geno_account
+                        .delete_contract_metadata(&StorageConverter::from_evm_storage(key.clone()));
                 }
             }
             state.upsert(&geno_address, geno_account);
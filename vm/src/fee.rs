@@ -0,0 +1,106 @@
+use crate::traits::BlockEnv;
+use state::{AccountFrame, CacheState};
+use types::{error::VmError, transaction::SignedTransaction};
+use utils::general::consensus_config;
+
+// gas used by a system contract call
+pub(crate) const SYSTEM_CONTRACT_GAS: u64 = 21000;
+// gas of a transaction that fails before its code runs
+pub(crate) const BASE_TX_GAS: u64 = 21000;
+
+// fees go to the configured treasury, the block proposer otherwise
+pub(crate) fn fee_beneficiary<E: BlockEnv>(env: &E) -> String {
+    let treasury = consensus_config().fee_treasury;
+    if treasury.is_empty() {
+        env.coinbase().to_string()
+    } else {
+        treasury
+    }
+}
+
+pub(crate) fn block_gas_limit() -> u64 {
+    consensus_config().block_max_gas_limit
+}
+
+// the sender must afford the whole gas limit plus the value it sends
+pub(crate) fn check_balance(
+    transaction: &SignedTransaction,
+    state: &CacheState,
+) -> std::result::Result<(), VmError> {
+    let account = load_account(transaction.sender(), state)?;
+    let cost = (transaction.gas_limit() as u128)
+        .checked_mul(transaction.gas_price())
+        .and_then(|fee| fee.checked_add(transaction.value()));
+    match cost {
+        Some(cost) if cost <= account.balance() => Ok(()),
+        _ => Err(VmError::VMExecuteError {
+            hash: transaction.hash_hex(),
+            message: format!(
+                "balance of {} is not enough for gas limit {} price {} value {}",
+                transaction.sender(),
+                transaction.gas_limit(),
+                transaction.gas_price(),
+                transaction.value()
+            ),
+        }),
+    }
+}
+
+// move gas_used * gas_price from the sender to the beneficiary
+pub(crate) fn charge_fee(
+    transaction: &SignedTransaction,
+    gas_used: u64,
+    beneficiary: &str,
+    state: &CacheState,
+) -> std::result::Result<(), VmError> {
+    let fee = match (gas_used as u128).checked_mul(transaction.gas_price()) {
+        Some(fee) => fee,
+        None => {
+            return Err(VmError::ValueConvertError {
+                error: format!("fee overflow, gas used {}", gas_used),
+            })
+        }
+    };
+    if fee == 0 || transaction.sender() == beneficiary {
+        return Ok(());
+    }
+
+    let mut sender = load_account(transaction.sender(), state)?;
+    if sender.sub_balance(fee).is_none() {
+        return Err(VmError::VMExecuteError {
+            hash: transaction.hash_hex(),
+            message: format!("balance of {} is not enough for fee {}", sender.address(), fee),
+        });
+    }
+    state.upsert(&transaction.sender().to_string(), sender);
+
+    let beneficiary = beneficiary.to_string();
+    let mut account = match state.get(&beneficiary) {
+        Ok(Some(account)) => account,
+        Ok(None) => AccountFrame::new(beneficiary.clone(), 0),
+        Err(e) => {
+            return Err(VmError::StateError {
+                error: format!("{:?}", e),
+            })
+        }
+    };
+    if account.add_balance(fee).is_none() {
+        return Err(VmError::ValueConvertError {
+            error: format!("balance of {} overflow", beneficiary),
+        });
+    }
+    state.upsert(&beneficiary, account);
+    Ok(())
+}
+
+fn load_account(address: &str, state: &CacheState) -> std::result::Result<AccountFrame, VmError> {
+    match state.get(&address.to_string()) {
+        Ok(Some(account)) => Ok(account),
+        Ok(None) => Err(VmError::StateError {
+            error: format!("can not find account {:?}", address),
+        }),
+        Err(e) => Err(VmError::StateError {
+            error: format!("{:?}", e),
+        }),
+    }
+}
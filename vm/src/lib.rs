@@ -6,6 +6,7 @@ mod wasm;
 mod sysvm;
 mod evm;
 mod traits;
+mod fee;
 
 
 pub use evm::post_state::PostState;
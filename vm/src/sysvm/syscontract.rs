@@ -1,3 +1,4 @@
+use crate::fee::{charge_fee, check_balance, fee_beneficiary, SYSTEM_CONTRACT_GAS};
 use crate::post_state::{PostAccount, PostState, Receipt};
 use crate::traits::BlockEnv;
 use state::CacheState;
@@ -11,6 +12,13 @@ pub fn execute<E: BlockEnv>(
     state: CacheState,
     env: E,
 ) -> std::result::Result<(), VmError> {
+    if transaction.gas_limit() < SYSTEM_CONTRACT_GAS {
+        return Err(VmError::VMExecuteError {
+            hash: transaction.hash_hex(),
+            message: format!("gas limit is lower than {}", SYSTEM_CONTRACT_GAS),
+        });
+    }
+    check_balance(transaction, &state)?;
     nonce_increase(&transaction.sender().to_string(), &state)?;
     let system_contract = match SYSTEM_CONTRACT_FACTORY_INSTANCE.get() {
         Some(s) => s,
@@ -23,35 +31,45 @@ pub fn execute<E: BlockEnv>(
 
     let tx_hash = transaction.hash_hex();
 
-    let contract_result = match system_contract.invoke(
+    // the changes of a failed invoke are thrown away, its fee is charged all the same
+    let tx_state = state.new_stack_state(false);
+    let mut receipt = match system_contract.invoke(
         String::new(),
         transaction.payload(),
-        state,
+        tx_state.clone(),
         transaction.sender().to_owned(),
         transaction.to().to_owned(),
         env.height(),
         env.timestamp(),
         &tx_hash,
     ) {
-        Ok(result) => result,
-        Err(e) => {
-            return Err(VmError::VMExecuteError {
-                hash: tx_hash,
-                message: e.to_string(),
-            })
-        }
+        Ok(contract_result) => match Receipt::from_contract_result(&contract_result) {
+            Ok(receipt) => {
+                tx_state.commit();
+                receipt
+            }
+            Err(e) => {
+                return Err(VmError::InternalError {
+                    error: e.to_string(),
+                })
+            }
+        },
+        Err(e) => Receipt {
+            success: false,
+            description: Some(e.to_string()),
+            ..Default::default()
+        },
     };
 
-    let mut receipt = match Receipt::from_contract_result(&contract_result) {
-        Ok(receipt) => receipt,
-        Err(e) => {
-            return Err(VmError::InternalError {
-                error: e.to_string(),
-            })
-        }
-    };
+    charge_fee(
+        transaction,
+        SYSTEM_CONTRACT_GAS,
+        &fee_beneficiary(&env),
+        &state,
+    )?;
 
     receipt.index = index;
+    receipt.gas_used = SYSTEM_CONTRACT_GAS;
     post_state.add_receipt(env.height(), receipt);
 
     return Ok(());
@@ -79,3 +97,54 @@ fn nonce_increase(source: &String, state: &CacheState) -> std::result::Result<()
     };
     return Ok(());
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protos::ledger::{LedgerHeader, Transaction, TransactionSign};
+    use state::{empty_trie_root, AccountFrame};
+    use syscontract::{
+        contract_factory::{
+            initialize_system_contract_factory, SystemContractFactory,
+            VALIDATORS_ELECT_CONTRACT_INDEX,
+        },
+        system_address::get_system_address,
+    };
+
+    const SENDER: &str = "did:gdt:0xf6b02a2d47b84e845b7e3623355f041bcb36daf1";
+    const PROPOSER: &str = "did:gdt:0x160b54be617f4bff07bd6c994fc6dd17a69d5e4e";
+
+    #[test]
+    fn failed_invoke_pays_fee_test() {
+        configure::init_test_config();
+        initialize_system_contract_factory();
+        let state = CacheState::new(empty_trie_root());
+        let sender = SENDER.to_string();
+        state.upsert(&sender, AccountFrame::new(sender.clone(), 1_000_000));
+        for account in SystemContractFactory::instance().all_account() {
+            state.upsert(&account.address(), account);
+        }
+
+        let mut tx = Transaction::default();
+        tx.set_source(sender.clone());
+        tx.set_to(get_system_address(VALIDATORS_ELECT_CONTRACT_INDEX).unwrap());
+        tx.set_value("0".to_string());
+        tx.set_payload(b"not json".to_vec());
+        tx.set_gas_limit(SYSTEM_CONTRACT_GAS);
+        tx.set_gas_price("1".to_string());
+        let mut tx_sign = TransactionSign::default();
+        tx_sign.set_transaction(tx);
+        let transaction = SignedTransaction::try_from(tx_sign).unwrap();
+
+        let mut env = LedgerHeader::default();
+        env.set_proposer(PROPOSER.to_string());
+        let mut post_state = PostState::new();
+        execute(0, &transaction, &mut post_state, state.clone(), env).unwrap();
+
+        let account = state.get(&sender).unwrap().unwrap();
+        assert_eq!(account.nonce(), 1);
+        assert_eq!(account.balance(), 1_000_000 - SYSTEM_CONTRACT_GAS as u128);
+        let proposer = state.get(&PROPOSER.to_string()).unwrap().unwrap();
+        assert_eq!(proposer.balance(), SYSTEM_CONTRACT_GAS as u128);
+    }
+}
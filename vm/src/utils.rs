@@ -59,6 +59,27 @@ pub fn u128_2_u256(value: u128) -> U256 {
     U256::from(value)
 }
 
+/// Selector of solidity `Error(string)`, prefixed to the output of `revert("...")`.
+const REVERT_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+pub fn decode_revert_reason(output: &[u8]) -> Option<String> {
+    if output.len() < 4 + 32 * 2 || output[..4] != REVERT_SELECTOR {
+        return None;
+    }
+    let data = &output[4..];
+    let offset = u256_into::<usize>(U256::try_from_be_slice(&data[..32])?).ok()?;
+    let len_end = offset.checked_add(32)?;
+    if data.len() < len_end {
+        return None;
+    }
+    let len = u256_into::<usize>(U256::try_from_be_slice(&data[offset..len_end])?).ok()?;
+    let end = len_end.checked_add(len)?;
+    if data.len() < end {
+        return None;
+    }
+    String::from_utf8(data[len_end..end].to_vec()).ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -79,6 +100,21 @@ mod tests {
         };
     }
 
+    #[test]
+    fn decode_revert_reason_test() {
+        // revert("not owner")
+        let output = hex::decode(
+            "08c379a0\
+             0000000000000000000000000000000000000000000000000000000000000020\
+             0000000000000000000000000000000000000000000000000000000000000009\
+             6e6f74206f776e65720000000000000000000000000000000000000000000000",
+        )
+        .unwrap();
+        assert_eq!(decode_revert_reason(&output), Some("not owner".to_string()));
+        assert_eq!(decode_revert_reason(&output[..40]), None);
+        assert_eq!(decode_revert_reason(&[]), None);
+    }
+
     #[test]
     fn u256_into_u64_test() {
         //AddressConverter::from_evm_address(value);
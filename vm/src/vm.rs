@@ -1,5 +1,7 @@
 use crate::post_state::PostState;
+use crate::post_state::Receipt;
 use crate::sysvm;
+use crate::wasm;
 use crate::evm::gevm::EvmVM;
 
 use protos::ledger::LedgerHeader;
@@ -44,7 +46,13 @@ impl Executor {
         transaction: &SignedTransaction,
         post_state: &mut PostState,
     ) -> std::result::Result<(), VmError> {
-        Ok(())
+        wasm::execute(
+            index,
+            transaction,
+            post_state,
+            self.state.clone(),
+            self.header.clone(),
+        )
     }
 
     pub fn sysvm_execute(
@@ -68,4 +76,11 @@ impl Executor {
     ) -> std::result::Result<ResultAndState, VmError> {
         self.evm.call(transaction)
     }
+
+    pub fn wasm_call(
+        &mut self,
+        transaction: &SignedTransaction,
+    ) -> std::result::Result<Receipt, VmError> {
+        wasm::call(transaction, self.state.clone(), self.header.clone())
+    }
 }
@@ -1,47 +1,91 @@
-
 use anyhow::{anyhow, Result};
-use wasmtime::{Engine, Config, Store, Module, Val};
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use utils::general::hash_crypto_byte;
+use wasmtime::{Engine, Config, Linker, Store, Module, Trap, Val};
+use tracing::error;
 use super::types::{Context, WasmResult, WasmError};
 use super::host_func::host_func_init;
 use wasmtime::Val::I64;
 
+// fuel handed out when the context is not metered
+const UNMETERED_FUEL: u64 = i64::MAX as u64;
+const MAX_CACHED_MODULES: usize = 256;
+
+lazy_static! {
+    // one engine for all contracts, fuel consumption is counted per instruction so that
+    // every node stops a contract at the same point
+    static ref WASM_ENGINE: Engine = {
+        let mut config = Config::new();
+        config.consume_fuel(true);
+        config.cranelift_nan_canonicalization(true);
+        Engine::new(&config).expect("create wasm engine")
+    };
+    // host functions are the same for every call, linked once for the engine
+    static ref WASM_LINKER: Linker<Context> =
+        host_func_init(&WASM_ENGINE).expect("create wasm linker");
+    // compiled modules by code hash
+    static ref WASM_MODULE_CACHE: RwLock<HashMap<Vec<u8>, Module>> = RwLock::new(HashMap::new());
+}
+
+fn load_module(binary: &[u8]) -> Result<Module> {
+    let code_hash = hash_crypto_byte(binary);
+    if let Some(module) = WASM_MODULE_CACHE.read().get(&code_hash) {
+        return Ok(module.clone());
+    }
+
+    let module = Module::from_binary(&WASM_ENGINE, binary)?;
+    let mut cache = WASM_MODULE_CACHE.write();
+    if cache.len() >= MAX_CACHED_MODULES {
+        cache.clear();
+    }
+    cache.insert(code_hash, module.clone());
+    Ok(module)
+}
+
+fn is_out_of_fuel(e: &anyhow::Error) -> bool {
+    matches!(e.downcast_ref::<Trap>(), Some(Trap::OutOfFuel))
+}
+
 // execute contract
 pub fn execute(func_name: &str, context: Context, binary: &[u8], amount: u64) -> Result<WasmResult> {
 
-    let engine = Engine::new(Config::new().epoch_interruption(true))?;
-    let module = Module::from_binary(&engine, binary)?;
-    let linker = host_func_init(&engine)?;
-
-    let mut store = Store::new(&engine, context );
-    store.set_epoch_deadline(1);
-    let instance = linker.instantiate(&mut store, &module)?;
+    let module = load_module(binary)?;
 
-    let engine_clone = engine.clone();
-    std::thread::spawn(move || {
-        std::thread::sleep(std::time::Duration::from_secs(1));
-        engine_clone.increment_epoch();
-    });
+    let fuel = if context.gas { context.gas_limit } else { UNMETERED_FUEL };
+    let mut store = Store::new(&WASM_ENGINE, context );
+    store.add_fuel(fuel)?;
+    let instance = match WASM_LINKER.instantiate(&mut store, &module) {
+        Ok(instance) => instance,
+        Err(e) if is_out_of_fuel(&e) => return Ok(WasmResult::OutOfGas),
+        Err(e) => return Err(e),
+    };
 
     let run = match instance.get_func(&mut store, func_name){
         Some(r) => r,
         None => return Err(anyhow!("can't find {} func from contract!",func_name))
     };
     let mut r = [wasmtime::Val::null()];
-    let ret = match run.call(&mut store, &[I64(amount as i64)], &mut r){
-        Ok(_) => (),
-        Err(trap) => {
-            println!("call {} error: {:?}", func_name, trap);
-            return Err(trap)
-        },
-    };
-    let mut used_gas = store.data().gas_limit-store.data().gas_counter;
+    let ret = run.call(&mut store, &[I64(amount as i64)], &mut r);
+    let used_gas = store.fuel_consumed().unwrap_or_default();
+    if store.data().gas_outof {
+        return Ok(WasmResult::OutOfGas)
+    }
+    if let Err(trap) = ret {
+        if is_out_of_fuel(&trap) {
+            return Ok(WasmResult::OutOfGas)
+        }
+        error!("call {} error: {:?}", func_name, trap);
+        return Ok(WasmResult::Reject {
+            code: WasmError::ExecuteFail,
+            reason: format!("{}", trap),
+            used_gas,
+        })
+    }
     let ret = Some(r[0].clone());
 
     if let Some(Val::I32(n)) = ret {
-        if store.data().gas_outof{
-            return Ok(WasmResult::OutOfGas)
-        };
-        
         if n == 1 {
             Ok(WasmResult::Success {
                 event: store.data().event.clone(),
@@ -64,3 +108,85 @@ pub fn execute(func_name: &str, context: Context, binary: &[u8], amount: u64) ->
     }
 }
 
+// check the binary is a valid wasm module before it is deployed
+pub fn validate(binary: &[u8]) -> Result<()> {
+    Module::validate(&WASM_ENGINE, binary)
+}
+
+// whether the contract exports a func with the name
+pub fn has_func(binary: &[u8], func_name: &str) -> Result<bool> {
+    let module = load_module(binary)?;
+    let exported = module.exports().any(|e| e.name() == func_name && e.ty().func().is_some());
+    Ok(exported)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTRACT: &str = r#"
+    (module
+      (memory (export "memory") 1)
+      (func (export "spin") (param i64) (result i32)
+        (loop $forever (br $forever))
+        (i32.const 1))
+      (func (export "sum") (param $n i64) (result i32)
+        (local $acc i64)
+        (block $done
+          (loop $next
+            (br_if $done (i64.eqz (local.get $n)))
+            (local.set $acc (i64.add (local.get $acc) (local.get $n)))
+            (local.set $n (i64.sub (local.get $n) (i64.const 1)))
+            (br $next)))
+        (i32.const 1))
+      (func (export "nan") (param i64) (result i32)
+        (i32.eq
+          (i32.reinterpret_f32 (f32.div (f32.const 0) (f32.const 0)))
+          (i32.const 0x7fc00000))))
+    "#;
+
+    fn context(gas_limit: u64) -> Context {
+        let mut context = Context::new();
+        context.gas = true;
+        context.gas_limit = gas_limit;
+        context
+    }
+
+    #[test]
+    fn out_of_gas_test() {
+        let code = wat::parse_str(CONTRACT).unwrap();
+        let result = execute("spin", context(100_000), &code, 0).unwrap();
+        assert!(matches!(result, WasmResult::OutOfGas));
+
+        // the same code completes with enough gas
+        let result = execute("sum", context(100_000), &code, 100).unwrap();
+        assert!(matches!(result, WasmResult::Success { .. }));
+        let result = execute("sum", context(10), &code, 100).unwrap();
+        assert!(matches!(result, WasmResult::OutOfGas));
+    }
+
+    fn sum_gas(code: &[u8], n: u64) -> u64 {
+        match execute("sum", context(100_000), code, n).unwrap() {
+            WasmResult::Success { used_gas, .. } => used_gas,
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+
+    #[test]
+    fn deterministic_gas_test() {
+        let code = wat::parse_str(CONTRACT).unwrap();
+        let used: Vec<u64> = (0..3).map(|_| sum_gas(&code, 100)).collect();
+        assert!(used[0] > 0);
+        assert!(used.iter().all(|gas| *gas == used[0]));
+
+        // more work costs more gas
+        assert!(sum_gas(&code, 200) > used[0]);
+    }
+
+    #[test]
+    fn canonical_nan_test() {
+        let code = wat::parse_str(CONTRACT).unwrap();
+        let result = execute("nan", context(100_000), &code, 0).unwrap();
+        assert!(matches!(result, WasmResult::Success { .. }));
+    }
+}
@@ -1,5 +1,5 @@
 
-use super::types::{GAS_ENV_FUNC_BASE, Context};
+use super::types::{AccountAddress, Context, WasmResult, ADDRESS_SIZE, GAS_ENV_FUNC_BASE, MAX_STORAGE_VALUE_SIZE};
 
 use anyhow::{ bail, Result};
 use wasmtime::{Engine, Linker, Caller, Extern};
@@ -161,30 +161,125 @@ pub fn host_func_init(engine: &Engine) -> Result<Linker<Context>>
         len as i32
     })?;
 
-    linker.func_wrap("gas", "gas", |mut caller: Caller<'_, Context>, amount: u32| {
+    linker.func_wrap("wasm", "set_storage", |mut caller: Caller<'_, Context>, key_ptr: i32, key_len: i32, value_ptr: i32, value_len: i32| -> i32 {
+        charge_gas(&mut caller, GAS_ENV_FUNC_BASE);
+        if value_len as u32 as usize > MAX_STORAGE_VALUE_SIZE {
+            return -102i32
+        }
+        let key = match read_memory(&mut caller, key_ptr, key_len) {
+            Some(k) => k,
+            None => return -101i32,
+        };
+        let value = match read_memory(&mut caller, value_ptr, value_len) {
+            Some(v) => v,
+            None => return -101i32,
+        };
+        match caller.data().metadata.set(&key, &value) {
+            Ok(_) => 0i32,
+            Err(e) => {
+                error!("set_storage error {:?}", e);
+                -107i32
+            }
+        }
+    })?;
+
+    linker.func_wrap("wasm", "get_storage", |mut caller: Caller<'_, Context>, key_ptr: i32, key_len: i32, value_ptr: i32| -> i32 {
+        charge_gas(&mut caller, GAS_ENV_FUNC_BASE);
+        let key = match read_memory(&mut caller, key_ptr, key_len) {
+            Some(k) => k,
+            None => return -101i32,
+        };
+        let value = match caller.data().metadata.get(&key) {
+            Ok(Some(v)) => v,
+            Ok(None) => return -108i32,
+            Err(e) => {
+                error!("get_storage error {:?}", e);
+                return -107i32
+            }
+        };
+        if value.len() > MAX_STORAGE_VALUE_SIZE {
+            return -102i32
+        }
+
+        let mem = match caller.get_export("memory") {
+            Some(Extern::Memory(mem)) => mem,
+            _ => return -100i32,
+        };
+        match mem.write(caller, value_ptr as usize, &value){
+            Ok(_) => (),
+            Err(_) => return -103i32,
+        };
+        value.len() as i32
+    })?;
 
-        if caller.data_mut().gas_outof{
-            return ()
+    linker.func_wrap("wasm", "transfer", |mut caller: Caller<'_, Context>, addr_ptr: i32, amount: i64| -> i32 {
+        charge_gas(&mut caller, GAS_ENV_FUNC_BASE);
+        let address = match read_address(&mut caller, addr_ptr) {
+            Some(a) => a,
+            None => return -101i32,
+        };
+        if amount < 0 || amount as u64 > caller.data().self_balance {
+            return -109i32
         }
-        let prev = caller.data_mut().gas_counter;
-
-        let ret = match prev.checked_add(amount as u64) {
-            None => false,
-            Some(val) if val > caller.data_mut().gas_limit => false,
-            Some(_) => {
-                caller.data_mut().gas_counter = prev + amount as u64;
-                true
+        match caller.data().metadata.transfer(address, amount as u64) {
+            Ok(_) => {
+                caller.data_mut().self_balance -= amount as u64;
+                0i32
+            }
+            Err(e) => {
+                error!("transfer error {:?}", e);
+                -107i32
             }
+        }
+    })?;
+
+    linker.func_wrap("wasm", "call_contract", |mut caller: Caller<'_, Context>, addr_ptr: i32, amount: i64, func_ptr: i32, func_len: i32, arg_ptr: i32, arg_len: i32| -> i32 {
+        charge_gas(&mut caller, GAS_ENV_FUNC_BASE);
+        let address = match read_address(&mut caller, addr_ptr) {
+            Some(a) => a,
+            None => return -101i32,
         };
+        let func = match read_memory(&mut caller, func_ptr, func_len).and_then(|f| String::from_utf8(f).ok()) {
+            Some(f) => f,
+            None => return -102i32,
+        };
+        let arg = match read_memory(&mut caller, arg_ptr, arg_len).and_then(|a| String::from_utf8(a).ok()) {
+            Some(a) => a,
+            None => return -102i32,
+        };
+        if amount < 0 || amount as u64 > caller.data().self_balance {
+            return -109i32
+        }
 
-        return if ret {
-            ()
-        } else {
-            caller.data_mut().gas_outof = true;
-            ()
+        let gas_left = gas_left(&caller);
+        let result = caller.data().metadata.call(address, amount as u64, func, arg, gas_left);
+        match result {
+            Ok(WasmResult::Success { used_gas, event, .. }) => {
+                // a failed call rolls its transfer back
+                caller.data_mut().self_balance -= amount as u64;
+                charge_gas(&mut caller, used_gas);
+                caller.data_mut().event.extend(event);
+                1i32
+            }
+            Ok(WasmResult::Reject { used_gas, .. }) => {
+                charge_gas(&mut caller, used_gas);
+                0i32
+            }
+            Ok(WasmResult::OutOfGas) => {
+                charge_gas(&mut caller, gas_left.saturating_add(1));
+                0i32
+            }
+            Err(e) => {
+                error!("call_contract error {:?}", e);
+                -107i32
+            }
         }
     })?;
 
+    // contracts instrumented with explicit gas calls still link, instructions are
+    // metered by fuel so the call itself charges nothing
+    linker.func_wrap("gas", "gas", |_caller: Caller<'_, Context>, _amount: u32| {})?;
+
     linker.func_wrap("wasm", "Debug", |mut caller: Caller<'_, Context>, ptr: i32, len: i32| {
         let mem = match caller.get_export("memory"){
             Some(Extern::Memory(mem)) => mem,
@@ -210,28 +305,39 @@ pub fn host_func_init(engine: &Engine) -> Result<Linker<Context>>
     Ok(linker)
 }
 
+fn read_memory(caller: &mut Caller<'_, Context>, ptr: i32, len: i32) -> Option<Vec<u8>> {
+    let mem = match caller.get_export("memory") {
+        Some(Extern::Memory(mem)) => mem,
+        _ => return None,
+    };
+    mem.data(&caller)
+        .get(ptr as u32 as usize..)
+        .and_then(|arr| arr.get(..len as u32 as usize))
+        .map(|data| data.to_vec())
+}
+
+fn read_address(caller: &mut Caller<'_, Context>, ptr: i32) -> Option<AccountAddress> {
+    let data = read_memory(caller, ptr, ADDRESS_SIZE as i32)?;
+    let mut address = AccountAddress::default();
+    address.0.copy_from_slice(&data);
+    Some(address)
+}
+
+fn gas_left(caller: &Caller<'_, Context>) -> u64 {
+    caller.data().gas_limit.saturating_sub(caller.fuel_consumed().unwrap_or_default())
+}
+
 fn charge_gas(caller: &mut Caller<'_, Context>, amount: u64){
     if !caller.data().gas{
         return ()
     }
-    if caller.data_mut().gas_outof{
+    if caller.data().gas_outof{
         return ()
     }
-    let prev = caller.data_mut().gas_counter;
-    let ret = match prev.checked_add(amount) {
-        // gas charge overflow protection
-        None => false,
-        Some(val) if val > caller.data_mut().gas_limit => false,
-        Some(_) => {
-            caller.data_mut().gas_counter = prev + amount;
-            true
-        }
-    };
-
-    return if ret {
-        ()
-    } else {
+    if caller.consume_fuel(amount).is_err() {
+        // burn the rest of fuel, the contract traps on its next instruction
+        let left = gas_left(caller);
+        let _ = caller.consume_fuel(left);
         caller.data_mut().gas_outof = true;
-        ()
     }
-}
\ No newline at end of file
+}
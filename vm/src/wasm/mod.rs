@@ -1,4 +1,6 @@
-
 mod gwasm;
 mod host_func;
-mod types;
+pub(crate) mod types;
+mod wasmvm;
+
+pub use self::wasmvm::{call, execute};
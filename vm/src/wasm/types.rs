@@ -1,39 +1,140 @@
+use anyhow::{anyhow, bail, Result};
+use protos::ledger::Contract_ContractType;
+use revm::primitives::B160;
+use state::{AccountFrame, CacheState};
 
-use anyhow::{ bail, Result};
+use super::gwasm;
+use crate::utils::AddressConverter;
 
 pub const ADDRESS_SIZE: usize = 20;
 pub const GAS_ENV_FUNC_BASE: u64 = 1;
+pub const MAX_STORAGE_VALUE_SIZE: usize = 4096;
 
 
 #[derive(Default, Clone, Copy)]
 pub struct AccountAddress(pub [u8; ADDRESS_SIZE]);
 
+impl AccountAddress {
+    pub fn from_geno_address(address: &str) -> Result<Self> {
+        match AddressConverter::to_evm_address(address) {
+            Ok(addr) => Ok(AccountAddress(addr.0)),
+            Err(e) => bail!("address convert error {}", e),
+        }
+    }
+
+    pub fn to_geno_address(&self) -> String {
+        AddressConverter::from_evm_address(B160(self.0))
+    }
+}
+
 
 #[derive(Default, Clone)]
 pub struct Metadata {
     pub block_time: u64,
-    pub block_height: u64,  
-    pub tx_hash: String,  
+    pub block_height: u64,
+    pub tx_hash: String,
+    pub(crate) contract_address: String,
+    pub(crate) state: CacheState,
 }
 
 impl Metadata{
     pub fn transfer(&self, address:AccountAddress, amount:u64)->Result<()>{
-        bail!("transfer fail!");
+        transfer(&self.state, &self.contract_address, &address.to_geno_address(), amount as u128)
+    }
+
+    pub fn call(&self, address:AccountAddress, amount:u64, func:String, arg:String, gas_limit:u64)->Result<WasmResult>{
+        let contract_address = address.to_geno_address();
+        let account = load_wasm_contract(&self.state, &contract_address)?;
+
+        // the changes of a failed call are rolled back, the caller goes on
+        let snapshot = self.state.get_buff().read().clone();
+        let result = self.call_contract(address, account, amount, func, arg, gas_limit);
+        if !matches!(result, Ok(WasmResult::Success { .. })) {
+            *self.state.get_buff().write() = snapshot;
+        }
+        result
     }
 
-    pub fn call(&self, address:AccountAddress, amount:u64, func:String, arg:String)->Result<()>{
-        bail!("invoker fail!");
+    fn call_contract(&self, address:AccountAddress, account:AccountFrame, amount:u64, func:String, arg:String, gas_limit:u64)->Result<WasmResult>{
+        let contract_address = address.to_geno_address();
+        self.transfer(address, amount)?;
+
+        let mut metadata = self.clone();
+        metadata.contract_address = contract_address;
+        let context = Context::init(
+            func.clone(),
+            String::new(),
+            arg,
+            AccountAddress::from_geno_address(&self.contract_address)?,
+            AccountAddress::from_geno_address(account.contract().get_creator())?,
+            address,
+            balance_of(&self.state, &metadata.contract_address)?,
+            metadata,
+            true,
+            gas_limit,
+        );
+        gwasm::execute(&func, context, account.contract().get_code(), amount)
     }
 
     pub fn set(&self, key:&[u8], value:&[u8]) -> Result<()>{
-        bail!("set fail!");
+        let mut account = load_account(&self.state, &self.contract_address)?;
+        if value.is_empty() {
+            account.delete_contract_metadata(key);
+        } else if !account.upsert_contract_metadata(key, value) {
+            bail!("set fail!");
+        }
+        self.state.upsert(&self.contract_address, account);
+        Ok(())
     }
 
-    pub fn get(&self, key:&[u8]) -> Result<Vec<u8>>{
-        bail!("get fail!");
+    pub fn get(&self, key:&[u8]) -> Result<Option<Vec<u8>>>{
+        let mut account = load_account(&self.state, &self.contract_address)?;
+        account.get_contract_metadata(key)
     }
 }
 
+pub(crate) fn load_account(state: &CacheState, address: &String) -> Result<AccountFrame> {
+    match state.get(address)? {
+        Some(account) => Ok(account),
+        None => Err(anyhow!("can not find account {}", address)),
+    }
+}
+
+pub(crate) fn load_wasm_contract(state: &CacheState, address: &String) -> Result<AccountFrame> {
+    let account = load_account(state, address)?;
+    if !account.has_contract() || account.contract().get_kind() != Contract_ContractType::WASM {
+        bail!("account {} is not a wasm contract", address);
+    }
+    Ok(account)
+}
+
+pub(crate) fn balance_of(state: &CacheState, address: &String) -> Result<u64> {
+    let balance = load_account(state, address)?.balance();
+    u64::try_from(balance).map_err(|_| anyhow!("balance {} of {} overflow", balance, address))
+}
+
+pub(crate) fn transfer(state: &CacheState, from: &String, to: &String, amount: u128) -> Result<()> {
+    if amount == 0 || from == to {
+        return Ok(());
+    }
+
+    let mut from_account = load_account(state, from)?;
+    if from_account.sub_balance(amount).is_none() {
+        bail!("account {} balance is not enough", from);
+    }
+    state.upsert(from, from_account);
+
+    let mut to_account = match state.get(to)? {
+        Some(account) => account,
+        None => AccountFrame::new(to.clone(), 0),
+    };
+    if to_account.add_balance(amount).is_none() {
+        bail!("account {} balance overflow", to);
+    }
+    state.upsert(to, to_account);
+    Ok(())
+}
+
 #[derive(Default)]
 pub struct Context {
     pub(crate) func_name: String,
@@ -47,10 +148,9 @@ pub struct Context {
     pub(crate) output_data: String,
     pub(crate) metadata: Metadata,
     pub(crate) gas: bool,
-    pub(crate) gas_counter: u64,
     pub(crate) gas_limit: u64,
     pub(crate) gas_outof: bool,
-    
+
 }
 
 impl Context {
@@ -81,7 +181,6 @@ impl Context {
             output_data: String::new(),
             metadata,
             gas,
-            gas_counter: 0,
             gas_limit,
             gas_outof: false,
         }
@@ -117,4 +216,3 @@ pub enum WasmError {
     KeyNotFound,
     HostFuncErr,
 }
-
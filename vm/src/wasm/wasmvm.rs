@@ -0,0 +1,356 @@
+use super::gwasm;
+use super::types::{
+    balance_of, load_wasm_contract, transfer, AccountAddress, Context, Metadata, WasmResult,
+};
+use crate::fee::{charge_fee, check_balance, fee_beneficiary, BASE_TX_GAS};
+use crate::post_state::{PostState, Receipt};
+use crate::traits::BlockEnv;
+use crate::utils::AddressConverter;
+use anyhow::{anyhow, bail, Result};
+use protos::ledger::{Contract, Contract_ContractType};
+use revm::primitives::{create_address, B160};
+use serde_json::Value;
+use state::{AccountFrame, CacheState};
+use syscontract::contract_factory::SystemContractFactory;
+use types::{error::VmError, transaction::SignedTransaction};
+
+pub const WASM_INIT_FUNC: &str = "init";
+
+pub fn execute<E: BlockEnv>(
+    index: usize,
+    transaction: &SignedTransaction,
+    post_state: &mut PostState,
+    state: CacheState,
+    env: E,
+) -> std::result::Result<(), VmError> {
+    check_balance(transaction, &state)?;
+    nonce_increase(&transaction.sender().to_string(), &state)?;
+
+    let mut receipt = apply(transaction, &state, &env)?;
+    // failed transactions pay for the gas as well
+    charge_fee(transaction, receipt.gas_used, &fee_beneficiary(&env), &state)?;
+    receipt.index = index;
+    post_state.add_receipt(env.height(), receipt);
+
+    return Ok(());
+}
+
+// execute the transaction without increasing nonce, the state is expected to be thrown away
+pub fn call<E: BlockEnv>(
+    transaction: &SignedTransaction,
+    state: CacheState,
+    env: E,
+) -> std::result::Result<Receipt, VmError> {
+    apply(transaction, &state, &env)
+}
+
+fn apply<E: BlockEnv>(
+    transaction: &SignedTransaction,
+    state: &CacheState,
+    env: &E,
+) -> std::result::Result<Receipt, VmError> {
+    let contract_address = if transaction.to().is_empty() {
+        let sender = AddressConverter::to_evm_address(transaction.sender())?;
+        create_address(sender, transaction.nonce())
+    } else {
+        AddressConverter::to_evm_address(transaction.to())?
+    };
+
+    // all changes of the transaction stay in the stack state until it succeeds
+    let tx_state = state.new_stack_state(false);
+    let result = if transaction.to().is_empty() {
+        deploy(transaction, contract_address, &tx_state, env)
+    } else {
+        invoke(transaction, contract_address, &tx_state, env)
+    };
+
+    let result = match result {
+        Ok(result) => result,
+        Err(e) => {
+            return Ok(Receipt {
+                success: false,
+                gas_used: BASE_TX_GAS.min(transaction.gas_limit()),
+                description: Some(e.to_string()),
+                ..Default::default()
+            });
+        }
+    };
+
+    if let WasmResult::Success { .. } = result {
+        tx_state.commit();
+    }
+
+    Ok(Receipt::from_wasm_result(
+        contract_address,
+        transaction.to().is_empty(),
+        transaction.gas_limit(),
+        result,
+    ))
+}
+
+fn deploy<E: BlockEnv>(
+    transaction: &SignedTransaction,
+    contract_address: B160,
+    state: &CacheState,
+    env: &E,
+) -> Result<WasmResult> {
+    let code = transaction.code();
+    gwasm::validate(code)?;
+
+    let address = AddressConverter::from_evm_address(contract_address);
+    if state.get(&address)?.is_some() {
+        bail!("contract account {} already exists", address);
+    }
+
+    let mut contract = Contract::default();
+    contract.set_code(code.to_vec());
+    contract.set_creator(transaction.sender().to_string());
+    contract.set_kind(Contract_ContractType::WASM);
+    let mut account = AccountFrame::new(address.clone(), 0);
+    account.set_contract(&contract);
+    state.upsert(&address, account);
+
+    if !gwasm::has_func(code, WASM_INIT_FUNC)? {
+        transfer(state, &transaction.sender().to_string(), &address, transaction.value())?;
+        return Ok(WasmResult::Success {
+            used_gas: 0,
+            event: Vec::new(),
+            data: String::new(),
+        });
+    }
+    run(
+        transaction,
+        WASM_INIT_FUNC.to_string(),
+        String::new(),
+        address,
+        state,
+        env,
+    )
+}
+
+fn invoke<E: BlockEnv>(
+    transaction: &SignedTransaction,
+    contract_address: B160,
+    state: &CacheState,
+    env: &E,
+) -> Result<WasmResult> {
+    let (method, params) = SystemContractFactory::parse_params(transaction.payload())?;
+    let param = match params {
+        Value::Null => String::new(),
+        Value::String(value) => value,
+        value => value.to_string(),
+    };
+
+    run(
+        transaction,
+        method,
+        param,
+        AddressConverter::from_evm_address(contract_address),
+        state,
+        env,
+    )
+}
+
+fn run<E: BlockEnv>(
+    transaction: &SignedTransaction,
+    func_name: String,
+    param: String,
+    address: String,
+    state: &CacheState,
+    env: &E,
+) -> Result<WasmResult> {
+    let account = load_wasm_contract(state, &address)?;
+    let amount = u64::try_from(transaction.value())
+        .map_err(|_| anyhow!("value {} overflow", transaction.value()))?;
+    transfer(state, &transaction.sender().to_string(), &address, amount as u128)?;
+
+    let metadata = Metadata {
+        block_time: env.timestamp() as u64,
+        block_height: env.height(),
+        tx_hash: transaction.hash_hex(),
+        contract_address: address.clone(),
+        state: state.clone(),
+    };
+    let context = Context::init(
+        func_name.clone(),
+        String::new(),
+        param,
+        AccountAddress::from_geno_address(transaction.sender())?,
+        AccountAddress::from_geno_address(account.contract().get_creator())?,
+        AccountAddress::from_geno_address(&address)?,
+        balance_of(state, &address)?,
+        metadata,
+        true,
+        transaction.gas_limit(),
+    );
+    gwasm::execute(&func_name, context, account.contract().get_code(), amount)
+}
+
+fn nonce_increase(source: &String, state: &CacheState) -> std::result::Result<(), VmError> {
+    let account = match state.get(source) {
+        Ok(result) => result,
+        Err(e) => {
+            return Err(VmError::StateError {
+                error: format!("{:?}", e),
+            })
+        }
+    };
+    match account {
+        Some(mut account) => {
+            account.nonce_increase();
+            state.upsert(source, account);
+        }
+        None => {
+            return Err(VmError::StateError {
+                error: format!("can not find account {:?}", source),
+            })
+        }
+    };
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protos::ledger::{LedgerHeader, Transaction, TransactionSign, TransactionType};
+    use state::empty_trie_root;
+
+    const SENDER: &str = "did:gdt:0xf6b02a2d47b84e845b7e3623355f041bcb36daf1";
+    const PROPOSER: &str = "did:gdt:0x160b54be617f4bff07bd6c994fc6dd17a69d5e4e";
+
+    const CONTRACT: &str = r#"
+    (module
+      (import "wasm" "get_parameter" (func $get_parameter (param i32) (result i32)))
+      (import "wasm" "set_storage" (func $set_storage (param i32 i32 i32 i32) (result i32)))
+      (import "wasm" "get_storage" (func $get_storage (param i32 i32 i32) (result i32)))
+      (import "wasm" "set_return_data" (func $set_return_data (param i32 i32) (result i32)))
+      (import "wasm" "get_contract_address" (func $get_contract_address (param i32)))
+      (import "wasm" "get_contract_balance" (func $get_contract_balance (result i64)))
+      (import "wasm" "call_contract" (func $call_contract (param i32 i64 i32 i32 i32 i32) (result i32)))
+      (memory (export "memory") 1)
+      (data (i32.const 0) "k")
+      (data (i32.const 16) "fail")
+      (data (i32.const 32) "init")
+      (func (export "init") (param i64) (result i32)
+        (drop (call $set_storage (i32.const 0) (i32.const 1) (i32.const 32) (i32.const 4)))
+        (i32.const 1))
+      (func (export "put") (param i64) (result i32)
+        (local $len i32)
+        (local.set $len (call $get_parameter (i32.const 1024)))
+        (if (i32.ne (call $set_storage (i32.const 0) (i32.const 1) (i32.const 1024) (local.get $len)) (i32.const 0))
+          (then (return (i32.const 0))))
+        (local.set $len (call $get_storage (i32.const 0) (i32.const 1) (i32.const 2048)))
+        (drop (call $set_return_data (i32.const 2048) (local.get $len)))
+        (i32.const 1))
+      (func (export "fail") (param i64) (result i32)
+        (i32.const 0))
+      (func (export "forward") (param i64) (result i32)
+        (local $balance i64)
+        (local.set $balance (call $get_contract_balance))
+        (call $get_contract_address (i32.const 64))
+        (if (i32.ne (call $call_contract (i32.const 64) (i64.const 5) (i32.const 16) (i32.const 4) (i32.const 0) (i32.const 0)) (i32.const 0))
+          (then (return (i32.const 0))))
+        (i64.eq (call $get_contract_balance) (local.get $balance))))
+    "#;
+
+    fn transaction(nonce: u64, to: &str, value: u128, payload: Vec<u8>) -> SignedTransaction {
+        let mut tx = Transaction::default();
+        tx.set_tx_type(TransactionType::WASM);
+        tx.set_source(SENDER.to_string());
+        tx.set_nonce(nonce);
+        tx.set_to(to.to_string());
+        tx.set_value(value.to_string());
+        tx.set_payload(payload);
+        tx.set_gas_limit(10_000_000);
+        tx.set_gas_price("1".to_string());
+        tx.set_chain_id("2024".to_string());
+        let mut tx_sign = TransactionSign::default();
+        tx_sign.set_transaction(tx);
+        SignedTransaction::try_from(tx_sign).unwrap()
+    }
+
+    fn invoke_payload(method: &str, params: &str) -> Vec<u8> {
+        serde_json::json!({ "method": method, "params": params })
+            .to_string()
+            .into_bytes()
+    }
+
+    fn funded_state() -> CacheState {
+        configure::init_test_config();
+        let state = CacheState::new(empty_trie_root());
+        let sender = SENDER.to_string();
+        state.upsert(&sender, AccountFrame::new(sender.clone(), 100_000_000));
+        state
+    }
+
+    fn contract_storage(state: &CacheState, address: &String, key: &[u8]) -> Option<Vec<u8>> {
+        let mut account = state.get(address).unwrap().unwrap();
+        account.get_contract_metadata(key).unwrap()
+    }
+
+    #[test]
+    fn deploy_call_storage_test() {
+        let state = funded_state();
+        let mut env = LedgerHeader::default();
+        env.set_height(2);
+
+        let code = wat::parse_str(CONTRACT).unwrap();
+        let receipt = call(&transaction(0, "", 0, code), state.clone(), env.clone()).unwrap();
+        assert!(receipt.success, "{:?}", receipt.description);
+        let address = AddressConverter::from_evm_address(receipt.contract_address.unwrap());
+        assert_eq!(
+            contract_storage(&state, &address, b"k"),
+            Some(b"init".to_vec())
+        );
+
+        let put = transaction(1, &address, 0, invoke_payload("put", "hello"));
+        let receipt = call(&put, state.clone(), env.clone()).unwrap();
+        assert!(receipt.success, "{:?}", receipt.description);
+        assert_eq!(receipt.output.unwrap().as_ref(), b"hello");
+        assert_eq!(
+            contract_storage(&state, &address, b"k"),
+            Some(b"hello".to_vec())
+        );
+
+        // a rejected call leaves the storage as it was
+        let fail = transaction(2, &address, 0, invoke_payload("fail", ""));
+        let receipt = call(&fail, state.clone(), env.clone()).unwrap();
+        assert!(!receipt.success);
+        assert_eq!(
+            contract_storage(&state, &address, b"k"),
+            Some(b"hello".to_vec())
+        );
+    }
+
+    #[test]
+    fn failed_nested_call_keeps_balance_test() {
+        let state = funded_state();
+        let env = LedgerHeader::default();
+
+        let code = wat::parse_str(CONTRACT).unwrap();
+        let receipt = call(&transaction(0, "", 0, code), state.clone(), env.clone()).unwrap();
+        let address = AddressConverter::from_evm_address(receipt.contract_address.unwrap());
+
+        let forward = transaction(1, &address, 10, invoke_payload("forward", ""));
+        let receipt = call(&forward, state.clone(), env).unwrap();
+        assert!(receipt.success, "{:?}", receipt.description);
+        assert_eq!(state.get(&address).unwrap().unwrap().balance(), 10);
+    }
+
+    #[test]
+    fn failed_deploy_pays_fee_test() {
+        let state = funded_state();
+        let mut env = LedgerHeader::default();
+        env.set_proposer(PROPOSER.to_string());
+        let mut post_state = PostState::new();
+
+        let deploy = transaction(0, "", 0, b"not a wasm module".to_vec());
+        execute(0, &deploy, &mut post_state, state.clone(), env).unwrap();
+
+        let sender = state.get(&SENDER.to_string()).unwrap().unwrap();
+        assert_eq!(sender.nonce(), 1);
+        assert_eq!(sender.balance(), 100_000_000 - BASE_TX_GAS as u128);
+        let proposer = state.get(&PROPOSER.to_string()).unwrap().unwrap();
+        assert_eq!(proposer.balance(), BASE_TX_GAS as u128);
+    }
+}